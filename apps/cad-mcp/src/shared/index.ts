@@ -14,8 +14,12 @@ export {
   safeValidateMessage,
   TransformSchema,
   StyleSchema,
+  GradientSchema,
+  HatchStyleSchema,
   MetadataSchema,
   BoundsSchema,
+  DimensionLayoutSchema,
+  HatchLinesSchema,
   ComputedSchema,
   EntitySchema,
   SceneTreeNodeSchema,
@@ -37,6 +41,10 @@ export type {
   Style,
   Metadata,
   Bounds,
+  DimensionLayout,
+  Gradient,
+  HatchStyle,
+  HatchLines,
   Computed,
   Entity,
   SceneTreeNode,
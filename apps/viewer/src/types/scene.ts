@@ -4,6 +4,8 @@ export interface Transform {
   rotate: number
   scale: [number, number]
   pivot?: [number, number]
+  /** X-axis skew angle in radians (SVG skewX), applied between rotate and scale */
+  skew?: number
 }
 
 export interface StrokeStyle {
@@ -14,8 +16,45 @@ export interface StrokeStyle {
   join?: string
 }
 
+export interface GradientStop {
+  /** Position (0.0-1.0) */
+  offset: number
+  color: [number, number, number, number]
+}
+
+/** Gradient fill in entity local coordinates */
+export type Gradient =
+  | {
+      type: 'Linear'
+      start: [number, number]
+      end: [number, number]
+      stops: GradientStop[]
+    }
+  | {
+      type: 'Radial'
+      center: [number, number]
+      radius: number
+      /** Focal point of the 0% color (defaults to center) */
+      focal?: [number, number]
+      stops: GradientStop[]
+    }
+
+export interface HatchStyle {
+  pattern: 'Ansi31' | 'Crosshatch' | 'Brick' | 'Dots'
+  /** Pattern rotation in radians (counterclockwise positive) */
+  angle: number
+  spacing: number
+  scale: number
+  /** Pattern line width (dot diameter for Dots) */
+  line_width: number
+}
+
 export interface FillStyle {
+  /** Solid color, or the hatch line color when hatch is set */
   color: [number, number, number, number]
+  hatch?: HatchStyle
+  /** Replaces color when set (drawn under the hatch) */
+  gradient?: Gradient
 }
 
 export interface Style {
@@ -78,6 +117,90 @@ export interface BezierGeometry {
   }
 }
 
+export interface EllipseGeometry {
+  Ellipse: {
+    center: [number, number]
+    rx: number
+    ry: number
+    /** Major axis rotation in radians (counterclockwise positive) */
+    rotation?: number
+  }
+}
+
+export interface EllipticalArcGeometry {
+  EllipticalArc: {
+    center: [number, number]
+    rx: number
+    ry: number
+    /** Major axis rotation in radians (counterclockwise positive) */
+    rotation?: number
+    /** Start parameter angle in radians (0 = major axis direction) */
+    start_angle: number
+    /** End parameter angle in radians (counterclockwise positive) */
+    end_angle: number
+  }
+}
+
+export type PathSegment =
+  | { Line: { to: [number, number] } }
+  | { Quadratic: { control: [number, number]; to: [number, number] } }
+  | {
+      Cubic: {
+        control1: [number, number]
+        control2: [number, number]
+        to: [number, number]
+      }
+    }
+  | {
+      /** sweep_angle in radians (positive = counterclockwise) */
+      Arc: { center: [number, number]; radius: number; start_angle: number; sweep_angle: number }
+    }
+  | {
+      EllipticalArc: {
+        center: [number, number]
+        rx: number
+        ry: number
+        rotation?: number
+        start_angle: number
+        sweep_angle: number
+      }
+    }
+
+export interface Subpath {
+  start: [number, number]
+  segments: PathSegment[]
+  closed?: boolean
+}
+
+export interface PathGeometry {
+  Path: {
+    subpaths: Subpath[]
+    fill_rule?: 'NonZero' | 'EvenOdd'
+  }
+}
+
+export interface DimensionGeometry {
+  Dimension: {
+    /** Measured references (Linear/Aligned/Radius/Diameter/Angular), resolved by WASM */
+    kind: Record<string, unknown>
+    text_size: number
+    precision: number
+  }
+}
+
+export interface TextGeometry {
+  Text: {
+    /** Anchor point (see anchor/align) */
+    position: [number, number]
+    /** Lines separated by '\n' */
+    content: string
+    font_family: string
+    font_size: number
+    anchor?: 'Start' | 'Middle' | 'End'
+    align?: 'Baseline' | 'Top' | 'Middle' | 'Bottom'
+  }
+}
+
 export interface GroupGeometry {
   Group: {
     children: string[]
@@ -91,10 +214,27 @@ export type Geometry =
   | PolygonGeometry
   | ArcGeometry
   | BezierGeometry
+  | EllipseGeometry
+  | EllipticalArcGeometry
+  | PathGeometry
+  | DimensionGeometry
+  | TextGeometry
   | GroupGeometry
   | 'Empty' // Groups may have "Empty" geometry string
 
-export type EntityType = 'Circle' | 'Rect' | 'Line' | 'Polygon' | 'Arc' | 'Bezier' | 'Group'
+export type EntityType =
+  | 'Circle'
+  | 'Rect'
+  | 'Line'
+  | 'Polygon'
+  | 'Arc'
+  | 'Bezier'
+  | 'Ellipse'
+  | 'EllipticalArc'
+  | 'Path'
+  | 'Dimension'
+  | 'Text'
+  | 'Group'
 
 /** Bounds (min/max) */
 export interface Bounds {
@@ -102,12 +242,38 @@ export interface Bounds {
   max: [number, number]
 }
 
+/** Dimension layout in world coordinates (calculated by WASM from referenced entities) */
+export interface DimensionLayout {
+  value: number
+  text: string
+  text_position: [number, number]
+  /** Text rotation in radians (counterclockwise positive) */
+  text_angle: number
+  text_size: number
+  /** Dimension/extension/leader line segments */
+  lines: [[number, number], [number, number]][]
+  /** Angular dimension arcs as polylines */
+  arcs?: [number, number][][]
+  /** Filled arrow triangles (first point is the tip) */
+  arrows: [[number, number], [number, number], [number, number]][]
+}
+
+/** Hatch pattern clipped to the filled region */
+export interface HatchLines {
+  lines: [[number, number], [number, number]][]
+  dots?: [number, number][]
+}
+
 /** Computed fields (calculated by WASM, read-only in Viewer) */
 export interface Computed {
   world_bounds?: Bounds
   local_bounds?: Bounds
   center?: [number, number]
   size?: [number, number]
+  /** Dimension entities only; omitted when a referenced entity no longer exists */
+  dimension?: DimensionLayout
+  /** Hatch fills only: clipped pattern lines/dots in entity local coordinates */
+  hatch?: HatchLines
 }
 
 export interface Entity {
@@ -5,7 +5,10 @@ import {
   Hexagon,
   CircleDot,
   Spline,
+  PenTool,
+  Ruler,
   Folder,
+  Type,
   type LucideIcon,
 } from 'lucide-react'
 import type { EntityType } from '@/types/scene'
@@ -17,6 +20,11 @@ export const ENTITY_ICONS: Record<EntityType, LucideIcon> = {
   Polygon: Hexagon,
   Arc: CircleDot,
   Bezier: Spline,
+  Ellipse: Circle,
+  EllipticalArc: CircleDot,
+  Path: PenTool,
+  Dimension: Ruler,
+  Text: Type,
   Group: Folder,
 }
 
@@ -28,5 +36,10 @@ export const ENTITY_ICON_COLORS: Record<EntityType, string> = {
   Polygon: '#16a34a',  // green
   Arc: '#16a34a',      // green
   Bezier: '#16a34a',   // green
+  Ellipse: '#16a34a',  // green
+  EllipticalArc: '#16a34a', // green
+  Path: '#16a34a',     // green
+  Dimension: '#0284c7', // blue
+  Text: '#16a34a',     // green
   Group: '#7c3aed',    // purple
 }
@@ -8,6 +8,13 @@ import type {
   PolygonGeometry,
   ArcGeometry,
   BezierGeometry,
+  EllipseGeometry,
+  EllipticalArcGeometry,
+  PathGeometry,
+  TextGeometry,
+  DimensionLayout,
+  FillStyle,
+  HatchLines,
 } from '@/types/scene'
 import { applyTransform } from './transform'
 
@@ -33,6 +40,18 @@ function isArc(geo: Entity['geometry']): geo is ArcGeometry {
 function isBezier(geo: Entity['geometry']): geo is BezierGeometry {
   return isObject(geo) && 'Bezier' in geo
 }
+function isEllipse(geo: Entity['geometry']): geo is EllipseGeometry {
+  return isObject(geo) && 'Ellipse' in geo
+}
+function isEllipticalArc(geo: Entity['geometry']): geo is EllipticalArcGeometry {
+  return isObject(geo) && 'EllipticalArc' in geo
+}
+function isPath(geo: Entity['geometry']): geo is PathGeometry {
+  return isObject(geo) && 'Path' in geo
+}
+function isText(geo: Entity['geometry']): geo is TextGeometry {
+  return isObject(geo) && 'Text' in geo
+}
 
 // Geometry rendering functions - return true if path was created
 function renderCircle(ctx: CanvasRenderingContext2D, geo: CircleGeometry): boolean {
@@ -103,6 +122,61 @@ function renderArc(ctx: CanvasRenderingContext2D, geo: ArcGeometry): boolean {
   return true
 }
 
+function renderEllipse(ctx: CanvasRenderingContext2D, geo: EllipseGeometry): boolean {
+  const { center, rx, ry, rotation = 0 } = geo.Ellipse
+  ctx.beginPath()
+  ctx.ellipse(center[0], center[1], rx, ry, rotation, 0, Math.PI * 2)
+  ctx.closePath()
+  return true
+}
+
+function renderEllipticalArc(ctx: CanvasRenderingContext2D, geo: EllipticalArcGeometry): boolean {
+  const { center, rx, ry, rotation = 0, start_angle, end_angle } = geo.EllipticalArc
+  ctx.beginPath()
+  ctx.ellipse(center[0], center[1], rx, ry, rotation, start_angle, end_angle)
+  return true
+}
+
+function renderPath(ctx: CanvasRenderingContext2D, geo: PathGeometry): boolean {
+  const { subpaths } = geo.Path
+  if (subpaths.length === 0) return false
+  ctx.beginPath()
+  for (const { start, segments, closed } of subpaths) {
+    ctx.moveTo(start[0], start[1])
+    for (const seg of segments) {
+      // 원호 시작점이 이전 끝점과 다르면 arc/ellipse가 직선으로 이어줌 (엔진과 동일)
+      if ('Line' in seg) {
+        ctx.lineTo(seg.Line.to[0], seg.Line.to[1])
+      } else if ('Quadratic' in seg) {
+        const { control, to } = seg.Quadratic
+        ctx.quadraticCurveTo(control[0], control[1], to[0], to[1])
+      } else if ('Cubic' in seg) {
+        const { control1, control2, to } = seg.Cubic
+        ctx.bezierCurveTo(control1[0], control1[1], control2[0], control2[1], to[0], to[1])
+      } else if ('Arc' in seg) {
+        const { center, radius, start_angle, sweep_angle } = seg.Arc
+        ctx.arc(center[0], center[1], radius, start_angle, start_angle + sweep_angle, sweep_angle < 0)
+      } else if ('EllipticalArc' in seg) {
+        const { center, rx, ry, rotation = 0, start_angle, sweep_angle } = seg.EllipticalArc
+        ctx.ellipse(
+          center[0],
+          center[1],
+          rx,
+          ry,
+          rotation,
+          start_angle,
+          start_angle + sweep_angle,
+          sweep_angle < 0
+        )
+      }
+    }
+    if (closed) {
+      ctx.closePath()
+    }
+  }
+  return true
+}
+
 function renderBezier(ctx: CanvasRenderingContext2D, geo: BezierGeometry): boolean {
   const { start, segments, closed } = geo.Bezier
   if (segments.length === 0) return false
@@ -132,11 +206,154 @@ function renderBezier(ctx: CanvasRenderingContext2D, geo: BezierGeometry): boole
   return true
 }
 
-// Style application
-function applyStyle(ctx: CanvasRenderingContext2D, style: Style, hasHoles = false) {
+// Dimension layout is in world coordinates: draw with the viewport transform only
+function renderDimension(
+  ctx: CanvasRenderingContext2D,
+  layout: DimensionLayout,
+  style: Style,
+  worldTransform: DOMMatrix
+) {
+  const [r, g, b, a] = style.stroke?.color ?? [0, 0, 0, 1]
+  const color = `rgba(${r * 255}, ${g * 255}, ${b * 255}, ${a})`
+  ctx.setTransform(worldTransform)
+  ctx.strokeStyle = color
+  ctx.fillStyle = color
+  ctx.lineWidth = style.stroke?.width ?? 1
+
+  ctx.beginPath()
+  for (const [from, to] of layout.lines) {
+    ctx.moveTo(from[0], from[1])
+    ctx.lineTo(to[0], to[1])
+  }
+  for (const arc of layout.arcs ?? []) {
+    if (arc.length < 2) continue
+    ctx.moveTo(arc[0][0], arc[0][1])
+    for (let i = 1; i < arc.length; i++) {
+      ctx.lineTo(arc[i][0], arc[i][1])
+    }
+  }
+  ctx.stroke()
+
+  ctx.beginPath()
+  for (const [tip, left, right] of layout.arrows) {
+    ctx.moveTo(tip[0], tip[1])
+    ctx.lineTo(left[0], left[1])
+    ctx.lineTo(right[0], right[1])
+    ctx.closePath()
+  }
+  ctx.fill()
+
+  // Y-up 좌표계에서 문자가 뒤집히지 않도록 로컬 Y를 다시 뒤집음
+  const [tx, ty] = layout.text_position
+  ctx.translate(tx, ty)
+  ctx.rotate(layout.text_angle)
+  ctx.scale(1, -1)
+  ctx.font = `${layout.text_size}px sans-serif`
+  ctx.textAlign = 'center'
+  ctx.textBaseline = 'middle'
+  ctx.fillText(layout.text, 0, 0)
+}
+
+// 엔진(geometry/text.rs)과 같은 줄 간격/폰트 메트릭 (font_size 배수)
+const TEXT_LINE_HEIGHT = 1.2
+
+function textMetrics(fontFamily: string): { ascent: number; descent: number } {
+  const family = fontFamily.toLowerCase()
+  const mono = ['mono', 'courier', 'consol', 'menlo'].some((key) => family.includes(key))
+  return mono ? { ascent: 0.629, descent: 0.157 } : { ascent: 0.718, descent: 0.207 }
+}
+
+// Text: position 기준으로 anchor/align에 맞춰 줄 단위로 그림
+function renderText(ctx: CanvasRenderingContext2D, geo: TextGeometry, style: Style) {
+  const { position, content, font_family, font_size, anchor, align } = geo.Text
+  const lines = content.split('\n')
+  const { ascent, descent } = textMetrics(font_family)
+  const lineHeight = TEXT_LINE_HEIGHT * font_size
+  const top = ascent * font_size
+  const bottom = -descent * font_size - (lines.length - 1) * lineHeight
+  // 첫 줄 baseline의 Y 오프셋 (Y-up)
+  const baseline =
+    align === 'Top' ? -top : align === 'Bottom' ? -bottom : align === 'Middle' ? -(top + bottom) / 2 : 0
+
+  // Y-up 좌표계에서 문자가 뒤집히지 않도록 로컬 Y를 다시 뒤집음
+  ctx.translate(position[0], position[1])
+  ctx.scale(1, -1)
+  ctx.font = `${font_size}px ${font_family}`
+  ctx.textAlign = anchor === 'Middle' ? 'center' : anchor === 'End' ? 'right' : 'left'
+  ctx.textBaseline = 'alphabetic'
+
   if (style.fill) {
     const [r, g, b, a] = style.fill.color
     ctx.fillStyle = `rgba(${r * 255}, ${g * 255}, ${b * 255}, ${a})`
+  }
+  if (style.stroke) {
+    const [r, g, b, a] = style.stroke.color
+    ctx.strokeStyle = `rgba(${r * 255}, ${g * 255}, ${b * 255}, ${a})`
+    ctx.lineWidth = style.stroke.width
+  }
+  lines.forEach((line, i) => {
+    const y = -(baseline - i * lineHeight)
+    if (style.fill) ctx.fillText(line, 0, y)
+    if (style.stroke) ctx.strokeText(line, 0, y)
+  })
+}
+
+function rgba([r, g, b, a]: [number, number, number, number]): string {
+  return `rgba(${r * 255}, ${g * 255}, ${b * 255}, ${a})`
+}
+
+// 그라디언트 좌표는 Entity 로컬 좌표 (현재 transform을 따라감)
+function fillPaint(ctx: CanvasRenderingContext2D, fill: FillStyle): string | CanvasGradient {
+  const gradient = fill.gradient
+  if (!gradient) {
+    return rgba(fill.color)
+  }
+  let paint: CanvasGradient
+  if (gradient.type === 'Linear') {
+    const { start, end } = gradient
+    paint = ctx.createLinearGradient(start[0], start[1], end[0], end[1])
+  } else {
+    const { center, radius } = gradient
+    const focal = gradient.focal ?? center
+    paint = ctx.createRadialGradient(focal[0], focal[1], 0, center[0], center[1], radius)
+  }
+  for (const stop of gradient.stops) {
+    paint.addColorStop(Math.min(Math.max(stop.offset, 0), 1), rgba(stop.color))
+  }
+  return paint
+}
+
+// Hatch: WASM이 영역에 맞게 잘라 둔 선/점을 fill color로 그림
+function renderHatch(ctx: CanvasRenderingContext2D, hatch: HatchLines, fill: FillStyle) {
+  ctx.save()
+  ctx.strokeStyle = rgba(fill.color)
+  ctx.lineWidth = fill.hatch?.line_width ?? 1
+
+  ctx.beginPath()
+  for (const [from, to] of hatch.lines) {
+    ctx.moveTo(from[0], from[1])
+    ctx.lineTo(to[0], to[1])
+  }
+  ctx.lineCap = 'butt'
+  ctx.stroke()
+
+  if (hatch.dots?.length) {
+    ctx.beginPath()
+    for (const [x, y] of hatch.dots) {
+      ctx.moveTo(x, y)
+      ctx.lineTo(x, y)
+    }
+    ctx.lineCap = 'round'
+    ctx.stroke()
+  }
+  ctx.restore()
+}
+
+// Style application
+function applyStyle(ctx: CanvasRenderingContext2D, style: Style, hasHoles = false) {
+  // 해치만 있으면 color는 해치 선 색이므로 단색으로 채우지 않음
+  if (style.fill && (style.fill.gradient || !style.fill.hatch)) {
+    ctx.fillStyle = fillPaint(ctx, style.fill)
     // holes가 있을 때만 evenodd 사용 (성능 최적화)
     if (hasHoles) {
       ctx.fill('evenodd')
@@ -158,6 +375,7 @@ function renderEntity(
   ctx: CanvasRenderingContext2D,
   entity: Entity,
   findEntity: (key: string) => Entity | undefined,
+  worldTransform: DOMMatrix,
   hiddenIds?: Set<string>
 ) {
   // Skip hidden entities
@@ -177,9 +395,23 @@ function renderEntity(
       .sort((a, b) => (a.metadata?.z_index ?? 0) - (b.metadata?.z_index ?? 0))
 
     for (const child of children) {
-      renderEntity(ctx, child, findEntity, hiddenIds)
+      renderEntity(ctx, child, findEntity, worldTransform, hiddenIds)
     }
+  } else if (entity.entity_type === 'Dimension') {
+    // 참조 도형이 삭제되면 레이아웃이 없으므로 그리지 않음
+    if (entity.computed?.dimension) {
+      renderDimension(ctx, entity.computed.dimension, entity.style, worldTransform)
+    }
+  } else if (isText(geo)) {
+    renderText(ctx, geo, entity.style)
   } else if (geo !== 'Empty') {
+    // SVG와 같은 순서: 그라디언트가 있으면 채운 뒤 해치, 없으면 해치 위에 외곽선
+    const fill = entity.style.fill
+    const hatch = fill && entity.computed?.hatch
+    if (fill && hatch && !fill.gradient) {
+      renderHatch(ctx, hatch, fill)
+    }
+
     // Render geometry - only apply style if path was created
     let hasHoles = false
     let pathCreated = false
@@ -196,10 +428,20 @@ function renderEntity(
       pathCreated = renderArc(ctx, geo)
     } else if (isBezier(geo)) {
       pathCreated = renderBezier(ctx, geo)
+    } else if (isEllipse(geo)) {
+      pathCreated = renderEllipse(ctx, geo)
+    } else if (isEllipticalArc(geo)) {
+      pathCreated = renderEllipticalArc(ctx, geo)
+    } else if (isPath(geo)) {
+      pathCreated = renderPath(ctx, geo)
+      hasHoles = geo.Path.fill_rule === 'EvenOdd'
     }
     if (pathCreated) {
       applyStyle(ctx, entity.style, hasHoles)
     }
+    if (fill && hatch && fill.gradient) {
+      renderHatch(ctx, hatch, fill)
+    }
   }
 
   ctx.restore()
@@ -238,7 +480,9 @@ export function renderScene(ctx: CanvasRenderingContext2D, scene: Scene, hiddenI
     .filter((e) => !childIds.has(e.id))
     .sort((a, b) => (a.metadata?.z_index ?? 0) - (b.metadata?.z_index ?? 0))
 
+  // Viewport transform (Y-up), used by entities laid out in world coordinates
+  const worldTransform = ctx.getTransform()
   for (const entity of rootEntities) {
-    renderEntity(ctx, entity, findEntity, hiddenIds)
+    renderEntity(ctx, entity, findEntity, worldTransform, hiddenIds)
   }
 }
@@ -12,13 +12,14 @@ export function setupCanvas(ctx: CanvasRenderingContext2D, width: number, height
 }
 
 /**
- * Apply entity transform (TRS order: translate → rotate → scale)
+ * Apply entity transform (order: translate → rotate → skew → scale)
  */
 export function applyTransform(ctx: CanvasRenderingContext2D, transform: Transform) {
   const [tx, ty] = transform.translate
   const [sx, sy] = transform.scale
   const rotation = transform.rotate
   const pivot = transform.pivot || [0, 0]
+  const skew = transform.skew || 0
 
   ctx.save()
 
@@ -28,6 +29,7 @@ export function applyTransform(ctx: CanvasRenderingContext2D, transform: Transfo
   // 2. Apply transform (TRS order)
   ctx.translate(tx, ty)
   ctx.rotate(rotation)
+  if (skew !== 0) ctx.transform(1, 0, Math.tan(skew), 1, 0, 0)
   ctx.scale(sx, sy)
 
   // 3. Move back from pivot
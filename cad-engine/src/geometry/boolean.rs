@@ -0,0 +1,627 @@
+//! 폴리곤 Boolean 연산 (union / intersection / difference / xor)
+//!
+//! 알고리즘 (edge classification overlay):
+//! 1. 두 도형의 모든 변을 서로의 교차점에서 분할
+//! 2. 분할된 각 변의 좌/우 양쪽이 A, B 내부인지 판정
+//! 3. 연산 결과의 경계가 되는 변만 남기고, 결과 내부가 왼쪽에 오도록 방향 지정
+//! 4. 남은 변을 이어 링을 만들고, CCW 링은 외곽선, CW 링은 구멍으로 분류
+//!
+//! 곡선은 사전에 `flatten` 모듈로 근사되어 있어야 합니다.
+
+use std::collections::{HashMap, HashSet};
+
+use super::hit::point_in_fill;
+use super::polygon::{PolygonWithHoles, Region, Ring, point_in_ring, signed_area};
+
+/// Boolean 연산 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+impl BooleanOp {
+    /// 문자열에서 BooleanOp 파싱 (대소문자 무시)
+    ///
+    /// "union", "intersection"(="intersect"), "difference"(="subtract"), "xor"
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "union" => Some(BooleanOp::Union),
+            "intersection" | "intersect" => Some(BooleanOp::Intersection),
+            "difference" | "subtract" => Some(BooleanOp::Difference),
+            "xor" => Some(BooleanOp::Xor),
+            _ => None,
+        }
+    }
+
+    /// 안정적인 문자열 표현
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BooleanOp::Union => "union",
+            BooleanOp::Intersection => "intersection",
+            BooleanOp::Difference => "difference",
+            BooleanOp::Xor => "xor",
+        }
+    }
+
+    fn apply(self, in_a: bool, in_b: bool) -> bool {
+        match self {
+            BooleanOp::Union => in_a || in_b,
+            BooleanOp::Intersection => in_a && in_b,
+            BooleanOp::Difference => in_a && !in_b,
+            BooleanOp::Xor => in_a != in_b,
+        }
+    }
+}
+
+/// 도형(여러 Region의 합집합)이 점을 포함하는지 확인합니다.
+pub fn shape_contains(shape: &[Region], point: [f64; 2]) -> bool {
+    shape.iter().any(|region| region.contains(point))
+}
+
+/// 두 도형에 Boolean 연산을 적용합니다.
+///
+/// 각 도형은 Region들의 합집합이며, Region은 even-odd 규칙으로 해석됩니다.
+/// 결과는 면적이 큰 순서로 정렬된 폴리곤 목록입니다 (비어 있을 수 있음).
+pub fn boolean(a: &[Region], b: &[Region], op: BooleanOp) -> Vec<PolygonWithHoles> {
+    let segments: Vec<([f64; 2], [f64; 2])> = a
+        .iter()
+        .chain(b.iter())
+        .flat_map(|region| region.rings.iter())
+        .flat_map(ring_segments)
+        .collect();
+    overlay(&segments, |p| {
+        op.apply(shape_contains(a, p), shape_contains(b, p))
+    })
+}
+
+/// nonzero 규칙으로 해석되는 링들을 같은 영역의 폴리곤으로 정규화합니다.
+///
+/// 같은 방향으로 겹친 링(nonzero에서는 합집합)과 자기교차 링을 풀어
+/// 외곽선/구멍으로 다시 구성하므로, 결과 링들은 even-odd로 해석해도 같은 영역입니다.
+pub fn nonzero_union(rings: &[Ring]) -> Vec<PolygonWithHoles> {
+    let segments: Vec<([f64; 2], [f64; 2])> = rings.iter().flat_map(ring_segments).collect();
+    overlay(&segments, |p| point_in_fill(rings, p, false))
+}
+
+/// 변들을 교차점에서 분할하고, `inside` 판정의 경계가 되는 변만 이어 폴리곤을 만듭니다.
+fn overlay(
+    segments: &[([f64; 2], [f64; 2])],
+    inside: impl Fn([f64; 2]) -> bool,
+) -> Vec<PolygonWithHoles> {
+    if segments.is_empty() {
+        return Vec::new();
+    }
+
+    let extent = segments_extent(segments);
+    let weld_eps = (extent * 1e-10).max(1e-12);
+    let probe = (extent * 1e-7).max(weld_eps * 100.0);
+
+    // 1. 교차점 분할 + 정점 용접
+    let params = split_params(segments, weld_eps);
+    let mut pool = VertexPool::new(weld_eps);
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    for (seg, ts) in segments.iter().zip(params) {
+        let ids: Vec<usize> = ts
+            .iter()
+            .map(|t| pool.insert(lerp(seg.0, seg.1, *t)))
+            .collect();
+        for pair in ids.windows(2) {
+            if pair[0] != pair[1] {
+                edges.insert((pair[0].min(pair[1]), pair[0].max(pair[1])));
+            }
+        }
+    }
+
+    // 2~3. 경계 변 선택 및 방향 지정
+    let mut directed: Vec<(usize, usize)> = Vec::new();
+    let mut sorted_edges: Vec<_> = edges.into_iter().collect();
+    sorted_edges.sort_unstable();
+    for (u, v) in sorted_edges {
+        let pu = pool.points[u];
+        let pv = pool.points[v];
+        let dx = pv[0] - pu[0];
+        let dy = pv[1] - pu[1];
+        let len = (dx * dx + dy * dy).sqrt();
+        if len == 0.0 {
+            continue;
+        }
+        let delta = probe.min(len * 0.1);
+        let normal = [-dy / len * delta, dx / len * delta];
+        let mid = [(pu[0] + pv[0]) / 2.0, (pu[1] + pv[1]) / 2.0];
+        let left = [mid[0] + normal[0], mid[1] + normal[1]];
+        let right = [mid[0] - normal[0], mid[1] - normal[1]];
+
+        let left_in = inside(left);
+        let right_in = inside(right);
+        if left_in != right_in {
+            directed.push(if left_in { (u, v) } else { (v, u) });
+        }
+    }
+
+    // 4. 링 구성 및 외곽선/구멍 분류
+    let rings = chain_rings(&directed, &pool.points);
+    assemble_polygons(rings, probe)
+}
+
+/// 여러 도형을 차례로 누적 연산합니다 (A op B op C ...).
+///
+/// Difference는 첫 도형에서 나머지를 모두 뺀 결과입니다.
+pub fn boolean_fold(shapes: &[Vec<Region>], op: BooleanOp) -> Vec<PolygonWithHoles> {
+    let mut iter = shapes.iter();
+    let first = match iter.next() {
+        Some(shape) => shape,
+        None => return Vec::new(),
+    };
+    // 첫 도형도 정규화(자기교차 해소)를 위해 빈 도형과 union
+    let mut acc = boolean(first, &[], BooleanOp::Union);
+    for shape in iter {
+        let regions: Vec<Region> = acc.iter().map(|p| p.to_region()).collect();
+        acc = boolean(&regions, shape, op);
+    }
+    acc
+}
+
+fn ring_segments(ring: &Ring) -> impl Iterator<Item = ([f64; 2], [f64; 2])> + '_ {
+    let n = ring.len();
+    let count = if n >= 3 { n } else { 0 };
+    (0..count).map(move |i| (ring[i], ring[(i + 1) % n]))
+}
+
+fn segments_extent(segments: &[([f64; 2], [f64; 2])]) -> f64 {
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    for (p, q) in segments {
+        for point in [p, q] {
+            min[0] = min[0].min(point[0]);
+            min[1] = min[1].min(point[1]);
+            max[0] = max[0].max(point[0]);
+            max[1] = max[1].max(point[1]);
+        }
+    }
+    (max[0] - min[0])
+        .max(max[1] - min[1])
+        .max(max[0].abs().max(min[0].abs()))
+        .max(max[1].abs().max(min[1].abs()))
+        .max(1e-9)
+}
+
+fn lerp(a: [f64; 2], b: [f64; 2], t: f64) -> [f64; 2] {
+    if t == 0.0 {
+        a
+    } else if t == 1.0 {
+        b
+    } else {
+        [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+    }
+}
+
+fn cross(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+/// 각 세그먼트의 분할 파라미터(0..=1, 정렬됨)를 계산합니다.
+fn split_params(segments: &[([f64; 2], [f64; 2])], eps: f64) -> Vec<Vec<f64>> {
+    let mut params: Vec<Vec<f64>> = vec![vec![0.0, 1.0]; segments.len()];
+
+    for i in 0..segments.len() {
+        let (p1, p2) = segments[i];
+        let d1 = sub(p2, p1);
+        let len1_sq = dot(d1, d1);
+        if len1_sq == 0.0 {
+            continue;
+        }
+        for j in (i + 1)..segments.len() {
+            let (q1, q2) = segments[j];
+            // 바운딩 박스 조기 배제
+            if p1[0].max(p2[0]) + eps < q1[0].min(q2[0])
+                || q1[0].max(q2[0]) + eps < p1[0].min(p2[0])
+                || p1[1].max(p2[1]) + eps < q1[1].min(q2[1])
+                || q1[1].max(q2[1]) + eps < p1[1].min(p2[1])
+            {
+                continue;
+            }
+            let d2 = sub(q2, q1);
+            let len2_sq = dot(d2, d2);
+            if len2_sq == 0.0 {
+                continue;
+            }
+
+            let denom = cross(d1, d2);
+            let w = sub(q1, p1);
+            if denom.abs() > 1e-12 * (len1_sq * len2_sq).sqrt() {
+                let t = cross(w, d2) / denom;
+                let u = cross(w, d1) / denom;
+                let t_eps = eps / len1_sq.sqrt();
+                let u_eps = eps / len2_sq.sqrt();
+                if (-t_eps..=1.0 + t_eps).contains(&t) && (-u_eps..=1.0 + u_eps).contains(&u) {
+                    params[i].push(t.clamp(0.0, 1.0));
+                    params[j].push(u.clamp(0.0, 1.0));
+                }
+            } else if cross(w, d1).abs() <= eps * len1_sq.sqrt() {
+                // 동일 직선 위: 서로의 끝점을 투영하여 겹침 구간 분할
+                for q in [q1, q2] {
+                    let t = dot(sub(q, p1), d1) / len1_sq;
+                    if t > 0.0 && t < 1.0 {
+                        params[i].push(t);
+                    }
+                }
+                for p in [p1, p2] {
+                    let u = dot(sub(p, q1), d2) / len2_sq;
+                    if u > 0.0 && u < 1.0 {
+                        params[j].push(u);
+                    }
+                }
+            }
+        }
+    }
+
+    for ts in &mut params {
+        ts.sort_by(|a, b| a.total_cmp(b));
+        ts.dedup();
+    }
+    params
+}
+
+/// 허용오차 내의 점을 하나의 정점으로 합치는 정점 저장소
+struct VertexPool {
+    eps: f64,
+    points: Vec<[f64; 2]>,
+    grid: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl VertexPool {
+    fn new(eps: f64) -> Self {
+        Self {
+            eps,
+            points: Vec::new(),
+            grid: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, p: [f64; 2]) -> (i64, i64) {
+        (
+            (p[0] / self.eps).floor() as i64,
+            (p[1] / self.eps).floor() as i64,
+        )
+    }
+
+    fn insert(&mut self, p: [f64; 2]) -> usize {
+        let (cx, cy) = self.cell(p);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(ids) = self.grid.get(&(cx + dx, cy + dy)) {
+                    for &id in ids {
+                        let q = self.points[id];
+                        if (q[0] - p[0]).abs() <= self.eps && (q[1] - p[1]).abs() <= self.eps {
+                            return id;
+                        }
+                    }
+                }
+            }
+        }
+        let id = self.points.len();
+        self.points.push(p);
+        self.grid.entry((cx, cy)).or_default().push(id);
+        id
+    }
+}
+
+/// 방향 있는 변들을 이어 닫힌 링들을 만듭니다.
+///
+/// 한 정점에서 나가는 변이 여럿이면 가장 왼쪽으로 꺾이는 변을 선택하여
+/// 맞닿은 링들이 하나로 합쳐지지 않도록 합니다.
+fn chain_rings(edges: &[(usize, usize)], points: &[[f64; 2]]) -> Vec<Ring> {
+    let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (idx, (u, _)) in edges.iter().enumerate() {
+        outgoing.entry(*u).or_default().push(idx);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+
+    for start_idx in 0..edges.len() {
+        if used[start_idx] {
+            continue;
+        }
+        let start_vertex = edges[start_idx].0;
+        let mut ring_ids = vec![start_vertex];
+        let mut current = start_idx;
+
+        loop {
+            used[current] = true;
+            let (from, to) = edges[current];
+            if to == start_vertex {
+                break;
+            }
+            ring_ids.push(to);
+
+            let incoming = sub(points[to], points[from]);
+            let next = outgoing.get(&to).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&c| !used[c])
+                    .max_by(|&x, &y| {
+                        let turn = |c: usize| {
+                            let out = sub(points[edges[c].1], points[to]);
+                            cross(incoming, out).atan2(dot(incoming, out))
+                        };
+                        turn(x).total_cmp(&turn(y))
+                    })
+            });
+
+            match next {
+                Some(n) => current = n,
+                None => break, // 열린 체인 (수치 오류) - 버림
+            }
+        }
+
+        if ring_ids.len() >= 3 {
+            let ring: Ring = ring_ids.iter().map(|&id| points[id]).collect();
+            let ring = simplify_ring(ring);
+            if ring.len() >= 3 {
+                rings.push(ring);
+            }
+        }
+    }
+
+    rings
+}
+
+/// 연속 중복점과 일직선 위의 중간 정점을 제거합니다.
+fn simplify_ring(ring: Ring) -> Ring {
+    let mut result = ring;
+    loop {
+        let n = result.len();
+        if n < 3 {
+            return result;
+        }
+        let mut removed = false;
+        let mut kept: Ring = Vec::with_capacity(n);
+        for i in 0..n {
+            let prev = result[(i + n - 1) % n];
+            let cur = result[i];
+            let next = result[(i + 1) % n];
+            let a = sub(cur, prev);
+            let b = sub(next, cur);
+            let scale = (dot(a, a) * dot(b, b)).sqrt();
+            let collinear = cross(a, b).abs() <= 1e-12 * scale && dot(a, b) > 0.0;
+            if scale == 0.0 || collinear {
+                removed = true;
+                continue;
+            }
+            kept.push(cur);
+        }
+        if !removed {
+            return kept;
+        }
+        result = kept;
+    }
+}
+
+/// 링들을 외곽선(CCW)과 구멍(CW)으로 분류하여 폴리곤으로 조립합니다.
+fn assemble_polygons(rings: Vec<Ring>, probe: f64) -> Vec<PolygonWithHoles> {
+    let (outers, holes): (Vec<Ring>, Vec<Ring>) =
+        rings.into_iter().partition(|r| signed_area(r) > 0.0);
+
+    let mut polygons: Vec<PolygonWithHoles> = outers
+        .into_iter()
+        .map(|outer| PolygonWithHoles {
+            outer,
+            holes: Vec::new(),
+        })
+        .collect();
+
+    for hole in holes {
+        // 구멍의 가장 긴 변 왼쪽(= 결과 내부)에서 탐침점 생성
+        let n = hole.len();
+        let (i, _) = (0..n)
+            .map(|i| {
+                let d = sub(hole[(i + 1) % n], hole[i]);
+                (i, dot(d, d))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap_or((0, 0.0));
+        let p = hole[i];
+        let q = hole[(i + 1) % n];
+        let d = sub(q, p);
+        let len = dot(d, d).sqrt().max(1e-300);
+        let delta = probe.min(len * 0.1);
+        let sample = [
+            (p[0] + q[0]) / 2.0 - d[1] / len * delta,
+            (p[1] + q[1]) / 2.0 + d[0] / len * delta,
+        ];
+
+        // 탐침점을 포함하는 가장 작은 외곽선에 배정
+        let owner = polygons
+            .iter_mut()
+            .filter(|poly| point_in_ring(&poly.outer, sample))
+            .min_by(|x, y| signed_area(&x.outer).total_cmp(&signed_area(&y.outer)));
+        if let Some(owner) = owner {
+            owner.holes.push(hole);
+        }
+    }
+
+    polygons.sort_by(|a, b| b.area().total_cmp(&a.area()));
+    polygons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn rect(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<Region> {
+        vec![Region {
+            rings: vec![vec![
+                [min_x, min_y],
+                [max_x, min_y],
+                [max_x, max_y],
+                [min_x, max_y],
+            ]],
+        }]
+    }
+
+    fn total_area(polygons: &[PolygonWithHoles]) -> f64 {
+        polygons.iter().map(|p| p.area()).sum()
+    }
+
+    #[test]
+    fn test_parse_op() {
+        assert_eq!(BooleanOp::parse_str("Union"), Some(BooleanOp::Union));
+        assert_eq!(
+            BooleanOp::parse_str("subtract"),
+            Some(BooleanOp::Difference)
+        );
+        assert_eq!(
+            BooleanOp::parse_str("intersect"),
+            Some(BooleanOp::Intersection)
+        );
+        assert_eq!(BooleanOp::parse_str("merge"), None);
+    }
+
+    #[test]
+    fn test_union_overlapping_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, 5.0, 15.0, 15.0);
+        let result = boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(result.len(), 1);
+        assert!(approx_eq(total_area(&result), 175.0, 1e-9));
+        // L자 두 개가 합쳐진 8각 외곽선
+        assert_eq!(result[0].outer.len(), 8);
+        assert!(signed_area(&result[0].outer) > 0.0);
+    }
+
+    #[test]
+    fn test_intersection_overlapping_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, 5.0, 15.0, 15.0);
+        let result = boolean(&a, &b, BooleanOp::Intersection);
+        assert_eq!(result.len(), 1);
+        assert!(approx_eq(total_area(&result), 25.0, 1e-9));
+        assert_eq!(result[0].outer.len(), 4);
+    }
+
+    #[test]
+    fn test_difference_creates_hole() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(4.0, 4.0, 6.0, 6.0);
+        let result = boolean(&a, &b, BooleanOp::Difference);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 1);
+        assert!(signed_area(&result[0].holes[0]) < 0.0);
+        assert!(approx_eq(total_area(&result), 96.0, 1e-9));
+    }
+
+    #[test]
+    fn test_xor_overlapping_rects() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(5.0, 5.0, 15.0, 15.0);
+        let result = boolean(&a, &b, BooleanOp::Xor);
+        assert_eq!(result.len(), 2);
+        assert!(approx_eq(total_area(&result), 150.0, 1e-9));
+    }
+
+    #[test]
+    fn test_disjoint_intersection_is_empty() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(5.0, 5.0, 6.0, 6.0);
+        assert!(boolean(&a, &b, BooleanOp::Intersection).is_empty());
+    }
+
+    #[test]
+    fn test_union_shared_edge_merges() {
+        // 변을 공유하는 두 사각형 → 하나의 사각형
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(10.0, 0.0, 20.0, 10.0);
+        let result = boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.len(), 4);
+        assert!(approx_eq(total_area(&result), 200.0, 1e-9));
+    }
+
+    #[test]
+    fn test_union_touching_corner_stays_separate() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.0, 1.0, 2.0, 2.0);
+        let result = boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(result.len(), 2);
+        assert!(approx_eq(total_area(&result), 2.0, 1e-9));
+    }
+
+    #[test]
+    fn test_difference_splits_shape() {
+        // 가운데를 관통하는 띠를 빼면 두 조각
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(4.0, -1.0, 6.0, 11.0);
+        let result = boolean(&a, &b, BooleanOp::Difference);
+        assert_eq!(result.len(), 2);
+        assert!(approx_eq(total_area(&result), 80.0, 1e-9));
+    }
+
+    #[test]
+    fn test_fold_difference_multiple() {
+        let shapes = vec![
+            rect(0.0, 0.0, 10.0, 10.0),
+            rect(1.0, 1.0, 3.0, 3.0),
+            rect(6.0, 6.0, 8.0, 8.0),
+        ];
+        let result = boolean_fold(&shapes, BooleanOp::Difference);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 2);
+        assert!(approx_eq(total_area(&result), 92.0, 1e-9));
+    }
+
+    #[test]
+    fn test_region_with_hole_input() {
+        // 구멍 있는 입력 ∪ 구멍 안쪽 작은 사각형 → 섬(island)이 별도 폴리곤
+        let a = vec![Region {
+            rings: vec![
+                vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+                vec![[2.0, 2.0], [8.0, 2.0], [8.0, 8.0], [2.0, 8.0]],
+            ],
+        }];
+        let b = rect(4.0, 4.0, 6.0, 6.0);
+        let result = boolean(&a, &b, BooleanOp::Union);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].holes.len(), 1);
+        assert!(approx_eq(total_area(&result), 100.0 - 36.0 + 4.0, 1e-9));
+    }
+
+    #[test]
+    fn test_nonzero_union_fills_star_center_and_keeps_reversed_hole() {
+        // 오각별 (자기교차): nonzero에서는 가운데 오각형도 내부
+        let star: Ring = (0..5)
+            .map(|i| {
+                let t = std::f64::consts::FRAC_PI_2 + i as f64 * 4.0 * std::f64::consts::PI / 5.0;
+                [10.0 * t.cos(), 10.0 * t.sin()]
+            })
+            .collect();
+        let result = nonzero_union(std::slice::from_ref(&star));
+        assert_eq!(result.len(), 1);
+        assert!(result[0].holes.is_empty());
+        assert!(result[0].to_region().contains([0.0, 0.0]));
+
+        // 반대 방향 링은 nonzero에서도 구멍
+        let outer = vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        let hole = vec![[2.0, 2.0], [2.0, 8.0], [8.0, 8.0], [8.0, 2.0]];
+        let result = nonzero_union(&[outer, hole]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 1);
+        assert!(approx_eq(total_area(&result), 64.0, 1e-9));
+    }
+}
@@ -0,0 +1,839 @@
+//! 해석적(analytic) 바운딩 박스 계산
+//!
+//! - 기하 바운드: Arc는 스윕 범위 안의 극점, Bezier는 도함수의 근으로 정확한 극점을 구합니다.
+//!   극점은 변환 행렬을 적용한 뒤의 좌표계에서 계산하므로 회전/비균일 스케일에서도 tight합니다.
+//! - 시각(visual) 바운드: stroke 폭의 절반과 miter/cap 돌출부까지 포함하여
+//!   실제 렌더링 결과와 일치하는 바운드를 계산합니다.
+
+use std::f64::consts::PI;
+
+use super::flatten::{arc_sweep, cubic_point, ellipse_point};
+use super::path::{PathPiece, subpath_pieces};
+use super::text::text_geometry_bounds;
+use crate::scene::entity::{Geometry, Matrix3x3, Transform};
+use crate::scene::style::{LineCap, LineJoin, StrokeStyle};
+
+/// SVG 기본 stroke-miterlimit (miter 길이 / stroke 폭)
+pub const SVG_MITER_LIMIT: f64 = 4.0;
+
+/// (min, max) 바운딩 박스
+pub type BoundingBox = ([f64; 2], [f64; 2]);
+
+/// 점들의 바운딩 박스 (비어 있으면 None)
+pub fn bbox_of(points: &[[f64; 2]]) -> Option<BoundingBox> {
+    let mut iter = points.iter();
+    let first = iter.next()?;
+    let mut min = *first;
+    let mut max = *first;
+    for p in iter {
+        min[0] = min[0].min(p[0]);
+        min[1] = min[1].min(p[1]);
+        max[0] = max[0].max(p[0]);
+        max[1] = max[1].max(p[1]);
+    }
+    Some((min, max))
+}
+
+/// 두 바운딩 박스가 겹치는지 확인합니다 (경계 접촉 포함).
+pub fn bbox_intersects(a: &BoundingBox, b: &BoundingBox) -> bool {
+    a.0[0] <= b.1[0] && a.1[0] >= b.0[0] && a.0[1] <= b.1[1] && a.1[1] >= b.0[1]
+}
+
+/// 두 바운딩 박스의 합집합
+pub fn bbox_union(a: Option<BoundingBox>, b: Option<BoundingBox>) -> Option<BoundingBox> {
+    match (a, b) {
+        (Some((amin, amax)), Some((bmin, bmax))) => Some((
+            [amin[0].min(bmin[0]), amin[1].min(bmin[1])],
+            [amax[0].max(bmax[0]), amax[1].max(bmax[1])],
+        )),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// 각도가 start에서 CCW로 sweep만큼의 범위 안에 있는지 확인합니다.
+fn angle_in_sweep(angle: f64, start: f64, sweep: f64) -> bool {
+    (angle - start).rem_euclid(2.0 * PI) <= sweep + 1e-12
+}
+
+/// 행렬 적용 후 원(호) 위에서 x, y가 극값이 되는 각도 (로컬 각도 기준, 4개)
+fn circle_extreme_angles(m: &Matrix3x3) -> [f64; 4] {
+    // x(θ) = r (m00 cosθ + m01 sinθ) → 극값 θ = atan2(m01, m00)
+    let ax = m[0][1].atan2(m[0][0]);
+    let ay = m[1][1].atan2(m[1][0]);
+    [ax, ax + PI, ay, ay + PI]
+}
+
+/// 단위원을 타원으로 보내는 행렬 (translate(center) · rotate(rotation) · scale(rx, ry))
+fn ellipse_frame(center: [f64; 2], rx: f64, ry: f64, rotation: f64) -> Matrix3x3 {
+    let (sin_r, cos_r) = rotation.sin_cos();
+    [
+        [rx * cos_r, -ry * sin_r, center[0]],
+        [rx * sin_r, ry * cos_r, center[1]],
+        [0.0, 0.0, 1.0],
+    ]
+}
+
+/// 행렬 적용 후 타원 위에서 x, y가 극값이 되는 매개변수 각도 (4개)
+fn ellipse_extreme_angles(
+    m: &Matrix3x3,
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+) -> [f64; 4] {
+    let frame = ellipse_frame(center, rx, ry, rotation);
+    circle_extreme_angles(&Transform::multiply_matrices(m, &frame))
+}
+
+/// 타원 위 매개변수 t에서의 CCW 진행 방향 접선
+fn ellipse_tangent(rx: f64, ry: f64, rotation: f64, t: f64) -> [f64; 2] {
+    let (sin_r, cos_r) = rotation.sin_cos();
+    let dx = -rx * t.sin();
+    let dy = ry * t.cos();
+    [dx * cos_r - dy * sin_r, dx * sin_r + dy * cos_r]
+}
+
+fn polar(center: [f64; 2], radius: f64, angle: f64) -> [f64; 2] {
+    [
+        center[0] + radius * angle.cos(),
+        center[1] + radius * angle.sin(),
+    ]
+}
+
+/// 큐빅 베지어의 한 축에서 도함수가 0이 되는 t (0 < t < 1)
+fn cubic_axis_roots(p0: f64, p1: f64, p2: f64, p3: f64) -> Vec<f64> {
+    // B'(t)/3 = (a - 2b + c) t² + 2(b - a) t + a,  a = p1-p0, b = p2-p1, c = p3-p2
+    let a = p1 - p0;
+    let b = p2 - p1;
+    let c = p3 - p2;
+    let qa = a - 2.0 * b + c;
+    let qb = 2.0 * (b - a);
+    let qc = a;
+
+    let scale = a.abs().max(b.abs()).max(c.abs());
+    let mut roots = Vec::new();
+    if scale == 0.0 {
+        return roots;
+    }
+    if qa.abs() <= 1e-12 * scale {
+        if qb.abs() > 1e-12 * scale {
+            roots.push(-qc / qb);
+        }
+    } else {
+        let disc = qb * qb - 4.0 * qa * qc;
+        if disc >= 0.0 {
+            let sq = disc.sqrt();
+            roots.push((-qb + sq) / (2.0 * qa));
+            roots.push((-qb - sq) / (2.0 * qa));
+        }
+    }
+    roots.retain(|t| *t > 0.0 && *t < 1.0);
+    roots
+}
+
+/// 행렬 적용 후 좌표계에서 베지어 세그먼트의 내부 극점 (파라미터 t, 축 0 = x / 1 = y)
+fn cubic_extreme_params(
+    m: &Matrix3x3,
+    p0: [f64; 2],
+    p1: [f64; 2],
+    p2: [f64; 2],
+    p3: [f64; 2],
+) -> Vec<(f64, usize)> {
+    let w = [p0, p1, p2, p3].map(|p| Transform::transform_point(m, p));
+    (0..2)
+        .flat_map(|axis| {
+            cubic_axis_roots(w[0][axis], w[1][axis], w[2][axis], w[3][axis])
+                .into_iter()
+                .map(move |t| (t, axis))
+        })
+        .collect()
+}
+
+/// 행렬 적용 후 좌표계에서 Path 조각 내부의 축 극점 (점, 축)
+fn piece_extremes(piece: &PathPiece, m: &Matrix3x3) -> Vec<([f64; 2], usize)> {
+    match piece {
+        PathPiece::Line(..) => vec![],
+        PathPiece::Cubic([p0, p1, p2, p3]) => cubic_extreme_params(m, *p0, *p1, *p2, *p3)
+            .into_iter()
+            .map(|(t, axis)| (cubic_point(*p0, *p1, *p2, *p3, t), axis))
+            .collect(),
+        PathPiece::Arc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            sweep,
+        } => {
+            // CW 호는 끝점에서 시작하는 CCW 호로 바꿔 스윕 범위 검사
+            let (start, sweep) = if *sweep < 0.0 {
+                (start_angle + sweep, -sweep)
+            } else {
+                (*start_angle, *sweep)
+            };
+            ellipse_extreme_angles(m, *center, *rx, *ry, *rotation)
+                .into_iter()
+                .enumerate()
+                .filter(|(_, t)| sweep >= 2.0 * PI || angle_in_sweep(*t, start, sweep))
+                .map(|(i, t)| (ellipse_point(*center, *rx, *ry, *rotation, t), i / 2))
+                .collect()
+        }
+    }
+}
+
+/// 도형의 바운드를 결정하는 로컬 점들 (행렬 적용 후 좌표계의 극점 기준)
+///
+/// 반환된 점들을 `m`으로 변환한 바운딩 박스가 변환된 도형의 정확한 바운드입니다.
+fn extreme_local_points(geometry: &Geometry, m: &Matrix3x3) -> Vec<[f64; 2]> {
+    match geometry {
+        Geometry::Line { points } | Geometry::Polygon { points, .. } => points.clone(),
+        Geometry::Circle { center, radius } => circle_extreme_angles(m)
+            .iter()
+            .map(|a| polar(*center, *radius, *a))
+            .collect(),
+        Geometry::Rect {
+            center,
+            width,
+            height,
+        } => {
+            let hw = width / 2.0;
+            let hh = height / 2.0;
+            vec![
+                [center[0] - hw, center[1] - hh],
+                [center[0] + hw, center[1] - hh],
+                [center[0] + hw, center[1] + hh],
+                [center[0] - hw, center[1] + hh],
+            ]
+        }
+        Geometry::Arc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let mut points = vec![
+                polar(*center, *radius, *start_angle),
+                polar(*center, *radius, start_angle + sweep),
+            ];
+            for angle in circle_extreme_angles(m) {
+                if angle_in_sweep(angle, *start_angle, sweep) {
+                    points.push(polar(*center, *radius, angle));
+                }
+            }
+            points
+        }
+        Geometry::Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+        } => ellipse_extreme_angles(m, *center, *rx, *ry, *rotation)
+            .iter()
+            .map(|t| ellipse_point(*center, *rx, *ry, *rotation, *t))
+            .collect(),
+        Geometry::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let mut points = vec![
+                ellipse_point(*center, *rx, *ry, *rotation, *start_angle),
+                ellipse_point(*center, *rx, *ry, *rotation, start_angle + sweep),
+            ];
+            for t in ellipse_extreme_angles(m, *center, *rx, *ry, *rotation) {
+                if angle_in_sweep(t, *start_angle, sweep) {
+                    points.push(ellipse_point(*center, *rx, *ry, *rotation, t));
+                }
+            }
+            points
+        }
+        Geometry::Bezier {
+            start, segments, ..
+        } => {
+            let mut points = vec![*start];
+            let mut current = *start;
+            for [cp1, cp2, end] in segments {
+                for (t, _) in cubic_extreme_params(m, current, *cp1, *cp2, *end) {
+                    points.push(cubic_point(current, *cp1, *cp2, *end, t));
+                }
+                points.push(*end);
+                current = *end;
+            }
+            points
+        }
+        Geometry::Path { subpaths, .. } => {
+            let mut points = Vec::new();
+            for subpath in subpaths {
+                points.push(subpath.start);
+                for piece in subpath_pieces(subpath) {
+                    points.push(piece.end());
+                    points.extend(piece_extremes(&piece, m).into_iter().map(|(p, _)| p));
+                }
+            }
+            points
+        }
+        Geometry::Text { .. } => match text_geometry_bounds(geometry) {
+            // 글자 상자의 네 꼭짓점 (회전 시에도 상자 전체를 포함)
+            Some((min, max)) => vec![
+                [min[0], min[1]],
+                [max[0], min[1]],
+                [max[0], max[1]],
+                [min[0], max[1]],
+            ],
+            None => vec![],
+        },
+        // 치수는 참조 도형 기준으로 scene에서 계산 (scene/dimension.rs)
+        Geometry::Dimension { .. } | Geometry::Empty => vec![],
+    }
+}
+
+/// 변환 행렬을 적용한 도형의 정확한 바운딩 박스
+///
+/// # Returns
+/// * None - 점이 없는 도형 (Group의 Empty geometry 등)
+pub fn geometry_bbox(geometry: &Geometry, m: &Matrix3x3) -> Option<BoundingBox> {
+    let world: Vec<[f64; 2]> = extreme_local_points(geometry, m)
+        .into_iter()
+        .map(|p| Transform::transform_point(m, p))
+        .collect();
+    bbox_of(&world)
+}
+
+// ========================================
+// Visual (stroke) bounds
+// ========================================
+
+fn normalize(v: [f64; 2]) -> Option<[f64; 2]> {
+    let len = (v[0] * v[0] + v[1] * v[1]).sqrt();
+    (len > 1e-12).then(|| [v[0] / len, v[1] / len])
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+/// stroke 외곽선의 극점을 수집하는 헬퍼
+///
+/// 모든 점은 로컬 좌표로 받아 행렬로 변환합니다 (stroke 폭도 transform에 따라 스케일됨).
+struct StrokeCollector<'a> {
+    m: &'a Matrix3x3,
+    hw: f64,
+    stroke: &'a StrokeStyle,
+    /// 정확한 외곽선 점 (월드)
+    points: Vec<[f64; 2]>,
+    /// 변환된 원판(반지름 hw)만큼 모든 방향으로 확장할 점 (월드)
+    disk_points: Vec<[f64; 2]>,
+    /// 매끄러운 곡선의 축 극점: 해당 축 방향으로만 확장 (월드, 축 0 = x / 1 = y)
+    axis_extremes: Vec<([f64; 2], usize)>,
+}
+
+impl<'a> StrokeCollector<'a> {
+    fn new(m: &'a Matrix3x3, stroke: &'a StrokeStyle) -> Self {
+        Self {
+            m,
+            hw: stroke.width.abs() / 2.0,
+            stroke,
+            points: Vec::new(),
+            disk_points: Vec::new(),
+            axis_extremes: Vec::new(),
+        }
+    }
+
+    fn add_point(&mut self, p: [f64; 2]) {
+        self.points.push(Transform::transform_point(self.m, p));
+    }
+
+    fn add_disk(&mut self, p: [f64; 2]) {
+        self.disk_points.push(Transform::transform_point(self.m, p));
+    }
+
+    /// 곡선의 축 극점에서는 stroke 외곽선의 극값도 같은 축으로 ±(변환된 반폭)만큼 떨어짐
+    fn add_axis_extreme(&mut self, p: [f64; 2], axis: usize) {
+        self.axis_extremes
+            .push((Transform::transform_point(self.m, p), axis));
+    }
+
+    /// 진행 방향 dir에 수직인 양쪽 offset 점 (butt 단면)
+    fn add_offsets(&mut self, p: [f64; 2], dir: [f64; 2]) {
+        match normalize(dir) {
+            Some(u) => {
+                let n = [-u[1] * self.hw, u[0] * self.hw];
+                self.add_point([p[0] + n[0], p[1] + n[1]]);
+                self.add_point([p[0] - n[0], p[1] - n[1]]);
+            }
+            None => self.add_disk(p),
+        }
+    }
+
+    /// 열린 경로의 끝(cap). outward는 경로 바깥쪽을 향하는 접선 방향
+    fn add_cap(&mut self, p: [f64; 2], outward: [f64; 2]) {
+        self.add_offsets(p, outward);
+        match self.stroke.cap {
+            LineCap::Butt => {}
+            LineCap::Round => self.add_disk(p),
+            LineCap::Square => {
+                if let Some(u) = normalize(outward) {
+                    let e = [p[0] + u[0] * self.hw, p[1] + u[1] * self.hw];
+                    self.add_offsets(e, outward);
+                }
+            }
+        }
+    }
+
+    /// 꺾이는 정점(join). d_in은 들어오는 방향, d_out은 나가는 방향
+    fn add_join(&mut self, p: [f64; 2], d_in: [f64; 2], d_out: [f64; 2]) {
+        self.add_offsets(p, d_in);
+        self.add_offsets(p, d_out);
+        let (Some(u_in), Some(u_out)) = (normalize(d_in), normalize(d_out)) else {
+            return;
+        };
+        let cross = u_in[0] * u_out[1] - u_in[1] * u_out[0];
+        let dot = u_in[0] * u_out[0] + u_in[1] * u_out[1];
+        if cross.abs() < 1e-12 && dot > 0.0 {
+            return; // 꺾이지 않음
+        }
+        match self.stroke.join {
+            LineJoin::Bevel => {}
+            LineJoin::Round => self.add_disk(p),
+            LineJoin::Miter => {
+                // miter 길이 비율 = 1 / sin(θ/2), θ = 두 변 사이 각
+                let sin_half = ((1.0 + dot) / 2.0).max(0.0).sqrt();
+                if sin_half > 1e-12 && 1.0 / sin_half <= SVG_MITER_LIMIT {
+                    if let Some(bisector) = normalize(sub(u_in, u_out)) {
+                        let len = self.hw / sin_half;
+                        self.add_point([p[0] + bisector[0] * len, p[1] + bisector[1] * len]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 폴리라인 (closed면 마지막 점 → 첫 점 연결)
+    fn add_polyline(&mut self, points: &[[f64; 2]], closed: bool) {
+        let n = points.len();
+        if n == 0 {
+            return;
+        }
+        if n == 1 {
+            self.add_disk(points[0]);
+            return;
+        }
+        let seg_count = if closed { n } else { n - 1 };
+        for i in 0..seg_count {
+            let a = points[i];
+            let b = points[(i + 1) % n];
+            let d = sub(b, a);
+            self.add_offsets(a, d);
+            self.add_offsets(b, d);
+        }
+        let join_range = if closed { 0..n } else { 1..n - 1 };
+        for i in join_range {
+            let prev = points[(i + n - 1) % n];
+            let next = points[(i + 1) % n];
+            self.add_join(points[i], sub(points[i], prev), sub(next, points[i]));
+        }
+        if !closed {
+            self.add_cap(points[0], sub(points[0], points[1]));
+            self.add_cap(points[n - 1], sub(points[n - 1], points[n - 2]));
+        }
+    }
+
+    fn finish(self) -> Option<BoundingBox> {
+        let ex = self.hw * (self.m[0][0].powi(2) + self.m[0][1].powi(2)).sqrt();
+        let ey = self.hw * (self.m[1][0].powi(2) + self.m[1][1].powi(2)).sqrt();
+        let mut expanded: Vec<[f64; 2]> = self
+            .disk_points
+            .iter()
+            .flat_map(|p| [[p[0] - ex, p[1] - ey], [p[0] + ex, p[1] + ey]])
+            .collect();
+        for (p, axis) in &self.axis_extremes {
+            let (dx, dy) = if *axis == 0 { (ex, 0.0) } else { (0.0, ey) };
+            expanded.push([p[0] - dx, p[1] - dy]);
+            expanded.push([p[0] + dx, p[1] + dy]);
+        }
+        bbox_union(bbox_of(&self.points), bbox_of(&expanded))
+    }
+}
+
+/// 베지어 세그먼트의 시작/끝 접선
+fn cubic_tangents(p0: [f64; 2], p1: [f64; 2], p2: [f64; 2], p3: [f64; 2]) -> ([f64; 2], [f64; 2]) {
+    let start = [sub(p1, p0), sub(p2, p0), sub(p3, p0)]
+        .into_iter()
+        .find(|d| normalize(*d).is_some())
+        .unwrap_or([0.0, 0.0]);
+    let end = [sub(p3, p2), sub(p3, p1), sub(p3, p0)]
+        .into_iter()
+        .find(|d| normalize(*d).is_some())
+        .unwrap_or([0.0, 0.0]);
+    (start, end)
+}
+
+/// stroke를 포함한 시각적 바운딩 박스 (변환 행렬 적용 후)
+///
+/// - 곡선 내부의 축 극점: 해당 축으로 변환된 stroke 반폭만큼 확장 (Minkowski 합의 support로 정확)
+/// - 꼭짓점/끝점: join(miter 돌출, round, bevel)과 cap(butt, round, square) 형태를 반영
+/// - miter는 SVG 기본 miterlimit(4)을 넘으면 bevel로 처리
+///
+/// stroke가 None이면 기하 바운드와 같습니다.
+pub fn visual_bbox(
+    geometry: &Geometry,
+    m: &Matrix3x3,
+    stroke: Option<&StrokeStyle>,
+) -> Option<BoundingBox> {
+    let base = geometry_bbox(geometry, m);
+    let stroke = match stroke {
+        Some(s) if s.width.abs() > 0.0 => s,
+        _ => return base,
+    };
+
+    let mut c = StrokeCollector::new(m, stroke);
+    match geometry {
+        Geometry::Line { points } => c.add_polyline(points, false),
+        Geometry::Polygon { points, holes } => {
+            c.add_polyline(points, true);
+            for hole in holes {
+                c.add_polyline(hole, true);
+            }
+        }
+        Geometry::Rect { .. } | Geometry::Text { .. } => {
+            let corners = extreme_local_points(geometry, m);
+            c.add_polyline(&corners, true);
+        }
+        Geometry::Circle { .. } | Geometry::Ellipse { .. } => {
+            // 극점 순서: [x, x, y, y]
+            for (i, p) in extreme_local_points(geometry, m).into_iter().enumerate() {
+                c.add_axis_extreme(p, i / 2);
+            }
+        }
+        Geometry::Arc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let end = start_angle + sweep;
+            for (i, angle) in circle_extreme_angles(m).into_iter().enumerate() {
+                if angle_in_sweep(angle, *start_angle, sweep) {
+                    c.add_axis_extreme(polar(*center, *radius, angle), i / 2);
+                }
+            }
+            // CCW 진행 방향 접선: (-sinθ, cosθ)
+            let start_p = polar(*center, *radius, *start_angle);
+            let end_p = polar(*center, *radius, end);
+            c.add_cap(start_p, [start_angle.sin(), -start_angle.cos()]);
+            c.add_cap(end_p, [-end.sin(), end.cos()]);
+        }
+        Geometry::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let end = start_angle + sweep;
+            let extremes = ellipse_extreme_angles(m, *center, *rx, *ry, *rotation);
+            for (i, t) in extremes.into_iter().enumerate() {
+                if angle_in_sweep(t, *start_angle, sweep) {
+                    c.add_axis_extreme(ellipse_point(*center, *rx, *ry, *rotation, t), i / 2);
+                }
+            }
+            let start_p = ellipse_point(*center, *rx, *ry, *rotation, *start_angle);
+            let end_p = ellipse_point(*center, *rx, *ry, *rotation, end);
+            let [tx, ty] = ellipse_tangent(*rx, *ry, *rotation, *start_angle);
+            c.add_cap(start_p, [-tx, -ty]);
+            c.add_cap(end_p, ellipse_tangent(*rx, *ry, *rotation, end));
+        }
+        Geometry::Bezier {
+            start,
+            segments,
+            closed,
+        } => {
+            let mut current = *start;
+            let mut tangents: Vec<([f64; 2], [f64; 2])> = Vec::new();
+            for [cp1, cp2, end] in segments {
+                for (t, axis) in cubic_extreme_params(m, current, *cp1, *cp2, *end) {
+                    c.add_axis_extreme(cubic_point(current, *cp1, *cp2, *end, t), axis);
+                }
+                tangents.push(cubic_tangents(current, *cp1, *cp2, *end));
+                current = *end;
+            }
+            if segments.is_empty() {
+                c.add_disk(*start);
+            }
+            // 세그먼트 사이 연결점
+            let mut joint = *start;
+            for (i, [_, _, end]) in segments.iter().enumerate() {
+                if i > 0 {
+                    c.add_join(joint, tangents[i - 1].1, tangents[i].0);
+                }
+                joint = *end;
+            }
+            if let (Some(first), Some(last)) = (tangents.first(), tangents.last()) {
+                if *closed {
+                    c.add_join(*start, last.1, first.0);
+                    c.add_offsets(current, last.1);
+                } else {
+                    c.add_cap(*start, [-first.0[0], -first.0[1]]);
+                    c.add_cap(current, last.1);
+                }
+            }
+        }
+        Geometry::Path { subpaths, .. } => {
+            for subpath in subpaths {
+                let pieces = subpath_pieces(subpath);
+                if pieces.is_empty() {
+                    c.add_disk(subpath.start);
+                    continue;
+                }
+                let tangents: Vec<([f64; 2], [f64; 2])> =
+                    pieces.iter().map(|piece| piece.tangents()).collect();
+                for (i, piece) in pieces.iter().enumerate() {
+                    for (p, axis) in piece_extremes(piece, m) {
+                        c.add_axis_extreme(p, axis);
+                    }
+                    if i > 0 {
+                        c.add_join(piece.start(), tangents[i - 1].1, tangents[i].0);
+                    }
+                }
+                let (first, last) = (tangents[0], tangents[tangents.len() - 1]);
+                let end = pieces[pieces.len() - 1].end();
+                if subpath.closed {
+                    c.add_join(subpath.start, last.1, first.0);
+                } else {
+                    c.add_cap(subpath.start, [-first.0[0], -first.0[1]]);
+                    c.add_cap(end, last.1);
+                }
+            }
+        }
+        Geometry::Dimension { .. } | Geometry::Empty => return base,
+    }
+
+    bbox_union(base, c.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn assert_bbox(actual: Option<BoundingBox>, min: [f64; 2], max: [f64; 2]) {
+        let (amin, amax) = actual.expect("bbox should exist");
+        for i in 0..2 {
+            assert!(
+                approx_eq(amin[i], min[i], 1e-9) && approx_eq(amax[i], max[i], 1e-9),
+                "expected {:?}..{:?}, got {:?}..{:?}",
+                min,
+                max,
+                amin,
+                amax
+            );
+        }
+    }
+
+    fn identity() -> Matrix3x3 {
+        Transform::identity_matrix()
+    }
+
+    fn stroke(width: f64, cap: LineCap, join: LineJoin) -> StrokeStyle {
+        StrokeStyle {
+            width,
+            cap,
+            join,
+            ..StrokeStyle::default()
+        }
+    }
+
+    #[test]
+    fn test_quarter_arc_bbox() {
+        let arc = Geometry::Arc {
+            center: [0.0, 0.0],
+            radius: 10.0,
+            start_angle: 0.0,
+            end_angle: PI / 2.0,
+        };
+        assert_bbox(geometry_bbox(&arc, &identity()), [0.0, 0.0], [10.0, 10.0]);
+    }
+
+    #[test]
+    fn test_arc_crossing_axis_bbox() {
+        // 45° → 135°: 꼭대기(90°) 포함
+        let arc = Geometry::Arc {
+            center: [0.0, 0.0],
+            radius: 10.0,
+            start_angle: PI / 4.0,
+            end_angle: 3.0 * PI / 4.0,
+        };
+        let s = 10.0 * (PI / 4.0).cos();
+        assert_bbox(geometry_bbox(&arc, &identity()), [-s, s], [s, 10.0]);
+    }
+
+    #[test]
+    fn test_bezier_bbox_uses_curve_extrema() {
+        let bezier = Geometry::Bezier {
+            start: [0.0, 0.0],
+            segments: vec![[[0.0, 10.0], [10.0, 10.0], [10.0, 0.0]]],
+            closed: false,
+        };
+        // 제어점 기준이면 max_y = 10, 실제 곡선은 7.5
+        assert_bbox(geometry_bbox(&bezier, &identity()), [0.0, 0.0], [10.0, 7.5]);
+    }
+
+    #[test]
+    fn test_rotated_circle_bbox_is_tight() {
+        let circle = Geometry::Circle {
+            center: [0.0, 0.0],
+            radius: 10.0,
+        };
+        let m = Transform {
+            rotate: PI / 4.0,
+            scale: [2.0, 1.0],
+            ..Default::default()
+        }
+        .to_matrix();
+        // 타원 (반축 20, 10)을 45° 회전 → 반폭 sqrt((400 + 100) / 2)
+        let e = (250.0f64).sqrt();
+        assert_bbox(geometry_bbox(&circle, &m), [-e, -e], [e, e]);
+    }
+
+    #[test]
+    fn test_rotated_ellipse_bbox() {
+        let ellipse = Geometry::Ellipse {
+            center: [5.0, 0.0],
+            rx: 20.0,
+            ry: 10.0,
+            rotation: PI / 2.0,
+        };
+        assert_bbox(
+            geometry_bbox(&ellipse, &identity()),
+            [-5.0, -20.0],
+            [15.0, 20.0],
+        );
+    }
+
+    #[test]
+    fn test_elliptical_arc_bbox() {
+        // 위쪽 절반: 장축 끝점 두 개 + 단축 꼭대기
+        let arc = Geometry::EllipticalArc {
+            center: [0.0, 0.0],
+            rx: 20.0,
+            ry: 10.0,
+            rotation: 0.0,
+            start_angle: 0.0,
+            end_angle: PI,
+        };
+        assert_bbox(geometry_bbox(&arc, &identity()), [-20.0, 0.0], [20.0, 10.0]);
+
+        let s = stroke(2.0, LineCap::Butt, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&arc, &identity(), Some(&s)),
+            [-21.0, 0.0],
+            [21.0, 11.0],
+        );
+    }
+
+    #[test]
+    fn test_visual_bbox_rect_miter() {
+        let rect = Geometry::Rect {
+            center: [0.0, 0.0],
+            width: 10.0,
+            height: 10.0,
+        };
+        let s = stroke(2.0, LineCap::Butt, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&rect, &identity(), Some(&s)),
+            [-6.0, -6.0],
+            [6.0, 6.0],
+        );
+    }
+
+    #[test]
+    fn test_visual_bbox_line_caps() {
+        let line = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.0]],
+        };
+        let butt = stroke(2.0, LineCap::Butt, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&line, &identity(), Some(&butt)),
+            [0.0, -1.0],
+            [10.0, 1.0],
+        );
+        let square = stroke(2.0, LineCap::Square, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&line, &identity(), Some(&square)),
+            [-1.0, -1.0],
+            [11.0, 1.0],
+        );
+        let round = stroke(2.0, LineCap::Round, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&line, &identity(), Some(&round)),
+            [-1.0, -1.0],
+            [11.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn test_visual_bbox_miter_tip_and_limit() {
+        // 90° 꺾임: miter 끝점은 꼭짓점에서 hw*√2 떨어짐
+        let line = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+        };
+        let miter = stroke(2.0, LineCap::Butt, LineJoin::Miter);
+        assert_bbox(
+            visual_bbox(&line, &identity(), Some(&miter)),
+            [0.0, -1.0],
+            [11.0, 10.0],
+        );
+
+        // 매우 뾰족한 각 → miterlimit 초과로 bevel
+        let spike = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.5], [0.0, 1.0]],
+        };
+        let (_, max) = visual_bbox(&spike, &identity(), Some(&miter)).unwrap();
+        assert!(max[0] < 11.0);
+    }
+
+    #[test]
+    fn test_visual_bbox_circle_scaled_stroke() {
+        let circle = Geometry::Circle {
+            center: [0.0, 0.0],
+            radius: 10.0,
+        };
+        let m = Transform {
+            scale: [2.0, 2.0],
+            ..Default::default()
+        }
+        .to_matrix();
+        let s = stroke(2.0, LineCap::Butt, LineJoin::Miter);
+        // 반지름 20 + stroke 반폭 1 * 2
+        assert_bbox(
+            visual_bbox(&circle, &m, Some(&s)),
+            [-22.0, -22.0],
+            [22.0, 22.0],
+        );
+    }
+
+    #[test]
+    fn test_visual_bbox_without_stroke_matches_geometry() {
+        let rect = Geometry::Rect {
+            center: [0.0, 0.0],
+            width: 10.0,
+            height: 4.0,
+        };
+        assert_eq!(
+            visual_bbox(&rect, &identity(), None),
+            geometry_bbox(&rect, &identity())
+        );
+    }
+}
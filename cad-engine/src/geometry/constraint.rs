@@ -0,0 +1,314 @@
+//! 기하 제약 조건 수치 해석 유틸리티
+//!
+//! 제약 조건을 잔차(residual) 함수 F(x) = 0 으로 표현하고 감쇠 가우스-뉴턴(Levenberg-Marquardt)으로 풉니다.
+//! - 야코비안은 중앙 차분으로 계산 (제약 종류마다 도함수를 따로 유지하지 않음)
+//! - 한 단계의 이동량은 최소 노름 해 → 덜 구속된 도형은 필요한 만큼만 움직임
+//! - 해가 없으면(충돌) 잔차 제곱합을 최소화한 지점에서 멈춤
+//! - 자유도/중복 판정: 야코비안 행의 선형 독립성 (그람-슈미트)
+//!
+//! 제약 정의와 Scene 연동(참조 점 해석, 월드 변환)은 scene/constraint.rs에서 처리합니다.
+
+/// 최대 반복 횟수
+const MAX_ITERATIONS: usize = 100;
+/// 감쇠 계수 한계 (넘으면 더 줄일 수 없는 것으로 보고 중단)
+const MAX_DAMPING: f64 = 1e8;
+/// 초기 감쇠 계수 (야코비안 대각 크기 대비)
+const INITIAL_DAMPING: f64 = 1e-9;
+/// 선형 독립 판정 비율 (직교 성분 / 원래 행 노름)
+const INDEPENDENCE_RATIO: f64 = 1e-6;
+
+/// 해석 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolveOutcome {
+    /// 모든 잔차가 허용 오차 이내로 수렴했는지
+    pub converged: bool,
+    /// 감쇠를 늘려도 잔차 제곱합이 줄지 않아 멈췄는지
+    /// (수렴 실패 시 true면 최소 제곱 지점 = 제약 충돌, false면 반복 한도 도달)
+    pub stalled: bool,
+    pub iterations: usize,
+    /// 최종 잔차의 최대 절댓값
+    pub residual: f64,
+}
+
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |acc, v| acc.max(v.abs()))
+}
+
+fn sum_sq(values: &[f64]) -> f64 {
+    values.iter().map(|v| v * v).sum()
+}
+
+/// 자유 변수에 대한 야코비안 (행: 잔차, 열: free가 true인 변수 순서)
+pub fn numeric_jacobian(
+    f: &impl Fn(&[f64]) -> Vec<f64>,
+    x: &[f64],
+    free: &[bool],
+) -> Vec<Vec<f64>> {
+    let m = f(x).len();
+    let columns: Vec<usize> = (0..x.len()).filter(|&i| free[i]).collect();
+    let mut jacobian = vec![vec![0.0; columns.len()]; m];
+    let mut probe = x.to_vec();
+    for (col, &i) in columns.iter().enumerate() {
+        let h = 1e-6 * (1.0 + x[i].abs());
+        probe[i] = x[i] + h;
+        let plus = f(&probe);
+        probe[i] = x[i] - h;
+        let minus = f(&probe);
+        probe[i] = x[i];
+        for row in 0..m {
+            jacobian[row][col] = (plus[row] - minus[row]) / (2.0 * h);
+        }
+    }
+    jacobian
+}
+
+/// 부분 피벗 가우스 소거로 A·y = b를 풉니다 (특이 행렬이면 None).
+pub fn solve_linear(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..n {
+            let factor = a[row][col] / a[col][col];
+            if factor == 0.0 {
+                continue;
+            }
+            let (upper, lower) = a.split_at_mut(row);
+            for (target, source) in lower[0][col..].iter_mut().zip(&upper[col][col..]) {
+                *target -= factor * source;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+    let mut y = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * y[k]).sum();
+        y[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(y)
+}
+
+/// 감쇠 최소제곱 단계: δ = -(JᵀJ + λI)⁻¹ Jᵀ r  (= -Jᵀ (JJᵀ + λI)⁻¹ r, 작은 쪽 행렬로 계산)
+fn damped_step(jacobian: &[Vec<f64>], residual: &[f64], damping: f64) -> Option<Vec<f64>> {
+    let m = jacobian.len();
+    let n = jacobian.first().map_or(0, |row| row.len());
+    if m == 0 || n == 0 {
+        return None;
+    }
+    if n <= m {
+        let mut normal = vec![vec![0.0; n]; n];
+        let mut rhs = vec![0.0; n];
+        for (row, r) in jacobian.iter().zip(residual) {
+            for i in 0..n {
+                rhs[i] -= row[i] * r;
+                for j in 0..n {
+                    normal[i][j] += row[i] * row[j];
+                }
+            }
+        }
+        let scale = (0..n).fold(0.0_f64, |acc, i| acc.max(normal[i][i]));
+        for (i, line) in normal.iter_mut().enumerate() {
+            line[i] += damping * (1.0 + scale);
+        }
+        solve_linear(normal, rhs)
+    } else {
+        let mut normal = vec![vec![0.0; m]; m];
+        for i in 0..m {
+            for j in 0..m {
+                normal[i][j] = (0..n).map(|k| jacobian[i][k] * jacobian[j][k]).sum();
+            }
+        }
+        let scale = (0..m).fold(0.0_f64, |acc, i| acc.max(normal[i][i]));
+        for (i, line) in normal.iter_mut().enumerate() {
+            line[i] += damping * (1.0 + scale);
+        }
+        let y = solve_linear(normal, residual.iter().map(|r| -r).collect())?;
+        Some(
+            (0..n)
+                .map(|k| (0..m).map(|i| jacobian[i][k] * y[i]).sum())
+                .collect(),
+        )
+    }
+}
+
+/// F(x) = 0 을 free가 true인 변수만 바꿔 풉니다 (x를 제자리에서 갱신).
+///
+/// 해가 있으면 시작점에서 가까운 해로 수렴하고, 없으면 잔차 제곱합이 더 줄지 않는 지점에서 멈춥니다.
+///
+/// # Arguments
+/// * `f` - 잔차 함수 (길이는 x와 무관하게 고정)
+/// * `x` - 시작 값, 결과로 갱신
+/// * `free` - 변수별 자유 여부 (x와 같은 길이)
+/// * `tolerance` - 잔차 최대 절댓값 허용 오차
+pub fn solve_least_squares(
+    f: impl Fn(&[f64]) -> Vec<f64>,
+    x: &mut [f64],
+    free: &[bool],
+    tolerance: f64,
+) -> SolveOutcome {
+    let mut residual = f(x);
+    let mut damping = INITIAL_DAMPING;
+    let mut iterations = 0;
+    let mut stalled = false;
+
+    while iterations < MAX_ITERATIONS && max_abs(&residual) > tolerance {
+        iterations += 1;
+        let jacobian = numeric_jacobian(&f, x, free);
+        let cost = sum_sq(&residual);
+
+        let mut improved = false;
+        while damping <= MAX_DAMPING {
+            let Some(step) = damped_step(&jacobian, &residual, damping) else {
+                damping *= 10.0;
+                continue;
+            };
+            let mut trial = x.to_vec();
+            let mut columns = (0..x.len()).filter(|&i| free[i]);
+            for delta in &step {
+                if let Some(i) = columns.next() {
+                    trial[i] += delta;
+                }
+            }
+            let trial_residual = f(&trial);
+            if sum_sq(&trial_residual) < cost {
+                x.copy_from_slice(&trial);
+                residual = trial_residual;
+                damping = (damping / 10.0).max(INITIAL_DAMPING);
+                improved = true;
+                break;
+            }
+            damping *= 10.0;
+        }
+        if !improved {
+            stalled = true;
+            break;
+        }
+    }
+
+    let worst = max_abs(&residual);
+    SolveOutcome {
+        converged: worst <= tolerance,
+        stalled,
+        iterations,
+        residual: worst,
+    }
+}
+
+/// 앞 행부터 차례로 보며 이전 행들과 선형 독립인지 판정합니다.
+///
+/// 0 행(자유 변수와 무관한 잔차)은 독립이 아닌 것으로 봅니다.
+pub fn independent_rows(rows: &[Vec<f64>]) -> Vec<bool> {
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    rows.iter()
+        .map(|row| {
+            let norm = sum_sq(row).sqrt();
+            if norm < 1e-12 {
+                return false;
+            }
+            let mut rest: Vec<f64> = row.iter().map(|v| v / norm).collect();
+            for axis in &basis {
+                let dot: f64 = rest.iter().zip(axis).map(|(a, b)| a * b).sum();
+                for (r, b) in rest.iter_mut().zip(axis) {
+                    *r -= dot * b;
+                }
+            }
+            let rest_norm = sum_sq(&rest).sqrt();
+            if rest_norm <= INDEPENDENCE_RATIO {
+                return false;
+            }
+            basis.push(rest.iter().map(|v| v / rest_norm).collect());
+            true
+        })
+        .collect()
+}
+
+/// 각도를 (-π, π] 로 정규화
+pub fn wrap_angle(angle: f64) -> f64 {
+    use std::f64::consts::PI;
+    let wrapped = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if wrapped <= -PI {
+        wrapped + 2.0 * PI
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_linear() {
+        let y = solve_linear(vec![vec![0.0, 2.0], vec![3.0, 1.0]], vec![4.0, 5.0]).unwrap();
+        assert!((y[0] - 1.0).abs() < 1e-12 && (y[1] - 2.0).abs() < 1e-12);
+        assert!(solve_linear(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_underdetermined_moves_minimally() {
+        // x + y = 2 : 시작점 (0, 0)에서 최소 노름 해 (1, 1)
+        let mut x = [0.0, 0.0];
+        let outcome =
+            solve_least_squares(|v| vec![v[0] + v[1] - 2.0], &mut x, &[true, true], 1e-12);
+        assert!(outcome.converged);
+        assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] - 1.0).abs() < 1e-9);
+
+        // y 고정 → x만 이동
+        let mut x = [0.0, 0.0];
+        solve_least_squares(|v| vec![v[0] + v[1] - 2.0], &mut x, &[true, false], 1e-12);
+        assert!((x[0] - 2.0).abs() < 1e-9 && x[1] == 0.0);
+    }
+
+    #[test]
+    fn test_nonlinear_and_conflicting() {
+        // 원 위의 점 + 직선 x = 3
+        let f = |v: &[f64]| vec![v[0].hypot(v[1]) - 5.0, v[0] - 3.0];
+        let mut x = [1.0, 1.0];
+        let outcome = solve_least_squares(f, &mut x, &[true, true], 1e-12);
+        assert!(outcome.converged);
+        assert!((x[0] - 3.0).abs() < 1e-9 && (x[1] - 4.0).abs() < 1e-9);
+
+        // x = 1 과 x = 3 동시 → 수렴 실패, 중간값
+        let mut x = [0.0];
+        let outcome = solve_least_squares(|v| vec![v[0] - 1.0, v[0] - 3.0], &mut x, &[true], 1e-12);
+        assert!(!outcome.converged && outcome.stalled);
+        assert!((x[0] - 2.0).abs() < 1e-6);
+        assert!((outcome.residual - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_iteration_limit_is_not_stalled() {
+        // e^-x = 0 : 해가 무한대 → 매 단계 x가 1씩 늘며 잔차는 계속 줄어듦
+        let mut x = [0.0];
+        let outcome = solve_least_squares(|v| vec![(-v[0]).exp()], &mut x, &[true], 1e-100);
+        assert!(!outcome.converged && !outcome.stalled);
+        assert_eq!(outcome.iterations, MAX_ITERATIONS);
+    }
+
+    #[test]
+    fn test_independent_rows() {
+        let rows = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![2.0, -3.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![1.0, 1.0, 1.0],
+        ];
+        assert_eq!(
+            independent_rows(&rows),
+            vec![true, true, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_wrap_angle() {
+        use std::f64::consts::PI;
+        assert!((wrap_angle(3.0 * PI / 2.0) + PI / 2.0).abs() < 1e-12);
+        assert!((wrap_angle(-PI) - PI).abs() < 1e-12);
+        assert!(wrap_angle(0.25).eq(&0.25));
+    }
+}
@@ -0,0 +1,321 @@
+//! 모서리 가공 (fillet / chamfer)
+//!
+//! 폴리라인(닫힌 링 또는 열린 경로)의 꺾임점을 둥글게(fillet) 또는 비스듬하게(chamfer) 깎습니다.
+//! - fillet 결과는 큐빅 베지어 세그먼트 (직선 구간도 직선과 정확히 같은 큐빅으로 표현)
+//! - chamfer 결과는 점 배열 (직선만 있으므로 Polygon/Line 그대로 유지)
+//!
+//! 인접한 두 모서리가 한 변을 나눠 쓰므로 반지름/거리는 변 길이의 절반까지로 제한됩니다.
+
+use std::f64::consts::FRAC_PI_2;
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn add_scaled(p: [f64; 2], v: [f64; 2], s: f64) -> [f64; 2] {
+    [p[0] + v[0] * s, p[1] + v[1] * s]
+}
+
+fn length(v: [f64; 2]) -> f64 {
+    v[0].hypot(v[1])
+}
+
+fn normalize(v: [f64; 2]) -> Option<[f64; 2]> {
+    let len = length(v);
+    (len > 1e-12).then(|| [v[0] / len, v[1] / len])
+}
+
+/// 꺾임점 하나의 가공 정보
+struct Corner {
+    /// 들어오는 변 위의 시작점
+    enter: [f64; 2],
+    /// 나가는 변 위의 끝점
+    exit: [f64; 2],
+    /// 들어오는/나가는 방향 (단위벡터)
+    dir_in: [f64; 2],
+    dir_out: [f64; 2],
+    /// 방향 전환 각도 (0 < turn < π)
+    turn: f64,
+    /// 실제 적용된 fillet 반지름 (chamfer면 0)
+    radius: f64,
+}
+
+/// 꺾임점 i에서 각 변을 따라 물러나는 거리(setback)를 계산합니다.
+///
+/// * `setback_for` - 전환 각도 → 원하는 setback
+///
+/// 일직선/되돌아가는 점, 길이 0 변에서는 None
+fn corner_at(
+    points: &[[f64; 2]],
+    i: usize,
+    setback_for: impl Fn(f64) -> f64,
+) -> Option<(Corner, f64)> {
+    let n = points.len();
+    let prev = points[(i + n - 1) % n];
+    let cur = points[i];
+    let next = points[(i + 1) % n];
+    let len_in = length(sub(cur, prev));
+    let len_out = length(sub(next, cur));
+    let dir_in = normalize(sub(cur, prev))?;
+    let dir_out = normalize(sub(next, cur))?;
+
+    let cos = (dir_in[0] * dir_out[0] + dir_in[1] * dir_out[1]).clamp(-1.0, 1.0);
+    let turn = cos.acos();
+    if !(1e-9..=std::f64::consts::PI - 1e-9).contains(&turn) {
+        return None;
+    }
+    let setback = setback_for(turn).min(len_in / 2.0).min(len_out / 2.0);
+    if setback <= 1e-12 {
+        return None;
+    }
+    Some((
+        Corner {
+            enter: add_scaled(cur, dir_in, -setback),
+            exit: add_scaled(cur, dir_out, setback),
+            dir_in,
+            dir_out,
+            turn,
+            radius: 0.0,
+        },
+        setback,
+    ))
+}
+
+/// 선택된 꺾임점 목록 (열린 경로의 양 끝점은 제외)
+fn selected_corners(n: usize, closed: bool, selected: Option<&[usize]>) -> Vec<bool> {
+    let mut mask = vec![selected.is_none(); n];
+    if let Some(indices) = selected {
+        for &i in indices {
+            if i < n {
+                mask[i] = true;
+            }
+        }
+    }
+    if !closed && n > 0 {
+        mask[0] = false;
+        mask[n - 1] = false;
+    }
+    mask
+}
+
+/// 직선과 정확히 일치하는 큐빅 세그먼트 [cp1, cp2, end]
+fn line_segment(from: [f64; 2], to: [f64; 2]) -> [[f64; 2]; 3] {
+    let d = sub(to, from);
+    [
+        add_scaled(from, d, 1.0 / 3.0),
+        add_scaled(from, d, 2.0 / 3.0),
+        to,
+    ]
+}
+
+/// 원호를 큐빅으로 근사 (90° 이하 조각으로 분할, 반지름 오차 < 0.03%)
+fn arc_segments(corner: &Corner) -> Vec<[[f64; 2]; 3]> {
+    let r = corner.radius;
+    // 회전 방향: 좌회전이면 중심이 진행 방향 왼쪽
+    let cross = corner.dir_in[0] * corner.dir_out[1] - corner.dir_in[1] * corner.dir_out[0];
+    let side = if cross >= 0.0 { 1.0 } else { -1.0 };
+    let to_center = [-corner.dir_in[1] * side, corner.dir_in[0] * side];
+    let center = add_scaled(corner.enter, to_center, r);
+
+    let pieces = ((corner.turn / FRAC_PI_2).ceil() as usize).max(1);
+    let step = corner.turn / pieces as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan() * r;
+    let start_angle = (corner.enter[1] - center[1]).atan2(corner.enter[0] - center[0]);
+
+    let point_at = |a: f64| [center[0] + r * a.cos(), center[1] + r * a.sin()];
+    // 진행 방향 접선 (side 방향으로 회전)
+    let tangent_at = |a: f64| [-a.sin() * side, a.cos() * side];
+
+    (0..pieces)
+        .map(|j| {
+            let a0 = start_angle + side * step * j as f64;
+            let a1 = a0 + side * step;
+            let p0 = if j == 0 { corner.enter } else { point_at(a0) };
+            let p1 = if j + 1 == pieces {
+                corner.exit
+            } else {
+                point_at(a1)
+            };
+            [
+                add_scaled(p0, tangent_at(a0), k),
+                add_scaled(p1, tangent_at(a1), -k),
+                p1,
+            ]
+        })
+        .collect()
+}
+
+/// 꺾임점을 반지름 radius로 둥글게 만든 베지어 경로 (start, segments)
+///
+/// # Arguments
+/// * `points` - 폴리라인 (닫힌 경우 마지막 점이 첫 점과 중복되지 않음)
+/// * `closed` - true면 마지막 → 첫 점 변 포함, 결과의 마지막 세그먼트는 start에서 끝남
+/// * `selected` - 둥글게 만들 꺾임점 인덱스 (None이면 전부)
+/// * `radius` - fillet 반지름 (변 길이 절반에 맞게 자동 축소)
+pub fn fillet_path(
+    points: &[[f64; 2]],
+    closed: bool,
+    selected: Option<&[usize]>,
+    radius: f64,
+) -> ([f64; 2], Vec<[[f64; 2]; 3]>) {
+    let n = points.len();
+    if n < 2 {
+        return (points.first().copied().unwrap_or([0.0, 0.0]), Vec::new());
+    }
+    let mask = selected_corners(n, closed, selected);
+    let corners: Vec<Option<Corner>> = (0..n)
+        .map(|i| {
+            if !mask[i] || radius <= 0.0 {
+                return None;
+            }
+            // setback = r·tan(turn/2), 제한되면 반지름도 같은 비율로 축소
+            let (mut corner, setback) = corner_at(points, i, |turn| radius * (turn / 2.0).tan())?;
+            corner.radius = setback / (corner.turn / 2.0).tan();
+            Some(corner)
+        })
+        .collect();
+
+    let enter_of = |i: usize| corners[i].as_ref().map_or(points[i], |c| c.enter);
+    let exit_of = |i: usize| corners[i].as_ref().map_or(points[i], |c| c.exit);
+
+    let start = exit_of(0);
+    let mut segments = Vec::new();
+    let mut current = start;
+    let last = if closed { n } else { n - 1 };
+    for step in 1..=last {
+        let i = step % n;
+        let enter = enter_of(i);
+        if length(sub(enter, current)) > 1e-12 {
+            segments.push(line_segment(current, enter));
+        }
+        current = enter;
+        if let Some(corner) = &corners[i] {
+            segments.extend(arc_segments(corner));
+            current = corner.exit;
+        }
+    }
+    (start, segments)
+}
+
+/// 꺾임점을 distance만큼 비스듬하게 깎은 점 배열
+///
+/// # Arguments
+/// * `points` - 폴리라인 (닫힌 경우 마지막 점이 첫 점과 중복되지 않음)
+/// * `closed` - 닫힌 링 여부
+/// * `selected` - 깎을 꺾임점 인덱스 (None이면 전부)
+/// * `distance` - 꺾임점에서 각 변을 따라 잘라낼 길이 (변 길이 절반에 맞게 자동 축소)
+pub fn chamfer_points(
+    points: &[[f64; 2]],
+    closed: bool,
+    selected: Option<&[usize]>,
+    distance: f64,
+) -> Vec<[f64; 2]> {
+    let n = points.len();
+    let mask = selected_corners(n, closed, selected);
+    let mut result = Vec::with_capacity(n * 2);
+    for i in 0..n {
+        let corner = (mask[i] && distance > 0.0)
+            .then(|| corner_at(points, i, |_| distance))
+            .flatten();
+        match corner {
+            Some((corner, _)) => {
+                result.push(corner.enter);
+                result.push(corner.exit);
+            }
+            None => result.push(points[i]),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::flatten::cubic_point;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn square() -> Vec<[f64; 2]> {
+        vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]]
+    }
+
+    #[test]
+    fn test_fillet_square_all_corners() {
+        let (start, segments) = fillet_path(&square(), true, None, 2.0);
+        // 4 직선 + 4 원호(각 90° → 큐빅 1개)
+        assert_eq!(segments.len(), 8);
+        assert!(approx_eq(start[0], 2.0, 1e-12) && approx_eq(start[1], 0.0, 1e-12));
+        // 닫힌 경로는 start에서 끝남
+        let end = segments.last().unwrap()[2];
+        assert!(approx_eq(end[0], start[0], 1e-12) && approx_eq(end[1], start[1], 1e-12));
+
+        // 모서리 (10,0)의 원호 중간점은 중심 (8,2)에서 반지름 2
+        let mut current = start;
+        for seg in &segments {
+            if approx_eq(current[0], 8.0, 1e-12) && approx_eq(current[1], 0.0, 1e-12) {
+                let mid = cubic_point(current, seg[0], seg[1], seg[2], 0.5);
+                let r = (mid[0] - 8.0).hypot(mid[1] - 2.0);
+                assert!(approx_eq(r, 2.0, 1e-3));
+            }
+            current = seg[2];
+        }
+    }
+
+    #[test]
+    fn test_fillet_radius_clamped_to_half_edge() {
+        let (start, _) = fillet_path(&square(), true, None, 100.0);
+        assert!(approx_eq(start[0], 5.0, 1e-12));
+    }
+
+    #[test]
+    fn test_fillet_selected_corner_open_line() {
+        let line = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]];
+        let (start, segments) = fillet_path(&line, false, None, 3.0);
+        assert_eq!(start, [0.0, 0.0]);
+        // 직선 + 원호 + 직선
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments.last().unwrap()[2], [10.0, 10.0]);
+    }
+
+    #[test]
+    fn test_fillet_reflex_corner() {
+        // L자 (오목 꼭짓점 (5,5)만 선택)
+        let l_shape = [
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 5.0],
+            [5.0, 5.0],
+            [5.0, 10.0],
+            [0.0, 10.0],
+        ];
+        let (_, segments) = fillet_path(&l_shape, true, Some(&[3]), 1.0);
+        assert_eq!(segments.len(), 7);
+    }
+
+    #[test]
+    fn test_chamfer_square_selected() {
+        let result = chamfer_points(&square(), true, Some(&[1]), 2.0);
+        assert_eq!(
+            result,
+            vec![
+                [0.0, 0.0],
+                [8.0, 0.0],
+                [10.0, 2.0],
+                [10.0, 10.0],
+                [0.0, 10.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chamfer_skips_line_endpoints() {
+        let line = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]];
+        let result = chamfer_points(&line, false, None, 1.0);
+        assert_eq!(
+            result,
+            vec![[0.0, 0.0], [9.0, 0.0], [10.0, 1.0], [10.0, 10.0]]
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! 치수(dimension) 레이아웃 계산
+//!
+//! 측정점(월드 좌표)으로부터 치수값, 치수선, 보조선, 화살표, 문자 위치를 계산합니다.
+//! 참조 Entity 해석은 `scene/dimension.rs`에서 처리하며, 이 모듈은 Scene에 의존하지 않습니다.
+
+use std::f64::consts::PI;
+
+use serde::Serialize;
+
+use super::flatten::arc_sweep;
+use super::text::FontMetrics;
+use crate::scene::entity::{DimensionAxis, Matrix3x3, Transform};
+
+/// 보조선이 치수선 너머로 나가는 길이 (text_size 배수)
+const EXTENSION_OVERSHOOT: f64 = 0.25;
+/// 화살표 길이 (text_size 배수)
+const ARROW_LENGTH: f64 = 0.6;
+/// 치수선과 문자 사이 간격 (text_size 배수)
+const TEXT_GAP: f64 = 0.8;
+
+/// 계산된 치수 도면 요소
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DimensionLayout {
+    /// 측정값 (거리 또는 도 단위 각도)
+    pub value: f64,
+    /// 표시 문자 (예: "25.00", "R10.00", "Ø20.00", "45.00°")
+    pub text: String,
+    /// 문자 중심점
+    pub text_position: [f64; 2],
+    /// 문자 회전 (라디안, 읽을 수 있는 방향으로 정규화)
+    pub text_angle: f64,
+    pub text_size: f64,
+    /// 치수선/보조선/지시선 (선분)
+    pub lines: Vec<[[f64; 2]; 2]>,
+    /// 호 치수선 (Angular, 폴리라인)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub arcs: Vec<Vec<[f64; 2]>>,
+    /// 화살표 (채워진 삼각형, 첫 점이 끝)
+    pub arrows: Vec<[[f64; 2]; 3]>,
+}
+
+impl DimensionLayout {
+    /// 바운드 계산용 점들 (선, 호, 화살표, 문자 상자 꼭짓점)
+    pub fn points(&self) -> Vec<[f64; 2]> {
+        let mut points: Vec<[f64; 2]> = self.lines.iter().flatten().copied().collect();
+        points.extend(self.arcs.iter().flatten().copied());
+        points.extend(self.arrows.iter().flatten().copied());
+        points.extend(self.text_box());
+        points
+    }
+
+    /// 문자 상자 네 꼭짓점 (회전 반영)
+    pub fn text_box(&self) -> [[f64; 2]; 4] {
+        let metrics = FontMetrics::for_family("sans-serif");
+        let hw = metrics.line_width(&self.text) * self.text_size / 2.0;
+        let hh = (metrics.ascent + metrics.descent) * self.text_size / 2.0;
+        let (sin, cos) = self.text_angle.sin_cos();
+        let [cx, cy] = self.text_position;
+        [[-hw, -hh], [hw, -hh], [hw, hh], [-hw, hh]]
+            .map(|[x, y]| [cx + x * cos - y * sin, cy + x * sin + y * cos])
+    }
+
+    /// 모든 점에 변환 행렬을 적용한 레이아웃 (문자 크기/각도도 함께 변환)
+    pub fn transformed(&self, m: &Matrix3x3) -> Self {
+        let tp = |p: [f64; 2]| Transform::transform_point(m, p);
+        let (sin, cos) = self.text_angle.sin_cos();
+        let dir = [m[0][0] * cos + m[0][1] * sin, m[1][0] * cos + m[1][1] * sin];
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        Self {
+            value: self.value,
+            text: self.text.clone(),
+            text_position: tp(self.text_position),
+            text_angle: dir[1].atan2(dir[0]),
+            text_size: self.text_size * det.abs().sqrt(),
+            lines: self.lines.iter().map(|l| l.map(tp)).collect(),
+            arcs: self
+                .arcs
+                .iter()
+                .map(|a| a.iter().map(|p| tp(*p)).collect())
+                .collect(),
+            arrows: self.arrows.iter().map(|a| a.map(tp)).collect(),
+        }
+    }
+}
+
+fn add(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn scale(v: [f64; 2], s: f64) -> [f64; 2] {
+    [v[0] * s, v[1] * s]
+}
+
+fn unit(angle: f64) -> [f64; 2] {
+    [angle.cos(), angle.sin()]
+}
+
+/// 문자가 뒤집혀 보이지 않도록 각도를 (-π/2, π/2]로 정규화
+fn readable_angle(angle: f64) -> f64 {
+    let a = (angle + PI).rem_euclid(2.0 * PI) - PI;
+    if a > PI / 2.0 + 1e-9 {
+        a - PI
+    } else if a <= -PI / 2.0 + 1e-9 {
+        a + PI
+    } else {
+        a
+    }
+}
+
+/// 치수값 문자열 (소수점 precision 자리)
+pub fn format_value(value: f64, precision: u32) -> String {
+    format!("{:.*}", precision as usize, value)
+}
+
+/// 끝점 tip에서 dir(단위벡터) 방향을 가리키는 화살표
+fn arrow(tip: [f64; 2], dir: [f64; 2], text_size: f64) -> [[f64; 2]; 3] {
+    let len = ARROW_LENGTH * text_size;
+    let half = len / 3.0;
+    let back = add(tip, scale(dir, -len));
+    let n = [-dir[1] * half, dir[0] * half];
+    [tip, add(back, n), add(back, scale(n, -1.0))]
+}
+
+/// 양 끝 화살표가 있는 직선 치수의 공통 레이아웃
+#[allow(clippy::too_many_arguments)]
+fn straight_layout(
+    p1: [f64; 2],
+    p2: [f64; 2],
+    d1: [f64; 2],
+    d2: [f64; 2],
+    outward: [f64; 2],
+    value: f64,
+    text: String,
+    text_size: f64,
+) -> DimensionLayout {
+    let mut lines = Vec::new();
+    // 보조선: 측정점 → 치수선 + overshoot (길이 0이면 생략)
+    for (p, d) in [(p1, d1), (p2, d2)] {
+        if (d[0] - p[0]).abs() > 1e-12 || (d[1] - p[1]).abs() > 1e-12 {
+            lines.push([p, add(d, scale(outward, EXTENSION_OVERSHOOT * text_size))]);
+        }
+    }
+    lines.push([d1, d2]);
+
+    let len = ((d2[0] - d1[0]).powi(2) + (d2[1] - d1[1]).powi(2)).sqrt();
+    let mut arrows = Vec::new();
+    if len > 1e-12 {
+        let u = [(d2[0] - d1[0]) / len, (d2[1] - d1[1]) / len];
+        arrows.push(arrow(d1, scale(u, -1.0), text_size));
+        arrows.push(arrow(d2, u, text_size));
+    }
+
+    let mid = scale(add(d1, d2), 0.5);
+    let text_angle = readable_angle(outward[1].atan2(outward[0]) - PI / 2.0);
+    DimensionLayout {
+        value,
+        text,
+        text_position: add(mid, scale(outward, TEXT_GAP * text_size)),
+        text_angle,
+        text_size,
+        lines,
+        arcs: Vec::new(),
+        arrows,
+    }
+}
+
+/// 수평/수직 치수
+pub fn linear_layout(
+    p1: [f64; 2],
+    p2: [f64; 2],
+    axis: DimensionAxis,
+    offset: f64,
+    text_size: f64,
+    precision: u32,
+) -> DimensionLayout {
+    // 측정 축에 수직인 좌표 성분 (Horizontal → y, Vertical → x)
+    let (along, across) = match axis {
+        DimensionAxis::Horizontal => (0, 1),
+        DimensionAxis::Vertical => (1, 0),
+    };
+    let sign = if offset < 0.0 { -1.0 } else { 1.0 };
+    let base = if sign > 0.0 {
+        p1[across].max(p2[across])
+    } else {
+        p1[across].min(p2[across])
+    };
+    let line_pos = base + offset;
+
+    let mut d1 = p1;
+    let mut d2 = p2;
+    d1[across] = line_pos;
+    d2[across] = line_pos;
+    let mut outward = [0.0, 0.0];
+    outward[across] = sign;
+
+    let value = (p2[along] - p1[along]).abs();
+    straight_layout(
+        p1,
+        p2,
+        d1,
+        d2,
+        outward,
+        value,
+        format_value(value, precision),
+        text_size,
+    )
+}
+
+/// 정렬(aligned) 치수: from → to 방향과 평행한 치수선
+pub fn aligned_layout(
+    p1: [f64; 2],
+    p2: [f64; 2],
+    offset: f64,
+    text_size: f64,
+    precision: u32,
+) -> DimensionLayout {
+    let dx = p2[0] - p1[0];
+    let dy = p2[1] - p1[1];
+    let value = (dx * dx + dy * dy).sqrt();
+    let u = if value > 1e-12 {
+        [dx / value, dy / value]
+    } else {
+        [1.0, 0.0]
+    };
+    let n = [-u[1], u[0]];
+    let sign = if offset < 0.0 { -1.0 } else { 1.0 };
+    let d1 = add(p1, scale(n, offset));
+    let d2 = add(p2, scale(n, offset));
+    straight_layout(
+        p1,
+        p2,
+        d1,
+        d2,
+        scale(n, sign),
+        value,
+        format_value(value, precision),
+        text_size,
+    )
+}
+
+/// 반지름/지름 치수
+///
+/// * `diameter` - true면 중심을 지나는 지름선, false면 중심 → 원주 지시선
+pub fn radial_layout(
+    center: [f64; 2],
+    radius: f64,
+    angle: f64,
+    diameter: bool,
+    text_size: f64,
+    precision: u32,
+) -> DimensionLayout {
+    let d = unit(angle);
+    let rim = add(center, scale(d, radius));
+    let metrics = FontMetrics::for_family("sans-serif");
+
+    if diameter {
+        let value = radius * 2.0;
+        let text = format!("Ø{}", format_value(value, precision));
+        let opposite = add(center, scale(d, -radius));
+        let n = [-d[1], d[0]];
+        DimensionLayout {
+            value,
+            text,
+            text_position: add(center, scale(n, TEXT_GAP * text_size)),
+            text_angle: readable_angle(angle),
+            text_size,
+            lines: vec![[opposite, rim]],
+            arcs: Vec::new(),
+            arrows: vec![
+                arrow(rim, d, text_size),
+                arrow(opposite, scale(d, -1.0), text_size),
+            ],
+        }
+    } else {
+        let value = radius;
+        let text = format!("R{}", format_value(value, precision));
+        // 문자는 원 바깥, 지시선 연장선 위
+        let half_width = metrics.line_width(&text) * text_size / 2.0;
+        let text_position = add(rim, scale(d, TEXT_GAP * text_size + half_width));
+        DimensionLayout {
+            value,
+            text,
+            text_position,
+            text_angle: readable_angle(angle),
+            text_size,
+            lines: vec![[center, rim]],
+            arcs: Vec::new(),
+            arrows: vec![arrow(rim, d, text_size)],
+        }
+    }
+}
+
+/// 두 선 사이 각도 치수
+///
+/// # Arguments
+/// * `vertex` - 두 직선의 교점
+/// * `angle_a`, `angle_b` - 교점에서 각 선분 쪽을 향하는 방향 (라디안)
+/// * `reach_a`, `reach_b` - 교점에서 각 선분 끝까지의 최대 거리 (보조선 길이 계산용)
+/// * `radius` - 치수 호의 반지름
+///
+/// 두 방향 사이의 작은 쪽(≤ 180°)을 측정합니다.
+#[allow(clippy::too_many_arguments)]
+pub fn angular_layout(
+    vertex: [f64; 2],
+    angle_a: f64,
+    angle_b: f64,
+    reach_a: f64,
+    reach_b: f64,
+    radius: f64,
+    text_size: f64,
+    precision: u32,
+) -> DimensionLayout {
+    let mut start = angle_a;
+    let mut reach_start = reach_a;
+    let mut reach_end = reach_b;
+    let mut sweep = arc_sweep(angle_a, angle_b);
+    if sweep > PI {
+        start = angle_b;
+        std::mem::swap(&mut reach_start, &mut reach_end);
+        sweep = 2.0 * PI - sweep;
+    }
+    let end = start + sweep;
+    let radius = radius.abs();
+
+    let steps = ((32.0 * sweep / PI).ceil() as usize).max(4);
+    let arc: Vec<[f64; 2]> = (0..=steps)
+        .map(|i| {
+            add(
+                vertex,
+                scale(unit(start + sweep * i as f64 / steps as f64), radius),
+            )
+        })
+        .collect();
+
+    // 호가 선분 바깥에 있으면 보조선으로 연결
+    let mut lines = Vec::new();
+    for (angle, reach) in [(start, reach_start), (end, reach_end)] {
+        if radius > reach {
+            let u = unit(angle);
+            lines.push([
+                add(vertex, scale(u, reach)),
+                add(vertex, scale(u, radius + EXTENSION_OVERSHOOT * text_size)),
+            ]);
+        }
+    }
+
+    let start_p = add(vertex, scale(unit(start), radius));
+    let end_p = add(vertex, scale(unit(end), radius));
+    // CCW 접선: (-sin, cos)
+    let tangent = |a: f64| [-a.sin(), a.cos()];
+    let arrows = vec![
+        arrow(start_p, scale(tangent(start), -1.0), text_size),
+        arrow(end_p, tangent(end), text_size),
+    ];
+
+    let value = sweep.to_degrees();
+    let mid = start + sweep / 2.0;
+    DimensionLayout {
+        value,
+        text: format!("{}°", format_value(value, precision)),
+        text_position: add(vertex, scale(unit(mid), radius + TEXT_GAP * text_size)),
+        text_angle: readable_angle(mid - PI / 2.0),
+        text_size,
+        lines,
+        arcs: vec![arc],
+        arrows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_linear_horizontal() {
+        let layout = linear_layout(
+            [0.0, 0.0],
+            [30.0, 10.0],
+            DimensionAxis::Horizontal,
+            5.0,
+            4.0,
+            1,
+        );
+        assert!(approx_eq(layout.value, 30.0, 1e-12));
+        assert_eq!(layout.text, "30.0");
+        // 치수선은 더 높은 점(y=10)에서 offset만큼 위
+        let dim_line = layout.lines.last().unwrap();
+        assert_eq!(dim_line[0], [0.0, 15.0]);
+        assert_eq!(dim_line[1], [30.0, 15.0]);
+        assert!(layout.text_position[1] > 15.0);
+        assert!(approx_eq(layout.text_angle, 0.0, 1e-12));
+        assert_eq!(layout.arrows.len(), 2);
+    }
+
+    #[test]
+    fn test_linear_vertical_text_is_readable() {
+        let layout = linear_layout(
+            [0.0, 0.0],
+            [10.0, 20.0],
+            DimensionAxis::Vertical,
+            -5.0,
+            4.0,
+            2,
+        );
+        assert!(approx_eq(layout.value, 20.0, 1e-12));
+        assert!(layout.text_angle.abs() <= PI / 2.0 + 1e-9);
+        let dim_line = layout.lines.last().unwrap();
+        assert_eq!(dim_line[0][0], -5.0);
+    }
+
+    #[test]
+    fn test_aligned_distance() {
+        let layout = aligned_layout([0.0, 0.0], [3.0, 4.0], 2.0, 4.0, 2);
+        assert!(approx_eq(layout.value, 5.0, 1e-12));
+        assert_eq!(layout.text, "5.00");
+    }
+
+    #[test]
+    fn test_radial_text() {
+        let radius = radial_layout([0.0, 0.0], 10.0, 0.0, false, 4.0, 1);
+        assert_eq!(radius.text, "R10.0");
+        assert!(radius.text_position[0] > 10.0);
+
+        let diameter = radial_layout([0.0, 0.0], 10.0, 0.0, true, 4.0, 0);
+        assert_eq!(diameter.text, "Ø20");
+        assert_eq!(diameter.lines[0], [[-10.0, 0.0], [10.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_angular_picks_smaller_angle() {
+        // 0° 와 270°(= -90°) 사이 → 90°
+        let layout = angular_layout([0.0, 0.0], 0.0, 1.5 * PI, 10.0, 10.0, 5.0, 2.0, 1);
+        assert!(approx_eq(layout.value, 90.0, 1e-9));
+        assert_eq!(layout.text, "90.0°");
+        // 호가 선분 안쪽이므로 보조선 없음
+        assert!(layout.lines.is_empty());
+    }
+
+    #[test]
+    fn test_transformed_layout() {
+        let layout = aligned_layout([0.0, 0.0], [10.0, 0.0], 2.0, 4.0, 2);
+        let m = Transform {
+            translate: [5.0, 0.0],
+            scale: [2.0, 2.0],
+            ..Default::default()
+        }
+        .to_matrix();
+        let moved = layout.transformed(&m);
+        assert!(approx_eq(moved.text_size, 8.0, 1e-12));
+        assert_eq!(moved.lines.last().unwrap()[0], [5.0, 4.0]);
+        // 값은 그대로 (측정은 월드에서 수행)
+        assert_eq!(moved.value, layout.value);
+    }
+}
@@ -0,0 +1,503 @@
+//! 곡선 간 최단 거리 유틸리티
+//!
+//! 선분과 원호(원 포함)로 분해된 외곽선 사이의 최단 거리와 가장 가까운 두 점을 해석적으로 계산합니다.
+//! 최단 거리는 다음 후보 중 하나에서 나옵니다.
+//! - 두 곡선의 교점 (거리 0)
+//! - 한 곡선의 끝점 ↔ 다른 곡선 위의 최근접점
+//! - 두 곡선에 모두 수직인 내부 점 쌍 (선분-호: 중심에서 내린 수선, 호-호: 중심을 잇는 직선)
+//!
+//! 곡선 분해(월드 변환, 근사)는 scene/distance.rs에서 처리합니다.
+
+use std::f64::consts::PI;
+
+/// 각도/매개변수 비교 허용 오차
+const EPSILON: f64 = 1e-12;
+
+/// 외곽선 조각
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    Segment([f64; 2], [f64; 2]),
+    /// 원호 (start에서 CCW로 sweep만큼, sweep이 2π면 원 전체)
+    Arc {
+        center: [f64; 2],
+        radius: f64,
+        start: f64,
+        sweep: f64,
+    },
+}
+
+/// 최단 거리와 각 곡선 위의 가장 가까운 점
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Closest {
+    pub distance: f64,
+    pub a: [f64; 2],
+    pub b: [f64; 2],
+}
+
+impl Closest {
+    fn between(a: [f64; 2], b: [f64; 2]) -> Self {
+        Closest {
+            distance: (b[0] - a[0]).hypot(b[1] - a[1]),
+            a,
+            b,
+        }
+    }
+
+    fn swapped(self) -> Self {
+        Closest {
+            distance: self.distance,
+            a: self.b,
+            b: self.a,
+        }
+    }
+
+    fn nearer(self, other: Self) -> Self {
+        if other.distance < self.distance {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+fn arc_point(center: [f64; 2], radius: f64, angle: f64) -> [f64; 2] {
+    [
+        center[0] + radius * angle.cos(),
+        center[1] + radius * angle.sin(),
+    ]
+}
+
+/// 각도가 호의 스윕 범위 안에 있는지 확인합니다 (시작/끝 각도에서 EPSILON 여유).
+pub fn arc_contains(start: f64, sweep: f64, angle: f64) -> bool {
+    let offset = (angle - start).rem_euclid(2.0 * PI);
+    sweep >= 2.0 * PI - EPSILON || offset <= sweep + EPSILON || offset >= 2.0 * PI - EPSILON
+}
+
+/// 점이 (원 위에 있다고 가정할 때) 호 범위 안에 있는지 확인합니다.
+fn arc_contains_point(center: [f64; 2], start: f64, sweep: f64, p: [f64; 2]) -> bool {
+    arc_contains(start, sweep, (p[1] - center[1]).atan2(p[0] - center[0]))
+}
+
+/// 선분 위에서 점에 가장 가까운 점
+fn closest_on_segment(p: [f64; 2], a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let len_sq = d[0] * d[0] + d[1] * d[1];
+    if len_sq <= 0.0 {
+        return a;
+    }
+    let t = (((p[0] - a[0]) * d[0] + (p[1] - a[1]) * d[1]) / len_sq).clamp(0.0, 1.0);
+    [a[0] + t * d[0], a[1] + t * d[1]]
+}
+
+/// 곡선 위에서 점에 가장 가까운 점
+pub fn closest_point_on_curve(curve: &Curve, p: [f64; 2]) -> [f64; 2] {
+    match *curve {
+        Curve::Segment(a, b) => closest_on_segment(p, a, b),
+        Curve::Arc {
+            center,
+            radius,
+            start,
+            sweep,
+        } => {
+            let (dx, dy) = (p[0] - center[0], p[1] - center[1]);
+            let start_point = arc_point(center, radius, start);
+            if dx.hypot(dy) <= EPSILON {
+                // 중심: 모든 점이 같은 거리
+                return start_point;
+            }
+            let angle = dy.atan2(dx);
+            if arc_contains(start, sweep, angle) {
+                return arc_point(center, radius, angle);
+            }
+            let end_point = arc_point(center, radius, start + sweep);
+            let dist = |q: [f64; 2]| (q[0] - p[0]).hypot(q[1] - p[1]);
+            if dist(start_point) <= dist(end_point) {
+                start_point
+            } else {
+                end_point
+            }
+        }
+    }
+}
+
+/// 곡선의 끝점 (원 전체는 시작점 하나)
+fn endpoints(curve: &Curve) -> Vec<[f64; 2]> {
+    match *curve {
+        Curve::Segment(a, b) => vec![a, b],
+        Curve::Arc {
+            center,
+            radius,
+            start,
+            sweep,
+        } => {
+            if sweep >= 2.0 * PI - EPSILON {
+                vec![arc_point(center, radius, start)]
+            } else {
+                vec![
+                    arc_point(center, radius, start),
+                    arc_point(center, radius, start + sweep),
+                ]
+            }
+        }
+    }
+}
+
+/// 두 선분의 교점 (겹치면 겹친 구간의 한 끝점)
+fn segment_intersection(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> Option<[f64; 2]> {
+    let r = [b[0] - a[0], b[1] - a[1]];
+    let s = [d[0] - c[0], d[1] - c[1]];
+    let denom = r[0] * s[1] - r[1] * s[0];
+    let qp = [c[0] - a[0], c[1] - a[1]];
+    let scale = (r[0].hypot(r[1]) * s[0].hypot(s[1])).max(f64::MIN_POSITIVE);
+    if denom.abs() > EPSILON * scale {
+        let t = (qp[0] * s[1] - qp[1] * s[0]) / denom;
+        let u = (qp[0] * r[1] - qp[1] * r[0]) / denom;
+        let range = -EPSILON..=1.0 + EPSILON;
+        return (range.contains(&t) && range.contains(&u)).then(|| {
+            [
+                a[0] + t.clamp(0.0, 1.0) * r[0],
+                a[1] + t.clamp(0.0, 1.0) * r[1],
+            ]
+        });
+    }
+    // 평행: 한 선분의 끝점이 다른 선분 위에 있으면 교차 (공선 겹침)
+    let on = |p: [f64; 2], s0: [f64; 2], s1: [f64; 2]| {
+        let q = closest_on_segment(p, s0, s1);
+        (q[0] - p[0]).hypot(q[1] - p[1]) <= EPSILON * scale.sqrt().max(1.0)
+    };
+    [(a, c, d), (b, c, d), (c, a, b), (d, a, b)]
+        .into_iter()
+        .find(|(p, s0, s1)| on(*p, *s0, *s1))
+        .map(|(p, _, _)| p)
+}
+
+/// 선분과 원의 교점 중 선분 위에 있는 것들
+fn segment_circle_intersections(
+    a: [f64; 2],
+    b: [f64; 2],
+    center: [f64; 2],
+    radius: f64,
+) -> Vec<[f64; 2]> {
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let f = [a[0] - center[0], a[1] - center[1]];
+    let qa = d[0] * d[0] + d[1] * d[1];
+    if qa <= 0.0 {
+        return Vec::new();
+    }
+    let qb = 2.0 * (f[0] * d[0] + f[1] * d[1]);
+    let qc = f[0] * f[0] + f[1] * f[1] - radius * radius;
+    let disc = qb * qb - 4.0 * qa * qc;
+    if disc < 0.0 {
+        return Vec::new();
+    }
+    let root = disc.sqrt();
+    [(-qb - root) / (2.0 * qa), (-qb + root) / (2.0 * qa)]
+        .into_iter()
+        .filter(|t| (-EPSILON..=1.0 + EPSILON).contains(t))
+        .map(|t| [a[0] + t * d[0], a[1] + t * d[1]])
+        .collect()
+}
+
+/// 두 원의 교점
+fn circle_intersections(c1: [f64; 2], r1: f64, c2: [f64; 2], r2: f64) -> Vec<[f64; 2]> {
+    let (dx, dy) = (c2[0] - c1[0], c2[1] - c1[1]);
+    let d = dx.hypot(dy);
+    if d <= EPSILON || d > r1 + r2 || d < (r1 - r2).abs() {
+        return Vec::new();
+    }
+    let along = (d * d + r1 * r1 - r2 * r2) / (2.0 * d);
+    let h = (r1 * r1 - along * along).max(0.0).sqrt();
+    let base = [c1[0] + along * dx / d, c1[1] + along * dy / d];
+    vec![
+        [base[0] - h * dy / d, base[1] + h * dx / d],
+        [base[0] + h * dy / d, base[1] - h * dx / d],
+    ]
+}
+
+/// 두 곡선의 교점 하나 (없으면 None)
+fn intersection(a: &Curve, b: &Curve) -> Option<[f64; 2]> {
+    match (*a, *b) {
+        (Curve::Segment(p0, p1), Curve::Segment(q0, q1)) => segment_intersection(p0, p1, q0, q1),
+        (
+            Curve::Segment(p0, p1),
+            Curve::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            },
+        )
+        | (
+            Curve::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            },
+            Curve::Segment(p0, p1),
+        ) => segment_circle_intersections(p0, p1, center, radius)
+            .into_iter()
+            .find(|p| arc_contains_point(center, start, sweep, *p)),
+        (
+            Curve::Arc {
+                center: c1,
+                radius: r1,
+                start: s1,
+                sweep: w1,
+            },
+            Curve::Arc {
+                center: c2,
+                radius: r2,
+                start: s2,
+                sweep: w2,
+            },
+        ) => circle_intersections(c1, r1, c2, r2)
+            .into_iter()
+            .find(|p| arc_contains_point(c1, s1, w1, *p) && arc_contains_point(c2, s2, w2, *p)),
+    }
+}
+
+/// 두 곡선에 모두 수직인 내부 점 쌍 (a 위의 점, b 위의 점)
+fn interior_pairs(a: &Curve, b: &Curve) -> Vec<([f64; 2], [f64; 2])> {
+    match (*a, *b) {
+        (Curve::Segment(..), Curve::Segment(..)) => Vec::new(),
+        (Curve::Arc { .. }, Curve::Segment(..)) => interior_pairs(b, a)
+            .into_iter()
+            .map(|(p, q)| (q, p))
+            .collect(),
+        (
+            Curve::Segment(p0, p1),
+            Curve::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            },
+        ) => {
+            // 중심에서 선분(직선)에 내린 수선의 발
+            let d = [p1[0] - p0[0], p1[1] - p0[1]];
+            let len_sq = d[0] * d[0] + d[1] * d[1];
+            if len_sq <= 0.0 {
+                return Vec::new();
+            }
+            let t = ((center[0] - p0[0]) * d[0] + (center[1] - p0[1]) * d[1]) / len_sq;
+            if !(0.0..=1.0).contains(&t) {
+                return Vec::new();
+            }
+            let foot = [p0[0] + t * d[0], p0[1] + t * d[1]];
+            let (dx, dy) = (foot[0] - center[0], foot[1] - center[1]);
+            let len = dx.hypot(dy);
+            if len <= EPSILON {
+                return Vec::new();
+            }
+            [1.0, -1.0]
+                .into_iter()
+                .map(|sign| {
+                    [
+                        center[0] + sign * radius * dx / len,
+                        center[1] + sign * radius * dy / len,
+                    ]
+                })
+                .filter(|q| arc_contains_point(center, start, sweep, *q))
+                .map(|q| (foot, q))
+                .collect()
+        }
+        (
+            Curve::Arc {
+                center: c1,
+                radius: r1,
+                start: s1,
+                sweep: w1,
+            },
+            Curve::Arc {
+                center: c2,
+                radius: r2,
+                start: s2,
+                sweep: w2,
+            },
+        ) => {
+            // 중심을 잇는 직선 위의 점들
+            let (dx, dy) = (c2[0] - c1[0], c2[1] - c1[1]);
+            let len = dx.hypot(dy);
+            if len <= EPSILON {
+                return Vec::new();
+            }
+            let u = [dx / len, dy / len];
+            let mut pairs = Vec::new();
+            for s in [1.0, -1.0] {
+                let p = [c1[0] + s * r1 * u[0], c1[1] + s * r1 * u[1]];
+                if !arc_contains_point(c1, s1, w1, p) {
+                    continue;
+                }
+                for t in [1.0, -1.0] {
+                    let q = [c2[0] + t * r2 * u[0], c2[1] + t * r2 * u[1]];
+                    if arc_contains_point(c2, s2, w2, q) {
+                        pairs.push((p, q));
+                    }
+                }
+            }
+            pairs
+        }
+    }
+}
+
+/// 두 곡선 사이 최단 거리와 가장 가까운 두 점
+pub fn curve_distance(a: &Curve, b: &Curve) -> Closest {
+    if let Some(p) = intersection(a, b) {
+        return Closest::between(p, p);
+    }
+    let from_a = endpoints(a)
+        .into_iter()
+        .map(|p| Closest::between(p, closest_point_on_curve(b, p)));
+    let from_b = endpoints(b)
+        .into_iter()
+        .map(|q| Closest::between(q, closest_point_on_curve(a, q)).swapped());
+    let interior = interior_pairs(a, b)
+        .into_iter()
+        .map(|(p, q)| Closest::between(p, q));
+    from_a
+        .chain(from_b)
+        .chain(interior)
+        .reduce(Closest::nearer)
+        .expect("every curve has at least one endpoint")
+}
+
+/// 두 곡선 집합 사이 최단 거리 (어느 한쪽이 비어 있으면 None)
+pub fn curves_distance(a: &[Curve], b: &[Curve]) -> Option<Closest> {
+    let mut best: Option<Closest> = None;
+    for ca in a {
+        for cb in b {
+            let closest = curve_distance(ca, cb);
+            best = Some(best.map_or(closest, |current| current.nearer(closest)));
+            if closest.distance == 0.0 {
+                return best;
+            }
+        }
+    }
+    best
+}
+
+/// 폴리라인을 선분 조각들로 분해합니다 (closed면 닫는 변 포함, 점 하나면 길이 0 선분).
+pub fn polyline_curves(points: &[[f64; 2]], closed: bool) -> Vec<Curve> {
+    match points {
+        [] => Vec::new(),
+        [only] => vec![Curve::Segment(*only, *only)],
+        _ => {
+            let closing = (closed && points.len() > 2)
+                .then(|| Curve::Segment(points[points.len() - 1], points[0]));
+            points
+                .windows(2)
+                .map(|w| Curve::Segment(w[0], w[1]))
+                .chain(closing)
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn circle(center: [f64; 2], radius: f64) -> Curve {
+        Curve::Arc {
+            center,
+            radius,
+            start: 0.0,
+            sweep: 2.0 * PI,
+        }
+    }
+
+    #[test]
+    fn test_segment_distances() {
+        let a = Curve::Segment([0.0, 0.0], [10.0, 0.0]);
+        let parallel = Curve::Segment([2.0, 3.0], [5.0, 3.0]);
+        let closest = curve_distance(&a, &parallel);
+        assert!(approx_eq(closest.distance, 3.0));
+        assert!(approx_eq(closest.a[1], 0.0) && approx_eq(closest.b[1], 3.0));
+
+        let crossing = Curve::Segment([4.0, -1.0], [4.0, 1.0]);
+        let closest = curve_distance(&a, &crossing);
+        assert_eq!(closest.distance, 0.0);
+        assert!(approx_eq(closest.a[0], 4.0));
+
+        // 끝점에서 반대편 선분 내부로
+        let skew = Curve::Segment([12.0, 1.0], [20.0, 9.0]);
+        let closest = curve_distance(&a, &skew);
+        assert!(approx_eq(closest.distance, 5.0_f64.sqrt()));
+        assert_eq!(closest.a, [10.0, 0.0]);
+        assert_eq!(closest.b, [12.0, 1.0]);
+    }
+
+    #[test]
+    fn test_segment_circle_and_arc() {
+        let c = circle([0.0, 0.0], 5.0);
+
+        // 원 밖의 선분: 수선의 발 ↔ 원 위의 점
+        let outside = Curve::Segment([-10.0, 8.0], [10.0, 8.0]);
+        let closest = curve_distance(&outside, &c);
+        assert!(approx_eq(closest.distance, 3.0));
+        assert!(approx_eq(closest.a[0], 0.0) && approx_eq(closest.b[1], 5.0));
+
+        // 원 안에 완전히 들어있는 선분: 먼 끝점 ↔ 원
+        let inside = Curve::Segment([-1.0, 0.0], [2.0, 0.0]);
+        let closest = curve_distance(&inside, &c);
+        assert!(approx_eq(closest.distance, 3.0));
+        assert_eq!(closest.a, [2.0, 0.0]);
+
+        // 원을 가로지르는 선분
+        let chord = Curve::Segment([-10.0, 0.0], [0.0, 0.0]);
+        assert_eq!(curve_distance(&chord, &c).distance, 0.0);
+
+        // 위쪽 반원: 아래 선분과의 최단 거리는 호 끝점
+        let upper = Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 5.0,
+            start: 0.0,
+            sweep: PI,
+        };
+        let below = Curve::Segment([-10.0, -8.0], [10.0, -8.0]);
+        let closest = curve_distance(&upper, &below);
+        assert!(approx_eq(closest.distance, 8.0));
+        assert!(approx_eq(closest.a[1], 0.0));
+    }
+
+    #[test]
+    fn test_circle_and_arc_pairs() {
+        // 떨어진 두 원
+        let closest = curve_distance(&circle([0.0, 0.0], 1.0), &circle([10.0, 0.0], 2.0));
+        assert!(approx_eq(closest.distance, 7.0));
+        assert!(approx_eq(closest.a[0], 1.0) && approx_eq(closest.b[0], 8.0));
+
+        // 안쪽에 포함된 원 (동심 포함)
+        let closest = curve_distance(&circle([0.0, 0.0], 10.0), &circle([2.0, 0.0], 3.0));
+        assert!(approx_eq(closest.distance, 5.0));
+        let closest = curve_distance(&circle([0.0, 0.0], 10.0), &circle([0.0, 0.0], 4.0));
+        assert!(approx_eq(closest.distance, 6.0));
+
+        // 교차
+        let closest = curve_distance(&circle([0.0, 0.0], 5.0), &circle([6.0, 0.0], 5.0));
+        assert_eq!(closest.distance, 0.0);
+
+        // 서로 등진 반원: 가장 가까운 점은 호 끝점
+        let left = Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 1.0,
+            start: PI / 2.0,
+            sweep: PI,
+        };
+        let right = Curve::Arc {
+            center: [5.0, 0.0],
+            radius: 1.0,
+            start: -PI / 2.0,
+            sweep: PI,
+        };
+        let closest = curve_distance(&left, &right);
+        assert!(approx_eq(closest.distance, 5.0));
+
+        assert!(curves_distance(&[], &[circle([0.0, 0.0], 1.0)]).is_none());
+    }
+}
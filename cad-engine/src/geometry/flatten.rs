@@ -0,0 +1,572 @@
+//! 곡선 근사(flattening) 유틸리티
+//!
+//! Circle, Arc, Bezier 등 곡선 도형을 직선 세그먼트의 연결(폴리라인)로 변환합니다.
+//! Boolean 연산처럼 폴리곤만 다루는 알고리즘의 입력을 만드는 데 사용됩니다.
+//! - `closed_rings`: 원 전체 기준 세그먼트 수로 근사한 닫힌 영역
+//! - `geometry_polylines`: 현(chord) 허용 오차로 근사한 모든 도형의 외곽선
+
+use std::f64::consts::PI;
+
+use serde::Serialize;
+
+use super::boolean::nonzero_union;
+use super::path::{subpath_pieces, subpath_points};
+use super::text::text_outline_polylines;
+use crate::scene::entity::{FillRule, Geometry};
+
+/// 원 전체를 근사할 때 사용하는 기본 세그먼트 수
+pub const DEFAULT_CURVE_SEGMENTS: usize = 64;
+
+/// 허용 오차 기반 근사에서 곡선 하나당 최대 분할 수 (과도한 점 생성 방지)
+pub const MAX_TOLERANCE_STEPS: usize = 4096;
+
+/// 근사된 폴리라인
+///
+/// 닫힌 폴리라인의 마지막 점은 첫 점과 중복되지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Polyline {
+    pub points: Vec<[f64; 2]>,
+    pub closed: bool,
+}
+
+/// 현과 호 사이 최대 거리(sagitta)가 `tolerance` 이하가 되는 호 분할 수
+///
+/// sagitta = r(1 - cos(θ/2)) ≤ tolerance → θ ≤ 2·acos(1 - tolerance/r)
+pub fn arc_steps_for_tolerance(radius: f64, sweep: f64, tolerance: f64) -> usize {
+    let radius = radius.abs();
+    if radius <= tolerance {
+        return 1;
+    }
+    let max_angle = 2.0 * (1.0 - tolerance / radius).acos();
+    ((sweep.abs() / max_angle).ceil() as usize).clamp(1, MAX_TOLERANCE_STEPS)
+}
+
+/// 큐빅 베지어를 균일 분할할 때 현 오차가 `tolerance` 이하가 되는 분할 수
+///
+/// 오차 ≤ max|B''| / (8n²), max|B''| ≤ 6·max(|p0 - 2p1 + p2|, |p1 - 2p2 + p3|)
+pub fn cubic_steps_for_tolerance(p: &[[f64; 2]; 4], tolerance: f64) -> usize {
+    let second_diff = |a: [f64; 2], b: [f64; 2], c: [f64; 2]| {
+        (a[0] - 2.0 * b[0] + c[0]).hypot(a[1] - 2.0 * b[1] + c[1])
+    };
+    let m = 6.0 * second_diff(p[0], p[1], p[2]).max(second_diff(p[1], p[2], p[3]));
+    ((m / (8.0 * tolerance)).sqrt().ceil() as usize).clamp(1, MAX_TOLERANCE_STEPS)
+}
+
+/// Arc의 CCW 스윕 각도를 반환합니다 (0 ~ 2π).
+///
+/// SVG 직렬화와 동일하게 `(end - start).rem_euclid(2π)`를 사용합니다.
+pub fn arc_sweep(start_angle: f64, end_angle: f64) -> f64 {
+    (end_angle - start_angle).rem_euclid(2.0 * PI)
+}
+
+/// 원을 N각형으로 근사합니다 (CCW, 마지막 점은 첫 점과 중복되지 않음).
+pub fn circle_points(center: [f64; 2], radius: f64, segments: usize) -> Vec<[f64; 2]> {
+    let n = segments.max(3);
+    (0..n)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / n as f64;
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        })
+        .collect()
+}
+
+/// 호를 폴리라인으로 근사합니다 (시작점과 끝점 포함).
+///
+/// `segments`는 원 전체 기준 세그먼트 수이며, 스윕 비율만큼 분할합니다.
+pub fn arc_points(
+    center: [f64; 2],
+    radius: f64,
+    start_angle: f64,
+    end_angle: f64,
+    segments: usize,
+) -> Vec<[f64; 2]> {
+    let sweep = arc_sweep(start_angle, end_angle);
+    let steps = ((segments.max(3) as f64) * sweep / (2.0 * PI))
+        .ceil()
+        .max(1.0) as usize;
+    (0..=steps)
+        .map(|i| {
+            let angle = start_angle + sweep * i as f64 / steps as f64;
+            [
+                center[0] + radius * angle.cos(),
+                center[1] + radius * angle.sin(),
+            ]
+        })
+        .collect()
+}
+
+/// 타원 위의 점 (t: 매개변수 각도, rotation: 장축 회전)
+pub fn ellipse_point(center: [f64; 2], rx: f64, ry: f64, rotation: f64, t: f64) -> [f64; 2] {
+    let (sin_r, cos_r) = rotation.sin_cos();
+    let x = rx * t.cos();
+    let y = ry * t.sin();
+    [
+        center[0] + x * cos_r - y * sin_r,
+        center[1] + x * sin_r + y * cos_r,
+    ]
+}
+
+/// 타원을 N각형으로 근사합니다 (CCW, 마지막 점은 첫 점과 중복되지 않음).
+pub fn ellipse_points(
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    segments: usize,
+) -> Vec<[f64; 2]> {
+    let n = segments.max(3);
+    (0..n)
+        .map(|i| ellipse_point(center, rx, ry, rotation, 2.0 * PI * i as f64 / n as f64))
+        .collect()
+}
+
+/// 타원 호를 폴리라인으로 근사합니다 (시작점과 끝점 포함).
+///
+/// `segments`는 타원 전체 기준 세그먼트 수이며, 스윕 비율만큼 분할합니다.
+pub fn elliptical_arc_points(
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    start_angle: f64,
+    end_angle: f64,
+    segments: usize,
+) -> Vec<[f64; 2]> {
+    let sweep = arc_sweep(start_angle, end_angle);
+    let steps = ((segments.max(3) as f64) * sweep / (2.0 * PI))
+        .ceil()
+        .max(1.0) as usize;
+    (0..=steps)
+        .map(|i| {
+            let t = start_angle + sweep * i as f64 / steps as f64;
+            ellipse_point(center, rx, ry, rotation, t)
+        })
+        .collect()
+}
+
+/// 사각형 꼭짓점 4개 (CCW, center 기준)
+pub fn rect_points(center: [f64; 2], width: f64, height: f64) -> Vec<[f64; 2]> {
+    let hw = width / 2.0;
+    let hh = height / 2.0;
+    vec![
+        [center[0] - hw, center[1] - hh],
+        [center[0] + hw, center[1] - hh],
+        [center[0] + hw, center[1] + hh],
+        [center[0] - hw, center[1] + hh],
+    ]
+}
+
+/// 큐빅 베지어 위의 점 B(t)
+pub fn cubic_point(p0: [f64; 2], p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], t: f64) -> [f64; 2] {
+    let mt = 1.0 - t;
+    let a = mt * mt * mt;
+    let b = 3.0 * mt * mt * t;
+    let c = 3.0 * mt * t * t;
+    let d = t * t * t;
+    [
+        a * p0[0] + b * p1[0] + c * p2[0] + d * p3[0],
+        a * p0[1] + b * p1[1] + c * p2[1] + d * p3[1],
+    ]
+}
+
+/// 베지어 경로를 폴리라인으로 근사합니다 (시작점과 각 세그먼트 끝점 포함).
+///
+/// # Arguments
+/// * `steps_per_segment` - 큐빅 세그먼트 하나당 분할 수 (최소 1)
+pub fn bezier_points(
+    start: [f64; 2],
+    segments: &[[[f64; 2]; 3]],
+    steps_per_segment: usize,
+) -> Vec<[f64; 2]> {
+    let steps = steps_per_segment.max(1);
+    let mut points = vec![start];
+    let mut current = start;
+    for [cp1, cp2, end] in segments {
+        for i in 1..=steps {
+            let t = i as f64 / steps as f64;
+            points.push(cubic_point(current, *cp1, *cp2, *end, t));
+        }
+        current = *end;
+    }
+    points
+}
+
+/// 닫힌 도형의 외곽선(링)들을 로컬 좌표로 반환합니다.
+///
+/// 반환된 링들은 even-odd 규칙으로 해석됩니다 (Polygon holes 포함).
+/// nonzero Path는 같은 영역의 외곽선+구멍으로 정규화하여 반환합니다.
+/// 각 링의 마지막 점은 첫 점과 중복되지 않습니다.
+///
+/// # Returns
+/// * Some(rings) - 닫힌 영역을 갖는 도형
+/// * None - 열린 Line/Bezier/Path, Text, Group(Empty) 등 영역이 없는 도형
+pub fn closed_rings(geometry: &Geometry, curve_segments: usize) -> Option<Vec<Vec<[f64; 2]>>> {
+    match geometry {
+        Geometry::Circle { center, radius } => {
+            Some(vec![circle_points(*center, *radius, curve_segments)])
+        }
+        Geometry::Rect {
+            center,
+            width,
+            height,
+        } => Some(vec![rect_points(*center, *width, *height)]),
+        Geometry::Arc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        } => {
+            // 부채꼴: 중심점 + 호
+            let mut ring = vec![*center];
+            ring.extend(arc_points(
+                *center,
+                *radius,
+                *start_angle,
+                *end_angle,
+                curve_segments,
+            ));
+            Some(vec![ring])
+        }
+        Geometry::Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+        } => Some(vec![ellipse_points(
+            *center,
+            *rx,
+            *ry,
+            *rotation,
+            curve_segments,
+        )]),
+        Geometry::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            end_angle,
+        } => {
+            // Arc와 동일하게 부채꼴로 취급
+            let mut ring = vec![*center];
+            ring.extend(elliptical_arc_points(
+                *center,
+                *rx,
+                *ry,
+                *rotation,
+                *start_angle,
+                *end_angle,
+                curve_segments,
+            ));
+            Some(vec![ring])
+        }
+        Geometry::Polygon { points, holes } => {
+            let mut rings = vec![strip_closing_point(points.clone())];
+            rings.extend(holes.iter().map(|h| strip_closing_point(h.clone())));
+            Some(rings)
+        }
+        Geometry::Line { points } => {
+            // 첫 점과 끝 점이 같은 폴리라인만 닫힌 영역으로 취급
+            let closed = points.len() >= 4
+                && points
+                    .first()
+                    .zip(points.last())
+                    .is_some_and(|(a, b)| same_point(*a, *b));
+            closed.then(|| vec![strip_closing_point(points.clone())])
+        }
+        Geometry::Bezier {
+            start,
+            segments,
+            closed,
+        } => {
+            if !closed || segments.is_empty() {
+                return None;
+            }
+            // 1/4 원을 근사하는 큐빅 하나가 원 전체 세그먼트의 1/4을 받도록 분할
+            let steps = (curve_segments / 4).max(4);
+            Some(vec![strip_closing_point(bezier_points(
+                *start, segments, steps,
+            ))])
+        }
+        Geometry::Path {
+            subpaths,
+            fill_rule,
+        } => {
+            // 닫힌 subpath만 영역으로 취급
+            let rings: Vec<Vec<[f64; 2]>> = subpaths
+                .iter()
+                .filter(|sub| sub.closed && !sub.segments.is_empty())
+                .map(|sub| strip_closing_point(subpath_points(sub, curve_segments)))
+                .collect();
+            // nonzero는 같은 방향으로 겹친 링/자기교차 링에서 even-odd와 다르므로 외곽선+구멍으로 정규화
+            let rings = match fill_rule {
+                FillRule::EvenOdd => rings,
+                FillRule::NonZero => nonzero_union(&rings)
+                    .into_iter()
+                    .flat_map(|poly| std::iter::once(poly.outer).chain(poly.holes))
+                    .collect(),
+            };
+            (!rings.is_empty()).then_some(rings)
+        }
+        Geometry::Text { .. } | Geometry::Dimension { .. } | Geometry::Empty => None,
+    }
+}
+
+/// 도형을 현 허용 오차 이내의 폴리라인들로 근사합니다 (로컬 좌표).
+///
+/// 렌더링되는 외곽선을 그대로 따릅니다.
+/// - Circle/Ellipse/Rect/Polygon(외곽 + holes): 닫힌 폴리라인
+/// - Arc/EllipticalArc: 호 자체 (부채꼴이 아닌 열린 폴리라인)
+/// - Line/Bezier/Path: 점 또는 subpath별 폴리라인 (closed 플래그 유지)
+/// - Text: 단선 폰트 획 (직선이므로 오차 없음)
+/// - Dimension, Empty: 빈 목록
+///
+/// # Arguments
+/// * `tolerance` - 허용 오차 (양수, 로컬 단위)
+pub fn geometry_polylines(geometry: &Geometry, tolerance: f64) -> Vec<Polyline> {
+    let closed = |points: Vec<[f64; 2]>| Polyline {
+        points: strip_closing_point(points),
+        closed: true,
+    };
+    let open = |points: Vec<[f64; 2]>| Polyline {
+        points,
+        closed: false,
+    };
+    match geometry {
+        Geometry::Circle { center, radius } => {
+            let steps = arc_steps_for_tolerance(*radius, 2.0 * PI, tolerance).max(3);
+            vec![closed(circle_points(*center, *radius, steps))]
+        }
+        Geometry::Rect {
+            center,
+            width,
+            height,
+        } => vec![closed(rect_points(*center, *width, *height))],
+        Geometry::Arc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let steps = arc_steps_for_tolerance(*radius, sweep, tolerance);
+            vec![open(
+                (0..=steps)
+                    .map(|i| {
+                        let angle = start_angle + sweep * i as f64 / steps as f64;
+                        [
+                            center[0] + radius * angle.cos(),
+                            center[1] + radius * angle.sin(),
+                        ]
+                    })
+                    .collect(),
+            )]
+        }
+        Geometry::Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+        } => {
+            let steps = arc_steps_for_tolerance(rx.max(*ry), 2.0 * PI, tolerance).max(3);
+            vec![closed(ellipse_points(*center, *rx, *ry, *rotation, steps))]
+        }
+        Geometry::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            end_angle,
+        } => {
+            let sweep = arc_sweep(*start_angle, *end_angle);
+            let steps = arc_steps_for_tolerance(rx.max(*ry), sweep, tolerance);
+            vec![open(
+                (0..=steps)
+                    .map(|i| {
+                        let t = start_angle + sweep * i as f64 / steps as f64;
+                        ellipse_point(*center, *rx, *ry, *rotation, t)
+                    })
+                    .collect(),
+            )]
+        }
+        Geometry::Polygon { points, holes } => std::iter::once(points)
+            .chain(holes.iter())
+            .map(|ring| closed(ring.clone()))
+            .collect(),
+        Geometry::Line { points } => vec![open(points.clone())],
+        Geometry::Bezier {
+            start,
+            segments,
+            closed: is_closed,
+        } => {
+            let mut points = vec![*start];
+            let mut current = *start;
+            for [cp1, cp2, end] in segments {
+                let cubic = [current, *cp1, *cp2, *end];
+                let steps = cubic_steps_for_tolerance(&cubic, tolerance);
+                points.extend(
+                    (1..=steps)
+                        .map(|i| cubic_point(current, *cp1, *cp2, *end, i as f64 / steps as f64)),
+                );
+                current = *end;
+            }
+            if *is_closed {
+                vec![closed(points)]
+            } else {
+                vec![open(points)]
+            }
+        }
+        Geometry::Path { subpaths, .. } => subpaths
+            .iter()
+            .filter(|sub| !sub.segments.is_empty())
+            .map(|sub| {
+                let mut points = vec![sub.start];
+                for piece in subpath_pieces(sub) {
+                    points.extend(piece.points_within(tolerance).into_iter().skip(1));
+                }
+                if sub.closed {
+                    closed(points)
+                } else {
+                    open(points)
+                }
+            })
+            .collect(),
+        Geometry::Text { .. } => text_outline_polylines(geometry)
+            .into_iter()
+            .filter(|stroke| !stroke.is_empty())
+            .map(open)
+            .collect(),
+        Geometry::Dimension { .. } | Geometry::Empty => Vec::new(),
+    }
+}
+
+fn same_point(a: [f64; 2], b: [f64; 2]) -> bool {
+    (a[0] - b[0]).abs() < 1e-9 && (a[1] - b[1]).abs() < 1e-9
+}
+
+/// 링의 마지막 점이 첫 점과 같으면 제거합니다.
+fn strip_closing_point(mut ring: Vec<[f64; 2]>) -> Vec<[f64; 2]> {
+    if ring.len() > 1 && same_point(ring[0], ring[ring.len() - 1]) {
+        ring.pop();
+    }
+    ring
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_circle_points_on_radius() {
+        let points = circle_points([10.0, 0.0], 5.0, 32);
+        assert_eq!(points.len(), 32);
+        for p in points {
+            let d = ((p[0] - 10.0).powi(2) + p[1].powi(2)).sqrt();
+            assert!(approx_eq(d, 5.0, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_arc_points_endpoints() {
+        let points = arc_points([0.0, 0.0], 10.0, 0.0, PI / 2.0, 64);
+        let first = points.first().unwrap();
+        let last = points.last().unwrap();
+        assert!(approx_eq(first[0], 10.0, 1e-10) && approx_eq(first[1], 0.0, 1e-10));
+        assert!(approx_eq(last[0], 0.0, 1e-10) && approx_eq(last[1], 10.0, 1e-10));
+        // 1/4 원 → 16 세그먼트 + 1
+        assert_eq!(points.len(), 17);
+    }
+
+    #[test]
+    fn test_arc_sweep_wraps() {
+        // 350° → 10° 는 CCW 20°
+        let sweep = arc_sweep(350f64.to_radians(), 10f64.to_radians());
+        assert!(approx_eq(sweep, 20f64.to_radians(), 1e-10));
+    }
+
+    #[test]
+    fn test_ellipse_point_rotated() {
+        // 90° 회전 → 장축이 y 방향
+        let p = ellipse_point([0.0, 0.0], 20.0, 10.0, PI / 2.0, 0.0);
+        assert!(approx_eq(p[0], 0.0, 1e-10) && approx_eq(p[1], 20.0, 1e-10));
+        let p = ellipse_point([0.0, 0.0], 20.0, 10.0, PI / 2.0, PI / 2.0);
+        assert!(approx_eq(p[0], -10.0, 1e-10) && approx_eq(p[1], 0.0, 1e-10));
+    }
+
+    #[test]
+    fn test_bezier_points_hits_end() {
+        let points = bezier_points([0.0, 0.0], &[[[0.0, 10.0], [10.0, 10.0], [10.0, 0.0]]], 8);
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[8], [10.0, 0.0]);
+    }
+
+    #[test]
+    fn test_closed_rings_open_line_is_none() {
+        let line = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.0]],
+        };
+        assert!(closed_rings(&line, 64).is_none());
+    }
+
+    #[test]
+    fn test_closed_rings_closed_polyline() {
+        let line = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 0.0]],
+        };
+        let rings = closed_rings(&line, 64).unwrap();
+        assert_eq!(rings[0].len(), 3);
+    }
+
+    #[test]
+    fn test_closed_rings_arc_is_sector() {
+        let arc = Geometry::Arc {
+            center: [0.0, 0.0],
+            radius: 10.0,
+            start_angle: 0.0,
+            end_angle: PI,
+        };
+        let rings = closed_rings(&arc, 64).unwrap();
+        assert_eq!(rings[0][0], [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_tolerance_steps() {
+        // r = 10, tol = 0.1 → θ = 2·acos(0.99) ≈ 0.2838 rad → 2π / θ ≈ 22.1
+        assert_eq!(arc_steps_for_tolerance(10.0, 2.0 * PI, 0.1), 23);
+        assert_eq!(arc_steps_for_tolerance(0.05, 2.0 * PI, 0.1), 1);
+        assert_eq!(
+            arc_steps_for_tolerance(1e9, 2.0 * PI, 1e-9),
+            MAX_TOLERANCE_STEPS
+        );
+
+        // 직선과 같은 큐빅은 분할 1
+        let straight = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0]];
+        assert_eq!(cubic_steps_for_tolerance(&straight, 0.01), 1);
+        let curved = [[0.0, 0.0], [0.0, 10.0], [10.0, 10.0], [10.0, 0.0]];
+        assert!(cubic_steps_for_tolerance(&curved, 0.01) > 10);
+    }
+
+    #[test]
+    fn test_geometry_polylines_text_and_empty() {
+        let text = Geometry::Text {
+            position: [0.0, 0.0],
+            content: "A".to_string(),
+            font_family: "sans-serif".to_string(),
+            font_size: 10.0,
+            anchor: Default::default(),
+            align: Default::default(),
+        };
+        let polylines = geometry_polylines(&text, 0.01);
+        assert!(!polylines.is_empty());
+        assert!(polylines.iter().all(|p| !p.closed));
+        assert!(geometry_polylines(&Geometry::Empty, 0.01).is_empty());
+    }
+}
@@ -0,0 +1,374 @@
+//! 해치(hatch) 패턴 생성
+//!
+//! 닫힌 영역(even-odd 링)을 패턴 선/점으로 채웁니다.
+//! 패턴은 로컬 원점 기준 격자에 정렬되므로 인접한 영역끼리 선이 이어집니다.
+
+use std::f64::consts::FRAC_PI_4;
+
+use serde::Serialize;
+
+use super::flatten::{DEFAULT_CURVE_SEGMENTS, closed_rings};
+use super::polygon::point_in_ring;
+use crate::scene::entity::{Geometry, HatchPattern, HatchStyle, Matrix3x3, Transform};
+
+/// 해치 하나의 최대 선/점 개수 (클리핑 전, 간격이 너무 작으면 간격을 늘려 제한)
+const MAX_HATCH_PRIMITIVES: f64 = 4000.0;
+
+/// 생성된 해치 선/점
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct HatchLines {
+    /// 영역에 맞게 잘린 선분들
+    pub lines: Vec<[[f64; 2]; 2]>,
+    /// 점 패턴 (Dots)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dots: Vec<[f64; 2]>,
+}
+
+impl HatchLines {
+    /// 모든 점에 변환 행렬을 적용합니다.
+    pub fn transformed(&self, m: &Matrix3x3) -> Self {
+        let tp = |p: [f64; 2]| Transform::transform_point(m, p);
+        Self {
+            lines: self.lines.iter().map(|l| l.map(tp)).collect(),
+            dots: self.dots.iter().map(|p| tp(*p)).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.dots.is_empty()
+    }
+}
+
+/// 도형의 해치 선/점 (로컬 좌표)
+///
+/// # Returns
+/// * Some - Polygon(holes 포함), Circle, Rect, Ellipse, 닫힌 Bezier/Line, 닫힌 Path (fill_rule 반영)
+/// * None - 열린 도형, Arc(부채꼴이 아닌 현으로 채워짐), Text 등 해치 불가 도형
+pub fn hatch_geometry(geometry: &Geometry, hatch: &HatchStyle) -> Option<HatchLines> {
+    if matches!(
+        geometry,
+        Geometry::Arc { .. } | Geometry::EllipticalArc { .. }
+    ) {
+        return None;
+    }
+    let rings = closed_rings(geometry, DEFAULT_CURVE_SEGMENTS)?;
+    Some(hatch_rings(&rings, hatch))
+}
+
+/// even-odd 링들을 해치 패턴으로 채웁니다.
+pub fn hatch_rings(rings: &[Vec<[f64; 2]>], hatch: &HatchStyle) -> HatchLines {
+    let mut result = HatchLines::default();
+    if rings.iter().all(|r| r.len() < 3) {
+        return result;
+    }
+    let spacing = hatch.spacing.abs() * hatch.scale.abs();
+    if !spacing.is_finite() || spacing <= 0.0 {
+        return result;
+    }
+    let angle = hatch.angle;
+
+    match hatch.pattern {
+        HatchPattern::Ansi31 => {
+            add_line_family(&mut result, rings, angle + FRAC_PI_4, spacing, 1.0);
+        }
+        HatchPattern::Crosshatch => {
+            add_line_family(&mut result, rings, angle + FRAC_PI_4, spacing, 2.0);
+            add_line_family(&mut result, rings, angle + 3.0 * FRAC_PI_4, spacing, 2.0);
+        }
+        HatchPattern::Brick => add_brick(&mut result, rings, angle, spacing),
+        HatchPattern::Dots => add_dots(&mut result, rings, angle, spacing),
+    }
+    result
+}
+
+fn rotate(p: [f64; 2], angle: f64) -> [f64; 2] {
+    let (sin, cos) = angle.sin_cos();
+    [p[0] * cos - p[1] * sin, p[0] * sin + p[1] * cos]
+}
+
+/// 링들을 -angle 회전한 패턴 좌표계에서의 바운드와, 개수 제한을 반영한 간격
+///
+/// `min_spacing` - 패턴 좌표계 바운드 크기 (w, h)에서 개수 제한을 지키는 최소 간격
+fn pattern_frame(
+    rings: &[Vec<[f64; 2]>],
+    angle: f64,
+    spacing: f64,
+    min_spacing: impl Fn(f64, f64) -> f64,
+) -> ([f64; 2], [f64; 2], f64) {
+    let mut min = [f64::INFINITY; 2];
+    let mut max = [f64::NEG_INFINITY; 2];
+    for p in rings.iter().flatten() {
+        let q = rotate(*p, -angle);
+        min = [min[0].min(q[0]), min[1].min(q[1])];
+        max = [max[0].max(q[0]), max[1].max(q[1])];
+    }
+    let limit = min_spacing(max[0] - min[0], max[1] - min[1]);
+    (min, max, spacing.max(limit))
+}
+
+/// 2차원 격자 (점, 벽돌)의 최소 간격: (w/s + 1)(h/s + 1) ≤ 3/4·MAX + 1
+fn grid_min_spacing(w: f64, h: f64) -> f64 {
+    (2.0 * (w * h / MAX_HATCH_PRIMITIVES).sqrt()).max(2.0 * (w + h) / MAX_HATCH_PRIMITIVES)
+}
+
+/// angle 방향의 평행선 (원점을 지나는 선 기준 spacing 간격)
+///
+/// `families` - 같은 해치의 선 방향 개수 (개수 제한을 나눠 가짐)
+fn add_line_family(
+    result: &mut HatchLines,
+    rings: &[Vec<[f64; 2]>],
+    angle: f64,
+    spacing: f64,
+    families: f64,
+) {
+    let (min, max, spacing) = pattern_frame(rings, angle, spacing, |_, h| {
+        h * families / MAX_HATCH_PRIMITIVES
+    });
+    let first = (min[1] / spacing).ceil() as i64;
+    let last = (max[1] / spacing).floor() as i64;
+    for k in first..=last {
+        let y = k as f64 * spacing;
+        let a = rotate([min[0] - spacing, y], angle);
+        let b = rotate([max[0] + spacing, y], angle);
+        result.lines.extend(clip_segment(a, b, rings));
+    }
+}
+
+/// 벽돌: 높이 spacing, 길이 2·spacing, 줄마다 반 장씩 엇갈림
+fn add_brick(result: &mut HatchLines, rings: &[Vec<[f64; 2]>], angle: f64, spacing: f64) {
+    let (min, max, spacing) = pattern_frame(rings, angle, spacing, grid_min_spacing);
+    let first = (min[1] / spacing).floor() as i64;
+    let last = (max[1] / spacing).ceil() as i64;
+    let brick = 2.0 * spacing;
+    for k in first..=last {
+        let y = k as f64 * spacing;
+        // 가로 줄
+        let a = rotate([min[0] - spacing, y], angle);
+        let b = rotate([max[0] + spacing, y], angle);
+        result.lines.extend(clip_segment(a, b, rings));
+
+        // 세로 줄눈 (이 줄과 다음 줄 사이)
+        if k == last {
+            continue;
+        }
+        let shift = if k.rem_euclid(2) == 1 { spacing } else { 0.0 };
+        let j_first = ((min[0] - shift) / brick).floor() as i64;
+        let j_last = ((max[0] - shift) / brick).ceil() as i64;
+        for j in j_first..=j_last {
+            let x = j as f64 * brick + shift;
+            let a = rotate([x, y], angle);
+            let b = rotate([x, y + spacing], angle);
+            result.lines.extend(clip_segment(a, b, rings));
+        }
+    }
+}
+
+/// 격자 점 (영역 안쪽만)
+fn add_dots(result: &mut HatchLines, rings: &[Vec<[f64; 2]>], angle: f64, spacing: f64) {
+    let (min, max, spacing) = pattern_frame(rings, angle, spacing, grid_min_spacing);
+    let (i_first, i_last) = (
+        (min[0] / spacing).ceil() as i64,
+        (max[0] / spacing).floor() as i64,
+    );
+    let (j_first, j_last) = (
+        (min[1] / spacing).ceil() as i64,
+        (max[1] / spacing).floor() as i64,
+    );
+    for j in j_first..=j_last {
+        for i in i_first..=i_last {
+            let p = rotate([i as f64 * spacing, j as f64 * spacing], angle);
+            if inside_even_odd(rings, p) {
+                result.dots.push(p);
+            }
+        }
+    }
+}
+
+fn inside_even_odd(rings: &[Vec<[f64; 2]>], p: [f64; 2]) -> bool {
+    rings.iter().filter(|r| point_in_ring(r, p)).count() % 2 == 1
+}
+
+/// 선분 a→b 중 영역(even-odd) 안쪽 구간들
+pub fn clip_segment(a: [f64; 2], b: [f64; 2], rings: &[Vec<[f64; 2]>]) -> Vec<[[f64; 2]; 2]> {
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let mut ts = vec![0.0, 1.0];
+    for ring in rings {
+        let n = ring.len();
+        for i in 0..n {
+            let p = ring[i];
+            let q = ring[(i + 1) % n];
+            let e = [q[0] - p[0], q[1] - p[1]];
+            let denom = d[0] * e[1] - d[1] * e[0];
+            if denom.abs() < 1e-15 {
+                continue;
+            }
+            let w = [p[0] - a[0], p[1] - a[1]];
+            let t = (w[0] * e[1] - w[1] * e[0]) / denom;
+            let u = (w[0] * d[1] - w[1] * d[0]) / denom;
+            if t > 0.0 && t < 1.0 && (0.0..=1.0).contains(&u) {
+                ts.push(t);
+            }
+        }
+    }
+    ts.sort_by(|x, y| x.total_cmp(y));
+    ts.dedup_by(|x, y| (*x - *y).abs() < 1e-12);
+
+    let at = |t: f64| [a[0] + d[0] * t, a[1] + d[1] * t];
+    let mut segments: Vec<[[f64; 2]; 2]> = Vec::new();
+    let mut open: Option<f64> = None;
+    for pair in ts.windows(2) {
+        let (t0, t1) = (pair[0], pair[1]);
+        let inside = inside_even_odd(rings, at((t0 + t1) / 2.0));
+        match (inside, open) {
+            (true, None) => open = Some(t0),
+            (false, Some(start)) => {
+                segments.push([at(start), at(t0)]);
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = open {
+        segments.push([at(start), at(1.0)]);
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// 해치 선들의 총 길이
+    fn total_length(lines: &[[[f64; 2]; 2]]) -> f64 {
+        lines
+            .iter()
+            .map(|[a, b]| (b[0] - a[0]).hypot(b[1] - a[1]))
+            .sum()
+    }
+
+    fn square(size: f64) -> Vec<[f64; 2]> {
+        vec![[0.0, 0.0], [size, 0.0], [size, size], [0.0, size]]
+    }
+
+    fn hatch(pattern: HatchPattern, spacing: f64) -> HatchStyle {
+        HatchStyle {
+            pattern,
+            spacing,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_clip_segment_with_hole() {
+        let outer = square(10.0);
+        let hole = vec![[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]];
+        let segments = clip_segment([-5.0, 5.0], [15.0, 5.0], &[outer, hole]);
+        assert_eq!(segments.len(), 2);
+        assert!((segments[0][0][0] - 0.0).abs() < 1e-12);
+        assert!((segments[0][1][0] - 4.0).abs() < 1e-12);
+        assert!((segments[1][0][0] - 6.0).abs() < 1e-12);
+        assert!((segments[1][1][0] - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ansi31_fills_area() {
+        // 평행선 총 길이 ≈ 면적 / 수직 간격
+        let result = hatch_rings(&[square(100.0)], &hatch(HatchPattern::Ansi31, 1.0));
+        let length = total_length(&result.lines);
+        assert!(
+            (length - 10000.0).abs() / 10000.0 < 0.02,
+            "length {}",
+            length
+        );
+        // 모든 선은 45°
+        for [a, b] in &result.lines {
+            let angle = (b[1] - a[1]).atan2(b[0] - a[0]);
+            assert!((angle - PI / 4.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_crosshatch_has_two_directions() {
+        let single = hatch_rings(&[square(20.0)], &hatch(HatchPattern::Ansi31, 2.0));
+        let cross = hatch_rings(&[square(20.0)], &hatch(HatchPattern::Crosshatch, 2.0));
+        let ratio = total_length(&cross.lines) / total_length(&single.lines);
+        assert!((ratio - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_brick_stays_inside() {
+        let result = hatch_rings(&[square(10.0)], &hatch(HatchPattern::Brick, 2.0));
+        assert!(!result.lines.is_empty());
+        for p in result.lines.iter().flatten() {
+            assert!((-1e-9..=10.0 + 1e-9).contains(&p[0]));
+            assert!((-1e-9..=10.0 + 1e-9).contains(&p[1]));
+        }
+    }
+
+    #[test]
+    fn test_dots_skip_holes() {
+        let outer = square(10.0);
+        let hole = vec![[2.5, 2.5], [7.5, 2.5], [7.5, 7.5], [2.5, 7.5]];
+        let result = hatch_rings(&[outer, hole], &hatch(HatchPattern::Dots, 1.0));
+        assert!(result.lines.is_empty());
+        assert!(!result.dots.is_empty());
+        assert!(
+            result
+                .dots
+                .iter()
+                .all(|p| !(p[0] > 2.5 && p[0] < 7.5 && p[1] > 2.5 && p[1] < 7.5))
+        );
+    }
+
+    #[test]
+    fn test_tiny_spacing_caps_total_primitives() {
+        for pattern in [
+            HatchPattern::Ansi31,
+            HatchPattern::Crosshatch,
+            HatchPattern::Brick,
+            HatchPattern::Dots,
+        ] {
+            let result = hatch_rings(&[square(1000.0)], &hatch(pattern, 1e-3));
+            let count = result.lines.len() + result.dots.len();
+            assert!(count > 0);
+            assert!(
+                count as f64 <= MAX_HATCH_PRIMITIVES,
+                "{:?}: {} primitives",
+                pattern,
+                count
+            );
+        }
+    }
+
+    #[test]
+    fn test_scale_multiplies_spacing() {
+        let base = hatch_rings(&[square(50.0)], &hatch(HatchPattern::Ansi31, 1.0));
+        let scaled = hatch_rings(
+            &[square(50.0)],
+            &HatchStyle {
+                scale: 2.0,
+                ..hatch(HatchPattern::Ansi31, 1.0)
+            },
+        );
+        let ratio = total_length(&base.lines) / total_length(&scaled.lines);
+        assert!((ratio - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_open_geometry_has_no_hatch() {
+        let line = Geometry::Line {
+            points: vec![[0.0, 0.0], [10.0, 0.0]],
+        };
+        assert!(hatch_geometry(&line, &HatchStyle::default()).is_none());
+        let circle = Geometry::Circle {
+            center: [0.0, 0.0],
+            radius: 5.0,
+        };
+        assert!(
+            !hatch_geometry(&circle, &HatchStyle::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! 점 hit test 유틸리티
+//!
+//! 폴리라인까지의 거리, fill-rule별 내부 판정, 선택 영역(window/crossing) 판정을 제공합니다.
+//! 좌표계에 의존하지 않으며, Scene 연동(z-order, 스타일, 월드 변환)은 scene/pick.rs, scene/select.rs에서 처리합니다.
+
+use super::polygon::point_in_ring;
+
+/// 점과 선분 사이 최단 거리
+pub fn point_segment_distance(p: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let len_sq = d[0] * d[0] + d[1] * d[1];
+    let t = if len_sq > 0.0 {
+        (((p[0] - a[0]) * d[0] + (p[1] - a[1]) * d[1]) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p[0] - a[0] - t * d[0]).hypot(p[1] - a[1] - t * d[1])
+}
+
+/// 점과 폴리라인 사이 최단 거리 (closed면 마지막 점 → 첫 점 변 포함, 빈 폴리라인은 무한대)
+pub fn point_polyline_distance(p: [f64; 2], points: &[[f64; 2]], closed: bool) -> f64 {
+    match points {
+        [] => f64::INFINITY,
+        [only] => (p[0] - only[0]).hypot(p[1] - only[1]),
+        _ => {
+            let closing = closed.then(|| [points[points.len() - 1], points[0]]);
+            points
+                .windows(2)
+                .map(|w| [w[0], w[1]])
+                .chain(closing)
+                .map(|[a, b]| point_segment_distance(p, a, b))
+                .fold(f64::INFINITY, f64::min)
+        }
+    }
+}
+
+/// 링이 점을 감는 횟수 (CCW +1, CW -1)
+pub fn winding_number(ring: &[[f64; 2]], p: [f64; 2]) -> i32 {
+    let n = ring.len();
+    if n < 3 {
+        return 0;
+    }
+    let mut winding = 0;
+    for i in 0..n {
+        let a = ring[i];
+        let b = ring[(i + 1) % n];
+        let side = (b[0] - a[0]) * (p[1] - a[1]) - (p[0] - a[0]) * (b[1] - a[1]);
+        if a[1] <= p[1] {
+            if b[1] > p[1] && side > 0.0 {
+                winding += 1;
+            }
+        } else if b[1] <= p[1] && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// 여러 링으로 이루어진 채움 영역에 점이 포함되는지 확인합니다.
+///
+/// # Arguments
+/// * `even_odd` - true면 even-odd, false면 nonzero 규칙 (SVG fill-rule)
+pub fn point_in_fill(rings: &[Vec<[f64; 2]>], p: [f64; 2], even_odd: bool) -> bool {
+    if even_odd {
+        rings.iter().filter(|ring| point_in_ring(ring, p)).count() % 2 == 1
+    } else {
+        rings
+            .iter()
+            .map(|ring| winding_number(ring, p))
+            .sum::<i32>()
+            != 0
+    }
+}
+
+/// 폴리라인의 변들 (closed면 마지막 점 → 첫 점 변 포함)
+fn edges(points: &[[f64; 2]], closed: bool) -> impl Iterator<Item = ([f64; 2], [f64; 2])> + '_ {
+    let closing = (closed && points.len() > 2).then(|| (points[points.len() - 1], points[0]));
+    points.windows(2).map(|w| (w[0], w[1])).chain(closing)
+}
+
+/// 두 선분이 만나는지 확인합니다 (끝점 접촉, 겹침 포함).
+pub fn segments_intersect(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> bool {
+    let orient = |p: [f64; 2], q: [f64; 2], r: [f64; 2]| {
+        (q[0] - p[0]) * (r[1] - p[1]) - (q[1] - p[1]) * (r[0] - p[0])
+    };
+    let on_segment = |p: [f64; 2], q: [f64; 2], r: [f64; 2]| {
+        r[0] >= p[0].min(q[0])
+            && r[0] <= p[0].max(q[0])
+            && r[1] >= p[1].min(q[1])
+            && r[1] <= p[1].max(q[1])
+    };
+    let (d1, d2) = (orient(c, d, a), orient(c, d, b));
+    let (d3, d4) = (orient(a, b, c), orient(a, b, d));
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    (d1 == 0.0 && on_segment(c, d, a))
+        || (d2 == 0.0 && on_segment(c, d, b))
+        || (d3 == 0.0 && on_segment(a, b, c))
+        || (d4 == 0.0 && on_segment(a, b, d))
+}
+
+/// 폴리라인이 선택 링의 경계와 만나는지 확인합니다.
+fn crosses_ring(points: &[[f64; 2]], closed: bool, ring: &[[f64; 2]]) -> bool {
+    edges(points, closed)
+        .any(|(a, b)| edges(ring, true).any(|(c, d)| segments_intersect(a, b, c, d)))
+}
+
+/// 폴리라인이 선택 링 안에 완전히 들어있는지 확인합니다 (window 선택).
+///
+/// 모든 점이 내부에 있고 어떤 변도 링 경계와 만나지 않아야 합니다 (오목한 lasso 대응).
+pub fn polyline_inside_ring(points: &[[f64; 2]], closed: bool, ring: &[[f64; 2]]) -> bool {
+    !points.is_empty()
+        && points.iter().all(|p| point_in_ring(ring, *p))
+        && !crosses_ring(points, closed, ring)
+}
+
+/// 폴리라인이 선택 링과 겹치는지 확인합니다 (crossing 선택: 내부에 있거나 경계와 교차).
+pub fn polyline_touches_ring(points: &[[f64; 2]], closed: bool, ring: &[[f64; 2]]) -> bool {
+    points.iter().any(|p| point_in_ring(ring, *p)) || crosses_ring(points, closed, ring)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polyline_distance() {
+        let square = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        // 닫는 변 (0,10)→(0,0)은 closed일 때만 포함
+        assert!((point_polyline_distance([-1.0, 5.0], &square, true) - 1.0).abs() < 1e-12);
+        assert!(
+            (point_polyline_distance([-1.0, 5.0], &square, false) - 26.0_f64.sqrt()).abs() < 1e-12
+        );
+        assert!(
+            (point_segment_distance([5.0, 3.0], [0.0, 0.0], [0.0, 0.0]) - 34.0_f64.sqrt()).abs()
+                < 1e-12
+        );
+        assert_eq!(
+            point_polyline_distance([0.0, 0.0], &[], true),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_point_in_fill_rules() {
+        let outer = vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        let same_dir = vec![[3.0, 3.0], [7.0, 3.0], [7.0, 7.0], [3.0, 7.0]];
+        let reversed: Vec<[f64; 2]> = same_dir.iter().rev().copied().collect();
+
+        assert_eq!(winding_number(&outer, [5.0, 5.0]), 1);
+        assert_eq!(winding_number(&reversed, [5.0, 5.0]), -1);
+
+        // 같은 방향 내부 링: nonzero는 채움, even-odd는 구멍
+        let rings = vec![outer.clone(), same_dir];
+        assert!(point_in_fill(&rings, [5.0, 5.0], false));
+        assert!(!point_in_fill(&rings, [5.0, 5.0], true));
+
+        // 반대 방향 내부 링: 두 규칙 모두 구멍
+        let rings = vec![outer, reversed];
+        assert!(!point_in_fill(&rings, [5.0, 5.0], false));
+        assert!(point_in_fill(&rings, [1.0, 1.0], false));
+        assert!(!point_in_fill(&rings, [11.0, 5.0], true));
+    }
+
+    #[test]
+    fn test_window_and_crossing() {
+        // 오목한 lasso (U자): 가운데 홈 (4..6, 4..10)은 바깥
+        let lasso = [
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [6.0, 10.0],
+            [6.0, 4.0],
+            [4.0, 4.0],
+            [4.0, 10.0],
+            [0.0, 10.0],
+        ];
+        let inside = [[1.0, 1.0], [3.0, 8.0]];
+        let spanning = [[2.0, 8.0], [8.0, 8.0]]; // 두 끝점은 안, 변이 홈을 지남
+        let outside = [[20.0, 20.0], [30.0, 30.0]];
+
+        assert!(polyline_inside_ring(&inside, false, &lasso));
+        assert!(!polyline_inside_ring(&spanning, false, &lasso));
+        assert!(polyline_touches_ring(&spanning, false, &lasso));
+        assert!(!polyline_touches_ring(&outside, false, &lasso));
+
+        // 끝점이 모두 밖이어도 경계를 가로지르면 crossing
+        assert!(polyline_touches_ring(
+            &[[-5.0, 2.0], [15.0, 2.0]],
+            false,
+            &lasso
+        ));
+        assert!(segments_intersect(
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 5.0]
+        ));
+        assert!(!segments_intersect(
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [3.0, 0.0]
+        ));
+    }
+}
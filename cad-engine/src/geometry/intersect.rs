@@ -0,0 +1,594 @@
+//! 곡선 교점 유틸리티
+//!
+//! 선분, 원호(원 포함), 3차 베지어 사이의 교점과 각 곡선 위의 매개변수를 계산합니다.
+//! - 선분/원호 조합은 해석적으로 정확 (접점은 교점 하나, 겹치는 구간은 구간의 끝점들)
+//! - 베지어가 포함된 조합은 제어점 상자가 겹치는 동안 반으로 분할하고,
+//!   충분히 평평해진 조각(제어점이 현에서 tolerance 이내)을 선분으로 보고 교차
+//! - 매개변수 t: 선분/베지어는 곡선 매개변수 [0, 1], 원호는 스윕 비율 (0 = start, 1 = start + sweep)
+//!
+//! 월드 변환과 Entity 분해는 scene/intersect.rs에서 처리합니다.
+
+use std::f64::consts::PI;
+
+use super::distance::{Curve, arc_contains};
+
+/// 매개변수/중복 판정 허용 오차 (해석적 교점용, 길이 대비 비율)
+const EPSILON: f64 = 1e-9;
+
+/// 베지어 분할 최대 깊이
+const MAX_DEPTH: u32 = 48;
+
+/// 교차 계산용 곡선 조각
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Edge {
+    /// 선분 또는 원호 (정확)
+    Curve(Curve),
+    /// 3차 베지어 [시작점, 제어점1, 제어점2, 끝점]
+    Cubic([[f64; 2]; 4]),
+}
+
+/// 두 곡선의 교점 (t_a는 첫 번째, t_b는 두 번째 곡선의 매개변수)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossing {
+    pub point: [f64; 2],
+    pub t_a: f64,
+    pub t_b: f64,
+}
+
+impl Crossing {
+    fn swapped(self) -> Self {
+        Crossing {
+            point: self.point,
+            t_a: self.t_b,
+            t_b: self.t_a,
+        }
+    }
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn cross(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn lerp(a: [f64; 2], b: [f64; 2], t: f64) -> [f64; 2] {
+    [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])]
+}
+
+fn dist(a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
+
+fn in_unit(t: f64) -> bool {
+    (-EPSILON..=1.0 + EPSILON).contains(&t)
+}
+
+/// 원 위의 점 각도를 호의 스윕 비율로 변환합니다 (시작 직전 각도는 0).
+fn arc_param(start: f64, sweep: f64, angle: f64) -> f64 {
+    if sweep <= 0.0 {
+        return 0.0;
+    }
+    let offset = (angle - start).rem_euclid(2.0 * PI);
+    if offset > sweep + EPSILON && sweep < 2.0 * PI - EPSILON {
+        0.0
+    } else {
+        (offset / sweep).min(1.0)
+    }
+}
+
+/// 중복 교점 제거 (먼저 나온 것 유지)
+fn push_unique(out: &mut Vec<Crossing>, crossing: Crossing, tolerance: f64) {
+    if out
+        .iter()
+        .all(|c| dist(c.point, crossing.point) > tolerance)
+    {
+        out.push(crossing);
+    }
+}
+
+fn segment_segment(a0: [f64; 2], a1: [f64; 2], b0: [f64; 2], b1: [f64; 2]) -> Vec<Crossing> {
+    let r = sub(a1, a0);
+    let s = sub(b1, b0);
+    let qp = sub(b0, a0);
+    let denom = cross(r, s);
+    let (len_r, len_s) = (r[0].hypot(r[1]), s[0].hypot(s[1]));
+    if denom.abs() > EPSILON * len_r * len_s {
+        let t = cross(qp, s) / denom;
+        let u = cross(qp, r) / denom;
+        if !(in_unit(t) && in_unit(u)) {
+            return Vec::new();
+        }
+        let t = t.clamp(0.0, 1.0);
+        return vec![Crossing {
+            point: lerp(a0, a1, t),
+            t_a: t,
+            t_b: u.clamp(0.0, 1.0),
+        }];
+    }
+
+    // 평행 (또는 길이 0): 한 선분의 끝점이 다른 선분 위에 있으면 교점 → 겹친 구간의 끝점들
+    let tolerance = EPSILON * (1.0 + len_r + len_s);
+    let project = |p: [f64; 2], o: [f64; 2], d: [f64; 2], len_sq: f64| {
+        let t = if len_sq > 0.0 {
+            (dot(sub(p, o), d) / len_sq).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (dist(lerp(o, [o[0] + d[0], o[1] + d[1]], t), p) <= tolerance).then_some(t)
+    };
+    let mut out = Vec::new();
+    for (p, t_a) in [(a0, 0.0), (a1, 1.0)] {
+        if let Some(t_b) = project(p, b0, s, len_s * len_s) {
+            push_unique(&mut out, Crossing { point: p, t_a, t_b }, tolerance);
+        }
+    }
+    for (p, t_b) in [(b0, 0.0), (b1, 1.0)] {
+        if let Some(t_a) = project(p, a0, r, len_r * len_r) {
+            push_unique(&mut out, Crossing { point: p, t_a, t_b }, tolerance);
+        }
+    }
+    out
+}
+
+fn segment_arc(
+    p0: [f64; 2],
+    p1: [f64; 2],
+    center: [f64; 2],
+    radius: f64,
+    start: f64,
+    sweep: f64,
+) -> Vec<Crossing> {
+    let d = sub(p1, p0);
+    let f = sub(p0, center);
+    let len_sq = dot(d, d);
+    let tolerance = EPSILON * (1.0 + radius + len_sq.sqrt());
+    let roots: Vec<f64> = if len_sq <= 0.0 {
+        // 길이 0 선분: 원 위의 점인지
+        if (f[0].hypot(f[1]) - radius).abs() <= tolerance {
+            vec![0.0]
+        } else {
+            Vec::new()
+        }
+    } else {
+        let len = len_sq.sqrt();
+        let foot = -dot(f, d) / len_sq;
+        let offset = cross(f, d).abs() / len;
+        if offset > radius + tolerance {
+            Vec::new()
+        } else if offset >= radius - tolerance {
+            // 접선
+            vec![foot]
+        } else {
+            let half = (radius * radius - offset * offset).sqrt() / len;
+            vec![foot - half, foot + half]
+        }
+    };
+    roots
+        .into_iter()
+        .filter(|t| in_unit(*t))
+        .filter_map(|t| {
+            let t = t.clamp(0.0, 1.0);
+            let point = lerp(p0, p1, t);
+            let angle = (point[1] - center[1]).atan2(point[0] - center[0]);
+            arc_contains(start, sweep, angle).then(|| Crossing {
+                point,
+                t_a: t,
+                t_b: arc_param(start, sweep, angle),
+            })
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn arc_arc(
+    c1: [f64; 2],
+    r1: f64,
+    s1: f64,
+    w1: f64,
+    c2: [f64; 2],
+    r2: f64,
+    s2: f64,
+    w2: f64,
+) -> Vec<Crossing> {
+    let delta = sub(c2, c1);
+    let d = delta[0].hypot(delta[1]);
+    let tolerance = EPSILON * (1.0 + r1 + r2);
+    let angle_on = |c: [f64; 2], p: [f64; 2]| (p[1] - c[1]).atan2(p[0] - c[0]);
+    let crossing = |point: [f64; 2]| {
+        let (a1, a2) = (angle_on(c1, point), angle_on(c2, point));
+        (arc_contains(s1, w1, a1) && arc_contains(s2, w2, a2)).then(|| Crossing {
+            point,
+            t_a: arc_param(s1, w1, a1),
+            t_b: arc_param(s2, w2, a2),
+        })
+    };
+
+    let mut out = Vec::new();
+    if d <= tolerance {
+        // 같은 원 위의 두 호: 서로의 범위 안에 있는 끝점들 (원 전체끼리는 보고하지 않음)
+        if (r1 - r2).abs() > tolerance {
+            return out;
+        }
+        let point =
+            |c: [f64; 2], r: f64, angle: f64| [c[0] + r * angle.cos(), c[1] + r * angle.sin()];
+        let full = 2.0 * PI - EPSILON;
+        let mut candidates = Vec::new();
+        if w1 < full {
+            candidates.extend([point(c1, r1, s1), point(c1, r1, s1 + w1)]);
+        }
+        if w2 < full {
+            candidates.extend([point(c2, r2, s2), point(c2, r2, s2 + w2)]);
+        }
+        for p in candidates {
+            if let Some(c) = crossing(p) {
+                push_unique(&mut out, c, tolerance);
+            }
+        }
+        return out;
+    }
+    if d > r1 + r2 + tolerance || d < (r1 - r2).abs() - tolerance {
+        return out;
+    }
+    let along = (d * d + r1 * r1 - r2 * r2) / (2.0 * d);
+    // 접하는 경우 h²의 반올림 오차로 두 점이 갈라지지 않도록 0으로 맞춤
+    let h_sq = r1 * r1 - along * along;
+    let h = if h_sq <= tolerance * r1 {
+        0.0
+    } else {
+        h_sq.sqrt()
+    };
+    let u = [delta[0] / d, delta[1] / d];
+    let base = [c1[0] + along * u[0], c1[1] + along * u[1]];
+    for sign in [1.0, -1.0] {
+        let p = [base[0] - sign * h * u[1], base[1] + sign * h * u[0]];
+        if let Some(c) = crossing(p) {
+            push_unique(&mut out, c, tolerance);
+        }
+    }
+    out
+}
+
+/// 두 선분/원호의 교점 (해석적)
+pub fn curve_intersections(a: &Curve, b: &Curve) -> Vec<Crossing> {
+    match (*a, *b) {
+        (Curve::Segment(a0, a1), Curve::Segment(b0, b1)) => segment_segment(a0, a1, b0, b1),
+        (
+            Curve::Segment(p0, p1),
+            Curve::Arc {
+                center,
+                radius,
+                start,
+                sweep,
+            },
+        ) => segment_arc(p0, p1, center, radius, start, sweep),
+        (Curve::Arc { .. }, Curve::Segment(..)) => curve_intersections(b, a)
+            .into_iter()
+            .map(Crossing::swapped)
+            .collect(),
+        (
+            Curve::Arc {
+                center: c1,
+                radius: r1,
+                start: s1,
+                sweep: w1,
+            },
+            Curve::Arc {
+                center: c2,
+                radius: r2,
+                start: s2,
+                sweep: w2,
+            },
+        ) => arc_arc(c1, r1, s1, w1, c2, r2, s2, w2),
+    }
+}
+
+/// 베지어의 매개변수 구간 [t0, t1] 조각
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    cubic: [[f64; 2]; 4],
+    t0: f64,
+    t1: f64,
+}
+
+impl Span {
+    /// de Casteljau로 절반 분할
+    fn split(&self) -> (Span, Span) {
+        let [p0, p1, p2, p3] = self.cubic;
+        let p01 = lerp(p0, p1, 0.5);
+        let p12 = lerp(p1, p2, 0.5);
+        let p23 = lerp(p2, p3, 0.5);
+        let p012 = lerp(p01, p12, 0.5);
+        let p123 = lerp(p12, p23, 0.5);
+        let mid = lerp(p012, p123, 0.5);
+        let tm = (self.t0 + self.t1) / 2.0;
+        (
+            Span {
+                cubic: [p0, p01, p012, mid],
+                t0: self.t0,
+                t1: tm,
+            },
+            Span {
+                cubic: [mid, p123, p23, p3],
+                t0: tm,
+                t1: self.t1,
+            },
+        )
+    }
+
+    /// 제어점이 모두 현(시작 → 끝)에서 tolerance 이내인지
+    fn is_flat(&self, tolerance: f64) -> bool {
+        let [p0, p1, p2, p3] = self.cubic;
+        let chord = sub(p3, p0);
+        let len = chord[0].hypot(chord[1]);
+        if len <= tolerance {
+            return dist(p1, p0) <= tolerance && dist(p2, p0) <= tolerance;
+        }
+        [p1, p2]
+            .iter()
+            .all(|p| cross(chord, sub(*p, p0)).abs() / len <= tolerance)
+    }
+
+    fn global(&self, t: f64) -> f64 {
+        self.t0 + t * (self.t1 - self.t0)
+    }
+}
+
+/// 분할 중인 조각 (정확한 곡선이거나 베지어 구간)
+#[derive(Debug, Clone, Copy)]
+enum Part {
+    Exact(Curve),
+    Span(Span),
+}
+
+impl Part {
+    fn bbox(&self) -> ([f64; 2], [f64; 2]) {
+        let points: Vec<[f64; 2]> = match self {
+            Part::Exact(Curve::Segment(a, b)) => vec![*a, *b],
+            // 원 전체 상자 (보수적)
+            Part::Exact(Curve::Arc { center, radius, .. }) => vec![
+                [center[0] - radius, center[1] - radius],
+                [center[0] + radius, center[1] + radius],
+            ],
+            Part::Span(span) => span.cubic.to_vec(),
+        };
+        points.iter().fold(
+            ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]),
+            |(lo, hi), p| {
+                (
+                    [lo[0].min(p[0]), lo[1].min(p[1])],
+                    [hi[0].max(p[0]), hi[1].max(p[1])],
+                )
+            },
+        )
+    }
+
+    /// 평평한 조각은 현 선분으로 취급
+    fn as_curve(&self, tolerance: f64, force: bool) -> Option<Curve> {
+        match self {
+            Part::Exact(curve) => Some(*curve),
+            Part::Span(span) => (force || span.is_flat(tolerance))
+                .then(|| Curve::Segment(span.cubic[0], span.cubic[3])),
+        }
+    }
+
+    fn global(&self, t: f64) -> f64 {
+        match self {
+            Part::Exact(_) => t,
+            Part::Span(span) => span.global(t),
+        }
+    }
+}
+
+fn subdivide(a: Part, b: Part, tolerance: f64, depth: u32, out: &mut Vec<Crossing>) {
+    let (a_lo, a_hi) = a.bbox();
+    let (b_lo, b_hi) = b.bbox();
+    if a_lo[0] > b_hi[0] + tolerance
+        || b_lo[0] > a_hi[0] + tolerance
+        || a_lo[1] > b_hi[1] + tolerance
+        || b_lo[1] > a_hi[1] + tolerance
+    {
+        return;
+    }
+
+    let force = depth >= MAX_DEPTH;
+    match (a.as_curve(tolerance, force), b.as_curve(tolerance, force)) {
+        (Some(ca), Some(cb)) => {
+            for c in curve_intersections(&ca, &cb) {
+                let crossing = Crossing {
+                    point: c.point,
+                    t_a: a.global(c.t_a),
+                    t_b: b.global(c.t_b),
+                };
+                push_unique(out, crossing, tolerance);
+            }
+        }
+        (None, Some(_)) => {
+            let Part::Span(span) = a else { unreachable!() };
+            let (left, right) = span.split();
+            subdivide(Part::Span(left), b, tolerance, depth + 1, out);
+            subdivide(Part::Span(right), b, tolerance, depth + 1, out);
+        }
+        (_, None) => {
+            let Part::Span(span) = b else { unreachable!() };
+            let (left, right) = span.split();
+            subdivide(a, Part::Span(left), tolerance, depth + 1, out);
+            subdivide(a, Part::Span(right), tolerance, depth + 1, out);
+        }
+    }
+}
+
+/// 두 곡선 조각의 교점을 첫 번째 곡선의 매개변수 순으로 반환합니다.
+///
+/// # Arguments
+/// * `tolerance` - 베지어 평탄도/중복 교점 허용 오차 (양수, 좌표 단위). 선분/원호끼리는 사용하지 않음
+pub fn edge_intersections(a: &Edge, b: &Edge, tolerance: f64) -> Vec<Crossing> {
+    let part = |edge: &Edge| match edge {
+        Edge::Curve(curve) => Part::Exact(*curve),
+        Edge::Cubic(cubic) => Part::Span(Span {
+            cubic: *cubic,
+            t0: 0.0,
+            t1: 1.0,
+        }),
+    };
+    let mut out = match (a, b) {
+        (Edge::Curve(ca), Edge::Curve(cb)) => curve_intersections(ca, cb),
+        _ => {
+            let mut out = Vec::new();
+            subdivide(part(a), part(b), tolerance, 0, &mut out);
+            out
+        }
+    };
+    out.sort_by(|x, y| x.t_a.total_cmp(&y.t_a));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::flatten::cubic_point;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn circle(center: [f64; 2], radius: f64) -> Curve {
+        Curve::Arc {
+            center,
+            radius,
+            start: 0.0,
+            sweep: 2.0 * PI,
+        }
+    }
+
+    #[test]
+    fn test_segment_segment_cross_and_overlap() {
+        let a = Curve::Segment([0.0, 0.0], [10.0, 0.0]);
+        let b = Curve::Segment([4.0, -2.0], [4.0, 8.0]);
+        let hits = curve_intersections(&a, &b);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].point, [4.0, 0.0]);
+        assert!(approx_eq(hits[0].t_a, 0.4, 1e-12) && approx_eq(hits[0].t_b, 0.2, 1e-12));
+
+        // 평행하지만 떨어짐 / 공선 겹침 → 겹친 구간 끝점
+        let parallel = Curve::Segment([0.0, 1.0], [10.0, 1.0]);
+        assert!(curve_intersections(&a, &parallel).is_empty());
+        let overlap = Curve::Segment([5.0, 0.0], [15.0, 0.0]);
+        let hits = curve_intersections(&a, &overlap);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().any(|c| c.point == [10.0, 0.0] && c.t_a == 1.0));
+        assert!(hits.iter().any(|c| c.point == [5.0, 0.0] && c.t_b == 0.0));
+
+        // 끝점끼리 맞닿음
+        let touching = Curve::Segment([10.0, 0.0], [10.0, 5.0]);
+        let hits = curve_intersections(&a, &touching);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].t_a, hits[0].t_b), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_segment_circle_and_arc() {
+        let c = circle([0.0, 0.0], 5.0);
+        let line = Curve::Segment([-10.0, 3.0], [10.0, 3.0]);
+        let hits = curve_intersections(&line, &c);
+        assert_eq!(hits.len(), 2);
+        assert!(approx_eq(hits[0].point[0], -4.0, 1e-12));
+        assert!(approx_eq(hits[1].point[0], 4.0, 1e-12));
+        assert!(approx_eq(hits[1].t_a, 0.7, 1e-12));
+        // (4, 3)의 각도 비율
+        assert!(approx_eq(
+            hits[1].t_b,
+            3.0_f64.atan2(4.0) / (2.0 * PI),
+            1e-12
+        ));
+
+        // 접선 → 교점 하나
+        let tangent = Curve::Segment([-10.0, 5.0], [10.0, 5.0]);
+        let hits = curve_intersections(&c, &tangent);
+        assert_eq!(hits.len(), 1);
+        assert!(approx_eq(hits[0].point[0], 0.0, 1e-9));
+        assert!(approx_eq(hits[0].t_a, 0.25, 1e-9));
+
+        // 위쪽 반원은 y = -3 선과 만나지 않음
+        let upper = Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 5.0,
+            start: 0.0,
+            sweep: PI,
+        };
+        let below = Curve::Segment([-10.0, -3.0], [10.0, -3.0]);
+        assert!(curve_intersections(&upper, &below).is_empty());
+        assert_eq!(curve_intersections(&upper, &line).len(), 2);
+    }
+
+    #[test]
+    fn test_circle_circle() {
+        let hits = curve_intersections(&circle([0.0, 0.0], 5.0), &circle([8.0, 0.0], 5.0));
+        assert_eq!(hits.len(), 2);
+        for c in &hits {
+            assert!(approx_eq(c.point[0], 4.0, 1e-12) && approx_eq(c.point[1].abs(), 3.0, 1e-12));
+        }
+        // 외접 → 하나, 떨어짐 → 없음
+        assert_eq!(
+            curve_intersections(&circle([0.0, 0.0], 5.0), &circle([10.0, 0.0], 5.0)).len(),
+            1
+        );
+        assert!(
+            curve_intersections(&circle([0.0, 0.0], 1.0), &circle([10.0, 0.0], 1.0)).is_empty()
+        );
+
+        // 같은 원 위에서 겹치는 두 호 → 겹친 구간 끝점
+        let a = Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 1.0,
+            start: 0.0,
+            sweep: PI,
+        };
+        let b = Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 1.0,
+            start: PI / 2.0,
+            sweep: PI,
+        };
+        let hits = curve_intersections(&a, &b);
+        assert_eq!(hits.len(), 2);
+        assert!(
+            hits.iter()
+                .any(|c| approx_eq(c.t_a, 1.0, 1e-9) && approx_eq(c.t_b, 0.5, 1e-9))
+        );
+    }
+
+    #[test]
+    fn test_cubic_intersections_by_subdivision() {
+        let cubic = [[0.0, 0.0], [0.0, 4.0], [10.0, 4.0], [10.0, 0.0]];
+        let line = Edge::Curve(Curve::Segment([-1.0, 1.0], [11.0, 1.0]));
+        let hits = edge_intersections(&Edge::Cubic(cubic), &line, 1e-9);
+        assert_eq!(hits.len(), 2);
+        for c in &hits {
+            assert!(approx_eq(c.point[1], 1.0, 1e-9));
+            let on_curve = cubic_point(cubic[0], cubic[1], cubic[2], cubic[3], c.t_a);
+            assert!(approx_eq(on_curve[0], c.point[0], 1e-6));
+        }
+        assert!(hits[0].t_a < hits[1].t_a);
+
+        // 위아래로 뒤집힌 베지어: y = 1.5 위의 교점 둘
+        let mirrored = [[0.0, 3.0], [0.0, -1.0], [10.0, -1.0], [10.0, 3.0]];
+        let hits = edge_intersections(&Edge::Cubic(cubic), &Edge::Cubic(mirrored), 1e-9);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|c| approx_eq(c.point[1], 1.5, 1e-6)));
+
+        // 베지어와 원
+        let c = Edge::Curve(circle([5.0, 2.0], 2.0));
+        let hits = edge_intersections(&c, &Edge::Cubic(cubic), 1e-9);
+        assert_eq!(hits.len(), 2);
+        assert!(
+            hits.iter()
+                .all(|h| approx_eq(dist(h.point, [5.0, 2.0]), 2.0, 1e-6))
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! 순수 기하 알고리즘 모듈
+//!
+//! Scene 상태에 의존하지 않는 계산 로직(곡선 근사, 폴리곤 연산 등)을 모아둡니다.
+//! Scene 연동(이름 조회, 월드 변환, WASM 바인딩)은 `scene/` 하위 모듈에서 처리합니다.
+
+pub mod boolean;
+pub mod bounds;
+pub mod constraint;
+pub mod corner;
+pub mod dimension;
+pub mod distance;
+pub mod flatten;
+pub mod hatch;
+pub mod hit;
+pub mod intersect;
+pub mod offset;
+pub mod path;
+pub mod polygon;
+pub mod snap;
+pub mod spatial;
+pub mod text;
+pub mod triangulate;
@@ -0,0 +1,298 @@
+//! 폴리곤/경로 오프셋 (outset / inset)
+//!
+//! 알고리즘 (boundary buffer + boolean):
+//! 1. 입력 영역을 정규화 (외곽선 CCW, 구멍 CW → 모든 링에서 내부가 왼쪽)
+//! 2. 경계의 각 변을 폭 2d 사각형으로, 각 꺾임점의 벌어진 쪽을 join 조각으로 덮어 buffer 생성
+//! 3. outset = 영역 ∪ buffer, inset = 영역 − buffer
+//!
+//! inset으로 도형이 여러 조각으로 갈라지면 결과도 여러 폴리곤이 됩니다.
+//! 곡선은 사전에 `flatten` 모듈로 근사되어 있어야 합니다.
+
+use std::f64::consts::PI;
+
+use super::boolean::{BooleanOp, boolean};
+use super::bounds::SVG_MITER_LIMIT;
+use super::polygon::{PolygonWithHoles, Region, Ring};
+use crate::scene::entity::LineJoin;
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn add_scaled(p: [f64; 2], v: [f64; 2], s: f64) -> [f64; 2] {
+    [p[0] + v[0] * s, p[1] + v[1] * s]
+}
+
+/// 변 방향의 왼쪽 단위 법선 (길이 0이면 None)
+fn left_normal(a: [f64; 2], b: [f64; 2]) -> Option<[f64; 2]> {
+    let d = sub(b, a);
+    let len = d[0].hypot(d[1]);
+    (len > 1e-12).then(|| [-d[1] / len, d[0] / len])
+}
+
+/// 변 하나를 덮는 폭 2d 사각형
+fn edge_piece(a: [f64; 2], b: [f64; 2], d: f64) -> Option<Region> {
+    let n = left_normal(a, b)?;
+    Some(Region {
+        rings: vec![vec![
+            add_scaled(a, n, d),
+            add_scaled(b, n, d),
+            add_scaled(b, n, -d),
+            add_scaled(a, n, -d),
+        ]],
+    })
+}
+
+/// 꺾임점 p에서 법선 n1 → n2 사이(벌어진 쪽)를 채우는 join 조각
+///
+/// * `arc_step` - Round join의 최대 각도 간격 (라디안)
+fn join_piece(
+    p: [f64; 2],
+    n1: [f64; 2],
+    n2: [f64; 2],
+    d: f64,
+    join: LineJoin,
+    arc_step: f64,
+) -> Option<Region> {
+    let cos = (n1[0] * n2[0] + n1[1] * n2[1]).clamp(-1.0, 1.0);
+    let sin = n1[0] * n2[1] - n1[1] * n2[0];
+    let angle = sin.atan2(cos);
+    if angle.abs() < 1e-9 {
+        return None;
+    }
+    let a = add_scaled(p, n1, d);
+    let b = add_scaled(p, n2, d);
+
+    let ring: Ring = match join {
+        LineJoin::Bevel => vec![p, a, b],
+        LineJoin::Miter => {
+            // 반각 θ/2: 법선 사이 각도의 절반, miter 길이 = d / cos(θ/2)
+            let half_cos = (angle / 2.0).cos();
+            if half_cos > 1e-12 && 1.0 / half_cos <= SVG_MITER_LIMIT {
+                let bisector = [n1[0] + n2[0], n1[1] + n2[1]];
+                let len = bisector[0].hypot(bisector[1]);
+                let tip = add_scaled(p, bisector, d / (half_cos * len));
+                vec![p, a, tip, b]
+            } else {
+                vec![p, a, b]
+            }
+        }
+        LineJoin::Round => {
+            let start = n1[1].atan2(n1[0]);
+            let steps = ((angle.abs() / arc_step).ceil() as usize).max(1);
+            let mut ring = vec![p];
+            ring.extend((0..=steps).map(|i| {
+                let t = start + angle * i as f64 / steps as f64;
+                [p[0] + d * t.cos(), p[1] + d * t.sin()]
+            }));
+            ring
+        }
+    };
+    Some(Region { rings: vec![ring] })
+}
+
+/// 경로(닫힌 링 또는 열린 폴리라인)의 양쪽 d 폭 buffer 조각들
+fn boundary_buffer(
+    points: &[[f64; 2]],
+    closed: bool,
+    d: f64,
+    join: LineJoin,
+    arc_step: f64,
+) -> Vec<Region> {
+    // 연속 중복점 제거
+    let mut pts: Vec<[f64; 2]> = Vec::with_capacity(points.len());
+    for p in points {
+        if pts.last().is_none_or(|q| left_normal(*q, *p).is_some()) {
+            pts.push(*p);
+        }
+    }
+    if closed && pts.len() > 1 && left_normal(pts[pts.len() - 1], pts[0]).is_none() {
+        pts.pop();
+    }
+    let n = pts.len();
+    if n < 2 {
+        return Vec::new();
+    }
+
+    let edge_count = if closed { n } else { n - 1 };
+    let mut pieces: Vec<Region> = (0..edge_count)
+        .filter_map(|i| edge_piece(pts[i], pts[(i + 1) % n], d))
+        .collect();
+
+    let (first, last) = if closed { (0, n) } else { (1, n - 1) };
+    for i in first..last {
+        let prev = pts[(i + n - 1) % n];
+        let cur = pts[i];
+        let next = pts[(i + 1) % n];
+        let (Some(n1), Some(n2)) = (left_normal(prev, cur), left_normal(cur, next)) else {
+            continue;
+        };
+        // 좌회전이면 오른쪽, 우회전이면 왼쪽이 벌어짐
+        let turn = n1[0] * n2[1] - n1[1] * n2[0];
+        let side = if turn > 0.0 { -1.0 } else { 1.0 };
+        let (g1, g2) = ([n1[0] * side, n1[1] * side], [n2[0] * side, n2[1] * side]);
+        pieces.extend(join_piece(cur, g1, g2, d, join, arc_step));
+    }
+    pieces
+}
+
+/// 닫힌 영역을 distance만큼 오프셋합니다.
+///
+/// # Arguments
+/// * `regions` - even-odd Region들의 합집합
+/// * `distance` - 양수 = outset(바깥), 음수 = inset(안쪽)
+/// * `join` - 꺾임점 처리 (Miter는 SVG 기본 miter limit 4 초과 시 Bevel)
+/// * `curve_segments` - Round join 근사 정밀도 (원 전체 세그먼트 수)
+///
+/// # Returns
+/// 면적이 큰 순서로 정렬된 폴리곤 목록 (inset으로 모두 사라지면 빈 목록)
+pub fn offset_regions(
+    regions: &[Region],
+    distance: f64,
+    join: LineJoin,
+    curve_segments: usize,
+) -> Vec<PolygonWithHoles> {
+    // 정규화: 외곽선 CCW, 구멍 CW → 내부가 항상 왼쪽
+    let base = boolean(regions, &[], BooleanOp::Union);
+    if distance == 0.0 || !distance.is_finite() || base.is_empty() {
+        return base;
+    }
+
+    let d = distance.abs();
+    let arc_step = 2.0 * PI / curve_segments.max(3) as f64;
+    let buffer: Vec<Region> = base
+        .iter()
+        .flat_map(|poly| std::iter::once(&poly.outer).chain(poly.holes.iter()))
+        .flat_map(|ring| boundary_buffer(ring, true, d, join, arc_step))
+        .collect();
+    let base_regions: Vec<Region> = base.iter().map(|p| p.to_region()).collect();
+
+    let op = if distance > 0.0 {
+        BooleanOp::Union
+    } else {
+        BooleanOp::Difference
+    };
+    boolean(&base_regions, &buffer, op)
+}
+
+/// 열린 폴리라인의 양쪽으로 distance 폭을 갖는 외곽 폴리곤 (butt cap)
+///
+/// 벽 중심선 → 두께 있는 벽처럼 열린 경로를 닫힌 영역으로 만듭니다.
+pub fn offset_polyline(
+    points: &[[f64; 2]],
+    distance: f64,
+    join: LineJoin,
+    curve_segments: usize,
+) -> Vec<PolygonWithHoles> {
+    let d = distance.abs();
+    if d == 0.0 || !d.is_finite() {
+        return Vec::new();
+    }
+    let arc_step = 2.0 * PI / curve_segments.max(3) as f64;
+    let buffer = boundary_buffer(points, false, d, join, arc_step);
+    boolean(&buffer, &[], BooleanOp::Union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn square(min: f64, max: f64) -> Region {
+        Region {
+            rings: vec![vec![[min, min], [max, min], [max, max], [min, max]]],
+        }
+    }
+
+    fn total_area(polygons: &[PolygonWithHoles]) -> f64 {
+        polygons.iter().map(|p| p.area()).sum()
+    }
+
+    #[test]
+    fn test_outset_square_miter() {
+        let result = offset_regions(&[square(0.0, 10.0)], 2.0, LineJoin::Miter, 64);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].outer.len(), 4);
+        assert!(approx_eq(total_area(&result), 196.0, 1e-9));
+    }
+
+    #[test]
+    fn test_outset_square_bevel_and_round() {
+        let bevel = offset_regions(&[square(0.0, 10.0)], 2.0, LineJoin::Bevel, 64);
+        // 100 + 4·(10·2) + 4·(2·2/2)
+        assert!(approx_eq(total_area(&bevel), 188.0, 1e-9));
+
+        let round = offset_regions(&[square(0.0, 10.0)], 2.0, LineJoin::Round, 256);
+        let exact = 100.0 + 80.0 + PI * 4.0;
+        assert!((total_area(&round) - exact).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_inset_square() {
+        let result = offset_regions(&[square(0.0, 10.0)], -2.0, LineJoin::Miter, 64);
+        assert_eq!(result.len(), 1);
+        assert!(approx_eq(total_area(&result), 36.0, 1e-9));
+    }
+
+    #[test]
+    fn test_inset_consumes_shape() {
+        let result = offset_regions(&[square(0.0, 10.0)], -6.0, LineJoin::Miter, 64);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_inset_splits_dumbbell() {
+        // 두 10x10 사각형을 폭 2 통로로 연결 → inset 1.5면 통로가 사라져 2조각
+        let dumbbell = Region {
+            rings: vec![vec![
+                [0.0, 0.0],
+                [10.0, 0.0],
+                [10.0, 4.0],
+                [20.0, 4.0],
+                [20.0, 0.0],
+                [30.0, 0.0],
+                [30.0, 10.0],
+                [20.0, 10.0],
+                [20.0, 6.0],
+                [10.0, 6.0],
+                [10.0, 10.0],
+                [0.0, 10.0],
+            ]],
+        };
+        let result = offset_regions(&[dumbbell], -1.5, LineJoin::Miter, 64);
+        assert_eq!(result.len(), 2);
+        assert!(approx_eq(result[0].area(), 49.0, 1e-9));
+        assert!(approx_eq(result[1].area(), 49.0, 1e-9));
+    }
+
+    #[test]
+    fn test_outset_shrinks_hole() {
+        let plate = Region {
+            rings: vec![
+                vec![[0.0, 0.0], [20.0, 0.0], [20.0, 20.0], [0.0, 20.0]],
+                vec![[5.0, 5.0], [15.0, 5.0], [15.0, 15.0], [5.0, 15.0]],
+            ],
+        };
+        let result = offset_regions(&[plate], 1.0, LineJoin::Miter, 64);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].holes.len(), 1);
+        // 외곽 22x22 - 구멍 8x8
+        assert!(approx_eq(total_area(&result), 484.0 - 64.0, 1e-9));
+    }
+
+    #[test]
+    fn test_offset_polyline_wall() {
+        let result = offset_polyline(
+            &[[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            1.0,
+            LineJoin::Miter,
+            64,
+        );
+        assert_eq!(result.len(), 1);
+        // 두 벽 (10x2) 2개 - 겹침 1x1 + 바깥 모서리 miter 1x1
+        assert!(approx_eq(total_area(&result), 40.0, 1e-9));
+    }
+}
@@ -0,0 +1,657 @@
+//! Path 도형 유틸리티
+//!
+//! `Geometry::Path`의 subpath를 계산용 조각(`PathPiece`)으로 펼칩니다.
+//! - 원호의 시작점이 이전 끝점과 다르면 그 사이 직선, 닫힌 subpath의 닫는 직선도 조각으로 포함
+//! - 2차 베지어는 같은 곡선의 3차 베지어로 승격 (정확)
+//! - 원호는 rx = ry인 타원호로 통일
+//!
+//! 바운드(bounds), 곡선 근사(flatten)가 이 조각들을 사용하고,
+//! SVG 직렬화는 원래 세그먼트를 그대로 출력합니다.
+
+use std::f64::consts::PI;
+
+use super::flatten::{
+    arc_steps_for_tolerance, cubic_point, cubic_steps_for_tolerance, ellipse_point,
+};
+use crate::scene::entity::{PathSegment, Subpath};
+
+/// 계산용 Path 조각
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathPiece {
+    Line([f64; 2], [f64; 2]),
+    /// [시작점, 제어점1, 제어점2, 끝점]
+    Cubic([[f64; 2]; 4]),
+    /// 타원호 (sweep 부호 = 방향, 양수 = CCW)
+    Arc {
+        center: [f64; 2],
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start_angle: f64,
+        sweep: f64,
+    },
+}
+
+impl PathPiece {
+    pub fn start(&self) -> [f64; 2] {
+        match self {
+            PathPiece::Line(a, _) => *a,
+            PathPiece::Cubic(p) => p[0],
+            PathPiece::Arc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                ..
+            } => ellipse_point(*center, *rx, *ry, *rotation, *start_angle),
+        }
+    }
+
+    pub fn end(&self) -> [f64; 2] {
+        match self {
+            PathPiece::Line(_, b) => *b,
+            PathPiece::Cubic(p) => p[3],
+            PathPiece::Arc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+            } => ellipse_point(*center, *rx, *ry, *rotation, start_angle + sweep),
+        }
+    }
+
+    /// 시작/끝 지점의 진행 방향 접선 (정규화하지 않음)
+    pub fn tangents(&self) -> ([f64; 2], [f64; 2]) {
+        match self {
+            PathPiece::Line(a, b) => {
+                let d = [b[0] - a[0], b[1] - a[1]];
+                (d, d)
+            }
+            PathPiece::Cubic([p0, p1, p2, p3]) => {
+                let pick = |candidates: [([f64; 2], [f64; 2]); 3]| {
+                    candidates
+                        .into_iter()
+                        .map(|(from, to)| [to[0] - from[0], to[1] - from[1]])
+                        .find(|d| d[0].hypot(d[1]) > 1e-12)
+                        .unwrap_or([0.0, 0.0])
+                };
+                (
+                    pick([(*p0, *p1), (*p0, *p2), (*p0, *p3)]),
+                    pick([(*p2, *p3), (*p1, *p3), (*p0, *p3)]),
+                )
+            }
+            PathPiece::Arc {
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+                ..
+            } => {
+                let dir = sweep.signum();
+                let tangent = |t: f64| {
+                    let (sin_r, cos_r) = rotation.sin_cos();
+                    let dx = -rx * t.sin() * dir;
+                    let dy = ry * t.cos() * dir;
+                    [dx * cos_r - dy * sin_r, dx * sin_r + dy * cos_r]
+                };
+                (tangent(*start_angle), tangent(start_angle + sweep))
+            }
+        }
+    }
+
+    /// 조각을 폴리라인으로 근사 (시작점, 끝점 포함)
+    ///
+    /// * `curve_segments` - 원 전체 기준 세그먼트 수 (큐빅 하나는 1/4 원으로 취급)
+    pub fn points(&self, curve_segments: usize) -> Vec<[f64; 2]> {
+        match self {
+            PathPiece::Line(a, b) => vec![*a, *b],
+            PathPiece::Cubic([p0, p1, p2, p3]) => {
+                let steps = (curve_segments / 4).max(4);
+                (0..=steps)
+                    .map(|i| cubic_point(*p0, *p1, *p2, *p3, i as f64 / steps as f64))
+                    .collect()
+            }
+            PathPiece::Arc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+            } => {
+                let steps = ((curve_segments.max(3) as f64) * sweep.abs() / (2.0 * PI))
+                    .ceil()
+                    .max(1.0) as usize;
+                (0..=steps)
+                    .map(|i| {
+                        let t = start_angle + sweep * i as f64 / steps as f64;
+                        ellipse_point(*center, *rx, *ry, *rotation, t)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// 조각을 현 허용 오차 이내의 폴리라인으로 근사 (시작점, 끝점 포함)
+    pub fn points_within(&self, tolerance: f64) -> Vec<[f64; 2]> {
+        match self {
+            PathPiece::Line(a, b) => vec![*a, *b],
+            PathPiece::Cubic(p) => {
+                let steps = cubic_steps_for_tolerance(p, tolerance);
+                (0..=steps)
+                    .map(|i| cubic_point(p[0], p[1], p[2], p[3], i as f64 / steps as f64))
+                    .collect()
+            }
+            PathPiece::Arc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+            } => {
+                let steps = arc_steps_for_tolerance(rx.max(*ry), *sweep, tolerance);
+                (0..=steps)
+                    .map(|i| {
+                        let t = start_angle + sweep * i as f64 / steps as f64;
+                        ellipse_point(*center, *rx, *ry, *rotation, t)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// 원호 → 큐빅 변환 기본 허용 오차 (도면 단위)
+pub const DEFAULT_ARC_TOLERANCE: f64 = 0.01;
+
+/// 원호 → 큐빅 변환의 최대 분할 수
+const MAX_ARC_PIECES: usize = 1024;
+
+/// 타원호를 큐빅 베지어들로 근사합니다 (각 조각의 최대 반지름 오차 ≤ tolerance).
+///
+/// 조각 각도 θ의 큐빅(k = 4/3·tan(θ/4)) 최대 오차는 약 r·2·sin⁶(θ/4) / (27·cos²(θ/4))이며,
+/// 90°를 넘는 조각은 만들지 않습니다.
+///
+/// # Returns
+/// 세그먼트 [cp1, cp2, end] 목록 (시작점은 호의 시작점)
+pub fn arc_to_cubics(
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    start_angle: f64,
+    sweep: f64,
+    tolerance: f64,
+) -> Vec<[[f64; 2]; 3]> {
+    if sweep == 0.0 {
+        return Vec::new();
+    }
+    let r = rx.abs().max(ry.abs());
+    let tolerance = if tolerance.is_finite() && tolerance > 0.0 {
+        tolerance
+    } else {
+        DEFAULT_ARC_TOLERANCE
+    };
+    let error = |theta: f64| {
+        let q = theta.abs() / 4.0;
+        r * 2.0 * q.sin().powi(6) / (27.0 * q.cos().powi(2))
+    };
+    let mut pieces = ((sweep.abs() / (PI / 2.0)).ceil() as usize).max(1);
+    while pieces < MAX_ARC_PIECES && error(sweep / pieces as f64) > tolerance {
+        pieces *= 2;
+    }
+    let pieces = pieces.min(MAX_ARC_PIECES);
+
+    let step = sweep / pieces as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let (sin_r, cos_r) = rotation.sin_cos();
+    let derivative = |t: f64| {
+        let dx = -rx * t.sin();
+        let dy = ry * t.cos();
+        [dx * cos_r - dy * sin_r, dx * sin_r + dy * cos_r]
+    };
+    (0..pieces)
+        .map(|i| {
+            let t0 = start_angle + step * i as f64;
+            let t1 = t0 + step;
+            let p0 = ellipse_point(center, rx, ry, rotation, t0);
+            let p1 = ellipse_point(center, rx, ry, rotation, t1);
+            let (d0, d1) = (derivative(t0), derivative(t1));
+            [
+                [p0[0] + k * d0[0], p0[1] + k * d0[1]],
+                [p1[0] - k * d1[0], p1[1] - k * d1[1]],
+                p1,
+            ]
+        })
+        .collect()
+}
+
+/// subpath를 큐빅 세그먼트만으로 변환합니다 (Bezier geometry용).
+///
+/// 직선/2차 베지어는 정확히, 원호는 tolerance 이내로 변환하며 닫힌 subpath의 닫는 직선을 포함합니다.
+pub fn subpath_to_cubics(subpath: &Subpath, tolerance: f64) -> Vec<[[f64; 2]; 3]> {
+    let mut segments = Vec::new();
+    for piece in subpath_pieces(subpath) {
+        match piece {
+            PathPiece::Line(a, b) => segments.push([
+                [a[0] + (b[0] - a[0]) / 3.0, a[1] + (b[1] - a[1]) / 3.0],
+                [
+                    a[0] + 2.0 * (b[0] - a[0]) / 3.0,
+                    a[1] + 2.0 * (b[1] - a[1]) / 3.0,
+                ],
+                b,
+            ]),
+            PathPiece::Cubic([_, c1, c2, end]) => segments.push([c1, c2, end]),
+            PathPiece::Arc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+            } => segments.extend(arc_to_cubics(
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep,
+                tolerance,
+            )),
+        }
+    }
+    segments
+}
+
+/// subpath의 원호/타원호 세그먼트만 큐빅으로 바꿉니다 (나머지 세그먼트는 그대로).
+pub fn subpath_arcs_to_cubics(subpath: &Subpath, tolerance: f64) -> Subpath {
+    let mut segments = Vec::with_capacity(subpath.segments.len());
+    let mut current = subpath.start;
+    for segment in &subpath.segments {
+        let arc = match segment {
+            PathSegment::Arc {
+                center,
+                radius,
+                start_angle,
+                sweep_angle,
+            } => Some((*center, *radius, *radius, 0.0, *start_angle, *sweep_angle)),
+            PathSegment::EllipticalArc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep_angle,
+            } => Some((*center, *rx, *ry, *rotation, *start_angle, *sweep_angle)),
+            _ => None,
+        };
+        match arc {
+            Some((center, rx, ry, rotation, start_angle, sweep)) => {
+                let arc_start = ellipse_point(center, rx, ry, rotation, start_angle);
+                if !same_point(current, arc_start) {
+                    segments.push(PathSegment::Line { to: arc_start });
+                }
+                segments.extend(
+                    arc_to_cubics(center, rx, ry, rotation, start_angle, sweep, tolerance)
+                        .into_iter()
+                        .map(|[control1, control2, to]| PathSegment::Cubic {
+                            control1,
+                            control2,
+                            to,
+                        }),
+                );
+            }
+            None => segments.push(segment.clone()),
+        }
+        current = segment_end(segment);
+    }
+    Subpath {
+        start: subpath.start,
+        segments,
+        closed: subpath.closed,
+    }
+}
+
+/// SVG 끝점 형식 원호(A 명령)를 중심 형식 세그먼트로 변환합니다 (SVG 명세 F.6.5/F.6.6).
+///
+/// # Arguments
+/// * `from`, `to` - 시작점, 끝점
+/// * `rx`, `ry` - 반지름 (부호 무시, 너무 작으면 명세대로 확대)
+/// * `rotation` - x축 회전 (라디안)
+/// * `large_arc`, `sweep` - SVG 플래그 (sweep = true → 각도 증가 방향)
+///
+/// # Returns
+/// * None - 시작점과 끝점이 같음 (명세상 생략)
+/// * Some(Line) - 반지름이 0
+/// * Some(Arc) - 원 (rx = ry), Some(EllipticalArc) - 그 외
+pub fn svg_arc_segment(
+    from: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: [f64; 2],
+) -> Option<PathSegment> {
+    if same_point(from, to) {
+        return None;
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx < 1e-12 || ry < 1e-12 {
+        return Some(PathSegment::Line { to });
+    }
+    let (sin_r, cos_r) = rotation.sin_cos();
+    // 1. 중점 기준 회전 좌표 (x1', y1')
+    let dx = (from[0] - to[0]) / 2.0;
+    let dy = (from[1] - to[1]) / 2.0;
+    let x1 = cos_r * dx + sin_r * dy;
+    let y1 = -sin_r * dx + cos_r * dy;
+    // 2. 반지름 보정
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        rx *= s;
+        ry *= s;
+    }
+    // 3. 중심 (cx', cy')
+    let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let den = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let cx1 = coef * rx * y1 / ry;
+    let cy1 = -coef * ry * x1 / rx;
+    let center = [
+        cos_r * cx1 - sin_r * cy1 + (from[0] + to[0]) / 2.0,
+        sin_r * cx1 + cos_r * cy1 + (from[1] + to[1]) / 2.0,
+    ];
+    // 4. 시작 각도와 스윕
+    let start_angle = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let end_angle = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut sweep_angle = end_angle - start_angle;
+    if sweep && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * PI;
+    } else if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * PI;
+    }
+
+    if (rx - ry).abs() <= 1e-12 * rx.max(1.0) {
+        // 원: 회전은 시작 각도에 흡수
+        Some(PathSegment::Arc {
+            center,
+            radius: rx,
+            start_angle: start_angle + rotation,
+            sweep_angle,
+        })
+    } else {
+        Some(PathSegment::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            sweep_angle,
+        })
+    }
+}
+
+fn same_point(a: [f64; 2], b: [f64; 2]) -> bool {
+    (a[0] - b[0]).abs() < 1e-9 && (a[1] - b[1]).abs() < 1e-9
+}
+
+/// 세그먼트 끝점
+pub fn segment_end(segment: &PathSegment) -> [f64; 2] {
+    match segment {
+        PathSegment::Line { to }
+        | PathSegment::Quadratic { to, .. }
+        | PathSegment::Cubic { to, .. } => *to,
+        PathSegment::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        } => ellipse_point(*center, *radius, *radius, 0.0, start_angle + sweep_angle),
+        PathSegment::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            sweep_angle,
+        } => ellipse_point(*center, *rx, *ry, *rotation, start_angle + sweep_angle),
+    }
+}
+
+/// subpath를 계산용 조각들로 펼칩니다 (원호 앞 연결 직선, 닫는 직선 포함).
+pub fn subpath_pieces(subpath: &Subpath) -> Vec<PathPiece> {
+    let mut pieces = Vec::with_capacity(subpath.segments.len() + 1);
+    let mut current = subpath.start;
+    for segment in &subpath.segments {
+        let piece = match segment {
+            PathSegment::Line { to } => PathPiece::Line(current, *to),
+            PathSegment::Quadratic { control, to } => {
+                let c1 = [
+                    current[0] + 2.0 / 3.0 * (control[0] - current[0]),
+                    current[1] + 2.0 / 3.0 * (control[1] - current[1]),
+                ];
+                let c2 = [
+                    to[0] + 2.0 / 3.0 * (control[0] - to[0]),
+                    to[1] + 2.0 / 3.0 * (control[1] - to[1]),
+                ];
+                PathPiece::Cubic([current, c1, c2, *to])
+            }
+            PathSegment::Cubic {
+                control1,
+                control2,
+                to,
+            } => PathPiece::Cubic([current, *control1, *control2, *to]),
+            PathSegment::Arc {
+                center,
+                radius,
+                start_angle,
+                sweep_angle,
+            } => PathPiece::Arc {
+                center: *center,
+                rx: *radius,
+                ry: *radius,
+                rotation: 0.0,
+                start_angle: *start_angle,
+                sweep: *sweep_angle,
+            },
+            PathSegment::EllipticalArc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                sweep_angle,
+            } => PathPiece::Arc {
+                center: *center,
+                rx: *rx,
+                ry: *ry,
+                rotation: *rotation,
+                start_angle: *start_angle,
+                sweep: *sweep_angle,
+            },
+        };
+        let piece_start = piece.start();
+        if !same_point(current, piece_start) {
+            pieces.push(PathPiece::Line(current, piece_start));
+        }
+        current = piece.end();
+        pieces.push(piece);
+    }
+    if subpath.closed && !same_point(current, subpath.start) {
+        pieces.push(PathPiece::Line(current, subpath.start));
+    }
+    pieces
+}
+
+/// subpath를 폴리라인으로 근사합니다 (시작점 포함, 닫힌 경우 마지막 점이 시작점과 같음).
+pub fn subpath_points(subpath: &Subpath, curve_segments: usize) -> Vec<[f64; 2]> {
+    let mut points = vec![subpath.start];
+    for piece in subpath_pieces(subpath) {
+        points.extend(piece.points(curve_segments).into_iter().skip(1));
+    }
+    points
+}
+
+/// 모든 subpath의 시작점과 세그먼트 끝점 (치수 Vertex 참조용, 순서대로)
+pub fn path_vertices(subpaths: &[Subpath]) -> Vec<[f64; 2]> {
+    subpaths
+        .iter()
+        .flat_map(|sub| std::iter::once(sub.start).chain(sub.segments.iter().map(segment_end)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_pieces_insert_gap_and_closing_lines() {
+        // 직선 → 반원(시작점이 떨어져 있음) → 닫기
+        let subpath = Subpath {
+            start: [0.0, 0.0],
+            segments: vec![
+                PathSegment::Line { to: [10.0, 0.0] },
+                PathSegment::Arc {
+                    center: [10.0, 5.0],
+                    radius: 5.0,
+                    start_angle: -PI / 2.0,
+                    sweep_angle: PI,
+                },
+            ],
+            closed: true,
+        };
+        let pieces = subpath_pieces(&subpath);
+        // 원호 시작점 (10,0) = 직선 끝점 → 연결 직선 없음, 닫는 직선 (10,10) → (0,0)
+        assert_eq!(pieces.len(), 3);
+        let end = pieces[1].end();
+        assert!(approx_eq(end[0], 10.0, 1e-12) && approx_eq(end[1], 10.0, 1e-12));
+        assert_eq!(pieces[2], PathPiece::Line(end, [0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_quadratic_elevated_exactly() {
+        let subpath = Subpath {
+            start: [0.0, 0.0],
+            segments: vec![PathSegment::Quadratic {
+                control: [5.0, 10.0],
+                to: [10.0, 0.0],
+            }],
+            closed: false,
+        };
+        let PathPiece::Cubic([p0, p1, p2, p3]) = subpath_pieces(&subpath)[0] else {
+            panic!("expected cubic piece");
+        };
+        // 2차 베지어 중간점 = 0.25·p0 + 0.5·c + 0.25·p2 = (5, 5)
+        let mid = cubic_point(p0, p1, p2, p3, 0.5);
+        assert!(approx_eq(mid[0], 5.0, 1e-12) && approx_eq(mid[1], 5.0, 1e-12));
+    }
+
+    #[test]
+    fn test_clockwise_arc_tangent() {
+        let piece = PathPiece::Arc {
+            center: [0.0, 0.0],
+            rx: 1.0,
+            ry: 1.0,
+            rotation: 0.0,
+            start_angle: 0.0,
+            sweep: -PI / 2.0,
+        };
+        let (start, _) = piece.tangents();
+        // (1,0)에서 CW → 아래 방향
+        assert!(approx_eq(start[0], 0.0, 1e-12) && approx_eq(start[1], -1.0, 1e-12));
+        let end = piece.end();
+        assert!(approx_eq(end[0], 0.0, 1e-12) && approx_eq(end[1], -1.0, 1e-12));
+    }
+
+    #[test]
+    fn test_arc_to_cubics_within_tolerance() {
+        for tolerance in [1.0, 0.01, 1e-6] {
+            let cubics = arc_to_cubics([0.0, 0.0], 100.0, 100.0, 0.0, 0.0, PI, tolerance);
+            let mut current = [100.0, 0.0];
+            for [c1, c2, end] in &cubics {
+                for i in 1..8 {
+                    let p = cubic_point(current, *c1, *c2, *end, i as f64 / 8.0);
+                    assert!((p[0].hypot(p[1]) - 100.0).abs() <= tolerance * 1.01);
+                }
+                current = *end;
+            }
+            assert!(approx_eq(current[0], -100.0, 1e-9) && approx_eq(current[1], 0.0, 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_svg_arc_segment_semicircle() {
+        // (0,0) → (10,0), r=5, sweep=1 → 중심 (5,0), 각도 증가 방향 반원
+        let segment = svg_arc_segment([0.0, 0.0], 5.0, 5.0, 0.0, false, true, [10.0, 0.0]);
+        let Some(PathSegment::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        }) = segment
+        else {
+            panic!("expected circular arc, got {:?}", segment);
+        };
+        assert!(approx_eq(center[0], 5.0, 1e-9) && approx_eq(center[1], 0.0, 1e-9));
+        assert!(approx_eq(radius, 5.0, 1e-9));
+        assert!(approx_eq(sweep_angle.abs(), PI, 1e-9));
+        assert!(sweep_angle > 0.0);
+        let end = segment_end(&PathSegment::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        });
+        assert!(approx_eq(end[0], 10.0, 1e-9) && approx_eq(end[1], 0.0, 1e-9));
+    }
+
+    #[test]
+    fn test_svg_arc_segment_scales_small_radii_and_ellipse() {
+        // 반지름이 너무 작으면 확대 → 반원
+        let Some(PathSegment::Arc { radius, .. }) =
+            svg_arc_segment([0.0, 0.0], 1.0, 1.0, 0.0, false, false, [10.0, 0.0])
+        else {
+            panic!("expected circular arc");
+        };
+        assert!(approx_eq(radius, 5.0, 1e-9));
+
+        let ellipse = svg_arc_segment([0.0, 0.0], 20.0, 10.0, 0.3, true, false, [15.0, 5.0]);
+        let end = segment_end(ellipse.as_ref().unwrap());
+        assert!(approx_eq(end[0], 15.0, 1e-9) && approx_eq(end[1], 5.0, 1e-9));
+        assert!(svg_arc_segment([1.0, 1.0], 5.0, 5.0, 0.0, false, true, [1.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn test_path_vertices() {
+        let subpaths = vec![
+            Subpath {
+                start: [0.0, 0.0],
+                segments: vec![PathSegment::Line { to: [1.0, 0.0] }],
+                closed: false,
+            },
+            Subpath {
+                start: [5.0, 5.0],
+                segments: vec![],
+                closed: false,
+            },
+        ];
+        assert_eq!(
+            path_vertices(&subpaths),
+            vec![[0.0, 0.0], [1.0, 0.0], [5.0, 5.0]]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! 폴리곤/링 기본 연산
+//!
+//! 링(ring)은 닫힌 점 배열이며, 마지막 점은 첫 점과 중복되지 않습니다.
+//! 여러 링은 even-odd 규칙으로 해석됩니다 (Polygon의 holes와 동일).
+
+/// 닫힌 점 배열 (마지막 점 → 첫 점으로 암묵적으로 연결)
+pub type Ring = Vec<[f64; 2]>;
+
+/// even-odd 규칙으로 해석되는 링 묶음 (예: 구멍이 있는 Polygon 하나)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Region {
+    pub rings: Vec<Ring>,
+}
+
+impl Region {
+    /// 점이 영역 내부에 있는지 확인합니다 (even-odd).
+    pub fn contains(&self, point: [f64; 2]) -> bool {
+        self.rings
+            .iter()
+            .filter(|ring| point_in_ring(ring, point))
+            .count()
+            % 2
+            == 1
+    }
+}
+
+/// 외곽선 1개 + 구멍들로 이루어진 폴리곤
+///
+/// 연산 결과에서 외곽선은 반시계방향(CCW), 구멍은 시계방향(CW)입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonWithHoles {
+    pub outer: Ring,
+    pub holes: Vec<Ring>,
+}
+
+impl PolygonWithHoles {
+    /// 면적 (외곽선 면적 - 구멍 면적)
+    pub fn area(&self) -> f64 {
+        signed_area(&self.outer).abs()
+            - self.holes.iter().map(|h| signed_area(h).abs()).sum::<f64>()
+    }
+
+    /// even-odd Region으로 변환합니다.
+    pub fn to_region(&self) -> Region {
+        let mut rings = vec![self.outer.clone()];
+        rings.extend(self.holes.iter().cloned());
+        Region { rings }
+    }
+}
+
+/// 링의 부호 있는 면적 (CCW면 양수, CW면 음수)
+pub fn signed_area(ring: &[[f64; 2]]) -> f64 {
+    let n = ring.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let mut sum = 0.0;
+    for i in 0..n {
+        let a = ring[i];
+        let b = ring[(i + 1) % n];
+        sum += a[0] * b[1] - b[0] * a[1];
+    }
+    sum / 2.0
+}
+
+/// 점이 링 내부에 있는지 확인합니다 (crossing number).
+///
+/// 경계 위의 점은 어느 쪽으로든 판정될 수 있습니다.
+pub fn point_in_ring(ring: &[[f64; 2]], point: [f64; 2]) -> bool {
+    let n = ring.len();
+    if n < 3 {
+        return false;
+    }
+    let [px, py] = point;
+    let mut inside = false;
+    let mut j = n - 1;
+    for i in 0..n {
+        let [xi, yi] = ring[i];
+        let [xj, yj] = ring[j];
+        if (yi > py) != (yj > py) {
+            let x_cross = xi + (py - yi) * (xj - xi) / (yj - yi);
+            if px < x_cross {
+                inside = !inside;
+            }
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f64, max: f64) -> Ring {
+        vec![[min, min], [max, min], [max, max], [min, max]]
+    }
+
+    #[test]
+    fn test_signed_area_orientation() {
+        let ccw = square(0.0, 10.0);
+        assert_eq!(signed_area(&ccw), 100.0);
+        let cw: Ring = ccw.iter().rev().cloned().collect();
+        assert_eq!(signed_area(&cw), -100.0);
+    }
+
+    #[test]
+    fn test_point_in_ring() {
+        let ring = square(0.0, 10.0);
+        assert!(point_in_ring(&ring, [5.0, 5.0]));
+        assert!(!point_in_ring(&ring, [15.0, 5.0]));
+    }
+
+    #[test]
+    fn test_region_even_odd_hole() {
+        let region = Region {
+            rings: vec![square(0.0, 10.0), square(4.0, 6.0)],
+        };
+        assert!(region.contains([2.0, 2.0]));
+        assert!(!region.contains([5.0, 5.0]));
+    }
+
+    #[test]
+    fn test_polygon_with_holes_area() {
+        let polygon = PolygonWithHoles {
+            outer: square(0.0, 10.0),
+            holes: vec![square(4.0, 6.0)],
+        };
+        assert_eq!(polygon.area(), 96.0);
+    }
+}
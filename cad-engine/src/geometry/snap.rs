@@ -0,0 +1,374 @@
+//! 객체 스냅 후보 유틸리티
+//!
+//! 외곽선 조각(`Edge`) 하나에서 나오는 스냅 후보 점들을 종류별로 계산합니다.
+//! - 끝점/중점/중심/사분점: 조각 자체에서 결정
+//! - 수직점/접점: 기준점(`from`, 그리던 선의 앞 점)이 있을 때만
+//! - 최근접점: 커서에서 조각 위로 가장 가까운 점
+//! - 큐빅 조각으로 분해된 타원/타원 호: 조각 이음매 대신 `conic_snap_points`로 도형 자체의 점
+//!
+//! 교점, 후보 도형 검색, 거리 순 정렬은 scene/snap.rs에서 처리합니다.
+
+use std::f64::consts::PI;
+
+use serde::Serialize;
+
+use super::distance::{Curve, arc_contains, closest_point_on_curve};
+use super::flatten::{cubic_point, cubic_steps_for_tolerance, ellipse_point};
+use super::intersect::Edge;
+
+/// 각도 비교 허용 오차
+const EPSILON: f64 = 1e-12;
+
+/// 베지어 수직점/접점 탐색 시 매개변수 구간 표본 수
+const ROOT_SAMPLES: usize = 32;
+
+/// 스냅 종류 (순서 = 같은 거리일 때의 우선순위)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapKind {
+    Endpoint,
+    Intersection,
+    Midpoint,
+    Center,
+    Quadrant,
+    Tangent,
+    Perpendicular,
+    Nearest,
+}
+
+impl SnapKind {
+    /// "endpoint" | "intersection" | "midpoint" | "center" | "quadrant" | "tangent" | "perpendicular" | "nearest"
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "endpoint" => Some(SnapKind::Endpoint),
+            "intersection" => Some(SnapKind::Intersection),
+            "midpoint" => Some(SnapKind::Midpoint),
+            "center" => Some(SnapKind::Center),
+            "quadrant" => Some(SnapKind::Quadrant),
+            "tangent" => Some(SnapKind::Tangent),
+            "perpendicular" => Some(SnapKind::Perpendicular),
+            "nearest" => Some(SnapKind::Nearest),
+            _ => None,
+        }
+    }
+}
+
+fn arc_point(center: [f64; 2], radius: f64, angle: f64) -> [f64; 2] {
+    [
+        center[0] + radius * angle.cos(),
+        center[1] + radius * angle.sin(),
+    ]
+}
+
+fn cubic_derivative(p: &[[f64; 2]; 4], t: f64) -> [f64; 2] {
+    let u = 1.0 - t;
+    let d = |i: usize| {
+        3.0 * (u * u * (p[1][i] - p[0][i])
+            + 2.0 * u * t * (p[2][i] - p[1][i])
+            + t * t * (p[3][i] - p[2][i]))
+    };
+    [d(0), d(1)]
+}
+
+/// [0, 1]에서 f(t) = 0인 t들 (표본 구간의 부호 변화 + 이분법)
+fn param_roots(f: impl Fn(f64) -> f64) -> Vec<f64> {
+    let mut roots = Vec::new();
+    let mut t0 = 0.0;
+    let mut f0 = f(t0);
+    if f0 == 0.0 {
+        roots.push(t0);
+    }
+    for i in 1..=ROOT_SAMPLES {
+        let t1 = i as f64 / ROOT_SAMPLES as f64;
+        let f1 = f(t1);
+        if f1 == 0.0 {
+            roots.push(t1);
+        } else if f0 * f1 < 0.0 {
+            let (mut lo, mut hi, mut f_lo) = (t0, t1, f0);
+            for _ in 0..60 {
+                let mid = (lo + hi) / 2.0;
+                let f_mid = f(mid);
+                if f_mid * f_lo <= 0.0 {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                    f_lo = f_mid;
+                }
+            }
+            roots.push((lo + hi) / 2.0);
+        }
+        t0 = t1;
+        f0 = f1;
+    }
+    roots
+}
+
+fn segment_snaps(
+    a: [f64; 2],
+    b: [f64; 2],
+    cursor: [f64; 2],
+    from: Option<[f64; 2]>,
+    out: &mut Vec<(SnapKind, [f64; 2])>,
+) {
+    let curve = Curve::Segment(a, b);
+    out.push((SnapKind::Endpoint, a));
+    out.push((SnapKind::Endpoint, b));
+    out.push((
+        SnapKind::Midpoint,
+        [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0],
+    ));
+    if let Some(from) = from {
+        let d = [b[0] - a[0], b[1] - a[1]];
+        let len_sq = d[0] * d[0] + d[1] * d[1];
+        if len_sq > 0.0 {
+            let t = ((from[0] - a[0]) * d[0] + (from[1] - a[1]) * d[1]) / len_sq;
+            if (0.0..=1.0).contains(&t) {
+                out.push((SnapKind::Perpendicular, [a[0] + t * d[0], a[1] + t * d[1]]));
+            }
+        }
+    }
+    out.push((SnapKind::Nearest, closest_point_on_curve(&curve, cursor)));
+}
+
+fn arc_snaps(
+    curve: &Curve,
+    cursor: [f64; 2],
+    from: Option<[f64; 2]>,
+    out: &mut Vec<(SnapKind, [f64; 2])>,
+) {
+    let Curve::Arc {
+        center,
+        radius,
+        start,
+        sweep,
+    } = *curve
+    else {
+        return;
+    };
+    let mut push_angle = |kind: SnapKind, angle: f64| {
+        if arc_contains(start, sweep, angle) {
+            out.push((kind, arc_point(center, radius, angle)));
+        }
+    };
+
+    if sweep < 2.0 * PI - EPSILON {
+        push_angle(SnapKind::Endpoint, start);
+        push_angle(SnapKind::Endpoint, start + sweep);
+        push_angle(SnapKind::Midpoint, start + sweep / 2.0);
+    }
+    for k in 0..4 {
+        push_angle(SnapKind::Quadrant, k as f64 * PI / 2.0);
+    }
+    if let Some(from) = from {
+        let (dx, dy) = (from[0] - center[0], from[1] - center[1]);
+        let d = dx.hypot(dy);
+        if d > EPSILON {
+            // 중심을 지나는 직선은 원과 수직으로 만남 (가까운 쪽, 먼 쪽)
+            let toward = dy.atan2(dx);
+            push_angle(SnapKind::Perpendicular, toward);
+            push_angle(SnapKind::Perpendicular, toward + PI);
+            if d > radius {
+                let spread = (radius / d).acos();
+                push_angle(SnapKind::Tangent, toward + spread);
+                push_angle(SnapKind::Tangent, toward - spread);
+            }
+        }
+    }
+    out.push((SnapKind::Center, center));
+    out.push((SnapKind::Nearest, closest_point_on_curve(curve, cursor)));
+}
+
+fn cubic_snaps(
+    p: &[[f64; 2]; 4],
+    cursor: [f64; 2],
+    from: Option<[f64; 2]>,
+    tolerance: f64,
+    out: &mut Vec<(SnapKind, [f64; 2])>,
+) {
+    let point = |t: f64| cubic_point(p[0], p[1], p[2], p[3], t);
+    out.push((SnapKind::Endpoint, p[0]));
+    out.push((SnapKind::Endpoint, p[3]));
+    if let Some(from) = from {
+        let offset = |t: f64| {
+            let q = point(t);
+            ([q[0] - from[0], q[1] - from[1]], cubic_derivative(p, t))
+        };
+        for t in param_roots(|t| {
+            let (v, d) = offset(t);
+            v[0] * d[0] + v[1] * d[1]
+        }) {
+            out.push((SnapKind::Perpendicular, point(t)));
+        }
+        for t in param_roots(|t| {
+            let (v, d) = offset(t);
+            v[0] * d[1] - v[1] * d[0]
+        }) {
+            // 기준점이 곡선 위에 있으면 그 점 자체가 자명한 근이므로 제외
+            let q = point(t);
+            if (q[0] - from[0]).hypot(q[1] - from[1]) > tolerance {
+                out.push((SnapKind::Tangent, q));
+            }
+        }
+    }
+
+    let steps = cubic_steps_for_tolerance(p, tolerance);
+    let nearest = (0..steps)
+        .map(|i| {
+            let a = point(i as f64 / steps as f64);
+            let b = point((i + 1) as f64 / steps as f64);
+            closest_point_on_curve(&Curve::Segment(a, b), cursor)
+        })
+        .min_by(|a, b| {
+            let da = (a[0] - cursor[0]).hypot(a[1] - cursor[1]);
+            let db = (b[0] - cursor[0]).hypot(b[1] - cursor[1]);
+            da.total_cmp(&db)
+        });
+    out.extend(nearest.map(|q| (SnapKind::Nearest, q)));
+}
+
+/// 타원(호)의 끝점/중점/중심/사분점 (로컬 좌표, 매개변수 각도 기준)
+///
+/// 큐빅 조각으로 분해된 외곽선은 조각 이음매가 끝점처럼 보이므로 도형 자체에서 계산합니다.
+/// 사분점은 장축/단축 끝 (t = 0, π/2, π, 3π/2 중 호 범위 안), 끝점/중점은 호일 때만.
+pub fn conic_snap_points(
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    start: f64,
+    sweep: f64,
+) -> Vec<(SnapKind, [f64; 2])> {
+    let mut out = vec![(SnapKind::Center, center)];
+    let mut push_param = |kind: SnapKind, t: f64| {
+        if arc_contains(start, sweep, t) {
+            out.push((kind, ellipse_point(center, rx, ry, rotation, t)));
+        }
+    };
+    if sweep < 2.0 * PI - EPSILON {
+        push_param(SnapKind::Endpoint, start);
+        push_param(SnapKind::Endpoint, start + sweep);
+        push_param(SnapKind::Midpoint, start + sweep / 2.0);
+    }
+    for k in 0..4 {
+        push_param(SnapKind::Quadrant, k as f64 * PI / 2.0);
+    }
+    out
+}
+
+/// 외곽선 조각 하나의 스냅 후보 점들 (거리 필터/정렬 전)
+///
+/// # Arguments
+/// * `cursor` - 커서 위치 (최근접점 기준)
+/// * `from` - 수직점/접점 기준점 (없으면 해당 종류 생략)
+/// * `tolerance` - 베지어 최근접점 근사 허용 오차 (양수)
+pub fn edge_snap_points(
+    edge: &Edge,
+    cursor: [f64; 2],
+    from: Option<[f64; 2]>,
+    tolerance: f64,
+) -> Vec<(SnapKind, [f64; 2])> {
+    let mut out = Vec::new();
+    match edge {
+        Edge::Curve(Curve::Segment(a, b)) => segment_snaps(*a, *b, cursor, from, &mut out),
+        Edge::Curve(curve) => arc_snaps(curve, cursor, from, &mut out),
+        Edge::Cubic(p) => cubic_snaps(p, cursor, from, tolerance, &mut out),
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn points_of(snaps: &[(SnapKind, [f64; 2])], kind: SnapKind) -> Vec<[f64; 2]> {
+        snaps
+            .iter()
+            .filter(|(k, _)| *k == kind)
+            .map(|(_, p)| *p)
+            .collect()
+    }
+
+    #[test]
+    fn test_segment_snaps() {
+        let edge = Edge::Curve(Curve::Segment([0.0, 0.0], [10.0, 0.0]));
+        let snaps = edge_snap_points(&edge, [3.0, 2.0], Some([7.0, 5.0]), 1e-6);
+        assert_eq!(points_of(&snaps, SnapKind::Endpoint).len(), 2);
+        assert_eq!(points_of(&snaps, SnapKind::Midpoint), [[5.0, 0.0]]);
+        assert_eq!(points_of(&snaps, SnapKind::Perpendicular), [[7.0, 0.0]]);
+        assert_eq!(points_of(&snaps, SnapKind::Nearest), [[3.0, 0.0]]);
+
+        // 수직의 발이 선분 밖이면 생략
+        let snaps = edge_snap_points(&edge, [3.0, 2.0], Some([12.0, 5.0]), 1e-6);
+        assert!(points_of(&snaps, SnapKind::Perpendicular).is_empty());
+    }
+
+    #[test]
+    fn test_arc_snaps() {
+        // 위쪽 반원 (0 → π)
+        let edge = Edge::Curve(Curve::Arc {
+            center: [0.0, 0.0],
+            radius: 5.0,
+            start: 0.0,
+            sweep: PI,
+        });
+        let snaps = edge_snap_points(&edge, [0.0, 10.0], Some([0.0, 10.0]), 1e-6);
+        assert_eq!(points_of(&snaps, SnapKind::Center), [[0.0, 0.0]]);
+        assert_eq!(points_of(&snaps, SnapKind::Endpoint).len(), 2);
+        let mid = points_of(&snaps, SnapKind::Midpoint)[0];
+        assert!(approx_eq(mid[0], 0.0) && approx_eq(mid[1], 5.0));
+        // 사분점: 0, π/2, π (3π/2는 범위 밖)
+        assert_eq!(points_of(&snaps, SnapKind::Quadrant).len(), 3);
+        // 수직점: 가까운 쪽 (0, 5)만 호 위
+        let perp = points_of(&snaps, SnapKind::Perpendicular);
+        assert_eq!(perp.len(), 1);
+        assert!(approx_eq(perp[0][1], 5.0));
+        // 접점: (0,10)에서 반지름 5 원 → y = 2.5
+        let tangents = points_of(&snaps, SnapKind::Tangent);
+        assert_eq!(tangents.len(), 2);
+        assert!(tangents.iter().all(|p| approx_eq(p[1], 2.5)));
+    }
+
+    #[test]
+    fn test_cubic_snaps() {
+        let cubic = [[0.0, 0.0], [0.0, 4.0], [10.0, 4.0], [10.0, 0.0]];
+        let snaps = edge_snap_points(&Edge::Cubic(cubic), [5.0, 10.0], Some([5.0, 10.0]), 1e-6);
+        assert_eq!(points_of(&snaps, SnapKind::Endpoint).len(), 2);
+        // 대칭축 위의 정점 (5, 3)이 수직점
+        let perp = points_of(&snaps, SnapKind::Perpendicular);
+        assert!(
+            perp.iter()
+                .any(|p| approx_eq(p[0], 5.0) && approx_eq(p[1], 3.0))
+        );
+        let nearest = points_of(&snaps, SnapKind::Nearest)[0];
+        assert!((nearest[1] - 3.0).abs() < 1e-5);
+        // 접점은 대칭축 양쪽에 하나씩
+        let tangents = points_of(&snaps, SnapKind::Tangent);
+        assert_eq!(tangents.len(), 2);
+        assert!((tangents[0][0] + tangents[1][0] - 10.0).abs() < 1e-6);
+
+        assert_eq!(SnapKind::parse_str("Tangent"), Some(SnapKind::Tangent));
+        assert_eq!(SnapKind::parse_str("vertex"), None);
+    }
+
+    #[test]
+    fn test_conic_snaps() {
+        // 타원 전체: 중심 + 사분점 4개, 끝점/중점 없음
+        let snaps = conic_snap_points([1.0, 0.0], 3.0, 1.0, 0.0, 0.0, 2.0 * PI);
+        assert_eq!(points_of(&snaps, SnapKind::Center), [[1.0, 0.0]]);
+        let quadrants = points_of(&snaps, SnapKind::Quadrant);
+        assert_eq!(quadrants.len(), 4);
+        assert!(approx_eq(quadrants[0][0], 4.0) && approx_eq(quadrants[1][1], 1.0));
+        assert!(points_of(&snaps, SnapKind::Endpoint).is_empty());
+
+        // 타원 호 (π/4 → 3π/4): 끝점 2 + 중점 (t = π/2, 사분점과 같은 위치) + 사분점 1
+        let snaps = conic_snap_points([0.0, 0.0], 3.0, 1.0, 0.0, PI / 4.0, PI / 2.0);
+        assert_eq!(points_of(&snaps, SnapKind::Endpoint).len(), 2);
+        let mid = points_of(&snaps, SnapKind::Midpoint)[0];
+        assert!(approx_eq(mid[0], 0.0) && approx_eq(mid[1], 1.0));
+        assert_eq!(points_of(&snaps, SnapKind::Quadrant).len(), 1);
+    }
+}
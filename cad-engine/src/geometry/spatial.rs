@@ -0,0 +1,240 @@
+//! 바운딩 박스 공간 인덱스 (R-tree)
+//!
+//! STR(Sort-Tile-Recursive)로 묶은 정적 R-tree와, 마지막 재구성 이후 변경된 키 목록(overlay)으로 구성됩니다.
+//! - 삽입/갱신/삭제: 항목 맵과 overlay에만 기록 (O(1)), overlay가 커지면 트리 전체 재구성
+//! - 질의: 트리에서 겹치는 항목을 찾되 overlay의 키는 트리 대신 현재 박스로 판정
+//!
+//! 재구성은 항목 수에 비례하므로, overlay 한도(항목 수의 1/8)를 넘을 때만 수행해 분할 상환 O(log n)을 유지합니다.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::bounds::{BoundingBox, bbox_intersects};
+
+/// 노드당 최대 자식 수
+const NODE_CAPACITY: usize = 16;
+
+/// 재구성 없이 overlay에 쌓을 수 있는 최소 변경 수
+const MIN_OVERLAY: usize = 64;
+
+fn union(a: &BoundingBox, b: &BoundingBox) -> BoundingBox {
+    (
+        [a.0[0].min(b.0[0]), a.0[1].min(b.0[1])],
+        [a.1[0].max(b.1[0]), a.1[1].max(b.1[1])],
+    )
+}
+
+fn center(b: &BoundingBox) -> [f64; 2] {
+    [(b.0[0] + b.1[0]) / 2.0, (b.0[1] + b.1[1]) / 2.0]
+}
+
+/// 바운딩 박스 R-tree (키별 하나의 박스)
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<K> {
+    /// 살아 있는 모든 항목의 현재 박스
+    items: HashMap<K, BoundingBox>,
+    /// 트리 잎 항목 (STR 순서로 정렬됨, 재구성 시점의 박스)
+    entries: Vec<(BoundingBox, K)>,
+    /// levels[0]: entries를 NODE_CAPACITY개씩 묶은 노드 박스, levels[i]: levels[i-1]을 묶은 박스
+    levels: Vec<Vec<BoundingBox>>,
+    /// 재구성 이후 추가/갱신/삭제된 키 (트리 항목 대신 items 값을 사용)
+    overlay: HashSet<K>,
+}
+
+impl<K> Default for SpatialIndex<K> {
+    fn default() -> Self {
+        SpatialIndex {
+            items: HashMap::new(),
+            entries: Vec::new(),
+            levels: Vec::new(),
+            overlay: HashSet::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> SpatialIndex<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 살아 있는 항목 수
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 키의 현재 박스
+    pub fn get(&self, key: &K) -> Option<BoundingBox> {
+        self.items.get(key).copied()
+    }
+
+    /// 키의 박스를 추가하거나 갱신합니다.
+    pub fn insert(&mut self, key: K, bbox: BoundingBox) {
+        self.items.insert(key.clone(), bbox);
+        self.overlay.insert(key);
+        self.maybe_rebuild();
+    }
+
+    /// 키를 삭제합니다 (없으면 무시).
+    pub fn remove(&mut self, key: &K) {
+        if self.items.remove(key).is_some() {
+            self.overlay.insert(key.clone());
+            self.maybe_rebuild();
+        }
+    }
+
+    /// 박스와 겹치는 (경계 접촉 포함) 모든 키를 반환합니다 (순서 무관).
+    pub fn query(&self, bbox: &BoundingBox) -> Vec<K> {
+        let mut result = Vec::new();
+        if let Some(top) = self.levels.last() {
+            for node in 0..top.len() {
+                self.query_node(self.levels.len() - 1, node, bbox, &mut result);
+            }
+        }
+        result.extend(
+            self.overlay
+                .iter()
+                .filter(|key| {
+                    self.items
+                        .get(*key)
+                        .is_some_and(|b| bbox_intersects(b, bbox))
+                })
+                .cloned(),
+        );
+        result
+    }
+
+    fn query_node(&self, level: usize, node: usize, bbox: &BoundingBox, out: &mut Vec<K>) {
+        if !bbox_intersects(&self.levels[level][node], bbox) {
+            return;
+        }
+        let start = node * NODE_CAPACITY;
+        if level == 0 {
+            let end = (start + NODE_CAPACITY).min(self.entries.len());
+            for (entry_bbox, key) in &self.entries[start..end] {
+                if bbox_intersects(entry_bbox, bbox) && !self.overlay.contains(key) {
+                    out.push(key.clone());
+                }
+            }
+        } else {
+            let end = (start + NODE_CAPACITY).min(self.levels[level - 1].len());
+            for child in start..end {
+                self.query_node(level - 1, child, bbox, out);
+            }
+        }
+    }
+
+    fn maybe_rebuild(&mut self) {
+        if self.overlay.len() > MIN_OVERLAY.max(self.items.len() / 8) {
+            self.rebuild();
+        }
+    }
+
+    /// 현재 항목들로 트리를 다시 묶습니다 (STR bulk loading).
+    pub fn rebuild(&mut self) {
+        self.overlay.clear();
+        let mut entries: Vec<(BoundingBox, K)> = self
+            .items
+            .iter()
+            .map(|(key, bbox)| (*bbox, key.clone()))
+            .collect();
+
+        // x 중심으로 정렬 → sqrt(노드 수)개의 세로 띠 → 띠마다 y 중심으로 정렬
+        entries.sort_by(|a, b| center(&a.0)[0].total_cmp(&center(&b.0)[0]));
+        let node_count = entries.len().div_ceil(NODE_CAPACITY);
+        let slice_count = (node_count as f64).sqrt().ceil().max(1.0) as usize;
+        let slice_len = node_count.div_ceil(slice_count) * NODE_CAPACITY;
+        if slice_len > 0 {
+            for slice in entries.chunks_mut(slice_len) {
+                slice.sort_by(|a, b| center(&a.0)[1].total_cmp(&center(&b.0)[1]));
+            }
+        }
+
+        let mut levels = Vec::new();
+        let mut boxes: Vec<BoundingBox> = entries.iter().map(|(bbox, _)| *bbox).collect();
+        while !boxes.is_empty() {
+            let packed: Vec<BoundingBox> = boxes
+                .chunks(NODE_CAPACITY)
+                .map(|chunk| chunk.iter().skip(1).fold(chunk[0], |acc, b| union(&acc, b)))
+                .collect();
+            let done = packed.len() == 1;
+            levels.push(packed.clone());
+            if done {
+                break;
+            }
+            boxes = packed;
+        }
+
+        self.entries = entries;
+        self.levels = levels;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box(x: f64, y: f64) -> BoundingBox {
+        ([x, y], [x + 1.0, y + 1.0])
+    }
+
+    fn sorted(mut keys: Vec<usize>) -> Vec<usize> {
+        keys.sort_unstable();
+        keys
+    }
+
+    #[test]
+    fn test_query_matches_linear_scan() {
+        let mut index = SpatialIndex::new();
+        let mut boxes = Vec::new();
+        for i in 0..2000 {
+            // 결정적 의사 난수 배치
+            let x = ((i * 7919) % 1000) as f64;
+            let y = ((i * 104_729) % 1000) as f64;
+            boxes.push(unit_box(x, y));
+            index.insert(i, unit_box(x, y));
+        }
+        assert_eq!(index.len(), 2000);
+        assert!(index.levels.len() > 1);
+
+        for window in [
+            ([100.0, 100.0], [200.0, 150.0]),
+            ([0.0, 0.0], [5.0, 5.0]),
+            ([-10.0, -10.0], [2000.0, 2000.0]),
+            ([500.5, 500.5], [500.5, 500.5]),
+        ] {
+            let expected: Vec<usize> = (0..boxes.len())
+                .filter(|i| bbox_intersects(&boxes[*i], &window))
+                .collect();
+            assert_eq!(sorted(index.query(&window)), expected);
+        }
+    }
+
+    #[test]
+    fn test_update_and_remove_through_overlay() {
+        let mut index = SpatialIndex::new();
+        for i in 0..200 {
+            index.insert(i, unit_box(i as f64 * 2.0, 0.0));
+        }
+        index.rebuild();
+
+        // 이동: 이전 위치에서는 사라지고 새 위치에서 찾아짐
+        index.insert(5, unit_box(1000.0, 1000.0));
+        assert!(!index.query(&unit_box(10.0, 0.0)).contains(&5));
+        assert_eq!(index.query(&unit_box(1000.0, 1000.0)), [5]);
+
+        index.remove(&6);
+        index.remove(&6);
+        assert_eq!(index.len(), 199);
+        assert!(index.query(&unit_box(12.0, 0.0)).is_empty());
+
+        index.rebuild();
+        assert_eq!(index.len(), 199);
+        assert_eq!(index.query(&unit_box(1000.0, 1000.0)), [5]);
+        assert_eq!(index.get(&7), Some(unit_box(14.0, 0.0)));
+        assert_eq!(index.get(&6), None);
+    }
+}
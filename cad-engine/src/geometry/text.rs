@@ -0,0 +1,432 @@
+//! 텍스트 레이아웃 / 폰트 메트릭
+//!
+//! 외부 폰트 파일 없이 바운드를 계산할 수 있도록 메트릭을 내장합니다.
+//! - sans-serif (기본): Helvetica AFM advance width
+//! - monospace: Courier (모든 글자 600/1000 em)
+//! - 한글/CJK 등 전각 문자: 1em
+//!
+//! 글리프 외곽선 출력용으로 CAD 도면에서 흔히 쓰는 단선(single-stroke) 폰트를 내장합니다.
+//! 대문자/숫자/기본 기호를 지원하며, 소문자는 x-height 크기의 대문자(small caps)로,
+//! 지원하지 않는 문자는 사각형(tofu)으로 그립니다.
+
+use crate::scene::entity::{Geometry, TextAlign, TextAnchor};
+
+/// 줄 간격 (font_size 배수)
+pub const LINE_HEIGHT: f64 = 1.2;
+
+/// 단선 폰트의 선 두께 (font_size 배수)
+pub const OUTLINE_STROKE_RATIO: f64 = 0.08;
+
+/// Helvetica advance width (1/1000 em), ASCII 32(space) ~ 126(~)
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // ' ' ~ '/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // '0' ~ '9'
+    278, 278, 584, 584, 584, 556, 1015, // ':' ~ '@'
+    667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, // 'A' ~ 'M'
+    722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, // 'N' ~ 'Z'
+    278, 278, 278, 469, 556, 333, // '[' ~ '`'
+    556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, // 'a' ~ 'm'
+    556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, // 'n' ~ 'z'
+    334, 260, 334, 584, // '{' ~ '~'
+];
+
+/// 폰트 메트릭 (단위: em)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// baseline 위 높이 (ascender)
+    pub ascent: f64,
+    /// baseline 아래 깊이 (descender, 양수)
+    pub descent: f64,
+    /// 대문자 높이
+    pub cap_height: f64,
+    /// 소문자 높이
+    pub x_height: f64,
+    monospace: bool,
+}
+
+const SANS_METRICS: FontMetrics = FontMetrics {
+    ascent: 0.718,
+    descent: 0.207,
+    cap_height: 0.718,
+    x_height: 0.523,
+    monospace: false,
+};
+
+const MONO_METRICS: FontMetrics = FontMetrics {
+    ascent: 0.629,
+    descent: 0.157,
+    cap_height: 0.562,
+    x_height: 0.426,
+    monospace: true,
+};
+
+impl FontMetrics {
+    /// font-family 문자열에 해당하는 메트릭 (알 수 없는 폰트는 sans-serif)
+    pub fn for_family(family: &str) -> Self {
+        let lower = family.to_ascii_lowercase();
+        if ["mono", "courier", "consol", "menlo"]
+            .iter()
+            .any(|k| lower.contains(k))
+        {
+            MONO_METRICS
+        } else {
+            SANS_METRICS
+        }
+    }
+
+    /// 문자 하나의 advance width (em)
+    pub fn advance(&self, c: char) -> f64 {
+        if is_wide(c) {
+            return 1.0;
+        }
+        if self.monospace {
+            return 0.6;
+        }
+        match c as u32 {
+            code @ 32..=126 => HELVETICA_WIDTHS[(code - 32) as usize] as f64 / 1000.0,
+            _ => 0.556,
+        }
+    }
+
+    /// 한 줄의 너비 (em)
+    pub fn line_width(&self, line: &str) -> f64 {
+        line.chars().map(|c| self.advance(c)).sum()
+    }
+}
+
+/// 전각 문자 (한글, CJK, 전각 기호)
+fn is_wide(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x115F
+        | 0x2E80..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6)
+}
+
+/// 배치된 한 줄의 텍스트
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine<'a> {
+    pub text: &'a str,
+    /// 줄 시작점 x (position 기준 상대 좌표, anchor 반영)
+    pub x: f64,
+    /// baseline y (position 기준 상대 좌표, y-up)
+    pub baseline: f64,
+    pub width: f64,
+}
+
+/// 텍스트 레이아웃 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLayout<'a> {
+    pub lines: Vec<TextLine<'a>>,
+    pub metrics: FontMetrics,
+    pub font_size: f64,
+}
+
+impl TextLayout<'_> {
+    /// position 기준 상대 바운딩 박스 (ascent ~ descent, 빈 줄 포함)
+    pub fn bounds(&self) -> ([f64; 2], [f64; 2]) {
+        let asc = self.metrics.ascent * self.font_size;
+        let desc = self.metrics.descent * self.font_size;
+        let mut min = [f64::INFINITY, f64::INFINITY];
+        let mut max = [f64::NEG_INFINITY, f64::NEG_INFINITY];
+        for line in &self.lines {
+            min[0] = min[0].min(line.x);
+            max[0] = max[0].max(line.x + line.width);
+            min[1] = min[1].min(line.baseline - desc);
+            max[1] = max[1].max(line.baseline + asc);
+        }
+        (min, max)
+    }
+}
+
+/// 텍스트를 줄 단위로 배치합니다 ('\n'으로 줄바꿈).
+///
+/// - anchor: 각 줄의 가로 기준 (Start = 왼쪽 끝, Middle = 가운데, End = 오른쪽 끝)
+/// - align: 전체 블록의 세로 기준 (Baseline = 첫 줄 baseline, Top/Middle/Bottom = ascent~descent 상자 기준)
+pub fn layout_text<'a>(
+    content: &'a str,
+    font_family: &str,
+    font_size: f64,
+    anchor: TextAnchor,
+    align: TextAlign,
+) -> TextLayout<'a> {
+    let metrics = FontMetrics::for_family(font_family);
+    let line_height = LINE_HEIGHT * font_size;
+    let line_count = content.split('\n').count() as f64;
+
+    let asc = metrics.ascent * font_size;
+    let desc = metrics.descent * font_size;
+    // 첫 줄 baseline = 0일 때의 상자: top = asc, bottom = -desc - (n - 1) * line_height
+    let top = asc;
+    let bottom = -desc - (line_count - 1.0) * line_height;
+    let dy = match align {
+        TextAlign::Baseline => 0.0,
+        TextAlign::Top => -top,
+        TextAlign::Bottom => -bottom,
+        TextAlign::Middle => -(top + bottom) / 2.0,
+    };
+
+    let lines = content
+        .split('\n')
+        .enumerate()
+        .map(|(i, text)| {
+            let width = metrics.line_width(text) * font_size;
+            let x = match anchor {
+                TextAnchor::Start => 0.0,
+                TextAnchor::Middle => -width / 2.0,
+                TextAnchor::End => -width,
+            };
+            TextLine {
+                text,
+                x,
+                baseline: dy - i as f64 * line_height,
+                width,
+            }
+        })
+        .collect();
+
+    TextLayout {
+        lines,
+        metrics,
+        font_size,
+    }
+}
+
+/// Text geometry의 로컬 바운딩 박스 (Text가 아니면 None)
+pub fn text_geometry_bounds(geometry: &Geometry) -> Option<([f64; 2], [f64; 2])> {
+    let Geometry::Text {
+        position,
+        content,
+        font_family,
+        font_size,
+        anchor,
+        align,
+    } = geometry
+    else {
+        return None;
+    };
+    let (min, max) = layout_text(content, font_family, *font_size, *anchor, *align).bounds();
+    Some((
+        [position[0] + min[0], position[1] + min[1]],
+        [position[0] + max[0], position[1] + max[1]],
+    ))
+}
+
+// ========================================
+// Single-stroke outline font
+// ========================================
+
+/// 단선 폰트 글리프 (격자: x 0~4, y 0~6 = cap height, 음수 = descender)
+///
+/// ';'로 구분된 폴리라인, 각 폴리라인은 "x y x y ..." 정수 쌍입니다.
+fn stroke_glyph(c: char) -> Option<&'static str> {
+    let glyph = match c.to_ascii_uppercase() {
+        ' ' => "",
+        'A' => "0 0 2 6 4 0;1 3 3 3",
+        'B' => "0 0 0 6 3 6 4 5 4 4 3 3 0 3;3 3 4 2 4 1 3 0 0 0",
+        'C' => "4 5 3 6 1 6 0 5 0 1 1 0 3 0 4 1",
+        'D' => "0 0 0 6 3 6 4 5 4 1 3 0 0 0",
+        'E' => "4 6 0 6 0 0 4 0;0 3 3 3",
+        'F' => "4 6 0 6 0 0;0 3 3 3",
+        'G' => "4 5 3 6 1 6 0 5 0 1 1 0 3 0 4 1 4 3 2 3",
+        'H' => "0 0 0 6;4 0 4 6;0 3 4 3",
+        'I' => "1 6 3 6;2 6 2 0;1 0 3 0",
+        'J' => "4 6 4 1 3 0 1 0 0 1",
+        'K' => "0 0 0 6;4 6 0 2;1 3 4 0",
+        'L' => "0 6 0 0 4 0",
+        'M' => "0 0 0 6 2 3 4 6 4 0",
+        'N' => "0 0 0 6 4 0 4 6",
+        'O' => "1 0 0 1 0 5 1 6 3 6 4 5 4 1 3 0 1 0",
+        'P' => "0 0 0 6 3 6 4 5 4 4 3 3 0 3",
+        'Q' => "1 0 0 1 0 5 1 6 3 6 4 5 4 1 3 0 1 0;2 2 4 0",
+        'R' => "0 0 0 6 3 6 4 5 4 4 3 3 0 3;2 3 4 0",
+        'S' => "4 5 3 6 1 6 0 5 0 4 1 3 3 3 4 2 4 1 3 0 1 0 0 1",
+        'T' => "0 6 4 6;2 6 2 0",
+        'U' => "0 6 0 1 1 0 3 0 4 1 4 6",
+        'V' => "0 6 2 0 4 6",
+        'W' => "0 6 1 0 2 4 3 0 4 6",
+        'X' => "0 0 4 6;0 6 4 0",
+        'Y' => "0 6 2 3 4 6;2 3 2 0",
+        'Z' => "0 6 4 6 0 0 4 0",
+        '0' => "1 0 0 1 0 5 1 6 3 6 4 5 4 1 3 0 1 0;0 1 4 5",
+        '1' => "1 5 2 6 2 0;1 0 3 0",
+        '2' => "0 5 1 6 3 6 4 5 4 4 0 0 4 0",
+        '3' => "0 5 1 6 3 6 4 5 4 4 3 3 4 2 4 1 3 0 1 0 0 1;1 3 3 3",
+        '4' => "3 0 3 6 0 2 4 2",
+        '5' => "4 6 0 6 0 3 3 3 4 2 4 1 3 0 0 0",
+        '6' => "4 5 3 6 1 6 0 5 0 1 1 0 3 0 4 1 4 2 3 3 0 3",
+        '7' => "0 6 4 6 1 0",
+        '8' => "1 3 0 4 0 5 1 6 3 6 4 5 4 4 3 3 1 3 0 2 0 1 1 0 3 0 4 1 4 2 3 3",
+        '9' => "4 3 1 3 0 4 0 5 1 6 3 6 4 5 4 1 3 0 1 0 0 1",
+        '-' => "1 3 3 3",
+        '+' => "0 3 4 3;2 1 2 5",
+        '=' => "0 2 4 2;0 4 4 4",
+        '.' => "2 0 2 1",
+        ',' => "2 1 2 0 1 -1",
+        ':' => "2 1 2 2;2 4 2 5",
+        ';' => "2 4 2 5;2 1 2 0 1 -1",
+        '/' => "0 0 4 6",
+        '\\' => "0 6 4 0",
+        '(' => "3 6 1 4 1 2 3 0",
+        ')' => "1 6 3 4 3 2 1 0",
+        '[' => "3 6 1 6 1 0 3 0",
+        ']' => "1 6 3 6 3 0 1 0",
+        '_' => "0 -1 4 -1",
+        '\'' => "2 6 2 4",
+        '"' => "1 6 1 4;3 6 3 4",
+        '!' => "2 6 2 2;2 1 2 0",
+        '?' => "0 5 1 6 3 6 4 5 4 4 2 3 2 2;2 1 2 0",
+        '#' => "1 0 1 6;3 0 3 6;0 2 4 2;0 4 4 4",
+        '%' => "0 0 4 6;0 6 1 6 1 5 0 5 0 6;3 1 4 1 4 0 3 0 3 1",
+        '*' => "2 1 2 5;0 2 4 4;0 4 4 2",
+        '<' => "4 5 0 3 4 1",
+        '>' => "0 5 4 3 0 1",
+        '^' => "0 4 2 6 4 4",
+        '~' => "0 3 1 4 3 2 4 3",
+        '|' => "2 0 2 6",
+        '$' => "4 5 3 6 1 6 0 5 0 4 1 3 3 3 4 2 4 1 3 0 1 0 0 1;2 6 2 0",
+        _ => return None,
+    };
+    Some(glyph)
+}
+
+/// 지원하지 않는 문자용 사각형
+const TOFU_GLYPH: &str = "0 0 0 6 4 6 4 0 0 0";
+
+fn parse_glyph(glyph: &str) -> Vec<Vec<[f64; 2]>> {
+    glyph
+        .split(';')
+        .filter(|s| !s.trim().is_empty())
+        .map(|stroke| {
+            let values: Vec<f64> = stroke
+                .split_whitespace()
+                .filter_map(|v| v.parse::<f64>().ok())
+                .collect();
+            values.chunks_exact(2).map(|p| [p[0], p[1]]).collect()
+        })
+        .collect()
+}
+
+/// 텍스트를 단선 폰트 폴리라인들로 변환합니다 (로컬 좌표, y-up).
+///
+/// 각 글자는 레이아웃의 advance 칸 안에 배치되므로 바운드와 일치합니다.
+/// 폴리라인은 `OUTLINE_STROKE_RATIO * font_size` 두께로 stroke하여 렌더링합니다.
+pub fn text_outline_polylines(geometry: &Geometry) -> Vec<Vec<[f64; 2]>> {
+    let Geometry::Text {
+        position,
+        content,
+        font_family,
+        font_size,
+        anchor,
+        align,
+    } = geometry
+    else {
+        return Vec::new();
+    };
+    let layout = layout_text(content, font_family, *font_size, *anchor, *align);
+    let mut polylines = Vec::new();
+
+    for line in &layout.lines {
+        let mut x = position[0] + line.x;
+        let baseline = position[1] + line.baseline;
+        for c in line.text.chars() {
+            let advance = layout.metrics.advance(c) * font_size;
+            let glyph = stroke_glyph(c).unwrap_or(TOFU_GLYPH);
+            let height = if c.is_ascii_lowercase() {
+                layout.metrics.x_height
+            } else {
+                layout.metrics.cap_height
+            } * font_size;
+            // 칸 좌우 여백 15%, 격자 폭 4 / 높이 6
+            let sx = advance * 0.7 / 4.0;
+            let sy = height / 6.0;
+            let ox = x + advance * 0.15;
+            for stroke in parse_glyph(glyph) {
+                polylines.push(
+                    stroke
+                        .iter()
+                        .map(|p| [ox + p[0] * sx, baseline + p[1] * sy])
+                        .collect(),
+                );
+            }
+            x += advance;
+        }
+    }
+    polylines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn text(content: &str, anchor: TextAnchor, align: TextAlign) -> Geometry {
+        Geometry::Text {
+            position: [0.0, 0.0],
+            content: content.to_string(),
+            font_family: "sans-serif".to_string(),
+            font_size: 10.0,
+            anchor,
+            align,
+        }
+    }
+
+    #[test]
+    fn test_helvetica_widths() {
+        let m = FontMetrics::for_family("Helvetica");
+        assert!(approx_eq(m.advance('A'), 0.667, 1e-12));
+        assert!(approx_eq(m.advance('i'), 0.222, 1e-12));
+        assert!(approx_eq(m.line_width("Hi"), 0.722 + 0.222, 1e-12));
+        // 한글은 전각
+        assert!(approx_eq(m.advance('방'), 1.0, 1e-12));
+    }
+
+    #[test]
+    fn test_monospace_widths() {
+        let m = FontMetrics::for_family("Courier New");
+        assert!(approx_eq(m.line_width("iW"), 1.2, 1e-12));
+    }
+
+    #[test]
+    fn test_text_bounds_anchor_and_align() {
+        let (min, max) =
+            text_geometry_bounds(&text("AA", TextAnchor::Start, TextAlign::Baseline)).unwrap();
+        assert!(approx_eq(min[0], 0.0, 1e-10) && approx_eq(max[0], 13.34, 1e-10));
+        assert!(approx_eq(min[1], -2.07, 1e-10) && approx_eq(max[1], 7.18, 1e-10));
+
+        let (min, max) =
+            text_geometry_bounds(&text("AA", TextAnchor::Middle, TextAlign::Middle)).unwrap();
+        assert!(approx_eq(min[0], -6.67, 1e-10) && approx_eq(max[0], 6.67, 1e-10));
+        assert!(approx_eq(min[1], -max[1], 1e-10));
+
+        let (_, max) = text_geometry_bounds(&text("A", TextAnchor::End, TextAlign::Top)).unwrap();
+        assert!(approx_eq(max[0], 0.0, 1e-10) && approx_eq(max[1], 0.0, 1e-10));
+    }
+
+    #[test]
+    fn test_multiline_bounds() {
+        let (min, max) =
+            text_geometry_bounds(&text("A\nA", TextAnchor::Start, TextAlign::Baseline)).unwrap();
+        assert!(approx_eq(max[1], 7.18, 1e-10));
+        assert!(approx_eq(min[1], -2.07 - 12.0, 1e-10));
+    }
+
+    #[test]
+    fn test_outline_stays_inside_bounds() {
+        let geometry = text("Room 1, a?", TextAnchor::Middle, TextAlign::Middle);
+        let (min, max) = text_geometry_bounds(&geometry).unwrap();
+        let polylines = text_outline_polylines(&geometry);
+        assert!(!polylines.is_empty());
+        for p in polylines.iter().flatten() {
+            assert!(p[0] >= min[0] - 1e-9 && p[0] <= max[0] + 1e-9);
+            assert!(p[1] >= min[1] - 1e-9 && p[1] <= max[1] + 1e-9);
+        }
+    }
+}
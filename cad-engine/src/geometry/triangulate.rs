@@ -0,0 +1,339 @@
+//! 폴리곤 삼각분할 (ear clipping)
+//!
+//! 구멍이 있는 폴리곤을 인덱스 삼각형 메시로 분할합니다 (WebGL 렌더링, 면적/무게중심 계산용).
+//!
+//! 알고리즘:
+//! 1. 외곽선은 CCW, 구멍은 CW로 정렬
+//! 2. 구멍을 최대 x가 큰 순서로 외곽선에 연결 (가시 정점까지 잇는 bridge, Eberly 방식)
+//! 3. 하나가 된 단순 다각형을 ear clipping (볼록 정점 중 내부에 다른 정점이 없는 귀를 반복 제거)
+//!
+//! 입력 폴리곤은 자기교차가 없어야 합니다 (`boolean` 정규화 결과를 사용).
+
+use super::polygon::{PolygonWithHoles, signed_area};
+
+/// 인덱스 삼각형 메시 (삼각형은 모두 CCW)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    pub vertices: Vec<[f64; 2]>,
+    /// 삼각형마다 정점 인덱스 3개
+    pub indices: Vec<u32>,
+}
+
+impl TriangleMesh {
+    fn triangles(&self) -> impl Iterator<Item = [[f64; 2]; 3]> + '_ {
+        self.indices.chunks_exact(3).map(|t| {
+            [
+                self.vertices[t[0] as usize],
+                self.vertices[t[1] as usize],
+                self.vertices[t[2] as usize],
+            ]
+        })
+    }
+
+    /// 삼각형 면적의 합
+    pub fn area(&self) -> f64 {
+        self.triangles().map(|[a, b, c]| cross(a, b, c) / 2.0).sum()
+    }
+
+    /// 면적 가중 무게중심 (면적이 0이면 None)
+    pub fn centroid(&self) -> Option<[f64; 2]> {
+        let mut area = 0.0;
+        let mut sum = [0.0, 0.0];
+        for [a, b, c] in self.triangles() {
+            let w = cross(a, b, c) / 2.0;
+            area += w;
+            sum[0] += w * (a[0] + b[0] + c[0]) / 3.0;
+            sum[1] += w * (a[1] + b[1] + c[1]) / 3.0;
+        }
+        (area.abs() > f64::EPSILON).then(|| [sum[0] / area, sum[1] / area])
+    }
+
+    /// 다른 메시를 뒤에 이어 붙입니다 (인덱스 오프셋 적용).
+    pub fn append(&mut self, other: TriangleMesh) {
+        let offset = self.vertices.len() as u32;
+        self.vertices.extend(other.vertices);
+        self.indices
+            .extend(other.indices.into_iter().map(|i| i + offset));
+    }
+}
+
+/// (b - a) × (c - a): 양수면 a → b → c가 CCW
+fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// 점이 CCW 삼각형 내부(경계 포함)에 있는지
+fn in_triangle(p: [f64; 2], a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
+
+/// 여러 폴리곤을 하나의 메시로 삼각분할합니다.
+pub fn triangulate_polygons(polygons: &[PolygonWithHoles]) -> TriangleMesh {
+    let mut mesh = TriangleMesh::default();
+    for polygon in polygons {
+        mesh.append(triangulate_polygon(polygon));
+    }
+    mesh
+}
+
+/// 구멍이 있는 폴리곤 하나를 삼각분할합니다.
+///
+/// 정점 배열은 외곽선 → 구멍 순서의 원래 점들이며, 중복 정점을 만들지 않습니다.
+pub fn triangulate_polygon(polygon: &PolygonWithHoles) -> TriangleMesh {
+    if polygon.outer.len() < 3 {
+        return TriangleMesh::default();
+    }
+
+    let mut vertices: Vec<[f64; 2]> = Vec::new();
+    let mut ring_indices = |ring: &[[f64; 2]], ccw: bool| -> Vec<usize> {
+        let start = vertices.len();
+        vertices.extend_from_slice(ring);
+        let mut ids: Vec<usize> = (start..vertices.len()).collect();
+        if (signed_area(ring) > 0.0) != ccw {
+            ids.reverse();
+        }
+        ids
+    };
+
+    let mut outer = ring_indices(&polygon.outer, true);
+    let mut holes: Vec<Vec<usize>> = polygon
+        .holes
+        .iter()
+        .filter(|h| h.len() >= 3)
+        .map(|h| ring_indices(h, false))
+        .collect();
+
+    // 구멍의 최대 x가 큰 순서로 연결해야 bridge가 다른 구멍을 가로지르지 않음
+    let max_x = |ids: &[usize], v: &[[f64; 2]]| {
+        ids.iter()
+            .map(|&i| v[i][0])
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    holes.sort_by(|a, b| max_x(b, &vertices).total_cmp(&max_x(a, &vertices)));
+    for hole in &holes {
+        outer = bridge_hole(&outer, hole, &vertices);
+    }
+
+    let indices = ear_clip(outer, &vertices);
+    TriangleMesh { vertices, indices }
+}
+
+/// 구멍의 최대 x 정점에서 +x 방향으로 보이는 외곽 정점을 찾아 연결합니다.
+fn bridge_hole(outer: &[usize], hole: &[usize], v: &[[f64; 2]]) -> Vec<usize> {
+    // 구멍의 최대 x 정점 M
+    let m_pos = (0..hole.len())
+        .max_by(|&a, &b| v[hole[a]][0].total_cmp(&v[hole[b]][0]))
+        .unwrap_or(0);
+    let m = v[hole[m_pos]];
+
+    // M에서 +x 방향 광선과 가장 가까운 외곽 변의 교차점 I
+    let n = outer.len();
+    let mut best: Option<(f64, usize)> = None;
+    for i in 0..n {
+        let a = v[outer[i]];
+        let b = v[outer[(i + 1) % n]];
+        let crosses = (a[1] <= m[1] && m[1] <= b[1]) || (b[1] <= m[1] && m[1] <= a[1]);
+        if !crosses || a[1] == b[1] {
+            continue;
+        }
+        let t = (m[1] - a[1]) / (b[1] - a[1]);
+        let x = a[0] + t * (b[0] - a[0]);
+        if x >= m[0] && best.is_none_or(|(bx, _)| x < bx) {
+            // 교차 변에서 x가 큰 끝점을 후보 P로 사용
+            let p = if a[0] >= b[0] { i } else { (i + 1) % n };
+            best = Some((x, p));
+        }
+    }
+    let Some((ix, mut p_pos)) = best else {
+        // 연결할 수 없는 구멍 (외곽선 밖): 무시
+        return outer.to_vec();
+    };
+
+    // 삼각형 (M, I, P) 내부의 reflex 정점이 있으면 광선과 각도가 가장 작은 정점으로 교체
+    let i_point = [ix, m[1]];
+    let p = v[outer[p_pos]];
+    if !(p[0] == ix && p[1] == m[1]) {
+        let tri = if cross(m, i_point, p) >= 0.0 {
+            [m, i_point, p]
+        } else {
+            [m, p, i_point]
+        };
+        let mut best_angle = f64::INFINITY;
+        let mut best_dist = f64::INFINITY;
+        for k in 0..n {
+            let q = v[outer[k]];
+            if k == p_pos || q[0] < m[0] || !in_triangle(q, tri[0], tri[1], tri[2]) {
+                continue;
+            }
+            let prev = v[outer[(k + n - 1) % n]];
+            let next = v[outer[(k + 1) % n]];
+            if cross(prev, q, next) > 0.0 {
+                continue; // 볼록 정점은 시야를 가리지 않음
+            }
+            let angle = (q[1] - m[1]).abs() / (q[0] - m[0]).max(f64::MIN_POSITIVE);
+            let dist = (q[0] - m[0]).hypot(q[1] - m[1]);
+            if angle < best_angle || (angle == best_angle && dist < best_dist) {
+                best_angle = angle;
+                best_dist = dist;
+                p_pos = k;
+            }
+        }
+    }
+
+    // outer[..=P] + hole[M..] + hole[..=M] + outer[P..]
+    let mut merged = Vec::with_capacity(outer.len() + hole.len() + 2);
+    merged.extend_from_slice(&outer[..=p_pos]);
+    merged.extend(hole[m_pos..].iter().chain(hole[..=m_pos].iter()).copied());
+    merged.extend_from_slice(&outer[p_pos..]);
+    merged
+}
+
+/// 단순 다각형(CCW, bridge로 중복 정점 허용)을 ear clipping 합니다.
+fn ear_clip(mut ring: Vec<usize>, v: &[[f64; 2]]) -> Vec<u32> {
+    let mut indices = Vec::with_capacity(ring.len().saturating_sub(2) * 3);
+    let same = |a: usize, b: usize| a == b || v[a] == v[b];
+    // 직전 귀 위치부터 탐색 (귀는 대개 이웃에서 다시 생기므로 평균 O(n²))
+    let mut cursor = 0;
+
+    while ring.len() > 3 {
+        let n = ring.len();
+        let corners = |i: usize| (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        let is_ear = |i: usize, strict: bool| {
+            let (a, b, c) = corners(i);
+            if cross(v[a], v[b], v[c]) <= 0.0 {
+                return false;
+            }
+            // 귀 내부에 들어갈 수 있는 정점은 reflex 정점뿐
+            !strict
+                || (0..n).all(|k| {
+                    let (prev, q, next) = corners(k);
+                    same(q, a)
+                        || same(q, b)
+                        || same(q, c)
+                        || cross(v[prev], v[q], v[next]) > 0.0
+                        || !in_triangle(v[q], v[a], v[b], v[c])
+                })
+        };
+        let order = (0..n).map(|k| (cursor + k) % n);
+
+        // 1차: 정상적인 귀, 2차: 내부 검사 생략 (수치 오차로 막힌 경우), 3차: 퇴화 정점 제거
+        let ear = order
+            .clone()
+            .find(|&i| is_ear(i, true))
+            .or_else(|| order.clone().find(|&i| is_ear(i, false)));
+        if let Some(i) = ear {
+            let (a, b, c) = corners(i);
+            indices.extend([a as u32, b as u32, c as u32]);
+            ring.remove(i);
+            cursor = i.saturating_sub(1);
+            continue;
+        }
+        // 볼록 정점이 없음 = 남은 다각형이 퇴화 (일직선/중복 정점):
+        // 가장 평평한 정점을 삼각형 없이 제거
+        let flattest = (0..n)
+            .max_by(|&i, &j| {
+                let area = |k: usize| {
+                    let (a, b, c) = corners(k);
+                    cross(v[a], v[b], v[c])
+                };
+                area(i).total_cmp(&area(j))
+            })
+            .unwrap_or(0);
+        ring.remove(flattest);
+        cursor = flattest.saturating_sub(1);
+    }
+    if ring.len() == 3 && cross(v[ring[0]], v[ring[1]], v[ring[2]]) > 0.0 {
+        indices.extend(ring.iter().map(|&i| i as u32));
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn square(min: f64, max: f64) -> Vec<[f64; 2]> {
+        vec![[min, min], [max, min], [max, max], [min, max]]
+    }
+
+    #[test]
+    fn test_triangulate_square_and_cw_input() {
+        let mut outer = square(0.0, 2.0);
+        outer.reverse(); // CW 입력도 CCW 삼각형으로 출력
+        let mesh = triangulate_polygon(&PolygonWithHoles {
+            outer,
+            holes: vec![],
+        });
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.indices.len(), 6);
+        assert!(approx_eq(mesh.area(), 4.0, 1e-12));
+        let c = mesh.centroid().unwrap();
+        assert!(approx_eq(c[0], 1.0, 1e-12) && approx_eq(c[1], 1.0, 1e-12));
+    }
+
+    #[test]
+    fn test_triangulate_concave_polygon() {
+        // L자: 면적 3
+        let outer = vec![
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ];
+        let mesh = triangulate_polygon(&PolygonWithHoles {
+            outer,
+            holes: vec![],
+        });
+        assert_eq!(mesh.indices.len(), 12);
+        assert!(approx_eq(mesh.area(), 3.0, 1e-12));
+    }
+
+    #[test]
+    fn test_triangulate_with_holes() {
+        let polygon = PolygonWithHoles {
+            outer: square(0.0, 10.0),
+            holes: vec![square(2.0, 4.0), square(6.0, 8.0)],
+        };
+        let mesh = triangulate_polygon(&polygon);
+        assert!(approx_eq(mesh.area(), 100.0 - 8.0, 1e-9));
+        // 모든 삼각형이 CCW이고 구멍 안에 무게중심이 없어야 함
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|k| mesh.vertices[tri[k] as usize]);
+            assert!(cross(a, b, c) > 0.0);
+            let g = [(a[0] + b[0] + c[0]) / 3.0, (a[1] + b[1] + c[1]) / 3.0];
+            assert!(
+                !polygon
+                    .holes
+                    .iter()
+                    .any(|h| { super::super::polygon::point_in_ring(h, g) })
+            );
+        }
+        // 정점은 원래 점 그대로 (중복 없음)
+        assert_eq!(mesh.vertices.len(), 12);
+    }
+
+    #[test]
+    fn test_mesh_append_offsets_indices() {
+        let mut mesh = triangulate_polygons(&[
+            PolygonWithHoles {
+                outer: square(0.0, 1.0),
+                holes: vec![],
+            },
+            PolygonWithHoles {
+                outer: square(5.0, 6.0),
+                holes: vec![],
+            },
+        ]);
+        assert_eq!(mesh.vertices.len(), 8);
+        assert!(mesh.indices[6..].iter().all(|&i| i >= 4));
+        assert!(approx_eq(mesh.area(), 2.0, 1e-12));
+        mesh.append(TriangleMesh::default());
+        assert_eq!(mesh.indices.len(), 12);
+    }
+}
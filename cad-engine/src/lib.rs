@@ -1,5 +1,6 @@
 use wasm_bindgen::prelude::*;
 
+pub mod geometry;
 pub mod primitives;
 pub mod scene;
 pub mod serializers;
@@ -0,0 +1,617 @@
+//! 정렬/분배 모듈
+//!
+//! 여러 Entity를 월드 바운드 기준으로 한 번에 정렬하거나 같은 간격으로 분배합니다.
+//! - 이동량은 시작 시점의 바운드로 한 번에 계산하고 Entity마다 한 번만 이동 → 반복 translate의 누적 오차 없음
+//! - 기준(reference): 생략 시 선택 전체 바운드, 목록 안의 Entity면 그 Entity(움직이지 않음),
+//!   목록 밖의 Entity/Group이면 그 바운드
+//! - 부모 그룹 변환은 translate_world가 월드 이동량을 로컬로 역산하여 처리
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use super::{Scene, SceneError};
+
+/// 정렬 기준 변
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignMode {
+    Left,
+    Right,
+    /// 최대 y (Scene은 y-up)
+    Top,
+    Bottom,
+    CenterX,
+    CenterY,
+}
+
+impl AlignMode {
+    /// "left" | "right" | "top" | "bottom" | "center_x" | "center_y" ("center-x", "centerx"도 허용)
+    pub fn parse_str(s: &str) -> Option<Self> {
+        let normalized: String = s
+            .trim()
+            .to_ascii_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect();
+        match normalized.as_str() {
+            "left" => Some(AlignMode::Left),
+            "right" => Some(AlignMode::Right),
+            "top" => Some(AlignMode::Top),
+            "bottom" => Some(AlignMode::Bottom),
+            "centerx" | "hcenter" => Some(AlignMode::CenterX),
+            "centery" | "vcenter" => Some(AlignMode::CenterY),
+            _ => None,
+        }
+    }
+
+    /// 바운드에서 기준 좌표를 뽑는 축 (0 = x, 1 = y)
+    fn axis(self) -> usize {
+        match self {
+            AlignMode::Left | AlignMode::Right | AlignMode::CenterX => 0,
+            AlignMode::Top | AlignMode::Bottom | AlignMode::CenterY => 1,
+        }
+    }
+
+    fn coordinate(self, (min, max): Bounds) -> f64 {
+        let axis = self.axis();
+        match self {
+            AlignMode::Left | AlignMode::Bottom => min[axis],
+            AlignMode::Right | AlignMode::Top => max[axis],
+            AlignMode::CenterX | AlignMode::CenterY => (min[axis] + max[axis]) / 2.0,
+        }
+    }
+}
+
+/// 분배 간격 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistributeMode {
+    /// 중심 사이 거리가 같음
+    Spacing,
+    /// 바운드 사이 빈 공간이 같음
+    Gap,
+}
+
+impl DistributeMode {
+    /// "spacing" | "center" | "gap"
+    pub fn parse_str(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "spacing" | "center" | "centers" => Some(DistributeMode::Spacing),
+            "gap" | "gaps" => Some(DistributeMode::Gap),
+            _ => None,
+        }
+    }
+}
+
+type Bounds = ([f64; 2], [f64; 2]);
+
+/// 정렬/분배로 이동한 Entity와 월드 이동량
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Moved {
+    pub name: String,
+    pub delta: [f64; 2],
+}
+
+/// 정렬/분배 기준
+enum Reference {
+    /// 선택 전체 바운드
+    Selection(Bounds),
+    /// 목록 안의 Entity (움직이지 않음)
+    Key(usize),
+    /// 목록 밖 Entity/Group 바운드
+    Outer(Bounds),
+}
+
+fn union(bounds: &[Bounds]) -> Bounds {
+    bounds.iter().fold(
+        ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]),
+        |(min, max), (b_min, b_max)| {
+            (
+                [min[0].min(b_min[0]), min[1].min(b_min[1])],
+                [max[0].max(b_max[0]), max[1].max(b_max[1])],
+            )
+        },
+    )
+}
+
+impl Scene {
+    /// 대상 목록의 월드 바운드를 조회하고 검증합니다.
+    ///
+    /// # Errors
+    /// * 빈 목록, Entity 미발견, 바운드 없는 Entity(빈 그룹), 조상 그룹과 함께 들어온 Entity
+    fn arrange_targets(
+        &self,
+        fn_name: &str,
+        names: &[String],
+    ) -> Result<(Vec<String>, Vec<Bounds>), SceneError> {
+        let mut unique: Vec<String> = Vec::new();
+        for name in names {
+            if !unique.contains(name) {
+                unique.push(name.clone());
+            }
+        }
+        if unique.is_empty() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: names must not be empty",
+                fn_name
+            )));
+        }
+        let mut bounds = Vec::with_capacity(unique.len());
+        for name in &unique {
+            if !self.has_entity(name) {
+                return Err(SceneError::EntityNotFound(
+                    fn_name.to_string(),
+                    name.clone(),
+                ));
+            }
+            // 조상이 함께 이동하면 두 번 이동하게 됨
+            if let Some(ancestor) = self
+                .collect_parent_chain(name)
+                .iter()
+                .rev()
+                .skip(1)
+                .find(|e| unique.contains(&e.metadata.name))
+            {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: '{}' is inside '{}', which is also in the list",
+                    fn_name, name, ancestor.metadata.name
+                )));
+            }
+            bounds.push(self.get_world_bounds_internal(name).ok_or_else(|| {
+                SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: '{}' has no bounds",
+                    fn_name, name
+                ))
+            })?);
+        }
+        Ok((unique, bounds))
+    }
+
+    /// 기준 해석 ("" → 선택 전체)
+    fn arrange_reference(
+        &self,
+        fn_name: &str,
+        reference: &str,
+        names: &[String],
+        bounds: &[Bounds],
+    ) -> Result<Reference, SceneError> {
+        if reference.is_empty() {
+            return Ok(Reference::Selection(union(bounds)));
+        }
+        if let Some(index) = names.iter().position(|n| n == reference) {
+            return Ok(Reference::Key(index));
+        }
+        if !self.has_entity(reference) {
+            return Err(SceneError::EntityNotFound(
+                fn_name.to_string(),
+                reference.to_string(),
+            ));
+        }
+        self.get_world_bounds_internal(reference)
+            .map(Reference::Outer)
+            .ok_or_else(|| {
+                SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: reference '{}' has no bounds",
+                    fn_name, reference
+                ))
+            })
+    }
+
+    /// 월드 이동량을 적용하고 이동한 목록을 반환 (0 이동은 생략)
+    fn apply_world_moves(&mut self, names: Vec<String>, deltas: Vec<[f64; 2]>) -> Vec<Moved> {
+        let mut moved = Vec::new();
+        for (name, delta) in names.into_iter().zip(deltas) {
+            if delta == [0.0, 0.0] {
+                continue;
+            }
+            if matches!(self.translate_world(&name, delta[0], delta[1]), Ok(true)) {
+                moved.push(Moved { name, delta });
+            }
+        }
+        moved
+    }
+
+    /// 여러 Entity를 월드 바운드 기준으로 정렬합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `names` - 대상 Entity/Group 이름
+    /// * `mode` - 정렬 기준 변
+    /// * `reference` - "" (선택 전체), 목록 안 Entity (고정), 목록 밖 Entity/Group (그 바운드)
+    ///
+    /// # Returns
+    /// 실제로 이동한 Entity와 월드 이동량
+    pub(crate) fn align_internal(
+        &mut self,
+        names: &[String],
+        mode: AlignMode,
+        reference: &str,
+    ) -> Result<Vec<Moved>, SceneError> {
+        let (names, bounds) = self.arrange_targets("align", names)?;
+        let target = match self.arrange_reference("align", reference, &names, &bounds)? {
+            Reference::Selection(b) | Reference::Outer(b) => mode.coordinate(b),
+            Reference::Key(index) => mode.coordinate(bounds[index]),
+        };
+        let axis = mode.axis();
+        let deltas = bounds
+            .iter()
+            .map(|b| {
+                let mut delta = [0.0, 0.0];
+                delta[axis] = target - mode.coordinate(*b);
+                delta
+            })
+            .collect();
+
+        let count = names.len();
+        let moved = self.apply_world_moves(names, deltas);
+        self.last_operation = Some(format!("align({:?}, {} entities)", mode, count));
+        Ok(moved)
+    }
+
+    /// 여러 Entity를 한 축으로 같은 간격이 되도록 분배합니다 (내부용).
+    ///
+    /// 현재 중심 위치 순서를 유지합니다.
+    /// - `value` 없음: 양 끝(선택 전체 또는 목록 밖 기준 바운드) 사이를 균등 분배
+    /// - `value` 있음: 중심 간격(spacing) 또는 빈 공간(gap)을 그 값으로 맞춤
+    ///   (첫 Entity 또는 기준 바운드 시작에서부터 배치)
+    /// - 기준이 목록 안 Entity면 그 Entity가 움직이지 않도록 전체를 평행 이동
+    ///
+    /// # Arguments
+    /// * `axis` - 0 = x(가로), 1 = y(세로)
+    ///
+    /// # Errors
+    /// * arrange 대상 오류, NaN/Infinity value
+    pub(crate) fn distribute_internal(
+        &mut self,
+        names: &[String],
+        axis: usize,
+        mode: DistributeMode,
+        reference: &str,
+        value: Option<f64>,
+    ) -> Result<Vec<Moved>, SceneError> {
+        if value.is_some_and(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(
+                "[distribute] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let (names, bounds) = self.arrange_targets("distribute", names)?;
+        let reference = self.arrange_reference("distribute", reference, &names, &bounds)?;
+
+        // 현재 중심 순서 (같으면 입력 순서)
+        let center = |b: &Bounds| (b.0[axis] + b.1[axis]) / 2.0;
+        let mut order: Vec<usize> = (0..names.len()).collect();
+        order.sort_by(|&a, &b| center(&bounds[a]).total_cmp(&center(&bounds[b])));
+        let sizes: Vec<f64> = order
+            .iter()
+            .map(|&i| bounds[i].1[axis] - bounds[i].0[axis])
+            .collect();
+
+        let span = match reference {
+            Reference::Outer(b) | Reference::Selection(b) => (b.0[axis], b.1[axis]),
+            Reference::Key(_) => {
+                let b = union(&bounds);
+                (b.0[axis], b.1[axis])
+            }
+        };
+        let start = match (&reference, value) {
+            (Reference::Outer(_), _) | (_, None) => span.0,
+            _ => bounds[order[0]].0[axis],
+        };
+        let steps = (order.len() - 1).max(1) as f64;
+        let last = sizes.len() - 1;
+
+        // 정렬 순서별 새 min 좌표
+        let mut mins = Vec::with_capacity(order.len());
+        match mode {
+            DistributeMode::Gap => {
+                let gap =
+                    value.unwrap_or_else(|| (span.1 - span.0 - sizes.iter().sum::<f64>()) / steps);
+                let mut cursor = start;
+                for size in &sizes {
+                    mins.push(cursor);
+                    cursor += size + gap;
+                }
+            }
+            DistributeMode::Spacing => {
+                let first = start + sizes[0] / 2.0;
+                let step = value.unwrap_or_else(|| (span.1 - sizes[last] / 2.0 - first) / steps);
+                for (k, size) in sizes.iter().enumerate() {
+                    mins.push(first + step * k as f64 - size / 2.0);
+                }
+            }
+        }
+
+        let mut deltas = vec![[0.0, 0.0]; names.len()];
+        for (k, &i) in order.iter().enumerate() {
+            deltas[i][axis] = mins[k] - bounds[i].0[axis];
+        }
+        if let Reference::Key(key) = reference {
+            let shift = deltas[key][axis];
+            for delta in &mut deltas {
+                delta[axis] -= shift;
+            }
+        }
+
+        let count = names.len();
+        let moved = self.apply_world_moves(names, deltas);
+        self.last_operation = Some(format!(
+            "distribute({}, {:?}, {} entities)",
+            if axis == 0 { "horizontal" } else { "vertical" },
+            mode,
+            count
+        ));
+        Ok(moved)
+    }
+}
+
+/// names_json 파싱
+fn parse_names(fn_name: &str, names_json: &str) -> Result<Vec<String>, SceneError> {
+    serde_json::from_str(names_json)
+        .map_err(|e| SceneError::InvalidInput(format!("[{}] invalid_json: {}", fn_name, e)))
+}
+
+// ========================================
+// WASM Bindings for Align / Distribute
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 여러 Entity를 월드 바운드 기준으로 정렬합니다.
+    ///
+    /// # Arguments
+    /// * `names_json` - 대상 이름 JSON 배열 (예: `["door", "window1", "window2"]`)
+    /// * `mode` - "left" | "right" | "top" | "bottom" | "center_x" | "center_y" (top = 최대 y)
+    /// * `reference` - 기준 ("" = 선택 전체 바운드, 목록 안 이름 = 그 Entity에 맞춤(고정),
+    ///   목록 밖 Entity/Group 이름 = 그 바운드에 맞춤)
+    ///
+    /// # Returns
+    /// 이동한 Entity JSON: `[{"name": "window1", "delta": [dx, dy]}, ...]` (월드 이동량, 이동 없으면 생략)
+    ///
+    /// # Errors
+    /// * 잘못된 JSON/mode, Entity 미발견, 빈 그룹, 그룹과 그 자식이 함께 있는 목록
+    pub fn align(
+        &mut self,
+        names_json: &str,
+        mode: &str,
+        reference: &str,
+    ) -> Result<String, JsValue> {
+        let mode = AlignMode::parse_str(mode).ok_or_else(|| {
+            JsValue::from_str(&format!(
+                "[align] invalid_input: unknown mode '{}' (expected left, right, top, bottom, center_x or center_y)",
+                mode
+            ))
+        })?;
+        let moved = parse_names("align", names_json)
+            .and_then(|names| self.align_internal(&names, mode, reference))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&moved).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// 여러 Entity를 가로 또는 세로로 같은 간격이 되도록 분배합니다.
+    ///
+    /// 현재 위치 순서를 유지하며, 이동량을 한 번에 계산해 적용합니다.
+    ///
+    /// # Arguments
+    /// * `names_json` - 대상 이름 JSON 배열
+    /// * `axis` - "horizontal" (x) | "vertical" (y)
+    /// * `mode` - "spacing" (중심 간격 균등) | "gap" (빈 공간 균등)
+    /// * `reference` - "" = 양 끝 Entity 사이, 목록 안 이름 = 그 Entity 고정,
+    ///   목록 밖 Entity/Group 이름 = 그 바운드 안에 양 끝을 맞춰 분배
+    /// * `value` - 지정하면 중심 간격(spacing) 또는 빈 공간(gap)을 그 값으로 고정
+    ///
+    /// # Returns
+    /// 이동한 Entity JSON: `[{"name": "post2", "delta": [dx, dy]}, ...]`
+    ///
+    /// # Errors
+    /// * 잘못된 JSON/axis/mode, Entity 미발견, 빈 그룹, 그룹과 그 자식이 함께 있는 목록
+    pub fn distribute(
+        &mut self,
+        names_json: &str,
+        axis: &str,
+        mode: &str,
+        reference: &str,
+        value: Option<f64>,
+    ) -> Result<String, JsValue> {
+        let axis = match axis.trim().to_ascii_lowercase().as_str() {
+            "horizontal" | "x" => 0,
+            "vertical" | "y" => 1,
+            _ => {
+                return Err(JsValue::from_str(&format!(
+                    "[distribute] invalid_input: unknown axis '{}' (expected horizontal or vertical)",
+                    axis
+                )));
+            }
+        };
+        let mode = DistributeMode::parse_str(mode).ok_or_else(|| {
+            JsValue::from_str(&format!(
+                "[distribute] invalid_input: unknown mode '{}' (expected spacing or gap)",
+                mode
+            ))
+        })?;
+        let moved = parse_names("distribute", names_json)
+            .and_then(|names| self.distribute_internal(&names, axis, mode, reference, value))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&moved).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn bounds(scene: &Scene, name: &str) -> Bounds {
+        scene.get_world_bounds_internal(name).unwrap()
+    }
+
+    fn scene_with_boxes() -> Scene {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("b", 5.0, 3.0, 4.0, 2.0).unwrap();
+        scene.add_circle_internal("c", 20.0, -4.0, 1.0).unwrap();
+        scene
+    }
+
+    #[test]
+    fn test_align_to_selection_and_key() {
+        let mut scene = scene_with_boxes();
+        let moved = scene
+            .align_internal(&names(&["a", "b", "c"]), AlignMode::Left, "")
+            .unwrap();
+        // a가 이미 가장 왼쪽 → 이동 없음
+        assert_eq!(moved.len(), 2);
+        for name in ["a", "b", "c"] {
+            assert!(approx_eq(bounds(&scene, name).0[0], -1.0));
+        }
+
+        scene
+            .align_internal(&names(&["a", "b", "c"]), AlignMode::Top, "b")
+            .unwrap();
+        assert_eq!(bounds(&scene, "b").1[1], 4.0);
+        assert!(approx_eq(bounds(&scene, "a").1[1], 4.0));
+        assert!(approx_eq(bounds(&scene, "c").1[1], 4.0));
+
+        scene
+            .align_internal(&names(&["a", "c"]), AlignMode::CenterY, "b")
+            .unwrap();
+        assert!(approx_eq(bounds(&scene, "c").0[1], 2.0));
+    }
+
+    #[test]
+    fn test_align_inside_rotated_group() {
+        let mut scene = scene_with_boxes();
+        scene.create_group_internal("g", names(&["b"])).unwrap();
+        scene.rotate("g", PI / 2.0).unwrap();
+        scene.scale("g", 2.0, 2.0).unwrap();
+        scene
+            .add_rect_internal("wall", 0.0, 30.0, 100.0, 1.0)
+            .unwrap();
+
+        scene
+            .align_internal(&names(&["a", "b"]), AlignMode::Bottom, "wall")
+            .unwrap();
+        let wall_bottom = bounds(&scene, "wall").0[1];
+        assert!(approx_eq(bounds(&scene, "b").0[1], wall_bottom));
+        assert!(approx_eq(bounds(&scene, "a").0[1], wall_bottom));
+    }
+
+    #[test]
+    fn test_distribute_gap_and_spacing() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("p1", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("p3", 20.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("p2", 3.0, 0.0, 6.0, 2.0).unwrap();
+
+        // 빈 공간 균등: 폭 합 10, 전체 -1..21 → gap (22 - 10) / 2 = 6
+        scene
+            .distribute_internal(
+                &names(&["p1", "p3", "p2"]),
+                0,
+                DistributeMode::Gap,
+                "",
+                None,
+            )
+            .unwrap();
+        assert!(approx_eq(bounds(&scene, "p2").0[0], 1.0 + 6.0));
+        assert!(approx_eq(bounds(&scene, "p3").0[0], 19.0));
+
+        // 중심 간격 균등 + 지정 값, 첫 Entity 기준
+        scene
+            .distribute_internal(
+                &names(&["p1", "p2", "p3"]),
+                0,
+                DistributeMode::Spacing,
+                "",
+                Some(5.0),
+            )
+            .unwrap();
+        let centers: Vec<f64> = ["p1", "p2", "p3"]
+            .iter()
+            .map(|n| {
+                let b = bounds(&scene, n);
+                (b.0[0] + b.1[0]) / 2.0
+            })
+            .collect();
+        assert!(approx_eq(centers[0], 0.0));
+        assert!(approx_eq(centers[1], 5.0) && approx_eq(centers[2], 10.0));
+    }
+
+    #[test]
+    fn test_distribute_key_and_outer_reference() {
+        let mut scene = Scene::new("test");
+        for (i, x) in [0.0, 1.0, 7.0].iter().enumerate() {
+            scene
+                .add_circle_internal(&format!("h{}", i), 0.0, *x, 0.5)
+                .unwrap();
+        }
+        scene
+            .add_rect_internal("frame", 0.0, 10.0, 4.0, 20.0)
+            .unwrap();
+
+        // 목록 밖 Group/Entity 바운드 안에 양 끝 맞춤
+        scene
+            .distribute_internal(
+                &names(&["h0", "h1", "h2"]),
+                1,
+                DistributeMode::Spacing,
+                "frame",
+                None,
+            )
+            .unwrap();
+        assert!(approx_eq(bounds(&scene, "h0").0[1], 0.0));
+        assert!(approx_eq(bounds(&scene, "h1").0[1], 9.5));
+        assert!(approx_eq(bounds(&scene, "h2").1[1], 20.0));
+
+        // 목록 안 기준 Entity는 고정
+        let before = bounds(&scene, "h1");
+        scene
+            .distribute_internal(
+                &names(&["h0", "h1", "h2"]),
+                1,
+                DistributeMode::Gap,
+                "h1",
+                Some(1.0),
+            )
+            .unwrap();
+        assert_eq!(bounds(&scene, "h1"), before);
+        assert!(approx_eq(bounds(&scene, "h0").1[1], before.0[1] - 1.0));
+        assert!(approx_eq(bounds(&scene, "h2").0[1], before.1[1] + 1.0));
+    }
+
+    #[test]
+    fn test_arrange_errors() {
+        let mut scene = scene_with_boxes();
+        scene.create_group_internal("g", names(&["a"])).unwrap();
+        scene.create_group_internal("empty", vec![]).unwrap();
+        assert!(matches!(
+            scene.align_internal(&names(&["a", "missing"]), AlignMode::Left, ""),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(matches!(
+            scene.align_internal(&names(&["b"]), AlignMode::Left, "missing"),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        let err = scene
+            .align_internal(&names(&["g", "a"]), AlignMode::Left, "")
+            .unwrap_err();
+        assert!(err.to_string().contains("inside 'g'"));
+        let err = scene
+            .distribute_internal(&names(&["b", "empty"]), 0, DistributeMode::Gap, "", None)
+            .unwrap_err();
+        assert!(err.to_string().contains("no bounds"));
+        assert!(
+            scene
+                .align_internal(&[], AlignMode::Left, "")
+                .unwrap_err()
+                .to_string()
+                .contains("must not be empty")
+        );
+        assert_eq!(AlignMode::parse_str("Center-X"), Some(AlignMode::CenterX));
+        assert_eq!(DistributeMode::parse_str("GAP"), Some(DistributeMode::Gap));
+    }
+}
@@ -0,0 +1,727 @@
+//! 배열 복사 모듈
+//!
+//! Entity 또는 Group을 격자(행×열), 직선, 원형(polar)으로 복사합니다.
+//! - 원본이 첫 번째 자리(격자 (0, 0), 직선/원형 0번)를 차지하고 나머지 자리에 복사본 생성
+//! - 이름: 격자 `{name}_{row}_{col}`, 직선/원형 `{name}_{index}` (그룹 복사 시 자식도 같은 접미사)
+//! - 복사본은 원본과 같은 부모 안, 바로 위 z-order에 순서대로 쌓임
+//! - 간격/중심은 월드 좌표 (부모 그룹 변환은 translate_world와 같은 방식으로 역산)
+//! - group_name을 주면 원본과 복사본을 create_group_internal로 묶음 (원본의 부모 안에 유지)
+
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityHandle, Geometry, Transform};
+use super::{Scene, SceneError, generate_id};
+
+/// 한 번에 만들 수 있는 최대 Entity 수 (복사본 × 하위 트리 크기)
+const MAX_ARRAY_ENTITIES: usize = 10_000;
+
+/// 배치 개수(원본 포함) 상한 검사 (이름 목록을 만들기 전)
+fn check_array_count(fn_name: &str, count: u64) -> Result<(), SceneError> {
+    if count > MAX_ARRAY_ENTITIES as u64 {
+        return Err(SceneError::InvalidInput(format!(
+            "[{}] invalid_input: too many copies ({}, max {})",
+            fn_name, count, MAX_ARRAY_ENTITIES
+        )));
+    }
+    Ok(())
+}
+
+/// 전체 원(2π)으로 볼 각도 오차
+const FULL_TURN_EPSILON: f64 = 1e-9;
+
+impl Scene {
+    /// 하위 트리 핸들 (전위 순회, root 먼저)
+    pub(super) fn subtree_handles(&self, root: EntityHandle) -> Vec<EntityHandle> {
+        let mut handles = Vec::new();
+        let mut stack = vec![root];
+        while let Some(handle) = stack.pop() {
+            let Some(entity) = self.entity_at(handle) else {
+                continue;
+            };
+            handles.push(handle);
+            stack.extend(entity.children.iter().rev());
+        }
+        handles
+    }
+
+    /// 하위 트리 전체 이름
+    pub(super) fn subtree_names(&self, root: EntityHandle) -> Vec<String> {
+        self.subtree_handles(root)
+            .into_iter()
+            .filter_map(|handle| self.entity_at(handle))
+            .map(|entity| entity.metadata.name.clone())
+            .collect()
+    }
+
+    /// Entity(그룹이면 하위 트리 전체)를 복사합니다.
+    ///
+    /// - 새 id, 이름은 `rename(원래 이름)`, 스타일/변환/자식 순서는 그대로
+    /// - 복사본 root는 원본과 같은 부모에 들어가며 `above` 바로 위 z-order (위쪽 형제는 한 칸씩 올림)
+    /// - 하위 트리 안을 참조하는 치수는 복사본을 참조하도록 갱신
+    ///
+    /// 새 이름의 중복 검사는 호출자 책임입니다.
+    ///
+    /// # Returns
+    /// 복사본 root 핸들 (source 미발견 시 None)
+    pub(super) fn copy_subtree(
+        &mut self,
+        source: EntityHandle,
+        rename: &dyn Fn(&str) -> String,
+        above: EntityHandle,
+    ) -> Option<EntityHandle> {
+        let handles = self.subtree_handles(source);
+        let originals: Vec<_> = handles
+            .iter()
+            .filter_map(|handle| self.entity_at(*handle).cloned())
+            .collect();
+        let root = originals.first()?;
+        let parent = root.parent;
+        let names: HashMap<String, String> = originals
+            .iter()
+            .map(|e| (e.metadata.name.clone(), rename(&e.metadata.name)))
+            .collect();
+
+        let mut copies: HashMap<EntityHandle, EntityHandle> = HashMap::new();
+        for original in &originals {
+            let mut entity = original.clone();
+            entity.id = generate_id();
+            entity.metadata.name = names[&original.metadata.name].clone();
+            entity.children = Vec::new();
+            if let Geometry::Dimension { kind, .. } = &mut entity.geometry {
+                for reference in kind.references_mut() {
+                    if let Some(renamed) = names.get(reference.as_str()) {
+                        *reference = renamed.clone();
+                    }
+                }
+            }
+            copies.insert(original.handle, self.push_entity(entity));
+        }
+        for original in &originals {
+            let copy = copies[&original.handle];
+            let children = original.children.iter().map(|c| copies[c]).collect();
+            let copy_parent = match original.parent {
+                Some(p) if original.handle != source => copies.get(&p).copied(),
+                _ => parent,
+            };
+            if let Some(entity) = self.entity_at_mut(copy) {
+                entity.children = children;
+                entity.parent = copy_parent;
+            }
+        }
+
+        // 원본과 같은 스코프, above 바로 위에 끼워 넣기
+        let copy_root = copies[&source];
+        let above_z = self
+            .entity_at(above)
+            .map_or(root.metadata.z_index, |e| e.metadata.z_index);
+        let siblings: Vec<EntityHandle> = self
+            .entities
+            .iter()
+            .filter(|e| e.parent == parent && e.handle != copy_root && e.metadata.z_index > above_z)
+            .map(|e| e.handle)
+            .collect();
+        for sibling in siblings {
+            if let Some(entity) = self.entity_at_mut(sibling) {
+                entity.metadata.z_index += 1;
+            }
+        }
+        if let Some(entity) = self.entity_at_mut(copy_root) {
+            entity.metadata.z_index = above_z + 1;
+        }
+        if let Some(parent) = parent
+            && let Some(parent_entity) = self.entity_at_mut(parent)
+        {
+            let position = parent_entity
+                .children
+                .iter()
+                .position(|c| *c == above)
+                .map_or(parent_entity.children.len(), |i| i + 1);
+            parent_entity.children.insert(position, copy_root);
+        }
+        Some(copy_root)
+    }
+
+    /// 월드 좌표 점을 중심으로 Entity를 회전합니다 (translate/rotate만 변경).
+    ///
+    /// 부모 좌표계에서 중심을 역산합니다. 부모가 뒤집혀(det < 0) 있으면 로컬 회전 방향이 반대입니다.
+    /// 부모에 비균등 스케일이 있으면 월드에서는 정확한 회전이 아닙니다 (로컬 변환으로 표현 불가).
+    fn rotate_about_world_point(&mut self, name: &str, center: [f64; 2], angle: f64) {
+        let parent_matrix = self
+            .find_by_name(name)
+            .and_then(|entity| self.parent_of(entity))
+            .and_then(|parent| self.get_world_transform_internal(&parent.metadata.name));
+        let (q, angle) = match parent_matrix
+            .and_then(|m| Transform::inverse_matrix(&m).map(|inverse| (m, inverse)))
+        {
+            Some((m, inverse)) => {
+                let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+                (
+                    Transform::transform_point(&inverse, center),
+                    if det < 0.0 { -angle } else { angle },
+                )
+            }
+            None => (center, angle),
+        };
+        let Some(entity) = self.find_by_name_mut(name) else {
+            return;
+        };
+        // T(t)·T(pv)·R·S·T(-pv) 앞에 q 중심 회전 → t' = q + R(θ)(t + pv - q) - pv
+        let transform = &mut entity.transform;
+        let (sin, cos) = angle.sin_cos();
+        let v = [
+            transform.translate[0] + transform.pivot[0] - q[0],
+            transform.translate[1] + transform.pivot[1] - q[1],
+        ];
+        transform.translate = [
+            q[0] + cos * v[0] - sin * v[1] - transform.pivot[0],
+            q[1] + sin * v[0] + cos * v[1] - transform.pivot[1],
+        ];
+        transform.rotate += angle;
+    }
+
+    /// 배열 복사 공통 검증: 원본 핸들
+    ///
+    /// # Errors
+    /// * Entity 미발견, 생성 개수 초과, 새 이름/그룹 이름 중복
+    fn array_source(
+        &self,
+        fn_name: &str,
+        name: &str,
+        suffixes: &[String],
+        group_name: Option<&str>,
+    ) -> Result<EntityHandle, SceneError> {
+        let source = self
+            .handle_of(name)
+            .ok_or_else(|| SceneError::EntityNotFound(fn_name.to_string(), name.to_string()))?;
+        let subtree = self.subtree_names(source);
+        if suffixes.len().saturating_mul(subtree.len()) > MAX_ARRAY_ENTITIES {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: too many copies ({} x {} entities, max {})",
+                fn_name,
+                suffixes.len(),
+                subtree.len(),
+                MAX_ARRAY_ENTITIES
+            )));
+        }
+
+        let mut taken = HashSet::new();
+        let new_names = suffixes
+            .iter()
+            .flat_map(|suffix| subtree.iter().map(move |n| format!("{}{}", n, suffix)))
+            .chain(group_name.map(str::to_string));
+        for new_name in new_names {
+            if self.has_entity(&new_name) || !taken.insert(new_name.clone()) {
+                return Err(SceneError::DuplicateEntityName(
+                    fn_name.to_string(),
+                    new_name,
+                ));
+            }
+        }
+        Ok(source)
+    }
+
+    /// 원본 바로 위로 복사본들을 순서대로 쌓습니다.
+    ///
+    /// # Returns
+    /// 복사본 root 이름 (suffixes 순서)
+    fn array_copies(&mut self, source: EntityHandle, suffixes: &[String]) -> Vec<String> {
+        let mut above = source;
+        let mut names = Vec::with_capacity(suffixes.len());
+        for suffix in suffixes {
+            let rename = |n: &str| format!("{}{}", n, suffix);
+            let Some(copy) = self.copy_subtree(source, &rename, above) else {
+                break;
+            };
+            if let Some(entity) = self.entity_at(copy) {
+                names.push(entity.metadata.name.clone());
+            }
+            above = copy;
+        }
+        names
+    }
+
+    /// 원본과 복사본을 새 그룹으로 묶습니다 (원본에 부모가 있으면 그 안으로 옮김).
+    ///
+    /// 그룹 변환은 항등이므로 월드 위치는 그대로입니다.
+    fn wrap_array_group(
+        &mut self,
+        group_name: &str,
+        source: EntityHandle,
+        members: Vec<String>,
+    ) -> Result<(), SceneError> {
+        let (parent, z_index) = self
+            .entity_at(source)
+            .map_or((None, 0), |e| (e.parent, e.metadata.z_index));
+        self.create_group_internal(group_name, members)?;
+        let (Some(parent), Some(group)) = (parent, self.handle_of(group_name)) else {
+            return Ok(());
+        };
+        if let Some(entity) = self.entity_at_mut(group) {
+            entity.parent = Some(parent);
+            entity.metadata.z_index = z_index;
+        }
+        if let Some(parent_entity) = self.entity_at_mut(parent) {
+            parent_entity.children.push(group);
+        }
+        self.normalize_scope_z_indices(Some(parent));
+        self.normalize_scope_z_indices(None);
+        Ok(())
+    }
+
+    fn finish_array(
+        &mut self,
+        source: EntityHandle,
+        names: Vec<String>,
+        group_name: Option<&str>,
+    ) -> Result<Vec<String>, SceneError> {
+        if let Some(group_name) = group_name {
+            self.wrap_array_group(group_name, source, names.clone())?;
+        }
+        Ok(names)
+    }
+
+    /// 격자 배열 복사 (내부용)
+    ///
+    /// (row, col) 자리의 복사본은 원본에서 월드 (col·dx, row·dy)만큼 이동합니다.
+    ///
+    /// # Arguments
+    /// * `rows`, `cols` - 행/열 수 (1 이상, 원본 포함)
+    /// * `dx`, `dy` - 열/행 간격 (월드 좌표, 음수 가능)
+    /// * `group_name` - 지정하면 원본과 복사본을 이 이름의 그룹으로 묶음
+    ///
+    /// # Returns
+    /// 원본 포함 배치 이름 (행 우선: `name`, `name_0_1`, ..., `name_1_0`, ...)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 0 행/열, NaN/Infinity, 개수 초과, 이름 중복
+    pub(crate) fn array_grid_internal(
+        &mut self,
+        name: &str,
+        rows: u32,
+        cols: u32,
+        dx: f64,
+        dy: f64,
+        group_name: Option<&str>,
+    ) -> Result<Vec<String>, SceneError> {
+        if rows == 0 || cols == 0 {
+            return Err(SceneError::InvalidInput(
+                "[array_grid] invalid_input: rows and cols must be at least 1".to_string(),
+            ));
+        }
+        if !dx.is_finite() || !dy.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[array_grid] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        check_array_count("array_grid", rows as u64 * cols as u64)?;
+        let cells: Vec<(u32, u32)> = (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .skip(1)
+            .collect();
+        let suffixes: Vec<String> = cells.iter().map(|(r, c)| format!("_{}_{}", r, c)).collect();
+        let source = self.array_source("array_grid", name, &suffixes, group_name)?;
+
+        let copies = self.array_copies(source, &suffixes);
+        for (copy, (r, c)) in copies.iter().zip(&cells) {
+            let _ = self.translate_world(copy, *c as f64 * dx, *r as f64 * dy);
+        }
+
+        let mut names = vec![name.to_string()];
+        names.extend(copies);
+        let names = self.finish_array(source, names, group_name)?;
+        self.last_operation = Some(format!("array_grid({}, {}x{})", name, rows, cols));
+        Ok(names)
+    }
+
+    /// 직선 배열 복사 (내부용)
+    ///
+    /// i번 복사본은 원본에서 월드 (i·dx, i·dy)만큼 이동합니다.
+    ///
+    /// # Returns
+    /// 원본 포함 배치 이름 (`name`, `name_1`, `name_2`, ...)
+    pub(crate) fn array_linear_internal(
+        &mut self,
+        name: &str,
+        count: u32,
+        dx: f64,
+        dy: f64,
+        group_name: Option<&str>,
+    ) -> Result<Vec<String>, SceneError> {
+        if count == 0 {
+            return Err(SceneError::InvalidInput(
+                "[array_linear] invalid_input: count must be at least 1".to_string(),
+            ));
+        }
+        if !dx.is_finite() || !dy.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[array_linear] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        check_array_count("array_linear", count as u64)?;
+        let suffixes: Vec<String> = (1..count).map(|i| format!("_{}", i)).collect();
+        let source = self.array_source("array_linear", name, &suffixes, group_name)?;
+
+        let copies = self.array_copies(source, &suffixes);
+        for (i, copy) in copies.iter().enumerate() {
+            let step = (i + 1) as f64;
+            let _ = self.translate_world(copy, step * dx, step * dy);
+        }
+
+        let mut names = vec![name.to_string()];
+        names.extend(copies);
+        let names = self.finish_array(source, names, group_name)?;
+        self.last_operation = Some(format!("array_linear({}, {})", name, count));
+        Ok(names)
+    }
+
+    /// 원형 배열 복사 (내부용)
+    ///
+    /// 전체 원(±2π)이면 count등분, 아니면 양 끝을 포함해 (count - 1)등분합니다.
+    /// - `rotate_copies` true: 각 복사본을 중심 기준으로 회전 (볼트 구멍, 톱니 등)
+    /// - false: 월드 바운드 중심만 원을 따라 이동 (방향 유지)
+    ///
+    /// # Arguments
+    /// * `count` - 원본 포함 개수 (1 이상)
+    /// * `cx`, `cy` - 중심 (월드 좌표)
+    /// * `sweep` - 전체 각도 (라디안, 양수 = 반시계방향)
+    ///
+    /// # Returns
+    /// 원본 포함 배치 이름 (`name`, `name_1`, `name_2`, ...)
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn array_polar_internal(
+        &mut self,
+        name: &str,
+        count: u32,
+        cx: f64,
+        cy: f64,
+        sweep: f64,
+        rotate_copies: bool,
+        group_name: Option<&str>,
+    ) -> Result<Vec<String>, SceneError> {
+        if count == 0 {
+            return Err(SceneError::InvalidInput(
+                "[array_polar] invalid_input: count must be at least 1".to_string(),
+            ));
+        }
+        if !cx.is_finite() || !cy.is_finite() || !sweep.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[array_polar] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        check_array_count("array_polar", count as u64)?;
+        let suffixes: Vec<String> = (1..count).map(|i| format!("_{}", i)).collect();
+        let source = self.array_source("array_polar", name, &suffixes, group_name)?;
+        let bounds = self.get_world_bounds_internal(name);
+        if !rotate_copies && bounds.is_none() && count > 1 {
+            return Err(SceneError::InvalidInput(format!(
+                "[array_polar] invalid_input: '{}' has no bounds",
+                name
+            )));
+        }
+
+        let full_turn = (sweep.abs() - 2.0 * PI).abs() < FULL_TURN_EPSILON;
+        let divisions = if full_turn { count } else { (count - 1).max(1) };
+        let step = sweep / divisions as f64;
+        let center = [cx, cy];
+
+        let copies = self.array_copies(source, &suffixes);
+        for (i, copy) in copies.iter().enumerate() {
+            let angle = step * (i + 1) as f64;
+            if rotate_copies {
+                self.rotate_about_world_point(copy, center, angle);
+            } else if let Some((min, max)) = bounds {
+                let from = [(min[0] + max[0]) / 2.0 - cx, (min[1] + max[1]) / 2.0 - cy];
+                let (sin, cos) = angle.sin_cos();
+                let to = [cos * from[0] - sin * from[1], sin * from[0] + cos * from[1]];
+                let _ = self.translate_world(copy, to[0] - from[0], to[1] - from[1]);
+            }
+        }
+
+        let mut names = vec![name.to_string()];
+        names.extend(copies);
+        let names = self.finish_array(source, names, group_name)?;
+        self.last_operation = Some(format!(
+            "array_polar({}, {}, {:.1}°)",
+            name,
+            count,
+            sweep.to_degrees()
+        ));
+        Ok(names)
+    }
+}
+
+/// "" → 그룹으로 묶지 않음
+fn optional_group(group_name: &str) -> Option<&str> {
+    (!group_name.is_empty()).then_some(group_name)
+}
+
+fn names_to_json(result: Result<Vec<String>, SceneError>) -> Result<String, JsValue> {
+    let names = result.map_err(|e| JsValue::from_str(&e.to_string()))?;
+    serde_json::to_string(&names).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+// ========================================
+// WASM Bindings for Array Copies
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// Entity/Group을 격자(행×열)로 복사합니다.
+    ///
+    /// 원본이 (0, 0) 자리이고, (row, col) 복사본은 `{name}_{row}_{col}` 이름으로 월드 (col·dx, row·dy)에 놓입니다.
+    /// 그룹을 복사하면 자식도 같은 접미사로 복사됩니다 (예: `bolt_head_0_3`).
+    ///
+    /// # Arguments
+    /// * `name` - 원본 Entity/Group 이름
+    /// * `rows`, `cols` - 행/열 수 (1 이상, 원본 포함)
+    /// * `dx`, `dy` - 열/행 간격 (월드 좌표)
+    /// * `group_name` - 원본과 복사본을 묶을 새 그룹 이름 ("" = 묶지 않음)
+    ///
+    /// # Returns
+    /// 원본 포함 이름 JSON 배열 (행 우선): `["bolt", "bolt_0_1", ..., "bolt_1_0", ...]`
+    ///
+    /// # Errors
+    /// * Entity 미발견, 0 행/열, NaN/Infinity, 개수 초과, 이름 중복
+    pub fn array_grid(
+        &mut self,
+        name: &str,
+        rows: u32,
+        cols: u32,
+        dx: f64,
+        dy: f64,
+        group_name: &str,
+    ) -> Result<String, JsValue> {
+        names_to_json(self.array_grid_internal(
+            name,
+            rows,
+            cols,
+            dx,
+            dy,
+            optional_group(group_name),
+        ))
+    }
+
+    /// Entity/Group을 한 방향으로 일정 간격 복사합니다.
+    ///
+    /// # Arguments
+    /// * `count` - 원본 포함 개수
+    /// * `dx`, `dy` - 복사본 사이 간격 (월드 좌표)
+    /// * `group_name` - 원본과 복사본을 묶을 새 그룹 이름 ("" = 묶지 않음)
+    ///
+    /// # Returns
+    /// 원본 포함 이름 JSON 배열: `["post", "post_1", "post_2"]`
+    pub fn array_linear(
+        &mut self,
+        name: &str,
+        count: u32,
+        dx: f64,
+        dy: f64,
+        group_name: &str,
+    ) -> Result<String, JsValue> {
+        names_to_json(self.array_linear_internal(name, count, dx, dy, optional_group(group_name)))
+    }
+
+    /// Entity/Group을 중심점 주위로 원형 복사합니다.
+    ///
+    /// 전체 원(2π)이면 count등분, 부분 각도면 시작과 끝을 포함해 배치합니다.
+    ///
+    /// # Arguments
+    /// * `count` - 원본 포함 개수
+    /// * `cx`, `cy` - 회전 중심 (월드 좌표)
+    /// * `sweep` - 전체 각도 (라디안, 양수 = 반시계방향, 2π = 한 바퀴)
+    /// * `rotate_copies` - true면 복사본도 회전, false면 방향 유지한 채 위치만 이동
+    /// * `group_name` - 원본과 복사본을 묶을 새 그룹 이름 ("" = 묶지 않음)
+    ///
+    /// # Returns
+    /// 원본 포함 이름 JSON 배열: `["bolt", "bolt_1", ..., "bolt_5"]`
+    #[allow(clippy::too_many_arguments)]
+    pub fn array_polar(
+        &mut self,
+        name: &str,
+        count: u32,
+        cx: f64,
+        cy: f64,
+        sweep: f64,
+        rotate_copies: bool,
+        group_name: &str,
+    ) -> Result<String, JsValue> {
+        names_to_json(self.array_polar_internal(
+            name,
+            count,
+            cx,
+            cy,
+            sweep,
+            rotate_copies,
+            optional_group(group_name),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn center(scene: &Scene, name: &str) -> [f64; 2] {
+        let (min, max) = scene.get_world_bounds_internal(name).unwrap();
+        [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]
+    }
+
+    fn z(scene: &Scene, name: &str) -> i32 {
+        scene.find_by_name(name).unwrap().metadata.z_index
+    }
+
+    #[test]
+    fn test_array_grid_names_positions_and_z() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("bolt", 0.0, 0.0, 1.0).unwrap();
+        scene.add_rect_internal("top", 0.0, 0.0, 1.0, 1.0).unwrap();
+
+        let names = scene
+            .array_grid_internal("bolt", 2, 3, 10.0, -5.0, None)
+            .unwrap();
+        assert_eq!(
+            names,
+            vec![
+                "bolt", "bolt_0_1", "bolt_0_2", "bolt_1_0", "bolt_1_1", "bolt_1_2"
+            ]
+        );
+        assert_eq!(center(&scene, "bolt_1_2"), [20.0, -5.0]);
+        assert_eq!(center(&scene, "bolt_0_1"), [10.0, 0.0]);
+        assert_ne!(
+            scene.find_by_name("bolt_0_1").unwrap().id,
+            scene.find_by_name("bolt").unwrap().id
+        );
+
+        // 복사본은 원본 바로 위, 기존 위쪽 Entity는 맨 위 유지
+        assert_eq!(z(&scene, "bolt"), 0);
+        assert_eq!(z(&scene, "bolt_0_1"), 1);
+        assert_eq!(z(&scene, "bolt_1_2"), 5);
+        assert_eq!(z(&scene, "top"), 6);
+    }
+
+    #[test]
+    fn test_array_group_subtree_inside_parent() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("head", 0.0, 0.0, 1.0).unwrap();
+        scene
+            .add_rect_internal("shaft", 0.0, -2.0, 0.5, 3.0)
+            .unwrap();
+        scene
+            .create_group_internal("bolt", vec!["head".into(), "shaft".into()])
+            .unwrap();
+        scene
+            .create_group_internal("plate", vec!["bolt".into()])
+            .unwrap();
+        scene.scale("plate", 2.0, 2.0).unwrap();
+
+        let names = scene
+            .array_linear_internal("bolt", 3, 10.0, 0.0, Some("bolts"))
+            .unwrap();
+        assert_eq!(names, vec!["bolt", "bolt_1", "bolt_2"]);
+
+        // 자식도 같은 접미사로 복사, 월드 간격은 부모 스케일과 무관
+        let head_2 = scene.find_by_name("head_2").unwrap();
+        assert_eq!(scene.parent_name(head_2), Some("bolt_2"));
+        assert!(approx_eq(center(&scene, "head_2")[0], 20.0));
+        assert!(approx_eq(center(&scene, "shaft_1")[1], -4.0));
+
+        // 그룹은 원래 부모 안에 생성, 월드 위치 유지
+        let group = scene.find_by_name("bolts").unwrap();
+        assert_eq!(scene.parent_name(group), Some("plate"));
+        assert_eq!(scene.child_names(group), vec!["bolt", "bolt_1", "bolt_2"]);
+        assert!(approx_eq(center(&scene, "head_1")[0], 10.0));
+        assert!(approx_eq(center(&scene, "head")[0], 0.0));
+    }
+
+    #[test]
+    fn test_array_polar_full_and_partial_sweep() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("tooth", 10.0, 0.0, 2.0, 1.0)
+            .unwrap();
+
+        let names = scene
+            .array_polar_internal("tooth", 4, 0.0, 0.0, 2.0 * PI, true, None)
+            .unwrap();
+        assert_eq!(names.len(), 4);
+        // 90°마다: 회전된 복사본은 가로/세로가 바뀜
+        let c = center(&scene, "tooth_1");
+        assert!(approx_eq(c[0], 0.0) && approx_eq(c[1], 10.0));
+        let (min, max) = scene.get_world_bounds_internal("tooth_1").unwrap();
+        assert!(approx_eq(max[0] - min[0], 1.0) && approx_eq(max[1] - min[1], 2.0));
+        let c = center(&scene, "tooth_3");
+        assert!(approx_eq(c[0], 0.0) && approx_eq(c[1], -10.0));
+
+        // 부분 각도: 끝점 포함, 방향 유지
+        scene.add_rect_internal("mark", 5.0, 0.0, 2.0, 1.0).unwrap();
+        scene
+            .array_polar_internal("mark", 3, 0.0, 0.0, PI, false, None)
+            .unwrap();
+        let c = center(&scene, "mark_2");
+        assert!(approx_eq(c[0], -5.0) && approx_eq(c[1], 0.0));
+        let (min, max) = scene.get_world_bounds_internal("mark_1").unwrap();
+        assert!(approx_eq(max[0] - min[0], 2.0));
+        assert!(approx_eq(center(&scene, "mark_1")[1], 5.0));
+    }
+
+    #[test]
+    fn test_array_polar_in_rotated_group() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("tooth", 10.0, 0.0, 2.0, 1.0)
+            .unwrap();
+        scene
+            .create_group_internal("gear", vec!["tooth".into()])
+            .unwrap();
+        scene.translate("gear", 3.0, 4.0).unwrap();
+        scene.rotate("gear", 0.3).unwrap();
+
+        let start = center(&scene, "tooth");
+        scene
+            .array_polar_internal("tooth", 2, 1.0, 1.0, PI / 2.0, true, None)
+            .unwrap();
+        let rotated = center(&scene, "tooth_1");
+        let expected = [1.0 - (start[1] - 1.0), 1.0 + (start[0] - 1.0)];
+        assert!(approx_eq(rotated[0], expected[0]) && approx_eq(rotated[1], expected[1]));
+    }
+
+    #[test]
+    fn test_array_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("bolt", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("bolt_2", 9.0, 0.0, 1.0).unwrap();
+
+        assert!(matches!(
+            scene.array_linear_internal("missing", 2, 1.0, 0.0, None),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(matches!(
+            scene.array_grid_internal("bolt", 0, 2, 1.0, 1.0, None),
+            Err(SceneError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            scene.array_grid_internal("bolt", 200, 200, 1.0, 1.0, None),
+            Err(SceneError::InvalidInput(_))
+        ));
+        // 이름 충돌 시 아무것도 만들지 않음
+        assert_eq!(
+            scene.array_linear_internal("bolt", 3, 1.0, 0.0, None),
+            Err(SceneError::DuplicateEntityName(
+                "array_linear".to_string(),
+                "bolt_2".to_string()
+            ))
+        );
+        assert!(matches!(
+            scene.array_linear_internal("bolt", 2, 1.0, 0.0, Some("bolt_1")),
+            Err(SceneError::DuplicateEntityName(..))
+        ));
+        assert_eq!(scene.entity_count(), 2);
+    }
+}
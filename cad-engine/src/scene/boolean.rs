@@ -0,0 +1,422 @@
+//! Boolean 연산 모듈
+//!
+//! 여러 Entity의 월드 좌표 외곽선에 union / intersection / difference / xor를 적용하여
+//! 새 Polygon(holes 포함)을 생성합니다.
+//! - Circle, Rect, Arc(부채꼴), 닫힌 Bezier는 `curve_segments`로 근사 후 연산
+//! - Group은 하위 도형 전체의 합집합으로 취급
+//! - 결과가 여러 조각이면 `{name}_0`, `{name}_1`, ... Polygon을 `name` 그룹으로 묶음
+//!
+//! 설계: docs/rfc/boolean-operations.md
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Geometry, Style, Transform};
+use super::primitives::parse_style_with_warning;
+use super::{Scene, SceneError};
+use crate::geometry::boolean::{BooleanOp, boolean_fold};
+use crate::geometry::flatten::{DEFAULT_CURVE_SEGMENTS, closed_rings};
+use crate::geometry::polygon::{PolygonWithHoles, Region};
+
+impl Scene {
+    /// Entity의 월드 좌표 영역을 수집합니다 (Group은 하위 도형 전체).
+    ///
+    /// # Errors
+    /// * Entity 미발견
+    /// * 열린 도형(열린 Line/Bezier)처럼 닫힌 영역이 없는 경우
+    pub(crate) fn world_regions(
+        &self,
+        fn_name: &str,
+        name: &str,
+        curve_segments: usize,
+    ) -> Result<Vec<Region>, SceneError> {
+        let entity = self
+            .find_by_name(name)
+            .ok_or_else(|| SceneError::EntityNotFound(fn_name.to_string(), name.to_string()))?;
+
+        if matches!(entity.entity_type, EntityType::Group) {
+            let mut regions = Vec::new();
+            for child in self.children_of(entity) {
+                regions.extend(self.world_regions(
+                    fn_name,
+                    &child.metadata.name,
+                    curve_segments,
+                )?);
+            }
+            return Ok(regions);
+        }
+
+        let rings = closed_rings(&entity.geometry, curve_segments).ok_or_else(|| {
+            SceneError::InvalidInput(format!(
+                "[{}] invalid_input: Entity '{}' ({}) is not a closed shape",
+                fn_name,
+                name,
+                entity.entity_type.as_str()
+            ))
+        })?;
+
+        let world_matrix = self
+            .get_world_transform_internal(name)
+            .unwrap_or_else(Transform::identity_matrix);
+        let rings = rings
+            .into_iter()
+            .map(|ring| {
+                ring.into_iter()
+                    .map(|p| Transform::transform_point(&world_matrix, p))
+                    .collect()
+            })
+            .collect();
+
+        Ok(vec![Region { rings }])
+    }
+
+    /// 연산 결과 폴리곤들을 Entity로 추가합니다.
+    ///
+    /// 폴리곤이 하나면 `name` Polygon, 여러 개면 `{name}_{i}` Polygon들을 `name` 그룹으로 묶습니다.
+    /// 모든 이름의 중복 여부를 먼저 검사하므로 실패 시 Scene은 변경되지 않습니다.
+    pub(crate) fn add_polygon_results(
+        &mut self,
+        fn_name: &str,
+        name: &str,
+        polygons: Vec<PolygonWithHoles>,
+        style: &Style,
+    ) -> Result<String, SceneError> {
+        let names: Vec<String> = if polygons.len() == 1 {
+            vec![name.to_string()]
+        } else {
+            (0..polygons.len())
+                .map(|i| format!("{}_{}", name, i))
+                .collect()
+        };
+
+        for candidate in names.iter().chain(std::iter::once(&name.to_string())) {
+            if self.has_entity(candidate) {
+                return Err(SceneError::DuplicateEntityName(
+                    fn_name.to_string(),
+                    candidate.clone(),
+                ));
+            }
+        }
+
+        for (polygon, entity_name) in polygons.into_iter().zip(&names) {
+            self.add_entity_internal(
+                fn_name,
+                entity_name,
+                EntityType::Polygon,
+                Geometry::Polygon {
+                    points: polygon.outer,
+                    holes: polygon.holes,
+                },
+            )?;
+            if let Some(entity) = self.find_by_name_mut(entity_name) {
+                entity.style = style.clone();
+            }
+        }
+
+        if names.len() > 1 {
+            self.create_group_internal(name, names)?;
+        }
+        Ok(name.to_string())
+    }
+
+    /// 내부용 Boolean 연산 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - 결과 Entity 이름
+    /// * `op` - "union" | "intersection" | "difference" | "xor"
+    /// * `sources` - 피연산자 Entity 이름들 (2개 이상, 왼쪽부터 누적 연산)
+    /// * `curve_segments` - 원 전체 근사 세그먼트 수 (0 = 기본값 64)
+    /// * `style` - 결과 스타일 (None이면 첫 번째 피연산자의 스타일 상속)
+    pub(crate) fn boolean_op_internal(
+        &mut self,
+        name: &str,
+        op: &str,
+        sources: Vec<String>,
+        curve_segments: usize,
+        style: Option<Style>,
+    ) -> Result<String, SceneError> {
+        let op_kind = BooleanOp::parse_str(op).ok_or_else(|| {
+            SceneError::InvalidInput(format!(
+                "[boolean_op] invalid_input: Unknown operation '{}' (use union, intersection, difference, xor)",
+                op
+            ))
+        })?;
+
+        if sources.len() < 2 {
+            return Err(SceneError::InvalidInput(
+                "[boolean_op] invalid_input: At least 2 entities required".to_string(),
+            ));
+        }
+
+        if self.has_entity(name) {
+            return Err(SceneError::DuplicateEntityName(
+                "boolean_op".to_string(),
+                name.to_string(),
+            ));
+        }
+
+        let curve_segments = if curve_segments == 0 {
+            DEFAULT_CURVE_SEGMENTS
+        } else {
+            curve_segments.max(3)
+        };
+
+        let shapes = sources
+            .iter()
+            .map(|source| self.world_regions("boolean_op", source, curve_segments))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let polygons = boolean_fold(&shapes, op_kind);
+        if polygons.is_empty() {
+            return Err(SceneError::InvalidOperation(format!(
+                "boolean_op '{}' of {:?} produced an empty result",
+                op_kind.as_str(),
+                sources
+            )));
+        }
+
+        let style = style.unwrap_or_else(|| {
+            self.find_by_name(&sources[0])
+                .filter(|e| !matches!(e.entity_type, EntityType::Group))
+                .map(|e| e.style.clone())
+                .unwrap_or_default()
+        });
+
+        self.add_polygon_results("boolean_op", name, polygons, &style)?;
+        self.last_operation = Some(format!(
+            "boolean_op({}, {}, {})",
+            name,
+            op_kind.as_str(),
+            sources.join(", ")
+        ));
+        Ok(name.to_string())
+    }
+}
+
+// ========================================
+// WASM Bindings for Boolean Operations
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 여러 도형의 월드 좌표 외곽선에 Boolean 연산을 적용하여 새 Polygon을 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - 결과 Entity 이름 - Scene 내 unique
+    /// * `op` - "union" | "intersection" | "difference" | "xor"
+    /// * `names_json` - 피연산자 이름들의 JSON 배열 (예: '["plate", "hole1", "hole2"]')
+    ///   - 2개 이상, 왼쪽부터 누적 연산 (difference = 첫 도형 - 나머지 전부)
+    /// * `curve_segments` - 원 전체 근사 세그먼트 수 (0 = 기본값 64)
+    /// * `style_json` - 결과 스타일 JSON (빈 문자열이면 첫 번째 피연산자 스타일 상속)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///   - 결과가 여러 조각이면 `{name}_0`, `{name}_1`, ... 를 자식으로 갖는 Group `name`
+    ///
+    /// # Errors
+    /// * name 중복, 알 수 없는 op, 2개 미만의 피연산자
+    /// * 피연산자 미발견 또는 열린 도형(열린 Line/Bezier)
+    /// * 결과가 비어 있음 (예: 겹치지 않는 도형의 intersection)
+    ///
+    /// # Notes
+    /// 결과는 월드 좌표로 저장되며 원본 도형은 그대로 유지됩니다.
+    pub fn boolean_op(
+        &mut self,
+        name: &str,
+        op: &str,
+        names_json: &str,
+        curve_segments: u32,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        let sources: Vec<String> = serde_json::from_str(names_json)
+            .map_err(|e| JsValue::from_str(&format!("[boolean_op] invalid_json: {}", e)))?;
+
+        let style = if style_json.is_empty() {
+            None
+        } else {
+            Some(parse_style_with_warning(style_json, "boolean_op"))
+        };
+
+        self.boolean_op_internal(name, op, sources, curve_segments as usize, style)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::polygon::signed_area;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn polygon_area(scene: &Scene, name: &str) -> f64 {
+        match &scene.find_by_name(name).unwrap().geometry {
+            Geometry::Polygon { points, holes } => {
+                signed_area(points).abs() - holes.iter().map(|h| signed_area(h).abs()).sum::<f64>()
+            }
+            other => panic!("Expected Polygon geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_boolean_union_rects() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 5.0, 5.0, 10.0, 10.0).unwrap();
+        scene
+            .add_rect_internal("b", 10.0, 10.0, 10.0, 10.0)
+            .unwrap();
+
+        let result = scene.boolean_op_internal("u", "union", names(&["a", "b"]), 0, None);
+        assert_eq!(result.unwrap(), "u");
+
+        let entity = scene.find_by_name("u").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Polygon));
+        assert!(approx_eq(polygon_area(&scene, "u"), 175.0, 1e-9));
+        // 원본은 유지
+        assert!(scene.has_entity("a") && scene.has_entity("b"));
+        assert_eq!(
+            scene.last_operation.as_deref(),
+            Some("boolean_op(u, union, a, b)")
+        );
+    }
+
+    #[test]
+    fn test_boolean_difference_circle_hole_in_rect() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("plate", 0.0, 0.0, 100.0, 100.0)
+            .unwrap();
+        scene.add_circle_internal("hole", 0.0, 0.0, 10.0).unwrap();
+
+        scene
+            .boolean_op_internal("cut", "difference", names(&["plate", "hole"]), 128, None)
+            .unwrap();
+
+        match &scene.find_by_name("cut").unwrap().geometry {
+            Geometry::Polygon { points, holes } => {
+                assert_eq!(points.len(), 4);
+                assert_eq!(holes.len(), 1);
+                assert_eq!(holes[0].len(), 128);
+            }
+            _ => panic!("Expected Polygon geometry"),
+        }
+        let expected = 10000.0 - 128.0 / 2.0 * 100.0 * (2.0 * std::f64::consts::PI / 128.0).sin();
+        assert!(approx_eq(polygon_area(&scene, "cut"), expected, 1e-6));
+    }
+
+    #[test]
+    fn test_boolean_uses_world_transform() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.add_rect_internal("b", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.create_group_internal("g", names(&["b"])).unwrap();
+        scene.find_by_name_mut("g").unwrap().transform.translate = [5.0, 0.0];
+
+        scene
+            .boolean_op_internal("i", "intersection", names(&["a", "b"]), 0, None)
+            .unwrap();
+        assert!(approx_eq(polygon_area(&scene, "i"), 50.0, 1e-9));
+    }
+
+    #[test]
+    fn test_boolean_group_operand_is_union_of_children() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("base", 0.0, 0.0, 30.0, 10.0)
+            .unwrap();
+        scene.add_rect_internal("h1", -10.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("h2", 10.0, 0.0, 2.0, 2.0).unwrap();
+        scene
+            .create_group_internal("holes", names(&["h1", "h2"]))
+            .unwrap();
+
+        scene
+            .boolean_op_internal("plate", "difference", names(&["base", "holes"]), 0, None)
+            .unwrap();
+        match &scene.find_by_name("plate").unwrap().geometry {
+            Geometry::Polygon { holes, .. } => assert_eq!(holes.len(), 2),
+            _ => panic!("Expected Polygon geometry"),
+        }
+    }
+
+    #[test]
+    fn test_boolean_multiple_pieces_grouped() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.add_rect_internal("b", 5.0, 5.0, 10.0, 10.0).unwrap();
+
+        scene
+            .boolean_op_internal("x", "xor", names(&["a", "b"]), 0, None)
+            .unwrap();
+
+        let group = scene.find_by_name("x").unwrap();
+        assert!(matches!(group.entity_type, EntityType::Group));
+        assert_eq!(scene.child_names(group), ["x_0", "x_1"]);
+        assert!(approx_eq(polygon_area(&scene, "x_0"), 75.0, 1e-9));
+        assert!(approx_eq(polygon_area(&scene, "x_1"), 75.0, 1e-9));
+    }
+
+    #[test]
+    fn test_boolean_inherits_first_style() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.add_rect_internal("b", 5.0, 0.0, 10.0, 10.0).unwrap();
+        scene.find_by_name_mut("a").unwrap().style.fill = Some(Default::default());
+
+        scene
+            .boolean_op_internal("u", "union", names(&["a", "b"]), 0, None)
+            .unwrap();
+        assert!(scene.find_by_name("u").unwrap().style.fill.is_some());
+    }
+
+    #[test]
+    fn test_boolean_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene
+            .add_rect_internal("far", 100.0, 0.0, 10.0, 10.0)
+            .unwrap();
+        scene
+            .add_line_internal("open", vec![0.0, 0.0, 10.0, 10.0])
+            .unwrap();
+
+        let err = scene
+            .boolean_op_internal("r", "merge", names(&["a", "far"]), 0, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown operation"));
+
+        let err = scene
+            .boolean_op_internal("r", "union", names(&["a"]), 0, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("At least 2"));
+
+        let err = scene
+            .boolean_op_internal("r", "union", names(&["a", "missing"]), 0, None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "[boolean_op] not_found: Entity 'missing' not found"
+        );
+
+        let err = scene
+            .boolean_op_internal("r", "union", names(&["a", "open"]), 0, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("not a closed shape"));
+
+        let err = scene
+            .boolean_op_internal("a", "union", names(&["a", "far"]), 0, None)
+            .unwrap_err();
+        assert!(matches!(err, SceneError::DuplicateEntityName(_, _)));
+
+        let err = scene
+            .boolean_op_internal("r", "intersection", names(&["a", "far"]), 0, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("empty result"));
+        assert!(!scene.has_entity("r"));
+    }
+}
@@ -0,0 +1,1421 @@
+//! 기하 제약 조건 모듈
+//!
+//! 도형 사이의 제약(일치, 수평/수직, 평행, 직교, 접선, 같은 길이/반지름, 거리, 각도, 고정)을
+//! 이름으로 저장하고, solve_constraints 호출 시 모두 만족하도록 도형 geometry를 조정합니다.
+//! - 참조는 치수와 같은 방식: Entity 이름 + 기준점(start/end/center/vertex:N) 또는 변 번호
+//! - 해석 변수: 참조된 도형의 로컬 geometry (Line/Polygon 점, Circle/Arc 중심·반지름, Rect 중심·크기)
+//!   부모 그룹을 포함한 월드 변환은 고정하고, 제약은 월드 좌표에서 평가
+//! - translate/scale 이나 geometry 편집 후 편집한 도형을 fixed로 두고 다시 풀면 나머지가 따라옴
+//! - 참조 도형이 삭제되거나 기준점이 사라진 제약은 해석에서 제외하고 상태에 사유를 표시
+//! - 수치 해석: geometry/constraint.rs
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use super::entity::{
+    Constraint, ConstraintKind, ConstraintLine, DimensionPoint, EntityType, Geometry, Matrix3x3,
+    PointAnchor, Transform,
+};
+use super::{Scene, SceneError};
+use crate::geometry::constraint::{
+    independent_rows, numeric_jacobian, solve_least_squares, wrap_angle,
+};
+
+/// 수렴 허용 오차 (모델 크기 대비)
+const SOLVE_TOLERANCE: f64 = 1e-10;
+/// 제약을 만족한 것으로 볼 잔차 (모델 크기 대비)
+const SATISFIED_TOLERANCE: f64 = 1e-6;
+
+/// 제약 대상 입력: 이름 문자열 또는 `{"entity", "anchor"}` / `{"entity", "edge"}`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TargetInput {
+    Name(String),
+    Detailed {
+        entity: String,
+        #[serde(default)]
+        anchor: Option<String>,
+        #[serde(default)]
+        edge: Option<usize>,
+    },
+}
+
+impl TargetInput {
+    fn entity(&self) -> &str {
+        match self {
+            TargetInput::Name(name) => name,
+            TargetInput::Detailed { entity, .. } => entity,
+        }
+    }
+
+    /// 기준점이나 변을 지정하지 않은 이름만의 참조인지
+    fn is_bare(&self) -> bool {
+        matches!(
+            self,
+            TargetInput::Name(_)
+                | TargetInput::Detailed {
+                    anchor: None,
+                    edge: None,
+                    ..
+                }
+        )
+    }
+}
+
+/// 제약별 상태
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct ConstraintReport {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// 현재 도형이 제약을 만족하는지
+    pub satisfied: bool,
+    /// 잔차 최대 절댓값 (길이 또는 라디안, 해석 불가면 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub residual: Option<f64>,
+    /// 앞선 제약들로 이미 결정되는 제약 (과잉 구속)
+    pub redundant: bool,
+    /// 다른 제약과 동시에 만족할 수 없는 제약
+    pub conflicting: bool,
+    /// 참조를 해석할 수 없는 사유 (삭제된 도형 등)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 제약 시스템 전체 상태
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct ConstraintStatus {
+    /// "well_constrained" | "under_constrained" | "over_constrained" | "conflicting" | "not_converged"
+    pub status: &'static str,
+    /// 남은 자유도 (자유 변수 수 - 독립 방정식 수)
+    pub dof: usize,
+    /// 자유 변수 수 (fixed 도형 제외)
+    pub variables: usize,
+    /// 방정식 수 (Coincident/Fix는 2개)
+    pub equations: usize,
+    pub constraints: Vec<ConstraintReport>,
+}
+
+/// 해석 변수를 가진 도형 (로컬 geometry + 고정된 월드 변환)
+struct Body {
+    name: String,
+    geometry: Geometry,
+    world: Matrix3x3,
+    /// 반지름 월드 배율 (비균등 스케일은 √|det| 근사, 치수와 동일)
+    scale: f64,
+    offset: usize,
+}
+
+/// 로컬 geometry 해석 변수 (제약을 걸 수 없는 도형은 None)
+fn geometry_params(geometry: &Geometry) -> Option<Vec<f64>> {
+    match geometry {
+        Geometry::Line { points } | Geometry::Polygon { points, .. } => {
+            Some(points.iter().flat_map(|p| [p[0], p[1]]).collect())
+        }
+        Geometry::Circle { center, radius } | Geometry::Arc { center, radius, .. } => {
+            Some(vec![center[0], center[1], *radius])
+        }
+        Geometry::Rect {
+            center,
+            width,
+            height,
+        } => Some(vec![center[0], center[1], *width, *height]),
+        _ => None,
+    }
+}
+
+fn apply_params(geometry: &mut Geometry, params: &[f64]) {
+    match geometry {
+        Geometry::Line { points } | Geometry::Polygon { points, .. } => {
+            for (point, xy) in points.iter_mut().zip(params.chunks(2)) {
+                *point = [xy[0], xy[1]];
+            }
+        }
+        Geometry::Circle { center, radius } | Geometry::Arc { center, radius, .. } => {
+            *center = [params[0], params[1]];
+            *radius = params[2];
+        }
+        Geometry::Rect {
+            center,
+            width,
+            height,
+        } => {
+            *center = [params[0], params[1]];
+            *width = params[2];
+            *height = params[3];
+        }
+        _ => {}
+    }
+}
+
+/// 해석 결과를 저장할 때 크기 값 보정 (반지름/폭/높이는 양수)
+fn normalize_sizes(geometry: &mut Geometry) {
+    match geometry {
+        Geometry::Circle { radius, .. } | Geometry::Arc { radius, .. } => {
+            *radius = radius.abs().max(0.001);
+        }
+        Geometry::Rect { width, height, .. } => {
+            *width = width.abs().max(0.001);
+            *height = height.abs().max(0.001);
+        }
+        _ => {}
+    }
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn cross(u: [f64; 2], v: [f64; 2]) -> f64 {
+    u[0] * v[1] - u[1] * v[0]
+}
+
+fn dot(u: [f64; 2], v: [f64; 2]) -> f64 {
+    u[0] * v[0] + u[1] * v[1]
+}
+
+fn length(u: [f64; 2]) -> f64 {
+    u[0].hypot(u[1])
+}
+
+/// 두 방향 벡터 길이의 곱으로 나눈 값 (길이 0이면 나누지 않음)
+fn normalized(value: f64, u: [f64; 2], v: [f64; 2]) -> f64 {
+    let norm = length(u) * length(v);
+    if norm > f64::MIN_POSITIVE {
+        value / norm
+    } else {
+        value
+    }
+}
+
+/// 변수 값 x에서의 도형 상태
+struct Frame<'a> {
+    system: &'a ConstraintSystem,
+    geometries: Vec<Geometry>,
+}
+
+impl Frame<'_> {
+    fn point(&self, point: &DimensionPoint) -> Option<[f64; 2]> {
+        let i = *self.system.index.get(&point.entity)?;
+        let local = Scene::dimension_local_point(&self.geometries[i], point.anchor)?;
+        Some(Transform::transform_point(
+            &self.system.bodies[i].world,
+            local,
+        ))
+    }
+
+    /// 직선의 시작점과 방향 벡터
+    fn line(&self, line: &ConstraintLine) -> Option<([f64; 2], [f64; 2])> {
+        let from = self.point(&line.from)?;
+        let to = self.point(&line.to)?;
+        Some((from, sub(to, from)))
+    }
+
+    /// Circle/Arc의 월드 중심과 반지름
+    fn circle(&self, name: &str) -> Option<([f64; 2], f64)> {
+        let i = *self.system.index.get(name)?;
+        let body = &self.system.bodies[i];
+        match &self.geometries[i] {
+            Geometry::Circle { center, radius } | Geometry::Arc { center, radius, .. } => Some((
+                Transform::transform_point(&body.world, *center),
+                radius * body.scale,
+            )),
+            _ => None,
+        }
+    }
+
+    /// 제약의 잔차 (0이면 만족)
+    fn residuals(&self, kind: &ConstraintKind) -> Option<Vec<f64>> {
+        Some(match kind {
+            ConstraintKind::Coincident { a, b } => {
+                let d = sub(self.point(b)?, self.point(a)?);
+                vec![d[0], d[1]]
+            }
+            ConstraintKind::Horizontal { a, b } => vec![self.point(b)?[1] - self.point(a)?[1]],
+            ConstraintKind::Vertical { a, b } => vec![self.point(b)?[0] - self.point(a)?[0]],
+            ConstraintKind::Parallel { a, b } => {
+                let ((_, u), (_, v)) = (self.line(a)?, self.line(b)?);
+                vec![normalized(cross(u, v), u, v)]
+            }
+            ConstraintKind::Perpendicular { a, b } => {
+                let ((_, u), (_, v)) = (self.line(a)?, self.line(b)?);
+                vec![normalized(dot(u, v), u, v)]
+            }
+            ConstraintKind::TangentLine { line, circle } => {
+                let (origin, u) = self.line(line)?;
+                let (center, radius) = self.circle(circle)?;
+                let offset = cross(u, sub(center, origin)).abs();
+                let distance = if length(u) > f64::MIN_POSITIVE {
+                    offset / length(u)
+                } else {
+                    length(sub(center, origin))
+                };
+                vec![distance - radius]
+            }
+            ConstraintKind::TangentCircles { a, b, internal } => {
+                let (ca, ra) = self.circle(a)?;
+                let (cb, rb) = self.circle(b)?;
+                let target = if *internal { (ra - rb).abs() } else { ra + rb };
+                vec![length(sub(cb, ca)) - target]
+            }
+            ConstraintKind::EqualLength { a, b } => {
+                let ((_, u), (_, v)) = (self.line(a)?, self.line(b)?);
+                vec![length(u) - length(v)]
+            }
+            ConstraintKind::EqualRadius { a, b } => vec![self.circle(a)?.1 - self.circle(b)?.1],
+            ConstraintKind::Distance { a, b, value } => {
+                vec![length(sub(self.point(b)?, self.point(a)?)) - value]
+            }
+            ConstraintKind::Angle { a, b, value } => {
+                let ((_, u), (_, v)) = (self.line(a)?, self.line(b)?);
+                vec![wrap_angle(v[1].atan2(v[0]) - u[1].atan2(u[0]) - value)]
+            }
+            ConstraintKind::Fix { point, at } => {
+                let p = self.point(point)?;
+                vec![p[0] - at[0], p[1] - at[1]]
+            }
+        })
+    }
+
+    /// 제약이 의미를 잃은 상태인지 (방향을 정하는 직선의 길이 0, 반지름 0 이하)
+    ///
+    /// horizontal + vertical처럼 모순된 제약은 직선을 점으로 줄여 잔차를 0으로 만들 수 있으므로
+    /// 이런 해는 충돌로 봅니다.
+    fn degenerate(&self, kind: &ConstraintKind, tolerance: f64) -> bool {
+        let short = |a: &DimensionPoint, b: &DimensionPoint| match (self.point(a), self.point(b)) {
+            (Some(p), Some(q)) => length(sub(q, p)) <= tolerance,
+            _ => false,
+        };
+        let short_line = |line: &ConstraintLine| short(&line.from, &line.to);
+        let flat = |name: &str| self.circle(name).is_some_and(|(_, r)| r <= tolerance);
+        match kind {
+            ConstraintKind::Horizontal { a, b } | ConstraintKind::Vertical { a, b } => short(a, b),
+            ConstraintKind::Parallel { a, b }
+            | ConstraintKind::Perpendicular { a, b }
+            | ConstraintKind::Angle { a, b, .. } => short_line(a) || short_line(b),
+            ConstraintKind::TangentLine { line, circle } => short_line(line) || flat(circle),
+            ConstraintKind::TangentCircles { a, b, .. } | ConstraintKind::EqualRadius { a, b } => {
+                flat(a) || flat(b)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// 제약 전체를 변수/방정식으로 펼친 시스템
+struct ConstraintSystem {
+    bodies: Vec<Body>,
+    index: HashMap<String, usize>,
+    x0: Vec<f64>,
+    free: Vec<bool>,
+    /// 해석 대상 제약 (scene.constraints 위치, 잔차 행 범위)
+    active: Vec<(usize, Range<usize>)>,
+    /// 제약별 해석 불가 사유
+    errors: Vec<Option<String>>,
+    /// 모델 크기 (허용 오차 기준)
+    scale: f64,
+}
+
+impl ConstraintSystem {
+    fn build(scene: &Scene, constraints: &[Constraint], fixed: &[String]) -> Self {
+        let mut system = ConstraintSystem {
+            bodies: Vec::new(),
+            index: HashMap::new(),
+            x0: Vec::new(),
+            free: Vec::new(),
+            active: Vec::new(),
+            errors: Vec::new(),
+            scale: 1.0,
+        };
+
+        for constraint in constraints {
+            let error = constraint
+                .kind
+                .references()
+                .into_iter()
+                .find_map(|name| system.register(scene, name, fixed).err());
+            system.errors.push(error);
+        }
+
+        let frame = system.frame(&system.x0);
+        let mut rows = 0;
+        let mut active = Vec::new();
+        let mut unresolved = Vec::new();
+        for (i, constraint) in constraints.iter().enumerate() {
+            if system.errors[i].is_some() {
+                continue;
+            }
+            match frame.residuals(&constraint.kind) {
+                Some(residuals) => {
+                    active.push((i, rows..rows + residuals.len()));
+                    rows += residuals.len();
+                }
+                None => unresolved.push(i),
+            }
+        }
+        for i in unresolved {
+            system.errors[i] =
+                Some("reference point no longer exists on the referenced entity".to_string());
+        }
+        system.active = active;
+        system.scale = 1.0
+            + system
+                .bodies
+                .iter()
+                .flat_map(|b| [b.world[0][2], b.world[1][2]])
+                .chain(system.x0.iter().copied())
+                .fold(0.0_f64, |acc, v| acc.max(v.abs()));
+        system
+    }
+
+    /// 참조 도형을 변수 목록에 추가 (이미 있으면 무시)
+    fn register(&mut self, scene: &Scene, name: &str, fixed: &[String]) -> Result<(), String> {
+        if self.index.contains_key(name) {
+            return Ok(());
+        }
+        let entity = scene
+            .find_by_name(name)
+            .ok_or_else(|| format!("Entity '{}' not found", name))?;
+        let params = geometry_params(&entity.geometry).ok_or_else(|| {
+            format!(
+                "'{}' ({}) cannot be constrained",
+                name,
+                entity.entity_type.as_str()
+            )
+        })?;
+        let world = scene
+            .get_world_transform_internal(name)
+            .unwrap_or_else(Transform::identity_matrix);
+        let det = world[0][0] * world[1][1] - world[0][1] * world[1][0];
+        let frozen = scene
+            .collect_parent_chain(name)
+            .iter()
+            .any(|e| fixed.contains(&e.metadata.name));
+
+        self.index.insert(name.to_string(), self.bodies.len());
+        self.bodies.push(Body {
+            name: name.to_string(),
+            geometry: entity.geometry.clone(),
+            world,
+            scale: det.abs().sqrt(),
+            offset: self.x0.len(),
+        });
+        self.free.extend(std::iter::repeat_n(!frozen, params.len()));
+        self.x0.extend(params);
+        Ok(())
+    }
+
+    fn frame(&self, x: &[f64]) -> Frame<'_> {
+        let geometries = self
+            .bodies
+            .iter()
+            .enumerate()
+            .map(|(i, body)| {
+                let end = self.bodies.get(i + 1).map_or(x.len(), |next| next.offset);
+                let mut geometry = body.geometry.clone();
+                apply_params(&mut geometry, &x[body.offset..end]);
+                geometry
+            })
+            .collect();
+        Frame {
+            system: self,
+            geometries,
+        }
+    }
+
+    /// 해석 대상 제약의 잔차를 이어 붙인 벡터
+    fn residuals(&self, constraints: &[Constraint], x: &[f64]) -> Vec<f64> {
+        let frame = self.frame(x);
+        self.active
+            .iter()
+            .flat_map(|(i, rows)| {
+                frame
+                    .residuals(&constraints[*i].kind)
+                    .unwrap_or_else(|| vec![0.0; rows.len()])
+            })
+            .collect()
+    }
+}
+
+/// 잔차 행 범위의 최대 절댓값
+fn worst(residuals: &[f64], rows: &Range<usize>) -> f64 {
+    residuals[rows.clone()]
+        .iter()
+        .fold(0.0_f64, |acc, r| acc.max(r.abs()))
+}
+
+/// 제약 해석 결과: 상태와 (해가 있으면) 바뀐 geometry
+struct Analysis {
+    status: ConstraintStatus,
+    solution: Option<Vec<(String, Geometry)>>,
+}
+
+impl Scene {
+    /// 저장된 제약 목록 (직렬화용)
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    /// Entity 이름 변경 시 그 이름을 참조하는 제약들의 참조를 함께 바꿉니다.
+    pub(super) fn rename_constraint_references(&mut self, old_name: &str, new_name: &str) {
+        for constraint in &mut self.constraints {
+            for reference in constraint.kind.references_mut() {
+                if reference == old_name {
+                    *reference = new_name.to_string();
+                }
+            }
+        }
+    }
+
+    /// 제약 대상 도형 조회 (제약 가능한 타입인지 검증)
+    fn constraint_entity(&self, fn_name: &str, name: &str) -> Result<&Geometry, SceneError> {
+        let entity = self
+            .find_by_name(name)
+            .ok_or_else(|| SceneError::EntityNotFound(fn_name.to_string(), name.to_string()))?;
+        if geometry_params(&entity.geometry).is_none() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: '{}' ({}) cannot be constrained (expected line, polygon, rect, circle or arc)",
+                fn_name,
+                name,
+                entity.entity_type.as_str()
+            )));
+        }
+        Ok(&entity.geometry)
+    }
+
+    /// 점 참조 해석 (이름만 주면 center)
+    fn constraint_point(
+        &self,
+        fn_name: &str,
+        target: &TargetInput,
+    ) -> Result<DimensionPoint, SceneError> {
+        let name = target.entity();
+        let geometry = self.constraint_entity(fn_name, name)?;
+        let anchor = match target {
+            TargetInput::Detailed { edge: Some(_), .. } => {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: expected a point on '{}', got an edge",
+                    fn_name, name
+                )));
+            }
+            TargetInput::Detailed {
+                anchor: Some(anchor),
+                ..
+            } => PointAnchor::parse_str(anchor).ok_or_else(|| {
+                SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: unknown anchor '{}' (expected start, end, center or vertex:N)",
+                    fn_name, anchor
+                ))
+            })?,
+            _ => PointAnchor::Center,
+        };
+        if Self::dimension_local_point(geometry, anchor).is_none() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: anchor {:?} is not available on '{}'",
+                fn_name, anchor, name
+            )));
+        }
+        Ok(DimensionPoint {
+            entity: name.to_string(),
+            anchor,
+        })
+    }
+
+    /// 직선 참조 해석: Line 세그먼트, Polygon/Rect 변 (edge 생략 시 0)
+    fn constraint_line(
+        &self,
+        fn_name: &str,
+        target: &TargetInput,
+    ) -> Result<ConstraintLine, SceneError> {
+        let name = target.entity();
+        let geometry = self.constraint_entity(fn_name, name)?;
+        let edge = match target {
+            TargetInput::Detailed {
+                anchor: Some(_), ..
+            } => {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: expected an edge of '{}', got a point",
+                    fn_name, name
+                )));
+            }
+            TargetInput::Detailed {
+                edge: Some(edge), ..
+            } => *edge,
+            _ => 0,
+        };
+        let edges = match geometry {
+            Geometry::Line { points } => points.len().saturating_sub(1),
+            Geometry::Polygon { points, .. } => points.len(),
+            Geometry::Rect { .. } => 4,
+            _ => 0,
+        };
+        if edge >= edges {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: '{}' has no straight edge {} ({} edges)",
+                fn_name, name, edge, edges
+            )));
+        }
+        let point = |i: usize| DimensionPoint {
+            entity: name.to_string(),
+            anchor: PointAnchor::Vertex(i),
+        };
+        Ok(ConstraintLine {
+            from: point(edge),
+            to: point((edge + 1) % edges.max(2)),
+        })
+    }
+
+    /// Circle/Arc 참조 해석
+    fn constraint_circle(&self, fn_name: &str, target: &TargetInput) -> Result<String, SceneError> {
+        let name = target.entity();
+        self.require_entity_type(fn_name, name, &[EntityType::Circle, EntityType::Arc])?;
+        if !target.is_bare() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: circle target '{}' must not have an anchor or edge",
+                fn_name, name
+            )));
+        }
+        Ok(name.to_string())
+    }
+
+    fn is_circle_target(&self, target: &TargetInput) -> bool {
+        target.is_bare()
+            && self
+                .find_by_name(target.entity())
+                .is_some_and(|e| matches!(e.entity_type, EntityType::Circle | EntityType::Arc))
+    }
+
+    /// kind 문자열과 대상 목록으로 제약 생성 (value 생략 시 현재 값)
+    fn parse_constraint(
+        &self,
+        kind: &str,
+        targets: &[TargetInput],
+        value: Option<f64>,
+    ) -> Result<ConstraintKind, SceneError> {
+        let fn_name = "add_constraint";
+        let kind = kind.trim().to_ascii_lowercase();
+        let expected: &[usize] = match kind.as_str() {
+            "horizontal" | "vertical" => &[1, 2],
+            "fix" => &[1],
+            "coincident" | "parallel" | "perpendicular" | "tangent" | "equal_length"
+            | "equal_radius" | "distance" | "angle" => &[2],
+            _ => {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: unknown constraint kind '{}'",
+                    fn_name, kind
+                )));
+            }
+        };
+        if !expected.contains(&targets.len()) {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: '{}' takes {} target(s), got {}",
+                fn_name,
+                kind,
+                expected
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" or "),
+                targets.len()
+            )));
+        }
+        if value.is_some_and(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: NaN or Infinity not allowed",
+                fn_name
+            )));
+        }
+
+        let point = |i: usize| self.constraint_point(fn_name, &targets[i]);
+        let line = |i: usize| self.constraint_line(fn_name, &targets[i]);
+        let circle = |i: usize| self.constraint_circle(fn_name, &targets[i]);
+        // 단일 직선 대상이면 양 끝점
+        let point_pair = || -> Result<(DimensionPoint, DimensionPoint), SceneError> {
+            if targets.len() == 1 {
+                let l = line(0)?;
+                Ok((l.from, l.to))
+            } else {
+                Ok((point(0)?, point(1)?))
+            }
+        };
+
+        let mut constraint = match kind.as_str() {
+            "coincident" => ConstraintKind::Coincident {
+                a: point(0)?,
+                b: point(1)?,
+            },
+            "horizontal" => {
+                let (a, b) = point_pair()?;
+                ConstraintKind::Horizontal { a, b }
+            }
+            "vertical" => {
+                let (a, b) = point_pair()?;
+                ConstraintKind::Vertical { a, b }
+            }
+            "parallel" => ConstraintKind::Parallel {
+                a: line(0)?,
+                b: line(1)?,
+            },
+            "perpendicular" => ConstraintKind::Perpendicular {
+                a: line(0)?,
+                b: line(1)?,
+            },
+            "tangent" => match (
+                self.is_circle_target(&targets[0]),
+                self.is_circle_target(&targets[1]),
+            ) {
+                (true, true) => {
+                    let (a, b) = (circle(0)?, circle(1)?);
+                    // 현재 배치로 내접/외접 결정 (중심 거리가 큰 반지름보다 작으면 내접)
+                    let internal = self.circles_nested(&a, &b);
+                    ConstraintKind::TangentCircles { a, b, internal }
+                }
+                (true, false) => ConstraintKind::TangentLine {
+                    line: line(1)?,
+                    circle: circle(0)?,
+                },
+                (false, _) => ConstraintKind::TangentLine {
+                    line: line(0)?,
+                    circle: circle(1)?,
+                },
+            },
+            "equal_length" => ConstraintKind::EqualLength {
+                a: line(0)?,
+                b: line(1)?,
+            },
+            "equal_radius" => ConstraintKind::EqualRadius {
+                a: circle(0)?,
+                b: circle(1)?,
+            },
+            "distance" => {
+                if value.is_some_and(|v| v < 0.0) {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: distance must not be negative",
+                        fn_name
+                    )));
+                }
+                ConstraintKind::Distance {
+                    a: point(0)?,
+                    b: point(1)?,
+                    value: 0.0,
+                }
+            }
+            "angle" => ConstraintKind::Angle {
+                a: line(0)?,
+                b: line(1)?,
+                value: 0.0,
+            },
+            _ => ConstraintKind::Fix {
+                point: point(0)?,
+                at: [0.0, 0.0],
+            },
+        };
+
+        // 값 지정: 생략 시 현재 측정값 (값 0 기준 잔차 = 현재 값)
+        let current = {
+            let probe = [Constraint {
+                name: String::new(),
+                kind: constraint.clone(),
+            }];
+            let system = ConstraintSystem::build(self, &probe, &[]);
+            system.residuals(&probe, &system.x0)
+        };
+        match &mut constraint {
+            ConstraintKind::Distance { value: v, .. } | ConstraintKind::Angle { value: v, .. } => {
+                *v = match value {
+                    Some(value) => value,
+                    None => current.first().copied().unwrap_or(0.0),
+                };
+            }
+            ConstraintKind::Fix { at, .. } => {
+                *at = [
+                    current.first().copied().unwrap_or(0.0),
+                    current.get(1).copied().unwrap_or(0.0),
+                ];
+            }
+            _ => {}
+        }
+        Ok(constraint)
+    }
+
+    /// 한 원이 다른 원 안에 있는지 (중심 거리 < 큰 반지름)
+    fn circles_nested(&self, a: &str, b: &str) -> bool {
+        let probe = [Constraint {
+            name: String::new(),
+            kind: ConstraintKind::EqualRadius {
+                a: a.to_string(),
+                b: b.to_string(),
+            },
+        }];
+        let system = ConstraintSystem::build(self, &probe, &[]);
+        let frame = system.frame(&system.x0);
+        match (frame.circle(a), frame.circle(b)) {
+            (Some((ca, ra)), Some((cb, rb))) => length(sub(cb, ca)) < ra.max(rb),
+            _ => false,
+        }
+    }
+
+    /// 제약 추가 (내부용)
+    ///
+    /// # Arguments
+    /// * `targets_json` - 대상 JSON 배열 (`add_constraint` 참고)
+    /// * `value` - distance/angle 값 (None이면 현재 측정값)
+    ///
+    /// # Errors
+    /// * 빈 이름이나 이미 있는 제약 이름, 잘못된 JSON, 알 수 없는 kind, 대상 개수/타입 불일치
+    pub(crate) fn add_constraint_internal(
+        &mut self,
+        name: &str,
+        kind: &str,
+        targets_json: &str,
+        value: Option<f64>,
+    ) -> Result<String, SceneError> {
+        if name.is_empty() {
+            return Err(SceneError::InvalidInput(
+                "[add_constraint] invalid_input: name must not be empty".to_string(),
+            ));
+        }
+        if self.constraints.iter().any(|c| c.name == name) {
+            return Err(SceneError::InvalidInput(format!(
+                "[add_constraint] duplicate_name: Constraint '{}' already exists",
+                name
+            )));
+        }
+        let targets: Vec<TargetInput> = serde_json::from_str(targets_json).map_err(|e| {
+            SceneError::InvalidInput(format!("[add_constraint] invalid_json: {}", e))
+        })?;
+        let kind = self.parse_constraint(kind, &targets, value)?;
+        self.constraints.push(Constraint {
+            name: name.to_string(),
+            kind,
+        });
+        self.last_operation = Some(format!("add_constraint({})", name));
+        Ok(name.to_string())
+    }
+
+    /// 제약 삭제 (내부용, 없으면 false)
+    pub(crate) fn remove_constraint_internal(&mut self, name: &str) -> bool {
+        let before = self.constraints.len();
+        self.constraints.retain(|c| c.name != name);
+        if self.constraints.len() == before {
+            return false;
+        }
+        self.last_operation = Some(format!("remove_constraint({})", name));
+        true
+    }
+
+    /// 제약 시스템을 풀어 상태와 해를 계산합니다 (Scene 변경 없음).
+    fn analyze_constraints(&self, fixed: &[String]) -> Analysis {
+        let constraints = &self.constraints;
+        let system = ConstraintSystem::build(self, constraints, fixed);
+        let residual_fn = |x: &[f64]| system.residuals(constraints, x);
+
+        let mut x = system.x0.clone();
+        let outcome = solve_least_squares(
+            residual_fn,
+            &mut x,
+            &system.free,
+            SOLVE_TOLERANCE * system.scale,
+        );
+        let current = residual_fn(&system.x0);
+        let solved = residual_fn(&x);
+        let independent = independent_rows(&numeric_jacobian(&residual_fn, &x, &system.free));
+
+        let satisfied_tolerance = SATISFIED_TOLERANCE * system.scale;
+        let solved_frame = system.frame(&x);
+        let mut reports: Vec<ConstraintReport> = constraints
+            .iter()
+            .zip(&system.errors)
+            .map(|(constraint, error)| ConstraintReport {
+                name: constraint.name.clone(),
+                kind: constraint.kind.as_str(),
+                satisfied: false,
+                residual: None,
+                redundant: false,
+                conflicting: false,
+                error: error.clone(),
+            })
+            .collect();
+        for (i, rows) in &system.active {
+            let residual = worst(&current, rows);
+            let report = &mut reports[*i];
+            report.residual = Some(residual);
+            report.satisfied = residual <= satisfied_tolerance;
+            report.redundant = rows.clone().any(|row| !independent[row]);
+            // 더 줄일 수 없는 지점에서 남은 잔차만 충돌로 봄 (반복 한도 도달은 not_converged)
+            // 도형을 점으로 줄여야만 만족되는 해도 충돌
+            report.conflicting = (outcome.stalled && worst(&solved, rows) > satisfied_tolerance)
+                || solved_frame.degenerate(&constraints[*i].kind, satisfied_tolerance);
+        }
+        let not_converged = !outcome.stalled
+            && system
+                .active
+                .iter()
+                .any(|(_, rows)| worst(&solved, rows) > satisfied_tolerance);
+
+        let variables = system.free.iter().filter(|f| **f).count();
+        let rank = independent.iter().filter(|i| **i).count();
+        let conflicting = reports.iter().any(|r| r.conflicting);
+        let status = if conflicting {
+            "conflicting"
+        } else if not_converged {
+            "not_converged"
+        } else if reports.iter().any(|r| r.redundant) {
+            "over_constrained"
+        } else if variables > rank {
+            "under_constrained"
+        } else {
+            "well_constrained"
+        };
+
+        let solution = (!conflicting && !not_converged).then(|| {
+            system
+                .bodies
+                .iter()
+                .zip(solved_frame.geometries)
+                .map(|(body, mut geometry)| {
+                    normalize_sizes(&mut geometry);
+                    (body.name.clone(), geometry)
+                })
+                .collect()
+        });
+
+        Analysis {
+            status: ConstraintStatus {
+                status,
+                dof: variables.saturating_sub(rank),
+                variables,
+                equations: solved.len(),
+                constraints: reports,
+            },
+            solution,
+        }
+    }
+
+    /// 현재 도형 기준으로 제약별 residual/satisfied를 다시 계산합니다.
+    fn refresh_constraint_residuals(&self, reports: &mut [ConstraintReport]) {
+        let system = ConstraintSystem::build(self, &self.constraints, &[]);
+        let current = system.residuals(&self.constraints, &system.x0);
+        let satisfied_tolerance = SATISFIED_TOLERANCE * system.scale;
+        for (i, rows) in &system.active {
+            let residual = worst(&current, rows);
+            reports[*i].residual = Some(residual);
+            reports[*i].satisfied = residual <= satisfied_tolerance;
+        }
+    }
+
+    /// 제약 상태 조회 (내부용, 도형은 바꾸지 않음)
+    pub(crate) fn get_constraint_status_internal(&self) -> ConstraintStatus {
+        self.analyze_constraints(&[]).status
+    }
+
+    /// 제약을 풀어 도형 geometry에 반영합니다 (내부용).
+    ///
+    /// 충돌이 있거나 수렴하지 않으면 도형을 바꾸지 않고 상태만 반환합니다.
+    /// 해를 반영하면 residual/satisfied는 해석 후 도형 기준입니다.
+    ///
+    /// # Arguments
+    /// * `fixed` - 이번 해석에서 움직이지 않을 Entity/Group 이름 (방금 편집한 도형 등)
+    ///
+    /// # Errors
+    /// * fixed에 없는 Entity 이름
+    pub(crate) fn solve_constraints_internal(
+        &mut self,
+        fixed: &[String],
+    ) -> Result<ConstraintStatus, SceneError> {
+        if let Some(missing) = fixed.iter().find(|name| !self.has_entity(name)) {
+            return Err(SceneError::EntityNotFound(
+                "solve_constraints".to_string(),
+                missing.clone(),
+            ));
+        }
+        let Analysis {
+            mut status,
+            solution,
+        } = self.analyze_constraints(fixed);
+        if let Some(solution) = solution {
+            for (name, geometry) in solution {
+                if let Some(entity) = self.find_by_name_mut(&name) {
+                    entity.geometry = geometry;
+                }
+            }
+            self.refresh_constraint_residuals(&mut status.constraints);
+        }
+        self.last_operation = Some("solve_constraints()".to_string());
+        Ok(status)
+    }
+}
+
+// ========================================
+// WASM Bindings for Constraints
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 도형 사이의 기하 제약을 추가합니다. 추가만 하고 도형은 바꾸지 않으므로
+    /// 이어서 `solve_constraints`를 호출하세요.
+    ///
+    /// 대상(targets)은 이름 문자열 또는 객체입니다.
+    /// - 점: `{"entity": "hole", "anchor": "center"}` (anchor: start, end, center, vertex:N / 이름만 주면 center)
+    /// - 직선: `{"entity": "plate", "edge": 2}` (Line 세그먼트, Polygon/Rect 변 / 이름만 주면 edge 0)
+    /// - 원: `"hole"` (Circle/Arc 이름)
+    ///
+    /// 종류별 대상:
+    /// - `coincident` [점, 점], `distance` [점, 점] + value (생략 시 현재 거리)
+    /// - `horizontal`, `vertical` [직선] 또는 [점, 점]
+    /// - `parallel`, `perpendicular`, `equal_length` [직선, 직선]
+    /// - `angle` [직선, 직선] + value (라디안, 첫 직선에서 CCW, 생략 시 현재 각도)
+    /// - `tangent` [직선, 원] 또는 [원, 원] (현재 배치로 내접/외접 결정)
+    /// - `equal_radius` [원, 원], `fix` [점] (현재 월드 위치에 고정)
+    ///
+    /// 제약 가능한 도형: Line, Polygon, Rect, Circle, Arc (그룹 변환 아래에서도 월드 좌표 기준)
+    ///
+    /// # Arguments
+    /// * `name` - 제약 이름 (제약끼리 unique)
+    /// * `kind` - 제약 종류
+    /// * `targets_json` - 대상 JSON 배열 (예: `[{"entity":"hole","anchor":"center"},{"entity":"plate","anchor":"center"}]`)
+    /// * `value` - distance/angle 값 (다른 종류는 무시)
+    ///
+    /// # Errors
+    /// * 중복 이름, 알 수 없는 kind, 대상 개수/타입 불일치, 존재하지 않는 anchor/edge
+    pub fn add_constraint(
+        &mut self,
+        name: &str,
+        kind: &str,
+        targets_json: &str,
+        value: Option<f64>,
+    ) -> Result<String, JsValue> {
+        self.add_constraint_internal(name, kind, targets_json, value)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// 제약을 삭제합니다. 도형은 그대로 둡니다.
+    ///
+    /// # Returns
+    /// * true - 삭제됨, false - 이름 미발견
+    pub fn remove_constraint(&mut self, name: &str) -> bool {
+        self.remove_constraint_internal(name)
+    }
+
+    /// 모든 제약을 풀어 도형 geometry를 조정합니다.
+    ///
+    /// 현재 배치에서 가장 적게 움직이는 해를 찾습니다. translate/scale 이나 geometry 편집 뒤에는
+    /// 편집한 도형을 `fixed`에 넣어 다시 풀면 나머지 도형이 따라옵니다
+    /// (예: 판을 키운 뒤 `["plate"]`로 풀면 구멍이 다시 가운데로).
+    /// 충돌하는 제약이 있거나 반복 한도 안에 수렴하지 않으면 도형을 바꾸지 않습니다.
+    ///
+    /// # Arguments
+    /// * `fixed_json` - 움직이지 않을 Entity/Group 이름 JSON 배열 (빈 문자열이면 없음)
+    ///
+    /// # Returns
+    /// 해석 후 상태 JSON (`get_constraint_status`와 같은 형식)
+    ///
+    /// # Errors
+    /// * 잘못된 JSON, fixed에 없는 Entity 이름
+    pub fn solve_constraints(&mut self, fixed_json: &str) -> Result<String, JsValue> {
+        let fixed: Vec<String> = if fixed_json.trim().is_empty() {
+            Vec::new()
+        } else {
+            serde_json::from_str(fixed_json).map_err(|e| {
+                JsValue::from_str(&format!("[solve_constraints] invalid_json: {}", e))
+            })?
+        };
+        let status = self
+            .solve_constraints_internal(&fixed)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&status).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// 제약 상태를 반환합니다 (도형은 바꾸지 않음).
+    ///
+    /// # Returns
+    /// JSON: `{"status": "under_constrained", "dof": 3, "variables": 7, "equations": 4,
+    /// "constraints": [{"name": "center_hole", "type": "coincident", "satisfied": false,
+    /// "residual": 12.5, "redundant": false, "conflicting": false}, ...]}`
+    /// - status: `well_constrained` (자유도 0) | `under_constrained` (더 움직일 수 있음)
+    ///   | `over_constrained` (중복 제약, 만족 가능) | `conflicting` (동시에 만족 불가)
+    ///   | `not_converged` (반복 한도 안에 수렴하지 못함, 충돌 여부 미정)
+    /// - satisfied: 현재 도형이 만족하는지, conflicting: 풀어도 만족할 수 없는지
+    /// - error: 참조 도형 삭제 등으로 해석에서 제외된 사유
+    pub fn get_constraint_status(&self) -> Result<String, JsValue> {
+        serde_json::to_string(&self.get_constraint_status_internal())
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn approx_eq(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    fn add(scene: &mut Scene, name: &str, kind: &str, targets: &str, value: Option<f64>) {
+        scene
+            .add_constraint_internal(name, kind, targets, value)
+            .unwrap_or_else(|e| panic!("{} failed: {}", name, e));
+    }
+
+    fn point(scene: &Scene, entity: &str, anchor: PointAnchor) -> [f64; 2] {
+        scene
+            .resolve_dimension_point(&DimensionPoint {
+                entity: entity.to_string(),
+                anchor,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hole_follows_plate_after_translate() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("plate", 0.0, 0.0, 100.0, 60.0)
+            .unwrap();
+        scene.add_circle_internal("hole", 10.0, 5.0, 3.0).unwrap();
+        add(
+            &mut scene,
+            "centered",
+            "coincident",
+            r#"["hole", "plate"]"#,
+            None,
+        );
+
+        let status = scene
+            .solve_constraints_internal(&["plate".to_string()])
+            .unwrap();
+        assert_eq!(status.status, "under_constrained");
+        assert_eq!((status.variables, status.equations, status.dof), (3, 2, 1));
+        assert!(status.constraints[0].satisfied);
+        // 잔차는 해석 전(약 11.2)이 아니라 해석 후 도형 기준
+        assert!(status.constraints[0].residual.unwrap() < 1e-6);
+        let center = point(&scene, "hole", PointAnchor::Center);
+        assert!(approx_eq(center[0], 0.0) && approx_eq(center[1], 0.0));
+
+        // 판을 옮기고 판을 고정한 채 다시 풀면 구멍이 따라옴
+        scene.translate("plate", 20.0, 10.0).unwrap();
+        assert!(!scene.get_constraint_status_internal().constraints[0].satisfied);
+        scene
+            .solve_constraints_internal(&["plate".to_string()])
+            .unwrap();
+        let center = point(&scene, "hole", PointAnchor::Center);
+        assert!(approx_eq(center[0], 20.0) && approx_eq(center[1], 10.0));
+        assert_eq!(point(&scene, "plate", PointAnchor::Center), [20.0, 10.0]);
+    }
+
+    #[test]
+    fn test_corner_hole_keeps_offset_when_plate_scaled() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("plate", 0.0, 0.0, 100.0, 60.0)
+            .unwrap();
+        scene
+            .add_circle_internal("hole", -40.0, -20.0, 3.0)
+            .unwrap();
+        let corner = r#"{"entity": "plate", "anchor": "vertex:0"}"#;
+        add(
+            &mut scene,
+            "dx",
+            "distance",
+            &format!(r#"["hole", {}]"#, corner),
+            None,
+        );
+        add(
+            &mut scene,
+            "h",
+            "horizontal",
+            r#"[{"entity": "hole", "anchor": "center"}, {"entity": "plate", "anchor": "vertex:0"}]"#,
+            None,
+        );
+        // 생략한 value는 현재 거리, 현재는 수평이 아니므로 풀면 맞춰짐
+        assert!(matches!(
+            scene.constraints()[0].kind,
+            ConstraintKind::Distance { value, .. } if approx_eq(value, 10.0_f64.hypot(10.0))
+        ));
+
+        scene.scale("plate", 2.0, 1.0).unwrap();
+        let status = scene
+            .solve_constraints_internal(&["plate".to_string()])
+            .unwrap();
+        assert_eq!(status.status, "under_constrained");
+        let hole = point(&scene, "hole", PointAnchor::Center);
+        let vertex = point(&scene, "plate", PointAnchor::Vertex(0));
+        assert!(approx_eq(hole[1], vertex[1]));
+        assert!(approx_eq((hole[0] - vertex[0]).abs(), 10.0_f64.hypot(10.0)));
+    }
+
+    #[test]
+    fn test_line_relations() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("base", vec![0.0, 0.0, 10.0, 1.0])
+            .unwrap();
+        scene
+            .add_line_internal("post", vec![10.0, 1.0, 12.0, 6.0])
+            .unwrap();
+        scene
+            .add_line_internal("top", vec![0.0, 8.0, 5.0, 9.0])
+            .unwrap();
+        add(&mut scene, "flat", "horizontal", r#"["base"]"#, None);
+        add(
+            &mut scene,
+            "upright",
+            "perpendicular",
+            r#"["base", "post"]"#,
+            None,
+        );
+        add(
+            &mut scene,
+            "same",
+            "equal_length",
+            r#"["base", "top"]"#,
+            None,
+        );
+        add(&mut scene, "par", "parallel", r#"["top", "base"]"#, None);
+        let status = scene.solve_constraints_internal(&[]).unwrap();
+        assert!(
+            status
+                .constraints
+                .iter()
+                .all(|c| c.satisfied && !c.conflicting)
+        );
+
+        let (b0, b1) = (
+            point(&scene, "base", PointAnchor::Start),
+            point(&scene, "base", PointAnchor::End),
+        );
+        let (p0, p1) = (
+            point(&scene, "post", PointAnchor::Start),
+            point(&scene, "post", PointAnchor::End),
+        );
+        let (t0, t1) = (
+            point(&scene, "top", PointAnchor::Start),
+            point(&scene, "top", PointAnchor::End),
+        );
+        assert!(approx_eq(b0[1], b1[1]));
+        assert!(approx_eq(p0[0], p1[0]));
+        assert!(approx_eq(t0[1], t1[1]));
+        assert!(approx_eq(length(sub(b1, b0)), length(sub(t1, t0))));
+    }
+
+    #[test]
+    fn test_angle_and_tangent() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("a", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .add_line_internal("b", vec![0.0, 0.0, 10.0, 3.0])
+            .unwrap();
+        scene.add_circle_internal("c1", 5.0, 4.0, 2.0).unwrap();
+        scene.add_circle_internal("c2", 12.0, 4.0, 3.0).unwrap();
+        add(
+            &mut scene,
+            "fa",
+            "fix",
+            r#"[{"entity": "a", "anchor": "start"}]"#,
+            None,
+        );
+        add(
+            &mut scene,
+            "fb",
+            "fix",
+            r#"[{"entity": "a", "anchor": "end"}]"#,
+            None,
+        );
+        add(&mut scene, "ang", "angle", r#"["a", "b"]"#, Some(PI / 4.0));
+        add(&mut scene, "touch", "tangent", r#"["a", "c1"]"#, None);
+        add(&mut scene, "kiss", "tangent", r#"["c1", "c2"]"#, None);
+        scene.solve_constraints_internal(&[]).unwrap();
+
+        let end = point(&scene, "a", PointAnchor::End);
+        assert!(approx_eq(end[0], 10.0) && approx_eq(end[1], 0.0));
+        let (b0, b1) = (
+            point(&scene, "b", PointAnchor::Start),
+            point(&scene, "b", PointAnchor::End),
+        );
+        assert!(approx_eq((b1[1] - b0[1]).atan2(b1[0] - b0[0]), PI / 4.0));
+        let Some(entity) = scene.find_by_name("c1") else {
+            panic!("c1 missing");
+        };
+        let Geometry::Circle { center, radius } = entity.geometry else {
+            panic!("not a circle");
+        };
+        assert!(approx_eq(center[1].abs(), radius));
+        let c2 = point(&scene, "c2", PointAnchor::Center);
+        let Geometry::Circle { radius: r2, .. } = scene.find_by_name("c2").unwrap().geometry else {
+            panic!("not a circle");
+        };
+        assert!(approx_eq(length(sub(c2, center)), radius + r2));
+    }
+
+    #[test]
+    fn test_constraints_under_group_transform() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("arm", vec![0.0, 0.0, 10.0, 2.0])
+            .unwrap();
+        scene
+            .create_group_internal("g", vec!["arm".to_string()])
+            .unwrap();
+        scene.rotate("g", PI / 2.0).unwrap();
+        add(&mut scene, "flat", "horizontal", r#"["arm"]"#, None);
+        scene.solve_constraints_internal(&[]).unwrap();
+
+        // 월드 기준 수평 → 90° 회전된 그룹 안에서는 로컬 수직
+        let start = point(&scene, "arm", PointAnchor::Start);
+        let end = point(&scene, "arm", PointAnchor::End);
+        assert!(approx_eq(start[1], end[1]));
+        let Geometry::Line { points } = &scene.find_by_name("arm").unwrap().geometry else {
+            panic!("not a line");
+        };
+        assert!(approx_eq(points[0][0], points[1][0]));
+
+        // 그룹을 fixed로 주면 하위 도형도 고정
+        add(
+            &mut scene,
+            "pin",
+            "fix",
+            r#"[{"entity": "arm", "anchor": "start"}]"#,
+            None,
+        );
+        let status = scene
+            .solve_constraints_internal(&["g".to_string()])
+            .unwrap();
+        assert_eq!(status.variables, 0);
+    }
+
+    #[test]
+    fn test_status_over_constrained_and_conflicting() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        add(
+            &mut scene,
+            "s",
+            "fix",
+            r#"[{"entity": "l", "anchor": "start"}]"#,
+            None,
+        );
+        add(
+            &mut scene,
+            "e",
+            "fix",
+            r#"[{"entity": "l", "anchor": "end"}]"#,
+            None,
+        );
+        let status = scene.get_constraint_status_internal();
+        assert_eq!(status.status, "well_constrained");
+        assert_eq!((status.dof, status.equations), (0, 4));
+
+        add(&mut scene, "flat", "horizontal", r#"["l"]"#, None);
+        let status = scene.get_constraint_status_internal();
+        assert_eq!(status.status, "over_constrained");
+        assert!(status.constraints[2].redundant && status.constraints[2].satisfied);
+
+        add(
+            &mut scene,
+            "len",
+            "distance",
+            r#"[{"entity": "l", "anchor": "start"}, {"entity": "l", "anchor": "end"}]"#,
+            Some(5.0),
+        );
+        let status = scene.solve_constraints_internal(&[]).unwrap();
+        assert_eq!(status.status, "conflicting");
+        assert!(status.constraints[3].conflicting && !status.constraints[3].satisfied);
+        // 충돌 시 도형은 그대로
+        assert_eq!(point(&scene, "l", PointAnchor::End), [10.0, 0.0]);
+
+        assert!(scene.remove_constraint("len"));
+        assert!(!scene.remove_constraint("len"));
+        assert_ne!(scene.get_constraint_status_internal().status, "conflicting");
+    }
+
+    #[test]
+    fn test_horizontal_and_vertical_line_is_conflicting() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l1", vec![0.0, 0.0, 5.0, 1.0])
+            .unwrap();
+        add(&mut scene, "flat", "horizontal", r#"["l1"]"#, None);
+        add(&mut scene, "upright", "vertical", r#"["l1"]"#, None);
+
+        // 길이 0 선분으로만 만족 가능 → 충돌, 도형은 그대로
+        let status = scene.solve_constraints_internal(&[]).unwrap();
+        assert_eq!(status.status, "conflicting");
+        assert!(status.constraints.iter().any(|c| c.conflicting));
+        assert_eq!(point(&scene, "l1", PointAnchor::Start), [0.0, 0.0]);
+        assert_eq!(point(&scene, "l1", PointAnchor::End), [5.0, 1.0]);
+    }
+
+    #[test]
+    fn test_rename_and_delete_references() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("a", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("b", 5.0, 0.0, 2.0).unwrap();
+        add(&mut scene, "eq", "equal_radius", r#"["a", "b"]"#, None);
+        scene.rename_internal("b", "big").unwrap();
+        assert_eq!(scene.constraints()[0].kind.references(), vec!["a", "big"]);
+        scene
+            .solve_constraints_internal(&["a".to_string()])
+            .unwrap();
+        let Geometry::Circle { radius, .. } = scene.find_by_name("big").unwrap().geometry else {
+            panic!("not a circle");
+        };
+        assert!(approx_eq(radius, 1.0));
+
+        scene.delete("big").unwrap();
+        let status = scene.get_constraint_status_internal();
+        let report = &status.constraints[0];
+        assert!(!report.satisfied && report.residual.is_none());
+        assert!(report.error.as_deref().unwrap().contains("not found"));
+
+        let json = scene.export_json();
+        assert!(json.contains("\"constraints\"") && json.contains("EqualRadius"));
+    }
+
+    #[test]
+    fn test_add_constraint_errors() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .draw_bezier("curve", "M 0,0 C 0,4 10,4 10,0", "{}")
+            .unwrap();
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        let err = |scene: &mut Scene, kind: &str, targets: &str| {
+            scene
+                .add_constraint_internal("x", kind, targets, None)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(err(&mut scene, "glue", r#"["l"]"#).contains("unknown constraint kind"));
+        assert!(err(&mut scene, "parallel", r#"["l"]"#).contains("takes 2 target"));
+        assert!(err(&mut scene, "horizontal", r#"["curve"]"#).contains("cannot be constrained"));
+        assert!(
+            err(
+                &mut scene,
+                "parallel",
+                r#"["l", {"entity": "l", "edge": 1}]"#
+            )
+            .contains("no straight edge")
+        );
+        assert!(err(&mut scene, "coincident", r#"["l", "c"]"#).contains("not available"));
+        assert!(err(&mut scene, "equal_radius", r#"["c", "l"]"#).contains("must be"));
+        assert!(err(&mut scene, "fix", "not json").contains("invalid_json"));
+        add(
+            &mut scene,
+            "x",
+            "distance",
+            r#"[{"entity": "l", "anchor": "start"}, "c"]"#,
+            None,
+        );
+        assert!(err(&mut scene, "fix", r#"["c"]"#).contains("duplicate_name"));
+    }
+}
@@ -0,0 +1,356 @@
+//! 모서리 가공 (fillet / chamfer) 모듈
+//!
+//! Polygon, Rect, Line Entity의 꺾임점을 제자리에서 둥글게(fillet) 또는 비스듬하게(chamfer) 깎습니다.
+//! - fillet: 결과를 Bezier로 저장 (원호는 큐빅, 직선 구간은 직선과 같은 큐빅) → SVG `<path>`로 그대로 출력
+//! - chamfer: Polygon/Line은 타입 유지, Rect는 Polygon으로 변환
+//!
+//! 꺾임점 인덱스는 로컬 좌표 점 순서 기준입니다 (Rect는 좌하단부터 CCW 0..3).
+//! transform/style/이름은 그대로 유지됩니다.
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Geometry};
+use super::{Scene, SceneError};
+use crate::geometry::corner::{chamfer_points, fillet_path};
+use crate::geometry::flatten::rect_points;
+
+/// 가공 대상 외곽선 (점, 닫힘 여부, 보존할 holes)
+struct CornerSource {
+    points: Vec<[f64; 2]>,
+    closed: bool,
+    holes: Vec<Vec<[f64; 2]>>,
+}
+
+/// corners_json 파싱 ("" 또는 "[]" → None = 모든 꺾임점)
+fn parse_corners(fn_name: &str, corners_json: &str) -> Result<Option<Vec<usize>>, SceneError> {
+    if corners_json.trim().is_empty() {
+        return Ok(None);
+    }
+    let corners: Vec<usize> = serde_json::from_str(corners_json).map_err(|e| {
+        SceneError::InvalidInput(format!(
+            "[{}] invalid_input: corners must be an array of vertex indices: {}",
+            fn_name, e
+        ))
+    })?;
+    Ok((!corners.is_empty()).then_some(corners))
+}
+
+/// 끝점이 시작점과 같으면 제거하고 true 반환
+fn strip_closing_point(points: &mut Vec<[f64; 2]>) -> bool {
+    let closing = points.len() > 2 && points.first() == points.last();
+    if closing {
+        points.pop();
+    }
+    closing
+}
+
+impl Scene {
+    /// fillet/chamfer 대상 외곽선 추출 (미발견이면 Ok(None))
+    fn corner_source(
+        &self,
+        fn_name: &str,
+        name: &str,
+        corners: Option<&[usize]>,
+    ) -> Result<Option<CornerSource>, SceneError> {
+        let Some(entity) = self.find_by_name(name) else {
+            return Ok(None);
+        };
+        let source = match &entity.geometry {
+            Geometry::Rect {
+                center,
+                width,
+                height,
+            } => CornerSource {
+                points: rect_points(*center, *width, *height),
+                closed: true,
+                holes: Vec::new(),
+            },
+            Geometry::Polygon { points, holes } => {
+                let mut points = points.clone();
+                strip_closing_point(&mut points);
+                CornerSource {
+                    points,
+                    closed: true,
+                    holes: holes.clone(),
+                }
+            }
+            Geometry::Line { points } => {
+                let mut points = points.clone();
+                let closed = strip_closing_point(&mut points);
+                CornerSource {
+                    points,
+                    closed,
+                    holes: Vec::new(),
+                }
+            }
+            _ => {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: '{}' must be a Polygon, Rect or Line",
+                    fn_name, name
+                )));
+            }
+        };
+
+        if let Some(&bad) = corners
+            .unwrap_or(&[])
+            .iter()
+            .find(|&&i| i >= source.points.len())
+        {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: corner index {} out of range (0..{})",
+                fn_name,
+                bad,
+                source.points.len()
+            )));
+        }
+        Ok(Some(source))
+    }
+
+    /// 꺾임점을 둥글게 만들기 (내부용)
+    ///
+    /// # Arguments
+    /// * `radius` - fillet 반지름 (로컬 좌표, 인접 변 길이의 절반에 맞게 자동 축소)
+    /// * `corners` - 꺾임점 인덱스 (None이면 전부, 열린 Line의 양 끝점은 무시)
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공 (geometry가 Bezier로 변환됨)
+    /// * Ok(false) - name이 존재하지 않음
+    ///
+    /// # Errors
+    /// * radius가 양수가 아님, 지원하지 않는 geometry, holes가 있는 Polygon, 인덱스 범위 초과
+    pub(crate) fn fillet_internal(
+        &mut self,
+        name: &str,
+        radius: f64,
+        corners: Option<Vec<usize>>,
+    ) -> Result<bool, SceneError> {
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(SceneError::InvalidInput(
+                "[fillet] invalid_input: radius must be a positive finite number".to_string(),
+            ));
+        }
+        let Some(source) = self.corner_source("fillet", name, corners.as_deref())? else {
+            return Ok(false);
+        };
+        if !source.holes.is_empty() {
+            return Err(SceneError::InvalidInput(format!(
+                "[fillet] invalid_input: '{}' has holes; fillet supports outer contours only",
+                name
+            )));
+        }
+
+        let (start, segments) =
+            fillet_path(&source.points, source.closed, corners.as_deref(), radius);
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.entity_type = EntityType::Bezier;
+            entity.geometry = Geometry::Bezier {
+                start,
+                segments,
+                closed: source.closed,
+            };
+        }
+        self.last_operation = Some(format!("fillet({}, {})", name, radius));
+        Ok(true)
+    }
+
+    /// 꺾임점을 비스듬하게 깎기 (내부용)
+    ///
+    /// # Arguments
+    /// * `distance` - 꺾임점에서 각 변을 따라 잘라낼 길이 (인접 변 길이의 절반에 맞게 자동 축소)
+    /// * `corners` - 꺾임점 인덱스 (None이면 전부, 열린 Line의 양 끝점은 무시)
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공 (Rect는 Polygon으로 변환, holes는 유지)
+    /// * Ok(false) - name이 존재하지 않음
+    ///
+    /// # Errors
+    /// * distance가 양수가 아님, 지원하지 않는 geometry, 인덱스 범위 초과
+    pub(crate) fn chamfer_internal(
+        &mut self,
+        name: &str,
+        distance: f64,
+        corners: Option<Vec<usize>>,
+    ) -> Result<bool, SceneError> {
+        if !distance.is_finite() || distance <= 0.0 {
+            return Err(SceneError::InvalidInput(
+                "[chamfer] invalid_input: distance must be a positive finite number".to_string(),
+            ));
+        }
+        let Some(source) = self.corner_source("chamfer", name, corners.as_deref())? else {
+            return Ok(false);
+        };
+
+        let mut points =
+            chamfer_points(&source.points, source.closed, corners.as_deref(), distance);
+        if let Some(entity) = self.find_by_name_mut(name) {
+            if matches!(entity.geometry, Geometry::Line { .. }) {
+                if source.closed {
+                    points.push(points[0]);
+                }
+                entity.geometry = Geometry::Line { points };
+            } else {
+                entity.entity_type = EntityType::Polygon;
+                entity.geometry = Geometry::Polygon {
+                    points,
+                    holes: source.holes,
+                };
+            }
+        }
+        self.last_operation = Some(format!("chamfer({}, {})", name, distance));
+        Ok(true)
+    }
+}
+
+// ========================================
+// WASM Bindings for Fillet / Chamfer
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 꺾임점을 반지름 radius로 둥글게 만듭니다. (WASM 바인딩)
+    ///
+    /// # Arguments
+    /// * `name` - Polygon, Rect 또는 Line Entity 이름
+    /// * `radius` - fillet 반지름 (로컬 좌표, 인접 변 길이의 절반을 넘으면 자동 축소)
+    /// * `corners_json` - 꺾임점 인덱스 배열 JSON (예: "[0, 2]"), 빈 문자열/"[]"이면 모든 꺾임점
+    ///   - Rect: 좌하단부터 CCW 0..3, Polygon/Line: points 순서
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공
+    /// * Ok(false) - name이 존재하지 않음
+    ///
+    /// # Errors
+    /// * radius가 0 이하/NaN, 지원하지 않는 타입, holes가 있는 Polygon, 인덱스 범위 초과
+    ///
+    /// # Notes
+    /// 결과는 Bezier로 저장되어 SVG export 시 원호가 곡선 그대로 출력됩니다.
+    /// 열린 Line의 양 끝점과 일직선 위의 점은 가공되지 않습니다.
+    pub fn fillet(&mut self, name: &str, radius: f64, corners_json: &str) -> Result<bool, JsValue> {
+        parse_corners("fillet", corners_json)
+            .and_then(|corners| self.fillet_internal(name, radius, corners))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// 꺾임점을 distance만큼 비스듬하게 깎습니다. (WASM 바인딩)
+    ///
+    /// # Arguments
+    /// * `name` - Polygon, Rect 또는 Line Entity 이름
+    /// * `distance` - 꺾임점에서 각 변을 따라 잘라낼 길이 (인접 변 길이의 절반을 넘으면 자동 축소)
+    /// * `corners_json` - 꺾임점 인덱스 배열 JSON, 빈 문자열/"[]"이면 모든 꺾임점
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공 (Rect는 Polygon으로 변환)
+    /// * Ok(false) - name이 존재하지 않음
+    ///
+    /// # Errors
+    /// * distance가 0 이하/NaN, 지원하지 않는 타입, 인덱스 범위 초과
+    pub fn chamfer(
+        &mut self,
+        name: &str,
+        distance: f64,
+        corners_json: &str,
+    ) -> Result<bool, JsValue> {
+        parse_corners("chamfer", corners_json)
+            .and_then(|corners| self.chamfer_internal(name, distance, corners))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_fillet_rect_becomes_closed_bezier() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("plate", 0.0, 0.0, 20.0, 10.0)
+            .unwrap();
+        assert!(scene.fillet_internal("plate", 2.0, None).unwrap());
+
+        let entity = scene.find_by_name("plate").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Bezier));
+        match &entity.geometry {
+            Geometry::Bezier {
+                start,
+                segments,
+                closed,
+            } => {
+                assert!(*closed);
+                assert_eq!(segments.len(), 8);
+                assert!(approx_eq(start[0], -8.0, 1e-12));
+                assert!(approx_eq(start[1], -5.0, 1e-12));
+            }
+            other => panic!("Expected Bezier geometry, got {:?}", other),
+        }
+        assert_eq!(scene.last_operation.as_deref(), Some("fillet(plate, 2)"));
+
+        // 바운드는 원래 사각형과 동일
+        let (min, max) = scene.get_world_bounds_internal("plate").unwrap();
+        assert!(approx_eq(min[0], -10.0, 1e-9) && approx_eq(max[1], 5.0, 1e-9));
+    }
+
+    #[test]
+    fn test_fillet_selected_corners_and_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.fillet_internal("r", 1.0, Some(vec![2])).unwrap();
+        match &scene.find_by_name("r").unwrap().geometry {
+            Geometry::Bezier { segments, .. } => assert_eq!(segments.len(), 5),
+            other => panic!("Expected Bezier geometry, got {:?}", other),
+        }
+
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+        assert!(scene.fillet_internal("c", 1.0, None).is_err());
+        assert!(!scene.fillet_internal("missing", 1.0, None).unwrap());
+
+        scene.add_rect_internal("r2", 0.0, 0.0, 10.0, 10.0).unwrap();
+        let err = scene
+            .fillet_internal("r2", 1.0, Some(vec![4]))
+            .expect_err("out of range corner should fail");
+        assert!(err.to_string().contains("out of range"));
+    }
+
+    #[test]
+    fn test_chamfer_line_keeps_endpoints() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 10.0, 0.0, 10.0, 10.0])
+            .unwrap();
+        scene.chamfer_internal("l", 2.0, None).unwrap();
+        match &scene.find_by_name("l").unwrap().geometry {
+            Geometry::Line { points } => {
+                assert_eq!(
+                    points,
+                    &vec![[0.0, 0.0], [8.0, 0.0], [10.0, 2.0], [10.0, 10.0]]
+                );
+            }
+            other => panic!("Expected Line geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_chamfer_rect_becomes_polygon() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 5.0, 5.0, 10.0, 10.0).unwrap();
+        scene.chamfer_internal("r", 1.0, None).unwrap();
+        let entity = scene.find_by_name("r").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Polygon));
+        match &entity.geometry {
+            Geometry::Polygon { points, .. } => assert_eq!(points.len(), 8),
+            other => panic!("Expected Polygon geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_corners() {
+        assert_eq!(parse_corners("fillet", "").unwrap(), None);
+        assert_eq!(parse_corners("fillet", "[]").unwrap(), None);
+        assert_eq!(parse_corners("fillet", "[0, 2]").unwrap(), Some(vec![0, 2]));
+        assert!(parse_corners("fillet", "[-1]").is_err());
+    }
+}
@@ -0,0 +1,1030 @@
+//! 치수(Dimension) Entity 모듈
+//!
+//! 다른 Entity를 name으로 참조하는 연관(associative) 치수를 생성합니다.
+//! - 값/치수선/보조선은 저장하지 않고 참조 도형의 현재 월드 좌표로 매번 계산
+//!   → translate/rotate/scale 후에도 export_json/export_svg가 자동으로 갱신된 치수를 출력
+//! - 참조 도형이 삭제되면 레이아웃을 계산할 수 없으므로 렌더링에서 제외
+
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{
+    DimensionAxis, DimensionKind, DimensionPoint, Entity, EntityType, Geometry, Matrix3x3,
+    PointAnchor, StrokeStyle, Style, Transform,
+};
+use super::{Scene, SceneError};
+use crate::geometry::dimension::{
+    DimensionLayout, aligned_layout, angular_layout, linear_layout, radial_layout,
+};
+use crate::geometry::flatten::{arc_sweep, rect_points};
+use crate::geometry::path::path_vertices;
+
+/// 치수 문자 크기 기본값
+pub const DEFAULT_TEXT_SIZE: f64 = 8.0;
+/// 소수점 자릿수 기본값
+pub const DEFAULT_PRECISION: u32 = 2;
+
+/// 치수 기본 스타일: 검은색 가는 선, fill 없음
+fn default_dimension_style() -> Style {
+    Style {
+        stroke: Some(StrokeStyle {
+            width: 0.5,
+            ..StrokeStyle::default()
+        }),
+        fill: None,
+    }
+}
+
+/// 변환 행렬의 선형 부분이 균등 스케일(+회전/반사)이면 그 배율
+///
+/// 두 열 벡터의 길이가 같고 서로 직교해야 원이 원으로 옮겨집니다.
+fn uniform_scale(m: &Matrix3x3) -> Option<f64> {
+    let (a, b) = ([m[0][0], m[1][0]], [m[0][1], m[1][1]]);
+    let (len_a, len_b) = (a[0].hypot(a[1]), b[0].hypot(b[1]));
+    let tolerance = 1e-9 * len_a.max(len_b);
+    let uniform = (len_a - len_b).abs() <= tolerance
+        && (a[0] * b[0] + a[1] * b[1]).abs() <= tolerance * len_a.max(len_b);
+    uniform.then_some(len_a)
+}
+
+/// 각도를 [0, 2π) 기준으로 start에서 CCW로 잰 값이 sweep 안에 있는지
+fn angle_in_sweep(angle: f64, start: f64, sweep: f64) -> bool {
+    (angle - start).rem_euclid(2.0 * std::f64::consts::PI) <= sweep
+}
+
+impl Scene {
+    /// 참조 점의 로컬 좌표 (도형 자체 좌표계)
+    pub(super) fn dimension_local_point(
+        geometry: &Geometry,
+        anchor: PointAnchor,
+    ) -> Option<[f64; 2]> {
+        match (geometry, anchor) {
+            (Geometry::Line { points } | Geometry::Polygon { points, .. }, anchor) => {
+                match anchor {
+                    PointAnchor::Start => points.first().copied(),
+                    PointAnchor::End => points.last().copied(),
+                    PointAnchor::Vertex(i) => points.get(i).copied(),
+                    PointAnchor::Center => None,
+                }
+            }
+            (Geometry::Bezier { start, .. }, PointAnchor::Start | PointAnchor::Vertex(0)) => {
+                Some(*start)
+            }
+            (Geometry::Bezier { segments, .. }, PointAnchor::End) => {
+                segments.last().map(|seg| seg[2])
+            }
+            (Geometry::Bezier { segments, .. }, PointAnchor::Vertex(i)) => {
+                segments.get(i - 1).map(|seg| seg[2])
+            }
+            (Geometry::Path { subpaths, .. }, anchor) => {
+                let vertices = path_vertices(subpaths);
+                match anchor {
+                    PointAnchor::Start => vertices.first().copied(),
+                    PointAnchor::End => vertices.last().copied(),
+                    PointAnchor::Vertex(i) => vertices.get(i).copied(),
+                    PointAnchor::Center => None,
+                }
+            }
+            (
+                Geometry::Circle { center, .. }
+                | Geometry::Arc { center, .. }
+                | Geometry::Ellipse { center, .. }
+                | Geometry::EllipticalArc { center, .. }
+                | Geometry::Rect { center, .. },
+                PointAnchor::Center,
+            ) => Some(*center),
+            (
+                Geometry::Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    end_angle,
+                },
+                PointAnchor::Start | PointAnchor::End,
+            ) => {
+                let angle = if anchor == PointAnchor::Start {
+                    *start_angle
+                } else {
+                    *end_angle
+                };
+                Some([
+                    center[0] + radius * angle.cos(),
+                    center[1] + radius * angle.sin(),
+                ])
+            }
+            (
+                Geometry::Rect {
+                    center,
+                    width,
+                    height,
+                },
+                PointAnchor::Vertex(i),
+            ) => rect_points(*center, *width, *height).get(i).copied(),
+            (Geometry::Text { position, .. }, PointAnchor::Start) => Some(*position),
+            _ => None,
+        }
+    }
+
+    /// 치수 참조 점의 월드 좌표
+    ///
+    /// Center는 중심이 정의되지 않은 도형(Line, Polygon, Group 등)에서 월드 바운드 중심을 사용합니다.
+    pub(super) fn resolve_dimension_point(&self, point: &DimensionPoint) -> Option<[f64; 2]> {
+        let entity = self.find_by_name(&point.entity)?;
+        let world = self.get_world_transform_internal(&point.entity)?;
+
+        if let Some(local) = Self::dimension_local_point(&entity.geometry, point.anchor) {
+            return Some(Transform::transform_point(&world, local));
+        }
+        if point.anchor == PointAnchor::Center {
+            let (min, max) = self.get_world_bounds_internal(&point.entity)?;
+            return Some([(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]);
+        }
+        None
+    }
+
+    /// Circle/Arc의 월드 중심, 반지름, 지시선 각도
+    ///
+    /// 월드 변환이 비균등 스케일/기울임이면 원이 타원이 되어 반지름이 정의되지 않으므로 None입니다.
+    /// Arc에서 angle이 호 범위 밖이면 호의 중간 각도를 사용합니다.
+    fn resolve_radial_target(&self, target: &str, angle: f64) -> Option<([f64; 2], f64, f64)> {
+        let entity = self.find_by_name(target)?;
+        let world = self.get_world_transform_internal(target)?;
+        let det = world[0][0] * world[1][1] - world[0][1] * world[1][0];
+        let scale = uniform_scale(&world)?;
+
+        match &entity.geometry {
+            Geometry::Circle { center, radius } => Some((
+                Transform::transform_point(&world, *center),
+                radius * scale,
+                angle,
+            )),
+            Geometry::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+            } => {
+                // 호 양 끝점을 월드로 옮겨 월드 기준 시작 각도를 구함 (반사 변환이면 방향 반전)
+                let world_center = Transform::transform_point(&world, *center);
+                let world_angle = |a: f64| {
+                    let p = Transform::transform_point(
+                        &world,
+                        [center[0] + radius * a.cos(), center[1] + radius * a.sin()],
+                    );
+                    (p[1] - world_center[1]).atan2(p[0] - world_center[0])
+                };
+                let sweep = arc_sweep(*start_angle, *end_angle);
+                let start = if det < 0.0 {
+                    world_angle(*end_angle)
+                } else {
+                    world_angle(*start_angle)
+                };
+                let angle = if angle_in_sweep(angle, start, sweep) {
+                    angle
+                } else {
+                    start + sweep / 2.0
+                };
+                Some((world_center, radius * scale, angle))
+            }
+            _ => None,
+        }
+    }
+
+    /// Line의 첫 세그먼트 (월드 좌표)
+    fn resolve_line_segment(&self, name: &str) -> Option<([f64; 2], [f64; 2])> {
+        let entity = self.find_by_name(name)?;
+        let world = self.get_world_transform_internal(name)?;
+        match &entity.geometry {
+            Geometry::Line { points } if points.len() >= 2 => Some((
+                Transform::transform_point(&world, points[0]),
+                Transform::transform_point(&world, points[1]),
+            )),
+            _ => None,
+        }
+    }
+
+    /// 치수 Entity 자신의 변환을 월드 좌표계로 옮긴 행렬 (레이아웃 오프셋)
+    ///
+    /// 부모 그룹 변환은 참조 도형 위치에 이미 반영되므로 자신의 로컬 변환만 사용합니다.
+    /// 월드 기준 이동(translate_world)이 그대로 월드 이동이 되도록 P·L·P⁻¹ = World·P⁻¹ 입니다.
+    fn dimension_offset(&self, entity: &Entity) -> Option<Matrix3x3> {
+        let world = self.get_world_transform_internal(&entity.metadata.name)?;
+        match self.parent_of(entity) {
+            Some(parent) => {
+                let parent_world = self.get_world_transform_internal(&parent.metadata.name)?;
+                let inverse = Transform::inverse_matrix(&parent_world)?;
+                Some(Transform::multiply_matrices(&world, &inverse))
+            }
+            None => Some(world),
+        }
+    }
+
+    /// 치수 Entity의 월드 좌표 레이아웃 (내부용)
+    ///
+    /// 참조 도형으로 계산한 레이아웃에 치수 Entity 자신의 변환을 오프셋으로 적용합니다.
+    ///
+    /// # Returns
+    /// * None - 치수가 아니거나, 참조 도형이 없거나, 계산 불가
+    ///   (평행선 각도, 비균등 스케일로 타원이 된 원의 반지름/지름 등)
+    pub(crate) fn dimension_world_layout(&self, name: &str) -> Option<DimensionLayout> {
+        let entity = self.find_by_name(name)?;
+        let Geometry::Dimension {
+            kind,
+            text_size,
+            precision,
+        } = &entity.geometry
+        else {
+            return None;
+        };
+        let (text_size, precision) = (*text_size, *precision);
+
+        let layout = match kind {
+            DimensionKind::Linear {
+                from,
+                to,
+                axis,
+                offset,
+            } => linear_layout(
+                self.resolve_dimension_point(from)?,
+                self.resolve_dimension_point(to)?,
+                *axis,
+                *offset,
+                text_size,
+                precision,
+            ),
+            DimensionKind::Aligned { from, to, offset } => aligned_layout(
+                self.resolve_dimension_point(from)?,
+                self.resolve_dimension_point(to)?,
+                *offset,
+                text_size,
+                precision,
+            ),
+            DimensionKind::Radius { target, angle } | DimensionKind::Diameter { target, angle } => {
+                let (center, radius, angle) = self.resolve_radial_target(target, *angle)?;
+                let diameter = matches!(kind, DimensionKind::Diameter { .. });
+                radial_layout(center, radius, angle, diameter, text_size, precision)
+            }
+            DimensionKind::Angular {
+                line_a,
+                line_b,
+                radius,
+            } => {
+                let (a0, a1) = self.resolve_line_segment(line_a)?;
+                let (b0, b1) = self.resolve_line_segment(line_b)?;
+                let da = [a1[0] - a0[0], a1[1] - a0[1]];
+                let db = [b1[0] - b0[0], b1[1] - b0[1]];
+                let cross = da[0] * db[1] - da[1] * db[0];
+                if cross.abs() < 1e-12 {
+                    return None;
+                }
+                // 두 무한 직선의 교점
+                let t = ((b0[0] - a0[0]) * db[1] - (b0[1] - a0[1]) * db[0]) / cross;
+                let vertex = [a0[0] + da[0] * t, a0[1] + da[1] * t];
+
+                // 교점에서 각 선분의 중점 쪽 방향, 선분 끝까지의 최대 거리
+                let direction = |p0: [f64; 2], p1: [f64; 2]| {
+                    let mid = [
+                        (p0[0] + p1[0]) / 2.0 - vertex[0],
+                        (p0[1] + p1[1]) / 2.0 - vertex[1],
+                    ];
+                    let reach = [p0, p1]
+                        .iter()
+                        .map(|p| (p[0] - vertex[0]).hypot(p[1] - vertex[1]))
+                        .fold(0.0, f64::max);
+                    let angle = if mid[0].hypot(mid[1]) < 1e-12 {
+                        (p1[1] - p0[1]).atan2(p1[0] - p0[0])
+                    } else {
+                        mid[1].atan2(mid[0])
+                    };
+                    (angle, reach)
+                };
+                let (angle_a, reach_a) = direction(a0, a1);
+                let (angle_b, reach_b) = direction(b0, b1);
+                angular_layout(
+                    vertex, angle_a, angle_b, reach_a, reach_b, *radius, text_size, precision,
+                )
+            }
+        };
+
+        let offset = self.dimension_offset(entity)?;
+        if offset == Transform::identity_matrix() {
+            Some(layout)
+        } else {
+            Some(layout.transformed(&offset))
+        }
+    }
+
+    /// 치수 Entity의 월드 레이아웃 (직렬화용 public wrapper)
+    pub fn get_dimension_layout_for_entity(&self, name: &str) -> Option<DimensionLayout> {
+        self.dimension_world_layout(name)
+    }
+
+    /// SVG 렌더링용: 각 치수의 레이아웃을 치수 Entity 자신의 로컬 좌표계로 변환
+    ///
+    /// SVG는 Entity transform과 부모 Group transform을 그대로 적용하므로
+    /// 월드 레이아웃에 월드 변환의 역행렬을 곱해 넘깁니다.
+    pub(crate) fn dimension_local_layouts(&self) -> HashMap<String, DimensionLayout> {
+        self.entities
+            .iter()
+            .filter(|e| matches!(e.entity_type, EntityType::Dimension))
+            .filter_map(|e| {
+                let name = &e.metadata.name;
+                let layout = self.dimension_world_layout(name)?;
+                let world: Matrix3x3 = self.get_world_transform_internal(name)?;
+                let inverse = Transform::inverse_matrix(&world)?;
+                Some((name.clone(), layout.transformed(&inverse)))
+            })
+            .collect()
+    }
+
+    /// 참조 Entity 존재 확인
+    fn require_entity(&self, fn_name: &str, name: &str) -> Result<(), SceneError> {
+        if self.has_entity(name) {
+            Ok(())
+        } else {
+            Err(SceneError::EntityNotFound(
+                fn_name.to_string(),
+                name.to_string(),
+            ))
+        }
+    }
+
+    /// 참조 점 검증 (Entity 존재 + 해당 도형에서 유효한 anchor)
+    fn validate_dimension_point(
+        &self,
+        fn_name: &str,
+        point: &DimensionPoint,
+    ) -> Result<(), SceneError> {
+        self.require_entity(fn_name, &point.entity)?;
+        if self.resolve_dimension_point(point).is_none() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: anchor {:?} is not available on '{}'",
+                fn_name, point.anchor, point.entity
+            )));
+        }
+        Ok(())
+    }
+
+    /// 참조 Entity가 기대하는 타입인지 검증
+    pub(super) fn require_entity_type(
+        &self,
+        fn_name: &str,
+        name: &str,
+        allowed: &[EntityType],
+    ) -> Result<(), SceneError> {
+        self.require_entity(fn_name, name)?;
+        let entity_type = &self.find_by_name(name).map(|e| e.entity_type.clone());
+        if allowed.iter().any(|t| Some(t) == entity_type.as_ref()) {
+            return Ok(());
+        }
+        let expected: Vec<&str> = allowed.iter().map(|t| t.as_str()).collect();
+        Err(SceneError::InvalidInput(format!(
+            "[{}] invalid_input: '{}' must be {}",
+            fn_name,
+            name,
+            expected.join(" or ")
+        )))
+    }
+
+    /// 내부용 치수 생성 함수 (테스트용)
+    ///
+    /// 참조 Entity 존재/타입을 검증한 뒤 기본 스타일(검은색 0.5 stroke)로 추가합니다.
+    ///
+    /// # Errors
+    /// * 참조 Entity가 없으면 EntityNotFound
+    /// * 타입이 맞지 않거나(Radius/Diameter → Circle/Arc, Angular → Line) anchor가 유효하지 않으면 InvalidInput
+    /// * Radius/Diameter 대상이 비균등 스케일/기울임으로 타원이 되었으면 InvalidInput
+    pub(crate) fn add_dimension_internal(
+        &mut self,
+        fn_name: &str,
+        name: &str,
+        kind: DimensionKind,
+    ) -> Result<String, SceneError> {
+        match &kind {
+            DimensionKind::Linear {
+                from, to, offset, ..
+            }
+            | DimensionKind::Aligned { from, to, offset } => {
+                if !offset.is_finite() {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: NaN or Infinity not allowed",
+                        fn_name
+                    )));
+                }
+                self.validate_dimension_point(fn_name, from)?;
+                self.validate_dimension_point(fn_name, to)?;
+            }
+            DimensionKind::Radius { target, angle } | DimensionKind::Diameter { target, angle } => {
+                if !angle.is_finite() {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: NaN or Infinity not allowed",
+                        fn_name
+                    )));
+                }
+                self.require_entity_type(fn_name, target, &[EntityType::Circle, EntityType::Arc])?;
+                if self.resolve_radial_target(target, *angle).is_none() {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: '{}' has non-uniform world scale, radius is undefined",
+                        fn_name, target
+                    )));
+                }
+            }
+            DimensionKind::Angular {
+                line_a,
+                line_b,
+                radius,
+            } => {
+                if !radius.is_finite() {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: NaN or Infinity not allowed",
+                        fn_name
+                    )));
+                }
+                self.require_entity_type(fn_name, line_a, &[EntityType::Line])?;
+                self.require_entity_type(fn_name, line_b, &[EntityType::Line])?;
+            }
+        }
+
+        self.add_entity_internal(
+            fn_name,
+            name,
+            EntityType::Dimension,
+            Geometry::Dimension {
+                kind,
+                text_size: DEFAULT_TEXT_SIZE,
+                precision: DEFAULT_PRECISION,
+            },
+        )?;
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.style = default_dimension_style();
+        }
+        self.last_operation = Some(format!("{}({})", fn_name, name));
+        Ok(name.to_string())
+    }
+
+    /// 두 참조 점으로 치수 생성 (anchor 문자열 파싱 포함)
+    #[allow(clippy::too_many_arguments)]
+    fn add_point_dimension(
+        &mut self,
+        fn_name: &str,
+        name: &str,
+        from_entity: &str,
+        from_anchor: &str,
+        to_entity: &str,
+        to_anchor: &str,
+        make: impl FnOnce(DimensionPoint, DimensionPoint) -> DimensionKind,
+    ) -> Result<String, SceneError> {
+        let parse = |anchor: &str| {
+            PointAnchor::parse_str(anchor).ok_or_else(|| {
+                SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: unknown anchor '{}' (expected start, end, center or vertex:N)",
+                    fn_name, anchor
+                ))
+            })
+        };
+        let from = DimensionPoint {
+            entity: from_entity.to_string(),
+            anchor: parse(from_anchor)?,
+        };
+        let to = DimensionPoint {
+            entity: to_entity.to_string(),
+            anchor: parse(to_anchor)?,
+        };
+        self.add_dimension_internal(fn_name, name, make(from, to))
+    }
+
+    /// 치수 문자 크기/자릿수 변경 (내부용)
+    pub(crate) fn set_dimension_format_internal(
+        &mut self,
+        name: &str,
+        text_size: f64,
+        precision: u32,
+    ) -> Result<bool, SceneError> {
+        if !text_size.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[set_dimension_format] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let Some(entity) = self.find_by_name_mut(name) else {
+            return Ok(false);
+        };
+        let Geometry::Dimension {
+            text_size: size,
+            precision: digits,
+            ..
+        } = &mut entity.geometry
+        else {
+            return Err(SceneError::InvalidInput(format!(
+                "[set_dimension_format] invalid_input: '{}' is not a Dimension",
+                name
+            )));
+        };
+        *size = text_size.abs().max(0.001);
+        *digits = precision.min(10);
+        self.last_operation = Some(format!("set_dimension_format({})", name));
+        Ok(true)
+    }
+
+    /// Entity 이름 변경 시 그 이름을 참조하는 치수들의 참조를 함께 바꿉니다.
+    ///
+    /// 치수 바운드는 질의 때마다 계산되므로 공간 인덱스 갱신은 필요 없습니다.
+    pub(super) fn rename_dimension_references(&mut self, old_name: &str, new_name: &str) {
+        for entity in &mut self.entities {
+            let Geometry::Dimension { kind, .. } = &mut entity.geometry else {
+                continue;
+            };
+            for reference in kind.references_mut() {
+                if reference == old_name {
+                    *reference = new_name.to_string();
+                }
+            }
+        }
+    }
+}
+
+// ========================================
+// WASM Bindings for Dimension
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 두 점 사이의 수평/수직 치수를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - 치수 Entity 이름 - Scene 내 unique
+    /// * `from_entity`, `from_anchor` - 첫 점 (anchor: "start" | "end" | "center" | "vertex:N")
+    /// * `to_entity`, `to_anchor` - 둘째 점
+    /// * `axis` - "horizontal" | "vertical" (그 외 → horizontal)
+    /// * `offset` - 치수선 위치 (바깥쪽 거리, 음수면 아래/왼쪽)
+    ///
+    /// # Errors
+    /// * 참조 Entity가 없거나 anchor가 유효하지 않으면 에러
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_linear_dimension(
+        &mut self,
+        name: &str,
+        from_entity: &str,
+        from_anchor: &str,
+        to_entity: &str,
+        to_anchor: &str,
+        axis: &str,
+        offset: f64,
+    ) -> Result<String, JsValue> {
+        let axis = if axis.trim().eq_ignore_ascii_case("vertical") {
+            DimensionAxis::Vertical
+        } else {
+            DimensionAxis::Horizontal
+        };
+        self.add_point_dimension(
+            "add_linear_dimension",
+            name,
+            from_entity,
+            from_anchor,
+            to_entity,
+            to_anchor,
+            |from, to| DimensionKind::Linear {
+                from,
+                to,
+                axis,
+                offset,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 두 점을 잇는 방향의 정렬(aligned) 치수를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `offset` - 치수선 위치 (from → to 방향의 왼쪽이 양수)
+    ///
+    /// # Errors
+    /// * 참조 Entity가 없거나 anchor가 유효하지 않으면 에러
+    pub fn add_aligned_dimension(
+        &mut self,
+        name: &str,
+        from_entity: &str,
+        from_anchor: &str,
+        to_entity: &str,
+        to_anchor: &str,
+        offset: f64,
+    ) -> Result<String, JsValue> {
+        self.add_point_dimension(
+            "add_aligned_dimension",
+            name,
+            from_entity,
+            from_anchor,
+            to_entity,
+            to_anchor,
+            |from, to| DimensionKind::Aligned { from, to, offset },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Circle/Arc의 반지름 치수를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `target` - Circle 또는 Arc 이름
+    /// * `angle` - 지시선 각도 (라디안, 월드 기준; Arc 범위 밖이면 호의 중간)
+    pub fn add_radius_dimension(
+        &mut self,
+        name: &str,
+        target: &str,
+        angle: f64,
+    ) -> Result<String, JsValue> {
+        self.add_dimension_internal(
+            "add_radius_dimension",
+            name,
+            DimensionKind::Radius {
+                target: target.to_string(),
+                angle,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// Circle/Arc의 지름 치수를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `target` - Circle 또는 Arc 이름
+    /// * `angle` - 지름선 각도 (라디안, 월드 기준)
+    pub fn add_diameter_dimension(
+        &mut self,
+        name: &str,
+        target: &str,
+        angle: f64,
+    ) -> Result<String, JsValue> {
+        self.add_dimension_internal(
+            "add_diameter_dimension",
+            name,
+            DimensionKind::Diameter {
+                target: target.to_string(),
+                angle,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 두 Line 사이의 각도 치수를 생성합니다 (작은 쪽 각도, ≤ 180°).
+    ///
+    /// # Arguments
+    /// * `line_a`, `line_b` - Line 이름 (첫 세그먼트 사용)
+    /// * `radius` - 교점 기준 치수 호의 반지름
+    pub fn add_angular_dimension(
+        &mut self,
+        name: &str,
+        line_a: &str,
+        line_b: &str,
+        radius: f64,
+    ) -> Result<String, JsValue> {
+        self.add_dimension_internal(
+            "add_angular_dimension",
+            name,
+            DimensionKind::Angular {
+                line_a: line_a.to_string(),
+                line_b: line_b.to_string(),
+                radius,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 치수 문자 크기와 소수점 자릿수를 변경합니다.
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공, Ok(false) - Entity 없음
+    ///
+    /// # Errors
+    /// * Dimension이 아니거나 NaN/Infinity 입력 시 에러
+    pub fn set_dimension_format(
+        &mut self,
+        name: &str,
+        text_size: f64,
+        precision: u32,
+    ) -> Result<bool, JsValue> {
+        self.set_dimension_format_internal(name, text_size, precision)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 치수의 현재 측정값과 레이아웃(월드 좌표)을 JSON으로 반환합니다.
+    ///
+    /// # Returns
+    /// * Some(JSON) - { value, text, text_position, text_angle, text_size, lines, arrows, ... }
+    /// * None - 치수가 아니거나 참조 도형이 삭제된 경우,
+    ///   또는 반지름/지름 대상 원이 비균등 스케일로 타원이 된 경우 (치수 무효)
+    pub fn get_dimension(&self, name: &str) -> Option<String> {
+        let layout = self.dimension_world_layout(name)?;
+        serde_json::to_string(&layout).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_radius_dimension_follows_scale() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 10.0).unwrap();
+        scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "r",
+                DimensionKind::Radius {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+        assert_eq!(scene.dimension_world_layout("r").unwrap().text, "R10.00");
+
+        scene.find_by_name_mut("c").unwrap().transform.scale = [2.0, 2.0];
+        let layout = scene.dimension_world_layout("r").unwrap();
+        assert!(approx_eq(layout.value, 20.0, 1e-10));
+    }
+
+    #[test]
+    fn test_aligned_dimension_follows_translate() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("a", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("b", 30.0, 40.0, 1.0).unwrap();
+        scene
+            .add_point_dimension(
+                "add_aligned_dimension",
+                "d",
+                "a",
+                "center",
+                "b",
+                "center",
+                |from, to| DimensionKind::Aligned {
+                    from,
+                    to,
+                    offset: 5.0,
+                },
+            )
+            .unwrap();
+        assert!(approx_eq(
+            scene.dimension_world_layout("d").unwrap().value,
+            50.0,
+            1e-10
+        ));
+
+        scene.find_by_name_mut("b").unwrap().transform.translate = [30.0, -40.0];
+        assert!(approx_eq(
+            scene.dimension_world_layout("d").unwrap().value,
+            60.0,
+            1e-10
+        ));
+    }
+
+    #[test]
+    fn test_linear_dimension_line_endpoints() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 25.0, 10.0])
+            .unwrap();
+        scene
+            .add_point_dimension(
+                "add_linear_dimension",
+                "d",
+                "l",
+                "start",
+                "l",
+                "end",
+                |from, to| DimensionKind::Linear {
+                    from,
+                    to,
+                    axis: DimensionAxis::Vertical,
+                    offset: 5.0,
+                },
+            )
+            .unwrap();
+        let layout = scene.dimension_world_layout("d").unwrap();
+        assert!(approx_eq(layout.value, 10.0, 1e-10));
+        assert_eq!(layout.text, "10.00");
+    }
+
+    #[test]
+    fn test_angular_dimension_follows_rotate() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("a", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .add_line_internal("b", vec![0.0, 0.0, 0.0, 10.0])
+            .unwrap();
+        scene
+            .add_dimension_internal(
+                "add_angular_dimension",
+                "ang",
+                DimensionKind::Angular {
+                    line_a: "a".to_string(),
+                    line_b: "b".to_string(),
+                    radius: 5.0,
+                },
+            )
+            .unwrap();
+        assert!(approx_eq(
+            scene.dimension_world_layout("ang").unwrap().value,
+            90.0,
+            1e-9
+        ));
+
+        // b를 원점 기준 -45° 회전 → 45°
+        let b = scene.find_by_name_mut("b").unwrap();
+        b.transform.pivot = [0.0, 0.0];
+        b.transform.rotate = -std::f64::consts::FRAC_PI_4;
+        assert!(approx_eq(
+            scene.dimension_world_layout("ang").unwrap().value,
+            45.0,
+            1e-9
+        ));
+    }
+
+    #[test]
+    fn test_radius_dimension_invalid_under_non_uniform_scale() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 10.0).unwrap();
+        scene.add_circle_internal("e", 50.0, 0.0, 10.0).unwrap();
+        scene.scale("e", 2.0, 1.0).unwrap();
+
+        let err = scene
+            .add_dimension_internal(
+                "add_diameter_dimension",
+                "d",
+                DimensionKind::Diameter {
+                    target: "e".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .expect_err("ellipse has no diameter");
+        assert_eq!(
+            err.to_string(),
+            "[add_diameter_dimension] invalid_input: 'e' has non-uniform world scale, radius is undefined"
+        );
+
+        // 생성 후 비균등 스케일 → 치수 무효 (레이아웃 없음), 균등으로 되돌리면 복구
+        scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "r",
+                DimensionKind::Radius {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+        scene.scale("c", 1.0, 3.0).unwrap();
+        assert!(scene.dimension_world_layout("r").is_none());
+        assert!(scene.get_dimension("r").is_none());
+        scene.scale("c", 3.0, 1.0).unwrap();
+        let layout = scene.dimension_world_layout("r").unwrap();
+        assert!(approx_eq(layout.value, 30.0, 1e-9));
+    }
+
+    #[test]
+    fn test_dimension_own_transform_is_offset() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .add_dimension_internal(
+                "add_aligned_dimension",
+                "d",
+                DimensionKind::Aligned {
+                    from: DimensionPoint {
+                        entity: "l".to_string(),
+                        anchor: PointAnchor::Start,
+                    },
+                    to: DimensionPoint {
+                        entity: "l".to_string(),
+                        anchor: PointAnchor::End,
+                    },
+                    offset: 5.0,
+                },
+            )
+            .unwrap();
+        let before = scene.dimension_world_layout("d").unwrap();
+
+        scene.translate("d", 0.0, 7.0).unwrap();
+        let after = scene.dimension_world_layout("d").unwrap();
+        assert_eq!(after.value, before.value);
+        assert!(approx_eq(
+            after.text_position[1],
+            before.text_position[1] + 7.0,
+            1e-9
+        ));
+
+        // 회전된 그룹 안에서도 월드 기준 이동은 월드 이동
+        scene.translate("d", 0.0, -7.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["l".to_string(), "d".to_string()])
+            .unwrap();
+        scene.rotate("g", std::f64::consts::FRAC_PI_2).unwrap();
+        let rotated = scene.dimension_world_layout("d").unwrap();
+        scene.translate_world("d", 3.0, 0.0).unwrap();
+        let moved = scene.dimension_world_layout("d").unwrap();
+        assert!(approx_eq(
+            moved.text_position[0],
+            rotated.text_position[0] + 3.0,
+            1e-9
+        ));
+        assert!(approx_eq(
+            moved.text_position[1],
+            rotated.text_position[1],
+            1e-9
+        ));
+
+        // SVG도 같은 위치 (로컬 레이아웃 + 자신의 변환 = 월드 레이아웃)
+        let local = &scene.dimension_local_layouts()["d"];
+        let world = scene.get_world_transform_internal("d").unwrap();
+        let p = Transform::transform_point(&world, local.text_position);
+        assert!(approx_eq(p[0], moved.text_position[0], 1e-9));
+        assert!(approx_eq(p[1], moved.text_position[1], 1e-9));
+    }
+
+    #[test]
+    fn test_dimension_reference_errors() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+
+        let err = scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "r",
+                DimensionKind::Radius {
+                    target: "missing".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .expect_err("missing target should error");
+        assert_eq!(
+            err.to_string(),
+            "[add_radius_dimension] not_found: Entity 'missing' not found"
+        );
+
+        let err = scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "r",
+                DimensionKind::Radius {
+                    target: "l".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .expect_err("line is not radial");
+        assert_eq!(
+            err.to_string(),
+            "[add_radius_dimension] invalid_input: 'l' must be Circle or Arc"
+        );
+    }
+
+    #[test]
+    fn test_deleted_reference_yields_no_layout() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+        scene
+            .add_dimension_internal(
+                "add_diameter_dimension",
+                "d",
+                DimensionKind::Diameter {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+        scene.delete("c").unwrap();
+        assert!(scene.dimension_world_layout("d").is_none());
+        assert!(scene.get_world_bounds_internal("d").is_none());
+    }
+
+    #[test]
+    fn test_dimension_svg_and_json() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+        scene
+            .add_dimension_internal(
+                "add_diameter_dimension",
+                "d",
+                DimensionKind::Diameter {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+
+        let svg = scene.export_svg();
+        assert!(svg.contains("Ø10.00"));
+
+        let json = scene.export_json();
+        assert!(json.contains("\"dimension\""));
+        assert!(json.contains("Ø10.00"));
+    }
+}
@@ -0,0 +1,196 @@
+//! 거리 측정 모듈
+//!
+//! 두 Entity 외곽선 사이의 월드 좌표 최단 거리와 가장 가까운 두 점을 계산합니다.
+//! "문이 벽에서 5 이상 떨어져 있는가" 같은 간격 검증을 바운딩 박스 비교 없이 할 수 있습니다.
+//! - 외곽선 조각은 교점 계산과 같은 분해를 사용 (scene/intersect.rs `collect_world_edges`)
+//! - Line/Rect/Polygon 변과 (회전·균일 스케일·반전 변환 아래의) Circle/Arc/Path 원호는 해석적으로 정확
+//! - Bezier 조각(타원, 비균일 스케일된 원호 포함)은 허용 오차 이내의 선분으로 근사
+//! - Group은 하위 도형 전체의 외곽선
+//! - 곡선 간 거리 계산: geometry/distance.rs
+
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use super::{Scene, SceneError};
+use crate::geometry::distance::{Closest, Curve, curves_distance};
+use crate::geometry::flatten::{cubic_point, cubic_steps_for_tolerance};
+use crate::geometry::intersect::Edge;
+
+impl Scene {
+    /// 두 Entity 외곽선 사이의 최단 거리와 가장 가까운 두 점을 계산합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `a`, `b` - Entity 또는 Group 이름
+    ///
+    /// # Returns
+    /// `Closest { distance, a, b }` (`a`는 첫 번째 Entity 위의 점)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 외곽선이 없는 도형 (Dimension, 빈 그룹)
+    pub(crate) fn measure_distance_internal(
+        &self,
+        a: &str,
+        b: &str,
+    ) -> Result<Closest, SceneError> {
+        let curves_a = self.measurable_curves(a)?;
+        let curves_b = self.measurable_curves(b)?;
+        Ok(curves_distance(&curves_a, &curves_b).expect("both curve sets are non-empty"))
+    }
+
+    fn measurable_curves(&self, name: &str) -> Result<Vec<Curve>, SceneError> {
+        let Some(entity) = self.find_by_name(name) else {
+            return Err(SceneError::EntityNotFound(
+                "measure_distance".to_string(),
+                name.to_string(),
+            ));
+        };
+        let mut leaves = Vec::new();
+        self.collect_world_edges(name, &mut leaves);
+
+        let mut curves = Vec::new();
+        for leaf in leaves {
+            for edge in leaf.edges {
+                match edge {
+                    Edge::Curve(curve) => curves.push(curve),
+                    Edge::Cubic(p) => {
+                        let steps = cubic_steps_for_tolerance(&p, leaf.tolerance);
+                        let points: Vec<[f64; 2]> = (0..=steps)
+                            .map(|i| cubic_point(p[0], p[1], p[2], p[3], i as f64 / steps as f64))
+                            .collect();
+                        curves.extend(points.windows(2).map(|w| Curve::Segment(w[0], w[1])));
+                    }
+                }
+            }
+        }
+        if curves.is_empty() {
+            return Err(SceneError::InvalidInput(format!(
+                "[measure_distance] invalid_input: Entity '{}' ({}) has no outline to measure",
+                name,
+                entity.entity_type.as_str()
+            )));
+        }
+        Ok(curves)
+    }
+}
+
+// ========================================
+// WASM Bindings for Distance Measurement
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 두 Entity 외곽선 사이의 월드 좌표 최단 거리와 가장 가까운 두 점을 반환합니다.
+    ///
+    /// 채움 영역이 아닌 외곽선(stroke 중심선) 기준이므로, 방 안에 놓인 가구도 벽까지의
+    /// 간격을 반환합니다. 외곽선이 교차하거나 닿으면 거리는 0입니다.
+    /// - 직선 변(Line, Rect, Polygon)과 Circle/Arc/Path 원호는 정확 (그룹 회전·균일 스케일·반전 포함)
+    /// - Bezier, 타원, 비균일 스케일된 원호는 바운드 대각선의 약 1e-5 이내로 근사
+    /// - Group은 하위 도형 전체 기준
+    ///
+    /// # Arguments
+    /// * `a`, `b` - Entity 또는 Group 이름
+    ///
+    /// # Returns
+    /// JSON: `{"distance": number, "point_a": [x, y], "point_b": [x, y]}`
+    /// (`point_a`는 `a` 위, `point_b`는 `b` 위의 가장 가까운 점)
+    ///
+    /// # Errors
+    /// * Entity 미발견, Dimension이나 빈 그룹처럼 외곽선이 없는 도형
+    pub fn measure_distance(&self, a: &str, b: &str) -> Result<String, JsValue> {
+        let closest = self
+            .measure_distance_internal(a, b)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&json!({
+            "distance": closest.distance,
+            "point_a": closest.a,
+            "point_b": closest.b,
+        }))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_measure_rect_and_circle_exact() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("wall", 0.0, 0.0, 10.0, 2.0)
+            .unwrap();
+        scene.add_circle_internal("post", 5.0, 10.0, 3.0).unwrap();
+
+        let closest = scene.measure_distance_internal("wall", "post").unwrap();
+        let (_, max) = scene.get_world_bounds_internal("wall").unwrap();
+        assert!(approx_eq(closest.distance, 10.0 - 3.0 - max[1], 1e-12));
+        assert!(approx_eq(closest.a[1], max[1], 1e-12));
+        assert!(approx_eq(closest.b[0], 5.0, 1e-12) && approx_eq(closest.b[1], 7.0, 1e-12));
+
+        // 순서를 바꾸면 점도 바뀜
+        let swapped = scene.measure_distance_internal("post", "wall").unwrap();
+        assert_eq!(swapped.distance, closest.distance);
+        assert_eq!(swapped.a, closest.b);
+    }
+
+    #[test]
+    fn test_measure_under_group_transform() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c1", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("c2", 5.0, 0.0, 1.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["c2".to_string()])
+            .unwrap();
+        scene.scale("g", 2.0, 2.0).unwrap();
+        scene.rotate("g", PI / 2.0).unwrap();
+
+        // 월드: c2 중심 (0, 10), 반지름 2 → 간격 10 - 1 - 2
+        let closest = scene.measure_distance_internal("c1", "g").unwrap();
+        assert!(approx_eq(closest.distance, 7.0, 1e-9));
+        assert!(approx_eq(closest.a[1], 1.0, 1e-9));
+        assert!(approx_eq(closest.b[1], 8.0, 1e-9));
+
+        // 비균일 스케일: 근사 경로 사용
+        scene.scale("c1", 1.0, 2.0).unwrap();
+        let closest = scene.measure_distance_internal("c1", "g").unwrap();
+        assert!(approx_eq(closest.distance, 6.0, 1e-3));
+    }
+
+    #[test]
+    fn test_measure_bezier_within_tolerance_and_touching() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("hump", "M 0,0 C 0,4 10,4 10,0", "{}")
+            .unwrap();
+        scene
+            .add_line_internal("floor", vec![-5.0, 10.0, 15.0, 10.0])
+            .unwrap();
+        // 큐빅의 최고점 y = 3
+        let closest = scene.measure_distance_internal("hump", "floor").unwrap();
+        assert!(approx_eq(closest.distance, 7.0, 1e-4));
+
+        scene
+            .add_line_internal("cross", vec![5.0, -1.0, 5.0, 5.0])
+            .unwrap();
+        let closest = scene.measure_distance_internal("hump", "cross").unwrap();
+        assert_eq!(closest.distance, 0.0);
+    }
+
+    #[test]
+    fn test_measure_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        scene.create_group_internal("empty", vec![]).unwrap();
+        assert!(matches!(
+            scene.measure_distance_internal("c", "missing"),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        let err = scene.measure_distance_internal("c", "empty").unwrap_err();
+        assert!(err.to_string().contains("no outline"));
+    }
+}
@@ -0,0 +1,309 @@
+//! 복제 모듈
+//!
+//! Entity 또는 Group 하위 트리 전체를 새 이름으로 깊은 복사합니다.
+//! - 새 id, 스타일/변환/자식 z-order 그대로, 원본 바로 위 z-order (scene/array.rs `copy_subtree`)
+//! - 자식 이름: `{원본 이름}_`으로 시작하면 그 부분을 새 이름으로 바꾸고 (`robot_arm` → `robot2_arm`),
+//!   아니면 새 이름을 접두사로 붙임 (`arm`, `robots` → `robot2_arm`, `robot2_robots`).
+//!   이미 있으면 `_2`, `_3`, ... 을 덧붙임
+//! - 오프셋은 월드 좌표 (translate_world)
+
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use super::{Scene, SceneError};
+
+/// 복제 결과
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Duplicated {
+    /// 복제본 root 이름
+    pub name: String,
+    /// 원본 이름 → 복제본 이름 (root 포함 하위 트리 전체)
+    pub names: BTreeMap<String, String>,
+}
+
+impl Scene {
+    /// 기존 Entity와 이번에 정한 이름 모두와 겹치지 않는 이름 (`base`, `base_2`, `base_3`, ...)
+    fn unique_copy_name(&self, base: &str, taken: &HashSet<String>) -> String {
+        let free = |candidate: &str| !self.has_entity(candidate) && !taken.contains(candidate);
+        if free(base) {
+            return base.to_string();
+        }
+        (2..)
+            .map(|k| format!("{}_{}", base, k))
+            .find(|candidate| free(candidate))
+            .unwrap_or_default()
+    }
+
+    /// Entity/Group을 깊은 복사합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `name` - 원본 Entity/Group 이름
+    /// * `new_name` - 복제본 이름 ("" = `{name}_copy`, 있으면 `{name}_copy_2`, ...)
+    /// * `dx`, `dy` - 복제본 이동량 (월드 좌표)
+    ///
+    /// # Errors
+    /// * Entity 미발견, new_name 중복, NaN/Infinity, 부모 변환을 역산할 수 없는 경우
+    ///   (이때 복제본을 만들지 않음)
+    pub(crate) fn duplicate_internal(
+        &mut self,
+        name: &str,
+        new_name: &str,
+        dx: f64,
+        dy: f64,
+    ) -> Result<Duplicated, SceneError> {
+        if !dx.is_finite() || !dy.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[duplicate] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let source = self
+            .handle_of(name)
+            .ok_or_else(|| SceneError::EntityNotFound("duplicate".to_string(), name.to_string()))?;
+
+        self.check_translate_world(name, dx, dy)?;
+
+        let mut taken = HashSet::new();
+        let root_name = if new_name.is_empty() {
+            self.unique_copy_name(&format!("{}_copy", name), &taken)
+        } else if self.has_entity(new_name) {
+            return Err(SceneError::DuplicateEntityName(
+                "duplicate".to_string(),
+                new_name.to_string(),
+            ));
+        } else {
+            new_name.to_string()
+        };
+        taken.insert(root_name.clone());
+
+        let mut names = BTreeMap::new();
+        names.insert(name.to_string(), root_name.clone());
+        for child in self.subtree_names(source).into_iter().skip(1) {
+            let base = match child.strip_prefix(name) {
+                Some(rest) if rest.starts_with('_') => format!("{}{}", root_name, rest),
+                _ => format!("{}_{}", root_name, child),
+            };
+            let unique = self.unique_copy_name(&base, &taken);
+            taken.insert(unique.clone());
+            names.insert(child, unique);
+        }
+
+        let rename = |n: &str| names.get(n).cloned().unwrap_or_else(|| n.to_string());
+        self.copy_subtree(source, &rename, source);
+        if dx != 0.0 || dy != 0.0 {
+            self.translate_world_internal(&root_name, dx, dy)?;
+        }
+
+        self.last_operation = Some(format!("duplicate({}, {})", name, root_name));
+        Ok(Duplicated {
+            name: root_name,
+            names,
+        })
+    }
+}
+
+// ========================================
+// WASM Bindings for Duplicate
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// Entity/Group을 깊은 복사하여 원본 바로 위에 놓습니다.
+    ///
+    /// 그룹이면 하위 트리 전체를 복사하며, 새 id를 발급하고 스타일/변환/z-order를 유지합니다.
+    /// 자식 이름은 `{원본 이름}_` 접두사를 새 이름으로 바꾸거나(`robot_arm` → `robot2_arm`)
+    /// 새 이름을 접두사로 붙이고(`arm` → `robot2_arm`), 이미 있으면 `_2`, `_3`, ... 을 덧붙입니다.
+    ///
+    /// # Arguments
+    /// * `name` - 원본 Entity/Group 이름
+    /// * `new_name` - 복제본 이름 ("" = `{name}_copy` 자동 생성)
+    /// * `dx`, `dy` - 복제본 이동량 (월드 좌표, 0이면 원본과 겹침)
+    ///
+    /// # Returns
+    /// 복제 결과 JSON: `{"name": "robot2", "names": {"robot": "robot2", "robot_arm": "robot2_arm", ...}}`
+    ///
+    /// # Errors
+    /// * Entity 미발견, new_name 중복, NaN/Infinity, 부모 변환을 역산할 수 없는 경우
+    pub fn duplicate(
+        &mut self,
+        name: &str,
+        new_name: &str,
+        dx: f64,
+        dy: f64,
+    ) -> Result<String, JsValue> {
+        let duplicated = self
+            .duplicate_internal(name, new_name, dx, dy)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&duplicated).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::entity::{DimensionKind, Geometry};
+    use crate::scene::style::FillStyle;
+
+    fn center(scene: &Scene, name: &str) -> [f64; 2] {
+        let (min, max) = scene.get_world_bounds_internal(name).unwrap();
+        [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]
+    }
+
+    #[test]
+    fn test_duplicate_entity_above_original() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("wheel", 0.0, 0.0, 2.0).unwrap();
+        scene
+            .add_rect_internal("body", 0.0, 5.0, 10.0, 4.0)
+            .unwrap();
+        scene.find_by_name_mut("wheel").unwrap().style.fill = Some(FillStyle {
+            color: [1.0, 0.0, 0.0, 1.0],
+            ..Default::default()
+        });
+        scene.rotate("wheel", 0.5).unwrap();
+
+        let result = scene
+            .duplicate_internal("wheel", "wheel2", 6.0, 0.0)
+            .unwrap();
+        assert_eq!(result.name, "wheel2");
+
+        let original = scene.find_by_name("wheel").unwrap();
+        let copy = scene.find_by_name("wheel2").unwrap();
+        assert_ne!(copy.id, original.id);
+        assert_eq!(copy.style, original.style);
+        assert_eq!(copy.transform.rotate, original.transform.rotate);
+        assert_eq!(copy.metadata.z_index, original.metadata.z_index + 1);
+        assert_eq!(scene.find_by_name("body").unwrap().metadata.z_index, 2);
+        assert_eq!(center(&scene, "wheel2"), [6.0, 0.0]);
+
+        // 자동 이름
+        let auto = scene.duplicate_internal("wheel", "", 0.0, 0.0).unwrap();
+        assert_eq!(auto.name, "wheel_copy");
+        let auto = scene.duplicate_internal("wheel", "", 0.0, 0.0).unwrap();
+        assert_eq!(auto.name, "wheel_copy_2");
+    }
+
+    #[test]
+    fn test_duplicate_group_subtree_names() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_circle_internal("robot_head", 0.0, 10.0, 2.0)
+            .unwrap();
+        scene.add_rect_internal("arm", 3.0, 5.0, 4.0, 1.0).unwrap();
+        scene
+            .add_rect_internal("robot2_arm", 50.0, 0.0, 1.0, 1.0)
+            .unwrap();
+        scene
+            .create_group_internal("robot", vec!["robot_head".into(), "arm".into()])
+            .unwrap();
+        scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "head_r",
+                DimensionKind::Radius {
+                    target: "robot_head".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+        scene.add_to_group_internal("robot", "head_r").unwrap();
+
+        let result = scene
+            .duplicate_internal("robot", "robot2", 20.0, 0.0)
+            .unwrap();
+        assert_eq!(result.names["robot_head"], "robot2_head");
+        // "robot2_arm"이 이미 있으므로 번호를 붙임
+        assert_eq!(result.names["arm"], "robot2_arm_2");
+        assert_eq!(result.names["head_r"], "robot2_head_r");
+
+        let group = scene.find_by_name("robot2").unwrap();
+        assert_eq!(
+            scene.child_names(group),
+            vec!["robot2_head", "robot2_arm_2", "robot2_head_r"]
+        );
+        assert_eq!(center(&scene, "robot2_head"), [20.0, 10.0]);
+        // 원본은 그대로
+        assert_eq!(center(&scene, "robot_head"), [0.0, 10.0]);
+
+        // 복제된 치수는 복제된 도형을 참조
+        let Geometry::Dimension { kind, .. } =
+            &scene.find_by_name("robot2_head_r").unwrap().geometry
+        else {
+            panic!("expected dimension");
+        };
+        assert!(matches!(kind, DimensionKind::Radius { target, .. } if target == "robot2_head"));
+    }
+
+    #[test]
+    fn test_duplicate_inside_group_and_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("a", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("b", 5.0, 0.0, 1.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["a".into(), "b".into()])
+            .unwrap();
+        scene.scale("g", 2.0, 2.0).unwrap();
+
+        scene.duplicate_internal("a", "a2", 0.0, 3.0).unwrap();
+        let group = scene.find_by_name("g").unwrap();
+        assert_eq!(scene.child_names(group), vec!["a", "a2", "b"]);
+        assert_eq!(scene.find_by_name("a2").unwrap().metadata.z_index, 1);
+        assert_eq!(scene.find_by_name("b").unwrap().metadata.z_index, 2);
+        // 월드 오프셋: 부모 스케일 역산
+        assert_eq!(center(&scene, "a2"), [0.0, 3.0]);
+
+        assert_eq!(
+            scene.duplicate_internal("a", "b", 0.0, 0.0),
+            Err(SceneError::DuplicateEntityName(
+                "duplicate".to_string(),
+                "b".to_string()
+            ))
+        );
+        assert!(matches!(
+            scene.duplicate_internal("missing", "x", 0.0, 0.0),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(matches!(
+            scene.duplicate_internal("a", "x", f64::NAN, 0.0),
+            Err(SceneError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_prefix_rename_requires_separator() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("bolts_row", 0.0, 0.0, 1.0, 1.0)
+            .unwrap();
+        scene
+            .add_rect_internal("bolt_head", 2.0, 0.0, 1.0, 1.0)
+            .unwrap();
+        scene
+            .create_group_internal("bolt", vec!["bolts_row".into(), "bolt_head".into()])
+            .unwrap();
+
+        let result = scene.duplicate_internal("bolt", "bolt2", 0.0, 0.0).unwrap();
+        assert_eq!(result.names["bolts_row"], "bolt2_bolts_row");
+        assert_eq!(result.names["bolt_head"], "bolt2_head");
+    }
+
+    #[test]
+    fn test_duplicate_propagates_translate_error() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("a", 0.0, 0.0, 1.0).unwrap();
+        scene.create_group_internal("g", vec!["a".into()]).unwrap();
+        scene.find_by_name_mut("g").unwrap().transform.scale = [f64::NAN, 1.0];
+
+        assert!(matches!(
+            scene.duplicate_internal("a", "a2", 1.0, 0.0),
+            Err(SceneError::InvalidOperation(_))
+        ));
+        // 실패 시 복제본을 남기지 않음
+        assert!(!scene.has_entity("a2"));
+        assert!(matches!(
+            scene.translate_world_internal("a", 1.0, 0.0),
+            Err(SceneError::InvalidOperation(_))
+        ));
+    }
+}
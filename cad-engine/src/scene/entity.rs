@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-pub use super::style::{FillStyle, LineCap, LineJoin, StrokeStyle, Style};
+pub use super::style::{
+    FillStyle, Gradient, GradientStop, HatchPattern, HatchStyle, LineCap, LineJoin, StrokeStyle,
+    Style,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entity {
@@ -10,12 +13,32 @@ pub struct Entity {
     pub transform: Transform,
     pub style: Style,
     pub metadata: Metadata,
-    /// 부모 그룹의 name (None이면 최상위)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_id: Option<String>,
-    /// 자식 Entity들의 name 목록 (Group만 사용)
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    pub children: Vec<String>,
+    /// Scene 내 핸들 (Scene에 추가될 때 할당)
+    #[serde(skip)]
+    pub handle: EntityHandle,
+    /// 부모 그룹 핸들 (None이면 최상위, JSON에는 parent_id 이름으로 출력)
+    #[serde(skip)]
+    pub parent: Option<EntityHandle>,
+    /// 자식 Entity 핸들 목록 (Group만 사용, JSON에는 children 이름 목록으로 출력)
+    #[serde(skip)]
+    pub children: Vec<EntityHandle>,
+}
+
+/// Scene 내 Entity의 안정적인 핸들 (저장 슬롯 번호)
+///
+/// 다른 Entity가 삭제되거나 이름이 바뀌어도 유지되며, 삭제된 슬롯은 재사용하지 않습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntityHandle(u32);
+
+impl EntityHandle {
+    pub(crate) fn from_index(index: usize) -> Self {
+        EntityHandle(index as u32)
+    }
+
+    /// 슬롯 번호
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -26,6 +49,11 @@ pub enum EntityType {
     Arc,
     Polygon,
     Bezier,
+    Path,
+    Ellipse,
+    EllipticalArc,
+    Text,
+    Dimension,
     Group,
 }
 
@@ -39,6 +67,11 @@ impl EntityType {
             EntityType::Arc => "Arc",
             EntityType::Polygon => "Polygon",
             EntityType::Bezier => "Bezier",
+            EntityType::Path => "Path",
+            EntityType::Ellipse => "Ellipse",
+            EntityType::EllipticalArc => "EllipticalArc",
+            EntityType::Text => "Text",
+            EntityType::Dimension => "Dimension",
             EntityType::Group => "Group",
         }
     }
@@ -83,10 +116,422 @@ pub enum Geometry {
         /// 닫힌 경로 여부 (true면 fill 가능)
         closed: bool,
     },
+    /// 일반 경로 (여러 subpath, 직선/원호/타원호/2차·3차 베지어 세그먼트를 변환 없이 보관)
+    /// 글자, 개스킷, 구멍 뚫린 외곽선 같은 복합 도형용 (geometry/path.rs)
+    Path {
+        subpaths: Vec<Subpath>,
+        #[serde(default)]
+        fill_rule: FillRule,
+    },
+    /// 타원 (rotation: 장축 회전, 라디안, CCW)
+    Ellipse {
+        center: [f64; 2],
+        rx: f64,
+        ry: f64,
+        #[serde(default)]
+        rotation: f64,
+    },
+    /// 타원 호 (각도는 타원의 매개변수 각도 t, 점 = center + R(rotation)·[rx cos t, ry sin t])
+    EllipticalArc {
+        center: [f64; 2],
+        rx: f64,
+        ry: f64,
+        #[serde(default)]
+        rotation: f64,
+        start_angle: f64, // 라디안, 0 = 장축(rx) 방향
+        end_angle: f64,   // 라디안, 양수 = 반시계방향 (CCW)
+    },
+    /// 텍스트 (라벨, 방 이름, 부품 번호 등)
+    /// 바운드는 내장 폰트 메트릭으로 계산 (geometry/text.rs)
+    Text {
+        /// 기준점 (anchor/align이 가리키는 위치)
+        position: [f64; 2],
+        /// 내용 ('\n'으로 줄바꿈)
+        content: String,
+        font_family: String,
+        font_size: f64,
+        #[serde(default)]
+        anchor: TextAnchor,
+        #[serde(default)]
+        align: TextAlign,
+    },
+    /// 치수 (다른 Entity를 name으로 참조하는 연관 치수)
+    /// 값/치수선/보조선은 참조 도형의 현재 월드 좌표로 매번 다시 계산 (scene/dimension.rs)
+    Dimension {
+        kind: DimensionKind,
+        /// 치수 문자 크기
+        text_size: f64,
+        /// 소수점 자릿수
+        precision: u32,
+    },
     /// Group용 빈 geometry (자체 도형 없음)
     Empty,
 }
 
+/// Path의 연속된 한 획 (SVG의 M ... [Z] 한 묶음)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Subpath {
+    /// 시작점
+    pub start: [f64; 2],
+    /// 세그먼트들 (각 세그먼트는 이전 끝점에서 이어짐)
+    pub segments: Vec<PathSegment>,
+    /// 닫힌 subpath 여부 (끝점 → 시작점 직선으로 닫힘)
+    #[serde(default)]
+    pub closed: bool,
+}
+
+/// Path 세그먼트
+///
+/// 원호/타원호의 시작점이 이전 끝점과 다르면 그 사이는 직선으로 이어집니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PathSegment {
+    Line {
+        to: [f64; 2],
+    },
+    /// 2차 베지어
+    Quadratic {
+        control: [f64; 2],
+        to: [f64; 2],
+    },
+    /// 3차 베지어
+    Cubic {
+        control1: [f64; 2],
+        control2: [f64; 2],
+        to: [f64; 2],
+    },
+    /// 원호 (sweep_angle: 라디안, 양수 = CCW, 음수 = CW)
+    Arc {
+        center: [f64; 2],
+        radius: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+    },
+    /// 타원호 (각도는 타원의 매개변수 각도, sweep_angle 부호 = 방향)
+    EllipticalArc {
+        center: [f64; 2],
+        rx: f64,
+        ry: f64,
+        #[serde(default)]
+        rotation: f64,
+        start_angle: f64,
+        sweep_angle: f64,
+    },
+}
+
+/// 채우기 규칙 (SVG fill-rule)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum FillRule {
+    #[default]
+    #[serde(alias = "nonzero")]
+    NonZero,
+    #[serde(alias = "evenodd")]
+    EvenOdd,
+}
+
+impl FillRule {
+    pub fn as_svg(&self) -> &'static str {
+        match self {
+            FillRule::NonZero => "nonzero",
+            FillRule::EvenOdd => "evenodd",
+        }
+    }
+}
+
+/// 치수 종류
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DimensionKind {
+    /// 수평/수직 거리
+    Linear {
+        from: DimensionPoint,
+        to: DimensionPoint,
+        axis: DimensionAxis,
+        /// 치수선 위치 (측정점에서 바깥쪽으로의 거리, 음수면 반대쪽)
+        offset: f64,
+    },
+    /// 두 점을 잇는 방향의 거리
+    Aligned {
+        from: DimensionPoint,
+        to: DimensionPoint,
+        /// 치수선 위치 (from → to 방향의 왼쪽이 양수)
+        offset: f64,
+    },
+    /// Circle/Arc 반지름
+    Radius {
+        target: String,
+        /// 지시선 각도 (라디안, 월드 기준)
+        angle: f64,
+    },
+    /// Circle/Arc 지름
+    Diameter { target: String, angle: f64 },
+    /// 두 Line(첫 세그먼트) 사이 각도
+    Angular {
+        line_a: String,
+        line_b: String,
+        /// 치수 호의 반지름 (교점 기준)
+        radius: f64,
+    },
+}
+
+impl DimensionKind {
+    /// 참조하는 Entity 이름 (가변, 이름 변경/복사본 참조 갱신용)
+    pub fn references_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            DimensionKind::Linear { from, to, .. } | DimensionKind::Aligned { from, to, .. } => {
+                vec![&mut from.entity, &mut to.entity]
+            }
+            DimensionKind::Radius { target, .. } | DimensionKind::Diameter { target, .. } => {
+                vec![target]
+            }
+            DimensionKind::Angular { line_a, line_b, .. } => vec![line_a, line_b],
+        }
+    }
+}
+
+/// 치수가 참조하는 점: Entity 이름 + 도형 위의 위치
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DimensionPoint {
+    pub entity: String,
+    pub anchor: PointAnchor,
+}
+
+/// 도형 위의 기준 위치
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PointAnchor {
+    /// 시작점 (Line/Polygon/Bezier/Path 첫 점, Arc 시작점, Text 기준점)
+    Start,
+    /// 끝점 (Line/Polygon/Bezier/Path 마지막 점, Arc 끝점)
+    End,
+    /// 중심 (Circle/Arc/Rect/Ellipse 중심, 그 외 바운드 중심)
+    Center,
+    /// N번째 꼭짓점 (Line/Polygon 점, Rect 모서리 CCW, Bezier 세그먼트 끝점,
+    /// Path는 subpath 시작점과 세그먼트 끝점을 순서대로)
+    Vertex(usize),
+}
+
+impl PointAnchor {
+    /// "start" | "end" | "center" | "vertex:N" 파싱
+    pub fn parse_str(s: &str) -> Option<Self> {
+        let lower = s.trim().to_ascii_lowercase();
+        match lower.as_str() {
+            "start" => Some(PointAnchor::Start),
+            "end" => Some(PointAnchor::End),
+            "center" => Some(PointAnchor::Center),
+            _ => lower
+                .strip_prefix("vertex:")
+                .and_then(|n| n.trim().parse().ok())
+                .map(PointAnchor::Vertex),
+        }
+    }
+}
+
+/// Linear 치수의 측정 축
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DimensionAxis {
+    Horizontal,
+    Vertical,
+}
+
+/// 도형 사이의 기하 제약 조건 (Scene에 이름으로 저장, solve_constraints로 해석)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Constraint {
+    pub name: String,
+    pub kind: ConstraintKind,
+}
+
+/// 제약이 참조하는 직선: 두 참조 점 (Line 세그먼트, Polygon/Rect 변)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintLine {
+    pub from: DimensionPoint,
+    pub to: DimensionPoint,
+}
+
+/// 제약 종류 (각도는 라디안, 월드 좌표 기준)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    /// 두 점 일치
+    Coincident {
+        a: DimensionPoint,
+        b: DimensionPoint,
+    },
+    /// 두 점의 y 좌표가 같음 (직선이면 양 끝점)
+    Horizontal {
+        a: DimensionPoint,
+        b: DimensionPoint,
+    },
+    /// 두 점의 x 좌표가 같음
+    Vertical {
+        a: DimensionPoint,
+        b: DimensionPoint,
+    },
+    Parallel {
+        a: ConstraintLine,
+        b: ConstraintLine,
+    },
+    Perpendicular {
+        a: ConstraintLine,
+        b: ConstraintLine,
+    },
+    /// 직선이 Circle/Arc에 접함 (중심에서 직선까지 거리 = 반지름)
+    TangentLine {
+        line: ConstraintLine,
+        circle: String,
+    },
+    /// 두 Circle/Arc가 접함 (internal이면 내접)
+    TangentCircles {
+        a: String,
+        b: String,
+        internal: bool,
+    },
+    EqualLength {
+        a: ConstraintLine,
+        b: ConstraintLine,
+    },
+    EqualRadius {
+        a: String,
+        b: String,
+    },
+    /// 두 점 사이 거리
+    Distance {
+        a: DimensionPoint,
+        b: DimensionPoint,
+        value: f64,
+    },
+    /// a 방향에서 b 방향까지 CCW 각도
+    Angle {
+        a: ConstraintLine,
+        b: ConstraintLine,
+        value: f64,
+    },
+    /// 점을 월드 좌표에 고정
+    Fix {
+        point: DimensionPoint,
+        at: [f64; 2],
+    },
+}
+
+impl ConstraintKind {
+    /// add_constraint의 kind 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConstraintKind::Coincident { .. } => "coincident",
+            ConstraintKind::Horizontal { .. } => "horizontal",
+            ConstraintKind::Vertical { .. } => "vertical",
+            ConstraintKind::Parallel { .. } => "parallel",
+            ConstraintKind::Perpendicular { .. } => "perpendicular",
+            ConstraintKind::TangentLine { .. } | ConstraintKind::TangentCircles { .. } => "tangent",
+            ConstraintKind::EqualLength { .. } => "equal_length",
+            ConstraintKind::EqualRadius { .. } => "equal_radius",
+            ConstraintKind::Distance { .. } => "distance",
+            ConstraintKind::Angle { .. } => "angle",
+            ConstraintKind::Fix { .. } => "fix",
+        }
+    }
+
+    /// 참조하는 Entity 이름 (중복 포함, 참조 순서)
+    pub fn references(&self) -> Vec<&str> {
+        fn line(line: &ConstraintLine) -> [&str; 2] {
+            [&line.from.entity, &line.to.entity]
+        }
+        match self {
+            ConstraintKind::Coincident { a, b }
+            | ConstraintKind::Horizontal { a, b }
+            | ConstraintKind::Vertical { a, b }
+            | ConstraintKind::Distance { a, b, .. } => vec![&a.entity, &b.entity],
+            ConstraintKind::Parallel { a, b }
+            | ConstraintKind::Perpendicular { a, b }
+            | ConstraintKind::EqualLength { a, b }
+            | ConstraintKind::Angle { a, b, .. } => line(a).into_iter().chain(line(b)).collect(),
+            ConstraintKind::TangentLine { line: l, circle } => {
+                line(l).into_iter().chain([circle.as_str()]).collect()
+            }
+            ConstraintKind::TangentCircles { a, b, .. } | ConstraintKind::EqualRadius { a, b } => {
+                vec![a, b]
+            }
+            ConstraintKind::Fix { point, .. } => vec![&point.entity],
+        }
+    }
+
+    /// 참조하는 Entity 이름 (가변, 이름 변경 반영용)
+    pub fn references_mut(&mut self) -> Vec<&mut String> {
+        fn line(line: &mut ConstraintLine) -> [&mut String; 2] {
+            [&mut line.from.entity, &mut line.to.entity]
+        }
+        match self {
+            ConstraintKind::Coincident { a, b }
+            | ConstraintKind::Horizontal { a, b }
+            | ConstraintKind::Vertical { a, b }
+            | ConstraintKind::Distance { a, b, .. } => vec![&mut a.entity, &mut b.entity],
+            ConstraintKind::Parallel { a, b }
+            | ConstraintKind::Perpendicular { a, b }
+            | ConstraintKind::EqualLength { a, b }
+            | ConstraintKind::Angle { a, b, .. } => line(a).into_iter().chain(line(b)).collect(),
+            ConstraintKind::TangentLine { line: l, circle } => {
+                let mut refs: Vec<&mut String> = line(l).into();
+                refs.push(circle);
+                refs
+            }
+            ConstraintKind::TangentCircles { a, b, .. } | ConstraintKind::EqualRadius { a, b } => {
+                vec![a, b]
+            }
+            ConstraintKind::Fix { point, .. } => vec![&mut point.entity],
+        }
+    }
+}
+
+/// 텍스트 가로 기준 (SVG text-anchor)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TextAnchor {
+    #[default]
+    Start,
+    Middle,
+    End,
+}
+
+impl TextAnchor {
+    /// 문자열에서 TextAnchor 파싱 (실패 시 기본값 반환)
+    pub fn parse_str(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "middle" | "center" => TextAnchor::Middle,
+            "end" | "right" => TextAnchor::End,
+            _ => TextAnchor::Start,
+        }
+    }
+
+    pub fn as_svg(&self) -> &'static str {
+        match self {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        }
+    }
+}
+
+/// 텍스트 세로 기준 (position이 가리키는 위치)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum TextAlign {
+    /// 첫 줄의 baseline
+    #[default]
+    Baseline,
+    /// 글자 상자(ascent)의 위쪽
+    Top,
+    /// 글자 상자 전체의 가운데
+    Middle,
+    /// 글자 상자(descent)의 아래쪽
+    Bottom,
+}
+
+impl TextAlign {
+    /// 문자열에서 TextAlign 파싱 (실패 시 기본값 반환)
+    pub fn parse_str(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "top" => TextAlign::Top,
+            "middle" | "center" => TextAlign::Middle,
+            "bottom" => TextAlign::Bottom,
+            _ => TextAlign::Baseline,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transform {
     pub translate: [f64; 2],
@@ -95,6 +540,11 @@ pub struct Transform {
     /// 회전/스케일 중심점 (로컬 좌표계). 기본값 [0, 0]
     #[serde(default, skip_serializing_if = "is_zero_pivot")]
     pub pivot: [f64; 2],
+    /// x축 기울임 각도 (라디안, SVG skewX와 같은 의미). 기본값 0
+    ///
+    /// rotate와 scale 사이에 적용되어, 역행렬이 있는 모든 affine 행렬을 표현할 수 있습니다.
+    #[serde(default, skip_serializing_if = "is_zero_skew")]
+    pub skew: f64,
 }
 
 /// pivot이 기본값([0, 0])인지 확인하는 헬퍼
@@ -102,6 +552,10 @@ fn is_zero_pivot(pivot: &[f64; 2]) -> bool {
     pivot[0] == 0.0 && pivot[1] == 0.0
 }
 
+fn is_zero_skew(skew: &f64) -> bool {
+    *skew == 0.0
+}
+
 impl Default for Transform {
     fn default() -> Self {
         Self {
@@ -109,6 +563,7 @@ impl Default for Transform {
             rotate: 0.0,
             scale: [1.0, 1.0],
             pivot: [0.0, 0.0],
+            skew: 0.0,
         }
     }
 }
@@ -134,40 +589,43 @@ impl Transform {
     }
 
     /// Transform을 3x3 동차 행렬로 변환
-    /// 변환 순서: translate * pivot * rotate * scale * (-pivot)
+    /// 변환 순서: translate * pivot * rotate * skew * scale * (-pivot)
     pub fn to_matrix(&self) -> Matrix3x3 {
         let [tx, ty] = self.translate;
         let [sx, sy] = self.scale;
         let [px, py] = self.pivot;
         let cos_r = self.rotate.cos();
         let sin_r = self.rotate.sin();
+        let k = self.skew.tan();
 
         // 변환 순서 (오른쪽부터 적용):
         // 1. -pivot 이동
         // 2. scale
-        // 3. rotate
-        // 4. +pivot 이동
-        // 5. translate
+        // 3. skew (x축 기울임)
+        // 4. rotate
+        // 5. +pivot 이동
+        // 6. translate
 
         // 결합된 행렬 계산
-        // M = T * Tp * R * S * T(-p)
+        // M = T * Tp * R * K * S * T(-p)
         // 여기서:
         // - T = translate(tx, ty)
         // - Tp = translate(px, py)
         // - R = rotate(angle)
+        // - K = [[1, tan(skew)], [0, 1]]
         // - S = scale(sx, sy)
         // - T(-p) = translate(-px, -py)
 
         // 행렬 결합 결과:
-        // a = cos * sx, b = -sin * sy
-        // c = sin * sx, d = cos * sy
-        // tx' = tx + px - (cos*sx*px - sin*sy*py)
-        // ty' = ty + py - (sin*sx*px + cos*sy*py)
+        // a = cos * sx, b = (cos * k - sin) * sy
+        // c = sin * sx, d = (sin * k + cos) * sy
+        // tx' = tx + px - (a*px + b*py)
+        // ty' = ty + py - (c*px + d*py)
 
         let a = cos_r * sx;
-        let b = -sin_r * sy;
+        let b = (cos_r * k - sin_r) * sy;
         let c = sin_r * sx;
-        let d = cos_r * sy;
+        let d = (sin_r * k + cos_r) * sy;
         let tx_final = tx + px - (a * px + b * py);
         let ty_final = ty + py - (c * px + d * py);
 
@@ -227,33 +685,54 @@ impl Transform {
 
     /// 행렬에서 Transform 구조체로 분해합니다
     ///
-    /// 회전과 스케일을 분리하여 추출합니다.
-    /// 주의: pivot은 추출할 수 없으므로 [0, 0]으로 설정됩니다.
+    /// 주의: pivot은 추출할 수 없으므로 [0, 0]으로 설정됩니다 (`from_matrix_with_pivot` 참고).
     pub fn from_matrix(m: &Matrix3x3) -> Self {
-        // 이동 성분 추출
-        let translate = [m[0][2], m[1][2]];
-
-        // 스케일 추출 (열 벡터의 길이)
-        let sx = (m[0][0] * m[0][0] + m[1][0] * m[1][0]).sqrt();
-        let sy = (m[0][1] * m[0][1] + m[1][1] * m[1][1]).sqrt();
-
-        // 음수 스케일 감지 (행렬식 부호로 판단)
-        // det < 0이면 하나의 축이 뒤집힌 것 (X축을 기준으로 처리)
-        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
-        let sx = if det < 0.0 { -sx } else { sx };
+        Self::from_matrix_with_pivot(m, [0.0, 0.0])
+    }
 
-        // 회전 추출 (첫 번째 열에서)
-        let rotate = if sx.abs() > 1e-10 {
-            (m[1][0] / sx).atan2(m[0][0] / sx)
+    /// 행렬을 주어진 pivot 기준 Transform으로 분해합니다 (to_matrix의 역).
+    ///
+    /// 선형 부분을 R(rotate) · K(skew) · S(scale)로 분해합니다 (QR 분해와 같음).
+    /// - scale.x > 0, 뒤집힌 행렬(det < 0)은 scale.y가 음수
+    /// - 역행렬이 있는 모든 affine 행렬이 to_matrix로 정확히 복원됨
+    /// - 첫 번째 열이 0이면 두 번째 열로 회전을 정함 (skew 0)
+    /// - 그 외 퇴화 행렬(두 열이 평행)은 두 번째 열을 표현할 수 없어 scale.y = 0
+    pub fn from_matrix_with_pivot(m: &Matrix3x3, pivot: [f64; 2]) -> Self {
+        let [[a, b, _], [c, d, _], _] = *m;
+        let sx = a.hypot(c);
+
+        let (rotate, scale, skew) = if sx > 1e-12 {
+            let rotate = c.atan2(a);
+            let (sin_r, cos_r) = rotate.sin_cos();
+            // Rᵀ · M = [[sx, k·sy], [0, sy]]
+            let sy = (a * d - b * c) / sx;
+            let shear = cos_r * b + sin_r * d;
+            let skew = if sy.abs() > 1e-12 {
+                (shear / sy).atan()
+            } else {
+                0.0
+            };
+            (rotate, [sx, sy], skew)
         } else {
-            0.0
+            // 두 번째 열 = sy · (-sin, cos)
+            let sy = b.hypot(d);
+            let rotate = if sy > 1e-12 { (-b).atan2(d) } else { 0.0 };
+            (rotate, [0.0, sy], 0.0)
         };
 
+        // pivot 기준 translate: tx' = tx + px - (a*px + b*py) 의 역
+        let [px, py] = pivot;
+        let translate = [
+            m[0][2] - px + (a * px + b * py),
+            m[1][2] - py + (c * px + d * py),
+        ];
+
         Self {
             translate,
             rotate,
-            scale: [sx, sy],
-            pivot: [0.0, 0.0], // pivot은 행렬에서 복원 불가
+            scale,
+            pivot,
+            skew,
         }
     }
 }
@@ -350,6 +829,7 @@ mod transform_tests {
             rotate: PI / 2.0,
             scale: [2.0, 1.0],
             pivot: [0.0, 0.0],
+            skew: 0.0,
         };
         let m = t.to_matrix();
         // (1, 0) -> scale(2,1) -> (2, 0) -> rotate(90) -> (0, 2) -> translate -> (10, 2)
@@ -358,6 +838,60 @@ mod transform_tests {
         assert!(approx_eq(p[1], 2.0, 1e-10));
     }
 
+    #[test]
+    fn test_skew() {
+        // skewX(45°): (0, 1) -> (1, 1)
+        let t = Transform {
+            skew: PI / 4.0,
+            ..Default::default()
+        };
+        let p = Transform::transform_point(&t.to_matrix(), [0.0, 1.0]);
+        assert!(approx_eq(p[0], 1.0, 1e-10));
+        assert!(approx_eq(p[1], 1.0, 1e-10));
+    }
+
+    #[test]
+    fn test_from_matrix_round_trip() {
+        let matrices: [Matrix3x3; 5] = [
+            // 회전 + 비균등 스케일
+            Transform {
+                translate: [3.0, -2.0],
+                rotate: 0.7,
+                scale: [2.0, 0.5],
+                ..Default::default()
+            }
+            .to_matrix(),
+            // 기울임
+            [[1.0, 0.8, 4.0], [0.3, 1.2, 5.0], [0.0, 0.0, 1.0]],
+            // 뒤집힘 (det < 0) + 기울임
+            [[-1.0, 0.4, 0.0], [0.5, 2.0, 1.0], [0.0, 0.0, 1.0]],
+            // 회전된 부모 안의 비균등 스케일 자식 (두 행렬 곱)
+            Transform::multiply_matrices(
+                &Transform {
+                    scale: [3.0, 1.0],
+                    ..Default::default()
+                }
+                .to_matrix(),
+                &Transform {
+                    rotate: PI / 6.0,
+                    scale: [1.0, -1.0],
+                    ..Default::default()
+                }
+                .to_matrix(),
+            ),
+            // 첫 번째 열이 0인 퇴화 행렬
+            [[0.0, -2.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 1.0]],
+        ];
+        for m in &matrices {
+            let t = Transform::from_matrix(m);
+            assert!(matrix_approx_eq(&t.to_matrix(), m, 1e-10), "{:?}", m);
+            // pivot을 지정해도 같은 행렬
+            let t = Transform::from_matrix_with_pivot(m, [7.0, -3.0]);
+            assert_eq!(t.pivot, [7.0, -3.0]);
+            assert!(matrix_approx_eq(&t.to_matrix(), m, 1e-10), "{:?}", m);
+        }
+    }
+
     #[test]
     fn test_matrix_multiply() {
         // 행렬 곱셈: a * b = b 먼저 적용, 그 다음 a 적용
@@ -0,0 +1,231 @@
+//! 월드 좌표 곡선 근사(flatten) 모듈
+//!
+//! Entity 또는 그룹 하위 트리 전체를 월드 좌표 폴리라인으로 반환합니다.
+//! boolean/offset, G-code/DXF 내보내기, 뷰어 hit test가 같은 근사 결과를 공유하기 위한 API입니다.
+//! - 허용 오차는 월드 단위: 변환의 최대 확대율로 나눈 로컬 오차로 근사 후 월드 좌표로 변환
+//! - 도형별 근사 규칙: geometry/flatten.rs `geometry_polylines`
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Matrix3x3, Transform};
+use super::{Scene, SceneError};
+use crate::geometry::flatten::geometry_polylines;
+
+/// 월드 좌표 폴리라인 (어느 Entity에서 나왔는지 포함)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct WorldPolyline {
+    pub name: String,
+    pub closed: bool,
+    pub points: Vec<[f64; 2]>,
+}
+
+/// 2x2 선형 부분의 최대 특이값 (변환이 길이를 늘리는 최대 배율)
+pub(super) fn max_scale(m: &Matrix3x3) -> f64 {
+    let (a, b, c, d) = (m[0][0], m[0][1], m[1][0], m[1][1]);
+    let sum = a * a + b * b + c * c + d * d;
+    let det = a * d - b * c;
+    let disc = (sum * sum - 4.0 * det * det).max(0.0).sqrt();
+    ((sum + disc) / 2.0).sqrt()
+}
+
+impl Scene {
+    /// Entity(그룹이면 하위 도형 전체)를 월드 좌표 폴리라인으로 근사합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름
+    /// * `tolerance` - 현(chord) 허용 오차 (양수, 월드 단위)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 양수가 아니거나 NaN/Infinity인 tolerance
+    pub(crate) fn flatten_entity_internal(
+        &self,
+        name: &str,
+        tolerance: f64,
+    ) -> Result<Vec<WorldPolyline>, SceneError> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(SceneError::InvalidInput(
+                "[flatten_entity] invalid_input: tolerance must be a positive finite number"
+                    .to_string(),
+            ));
+        }
+        if !self.has_entity(name) {
+            return Err(SceneError::EntityNotFound(
+                "flatten_entity".to_string(),
+                name.to_string(),
+            ));
+        }
+        let mut polylines = Vec::new();
+        self.collect_world_polylines(name, tolerance, &mut polylines);
+        Ok(polylines)
+    }
+
+    fn collect_world_polylines(&self, name: &str, tolerance: f64, out: &mut Vec<WorldPolyline>) {
+        let Some(entity) = self.find_by_name(name) else {
+            return;
+        };
+
+        if matches!(entity.entity_type, EntityType::Group) {
+            for child in self.children_of(entity) {
+                self.collect_world_polylines(&child.metadata.name, tolerance, out);
+            }
+            return;
+        }
+
+        let world_matrix = self
+            .get_world_transform_internal(name)
+            .unwrap_or_else(Transform::identity_matrix);
+        let scale = max_scale(&world_matrix);
+        if scale <= f64::EPSILON {
+            return;
+        }
+
+        out.extend(
+            geometry_polylines(&entity.geometry, tolerance / scale)
+                .into_iter()
+                .map(|polyline| WorldPolyline {
+                    name: name.to_string(),
+                    closed: polyline.closed,
+                    points: polyline
+                        .points
+                        .into_iter()
+                        .map(|p| Transform::transform_point(&world_matrix, p))
+                        .collect(),
+                }),
+        );
+    }
+}
+
+// ========================================
+// WASM Bindings for Flatten
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// Entity(그룹이면 하위 트리 전체)를 허용 오차 이내의 월드 좌표 폴리라인으로 반환합니다.
+    ///
+    /// Transform과 부모 그룹 변환이 모두 적용된 좌표이며, 곡선과 폴리라인 사이
+    /// 최대 거리가 `tolerance` 이하가 되도록 분할합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 또는 Group 이름
+    /// * `tolerance` - 현(chord) 허용 오차 (양수, 월드 단위, 예: 0.01)
+    ///
+    /// # Returns
+    /// JSON 배열: `[{"name": "c1", "closed": true, "points": [[x, y], ...]}, ...]`
+    /// - 닫힌 폴리라인은 마지막 점이 첫 점과 중복되지 않음
+    /// - Arc는 부채꼴이 아닌 호 자체, Polygon holes는 별도의 닫힌 폴리라인
+    /// - Dimension과 빈 그룹은 폴리라인 없음
+    ///
+    /// # Errors
+    /// * Entity 미발견, 양수가 아닌 tolerance
+    pub fn flatten_entity(&self, name: &str, tolerance: f64) -> Result<String, JsValue> {
+        let polylines = self
+            .flatten_entity_internal(name, tolerance)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&polylines).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    /// 폴리라인 각 변의 중점과 원 사이 최대 거리
+    fn max_chord_error(points: &[[f64; 2]], center: [f64; 2], radius: f64) -> f64 {
+        (0..points.len())
+            .map(|i| {
+                let a = points[i];
+                let b = points[(i + 1) % points.len()];
+                let mid = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+                (radius - (mid[0] - center[0]).hypot(mid[1] - center[1])).abs()
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_flatten_circle_respects_world_tolerance() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 10.0).unwrap();
+
+        let coarse = scene.flatten_entity_internal("c", 0.1).unwrap();
+        let fine = scene.flatten_entity_internal("c", 0.001).unwrap();
+        assert_eq!(coarse.len(), 1);
+        assert!(coarse[0].closed);
+        assert!(fine[0].points.len() > coarse[0].points.len());
+        assert!(max_chord_error(&coarse[0].points, [0.0, 0.0], 10.0) <= 0.1);
+
+        // scale 3배 → 월드 반지름 30에서도 오차 유지
+        scene.scale("c", 3.0, 3.0).unwrap();
+        let scaled = scene.flatten_entity_internal("c", 0.1).unwrap();
+        assert!(scaled[0].points.len() > coarse[0].points.len());
+        assert!(max_chord_error(&scaled[0].points, [0.0, 0.0], 30.0) <= 0.1);
+    }
+
+    #[test]
+    fn test_flatten_group_applies_parent_chain() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene
+            .add_arc_internal("a", 0.0, 0.0, 5.0, 0.0, PI / 2.0)
+            .unwrap();
+        scene
+            .create_group_internal("g", vec!["r".to_string(), "a".to_string()])
+            .unwrap();
+        scene.translate("g", 100.0, 0.0).unwrap();
+
+        let polylines = scene.flatten_entity_internal("g", 0.01).unwrap();
+        assert_eq!(polylines.len(), 2);
+
+        let rect = polylines.iter().find(|p| p.name == "r").unwrap();
+        assert!(rect.closed);
+        assert_eq!(rect.points.len(), 4);
+        assert!(rect.points.iter().all(|p| (99.0..=101.0).contains(&p[0])));
+
+        // Arc는 부채꼴이 아닌 열린 호: 끝점 (105, 0) → (100, 5)
+        let arc = polylines.iter().find(|p| p.name == "a").unwrap();
+        assert!(!arc.closed);
+        let first = arc.points[0];
+        let last = *arc.points.last().unwrap();
+        assert!(approx_eq(first[0], 105.0, 1e-9) && approx_eq(first[1], 0.0, 1e-9));
+        assert!(approx_eq(last[0], 100.0, 1e-9) && approx_eq(last[1], 5.0, 1e-9));
+    }
+
+    #[test]
+    fn test_flatten_bezier_and_polygon_holes() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("b", "M 0,0 C 0,10 10,10 10,0", "{}")
+            .unwrap();
+        let bezier = scene.flatten_entity_internal("b", 0.01).unwrap();
+        assert!(!bezier[0].closed);
+        assert!(bezier[0].points.len() > 4);
+        let last = *bezier[0].points.last().unwrap();
+        assert!(approx_eq(last[0], 10.0, 1e-9) && approx_eq(last[1], 0.0, 1e-9));
+
+        scene
+            .draw_svg_path_internal("frame", "M0 0h10v10H0zM2 2v6h6V2z", 0.0)
+            .unwrap();
+        let frame = scene.flatten_entity_internal("frame", 0.01).unwrap();
+        assert_eq!(frame.len(), 2);
+        assert!(frame.iter().all(|p| p.closed && p.points.len() == 4));
+    }
+
+    #[test]
+    fn test_flatten_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        assert!(matches!(
+            scene.flatten_entity_internal("missing", 0.1),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        for bad in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(scene.flatten_entity_internal("c", bad).is_err());
+        }
+    }
+}
@@ -6,36 +6,20 @@
 //! - add_to_group: 그룹에 Entity 추가
 //! - remove_from_group: 그룹에서 Entity 제거
 
-use super::entity::{Entity, EntityType, Geometry, Metadata, Style, Transform};
+use super::entity::{Entity, EntityHandle, EntityType, Geometry, Metadata, Style, Transform};
 use super::{Scene, SceneError, generate_id};
 
 impl Scene {
-    /// 순환 참조 검사: ancestor가 descendant의 조상인지 확인
+    /// 순환 참조 검사: ancestor가 descendant 자신이거나 조상인지 확인
     ///
-    /// parent_id 체인을 따라가며 ancestor가 있는지 확인합니다.
-    /// 최대 100단계까지만 탐색하여 무한루프를 방지합니다.
+    /// 부모 핸들 링크를 따라가며 ancestor가 있는지 확인합니다.
     fn is_ancestor_of(&self, ancestor: &str, descendant: &str) -> bool {
-        const MAX_DEPTH: usize = 100;
-        let mut current = descendant.to_string();
-        let mut depth = 0;
-
-        while depth < MAX_DEPTH {
-            if let Some(entity) = self.find_by_name(&current) {
-                if let Some(ref parent_id) = entity.parent_id {
-                    if parent_id == ancestor {
-                        return true;
-                    }
-                    current = parent_id.clone();
-                    depth += 1;
-                } else {
-                    return false;
-                }
-            } else {
-                return false;
-            }
-        }
-        // 최대 깊이 도달 시 안전하게 true 반환 (순환으로 간주)
-        true
+        let Some(ancestor) = self.handle_of(ancestor) else {
+            return false;
+        };
+        self.collect_parent_chain(descendant)
+            .iter()
+            .any(|e| e.handle == ancestor)
     }
 
     /// 내부용 그룹 생성 함수 (테스트용)
@@ -52,40 +36,42 @@ impl Scene {
             ));
         }
 
-        // 존재하는 자식만 필터링
-        let valid_children: Vec<String> = children_names
-            .into_iter()
-            .filter(|child_name| self.has_entity(child_name))
-            .collect();
-
-        // 자식들의 현재 z_index를 수집하여 상대 순서 유지
-        let mut children_with_z: Vec<(String, i32)> = valid_children
+        // 존재하는 자식만 필터링 + 현재 z_index 순으로 정렬하여 상대 순서 보존
+        let mut children_with_z: Vec<(EntityHandle, i32)> = children_names
             .iter()
-            .filter_map(|child_name| {
-                self.find_by_name(child_name)
-                    .map(|e| (child_name.clone(), e.metadata.z_index))
-            })
+            .filter_map(|child_name| self.find_by_name(child_name))
+            .map(|e| (e.handle, e.metadata.z_index))
             .collect();
-        // z_index 순으로 정렬하여 상대 순서 보존
         children_with_z.sort_by_key(|(_, z)| *z);
 
-        // 자식의 기존 부모에서 제거 + parent_id 설정 + z_index 정규화
-        for (new_z, (child_name, _old_z)) in children_with_z.iter().enumerate() {
-            // 자식의 현재 parent_id 확인
-            let old_parent = self
-                .find_by_name(child_name)
-                .and_then(|e| e.parent_id.clone());
+        // Group Entity 생성 (z-index는 자식 이동 후 할당)
+        let group = self.push_entity(Entity {
+            id: generate_id(),
+            entity_type: EntityType::Group,
+            geometry: Geometry::Empty,
+            transform: Transform::default(),
+            style: Style::default(),
+            metadata: Metadata {
+                name: name.to_string(),
+                ..Default::default()
+            },
+            handle: EntityHandle::default(),
+            parent: None,
+            children: children_with_z.iter().map(|(child, _)| *child).collect(),
+        });
 
+        // 자식의 기존 부모에서 제거 + 부모 설정 + z_index 정규화
+        for (new_z, (child, _old_z)) in children_with_z.iter().enumerate() {
             // 기존 부모가 있으면 그 부모의 children에서 제거
-            if let Some(old_parent_name) = old_parent
-                && let Some(old_parent_entity) = self.find_by_name_mut(&old_parent_name)
+            if let Some(old_parent) = self.entity_at(*child).and_then(|e| e.parent)
+                && let Some(old_parent_entity) = self.entity_at_mut(old_parent)
             {
-                old_parent_entity.children.retain(|c| c != child_name);
+                old_parent_entity.children.retain(|c| c != child);
             }
 
-            // 자식의 parent_id를 새 그룹으로 설정 + z_index 정규화 (0, 1, 2, ...)
-            if let Some(child_entity) = self.find_by_name_mut(child_name) {
-                child_entity.parent_id = Some(name.to_string());
+            // 자식의 부모를 새 그룹으로 설정 + z_index 정규화 (0, 1, 2, ...)
+            if let Some(child_entity) = self.entity_at_mut(*child) {
+                child_entity.parent = Some(group);
                 child_entity.metadata.z_index = new_z as i32;
             }
         }
@@ -94,32 +80,13 @@ impl Scene {
         let max_root_z = self
             .entities
             .iter()
-            .filter(|e| e.parent_id.is_none())
+            .filter(|e| e.parent.is_none() && e.handle != group)
             .map(|e| e.metadata.z_index)
             .max()
             .unwrap_or(-1);
-        let z_order = max_root_z + 1;
-
-        // 정렬된 순서로 children 목록 생성
-        let sorted_children: Vec<String> = children_with_z.iter().map(|(n, _)| n.clone()).collect();
-
-        // Group Entity 생성
-        let group_entity = Entity {
-            id: generate_id(),
-            entity_type: EntityType::Group,
-            geometry: Geometry::Empty,
-            transform: Transform::default(),
-            style: Style::default(),
-            metadata: Metadata {
-                name: name.to_string(),
-                z_index: z_order,
-                ..Default::default()
-            },
-            parent_id: None,
-            children: sorted_children,
-        };
-
-        self.entities.push(group_entity);
+        if let Some(group_entity) = self.entity_at_mut(group) {
+            group_entity.metadata.z_index = max_root_z + 1;
+        }
 
         // Root level z-index 정규화 (갭과 중복 제거)
         self.normalize_root_z_indices();
@@ -133,21 +100,22 @@ impl Scene {
     /// 갭이나 중복 없이 순서대로 재정렬합니다.
     fn normalize_root_z_indices(&mut self) {
         // Root level 엔티티의 (index, z_index) 수집
-        let mut roots: Vec<(usize, i32)> = self
+        let mut roots: Vec<(usize, (i32, usize))> = self
             .entities
             .iter()
             .enumerate()
-            .filter(|(_, e)| e.parent_id.is_none())
-            .map(|(idx, e)| (idx, e.metadata.z_index))
+            .filter(|(_, e)| e.parent.is_none())
+            .map(|(idx, e)| (idx, (e.metadata.z_index, e.handle.index())))
             .collect();
 
-        // 현재 z_index 순으로 정렬 (상대 순서 유지)
-        roots.sort_by_key(|(_, z)| *z);
+        // 현재 z_index 순으로 정렬 (같으면 추가 순서, 상대 순서 유지)
+        roots.sort_by_key(|(_, key)| *key);
 
         // 0, 1, 2...로 재할당
         for (new_z, (idx, _)) in roots.iter().enumerate() {
             self.entities[*idx].metadata.z_index = new_z as i32;
         }
+        self.invalidate_paint_order();
     }
 
     /// 내부용 그룹 해제 함수 (테스트용)
@@ -171,19 +139,17 @@ impl Scene {
             ));
         }
 
-        // children 목록 복사 (borrow 문제 회피)
-        let children = entity.children.clone();
-
-        // 각 자식의 parent_id를 None으로 설정
-        for child_name in &children {
-            if let Some(child) = self.find_by_name_mut(child_name) {
-                child.parent_id = None;
+        // 그룹 Entity 삭제 후 각 자식을 최상위로
+        let group = entity.handle;
+        if let Some(removed) = self.remove_entity(group) {
+            for child in removed.children {
+                if let Some(child_entity) = self.entity_at_mut(child) {
+                    child_entity.parent = None;
+                }
             }
         }
 
-        // 그룹 Entity 삭제
-        self.entities.retain(|e| e.metadata.name != name);
-
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("ungroup({})", name));
         Ok(true)
     }
@@ -217,10 +183,12 @@ impl Scene {
             ));
         }
 
+        let group = group.handle;
+
         // 추가할 Entity 존재 여부 확인
-        if !self.has_entity(entity_name) {
+        let Some(child) = self.handle_of(entity_name) else {
             return Ok(false);
-        }
+        };
 
         // 순환 참조 방지: entity_name이 group_name의 조상인지 확인
         if self.is_ancestor_of(entity_name, group_name) {
@@ -237,73 +205,63 @@ impl Scene {
         // 2. 부모 그룹의 월드 transform 역행렬 계산
         // 3. 새 로컬 transform = 역행렬 × entity 월드 transform
 
-        // Entity의 현재 월드 transform 저장
+        // Entity의 현재 월드 transform, pivot 저장
         let entity_world_matrix = self.get_world_transform_internal(entity_name);
+        let pivot = self
+            .entity_at(child)
+            .map_or([0.0, 0.0], |e| e.transform.pivot);
 
         // 부모 그룹의 월드 transform 저장
         let group_world_matrix = self.get_world_transform_internal(group_name);
 
         // 자식의 기존 부모에서 제거
-        let old_parent = self
-            .find_by_name(entity_name)
-            .and_then(|e| e.parent_id.clone());
-
-        if let Some(old_parent_name) = old_parent
-            && let Some(old_parent_entity) = self.find_by_name_mut(&old_parent_name)
+        if let Some(old_parent) = self.entity_at(child).and_then(|e| e.parent)
+            && let Some(old_parent_entity) = self.entity_at_mut(old_parent)
         {
-            old_parent_entity.children.retain(|c| c != entity_name);
+            old_parent_entity.children.retain(|c| *c != child);
         }
 
-        // 새 로컬 transform 계산 및 적용
-        if let (Some(entity_world), Some(group_world)) = (entity_world_matrix, group_world_matrix) {
-            // 부모 그룹의 역행렬
-            if let Some(group_inverse) = Transform::inverse_matrix(&group_world) {
-                // 새 로컬 = 그룹역행렬 × 엔티티월드
-                let new_local_matrix = Transform::multiply_matrices(&group_inverse, &entity_world);
-                let new_transform = Transform::from_matrix(&new_local_matrix);
-
-                // transform 업데이트
-                if let Some(child) = self.find_by_name_mut(entity_name) {
-                    child.transform = new_transform;
-                    child.parent_id = Some(group_name.to_string());
-                }
-            } else {
-                // 역행렬 계산 실패 시 기존 동작 (parent_id만 변경)
-                if let Some(child) = self.find_by_name_mut(entity_name) {
-                    child.parent_id = Some(group_name.to_string());
-                }
-            }
-        } else {
-            // 월드 transform 없으면 기존 동작
-            if let Some(child) = self.find_by_name_mut(entity_name) {
-                child.parent_id = Some(group_name.to_string());
-            }
-        }
+        // 새 로컬 transform 계산 (역행렬 계산 실패 또는 월드 transform이 없으면 기존 transform 유지)
+        // 새 로컬 = 그룹역행렬 × 엔티티월드
+        let new_transform =
+            entity_world_matrix
+                .zip(group_world_matrix)
+                .and_then(|(entity_world, group_world)| {
+                    let group_inverse = Transform::inverse_matrix(&group_world)?;
+                    let new_local_matrix =
+                        Transform::multiply_matrices(&group_inverse, &entity_world);
+                    Some(Transform::from_matrix_with_pivot(&new_local_matrix, pivot))
+                });
 
         // 그룹의 children에 추가 + z_index 정규화
         // 기존 자식들 중 최대 z_index를 찾아 +1로 설정 (로컬 스코프)
         let max_child_z = self
-            .find_by_name(group_name)
+            .entity_at(group)
             .map(|g| {
-                g.children
-                    .iter()
-                    .filter_map(|c| self.find_by_name(c).map(|e| e.metadata.z_index))
+                self.children_of(g)
+                    .filter(|e| e.handle != child)
+                    .map(|e| e.metadata.z_index)
                     .max()
                     .unwrap_or(-1)
             })
             .unwrap_or(-1);
 
-        // 새 자식의 z_index를 그룹 내 최대값 + 1로 설정
-        if let Some(child) = self.find_by_name_mut(entity_name) {
-            child.metadata.z_index = max_child_z + 1;
+        if let Some(child_entity) = self.entity_at_mut(child) {
+            if let Some(transform) = new_transform {
+                child_entity.transform = transform;
+            }
+            child_entity.parent = Some(group);
+            // 새 자식의 z_index를 그룹 내 최대값 + 1로 설정
+            child_entity.metadata.z_index = max_child_z + 1;
         }
 
-        if let Some(group) = self.find_by_name_mut(group_name)
-            && !group.children.contains(&entity_name.to_string())
+        if let Some(group_entity) = self.entity_at_mut(group)
+            && !group_entity.children.contains(&child)
         {
-            group.children.push(entity_name.to_string());
+            group_entity.children.push(child);
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("add_to_group({}, {})", group_name, entity_name));
         Ok(true)
     }
@@ -340,20 +298,23 @@ impl Scene {
         };
 
         // 해당 그룹의 자식인지 확인
-        if entity.parent_id.as_deref() != Some(group_name) {
+        let group = group.handle;
+        let child = entity.handle;
+        if entity.parent != Some(group) {
             return Ok(false);
         }
 
-        // Entity의 parent_id 해제
-        if let Some(child) = self.find_by_name_mut(entity_name) {
-            child.parent_id = None;
+        // Entity의 부모 해제
+        if let Some(child_entity) = self.entity_at_mut(child) {
+            child_entity.parent = None;
         }
 
         // 그룹의 children에서 제거
-        if let Some(group) = self.find_by_name_mut(group_name) {
-            group.children.retain(|c| c != entity_name);
+        if let Some(group_entity) = self.entity_at_mut(group) {
+            group_entity.children.retain(|c| *c != child);
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!(
             "remove_from_group({}, {})",
             group_name, entity_name
@@ -484,15 +445,15 @@ mod tests {
         assert!(matches!(group.entity_type, EntityType::Group));
         assert!(matches!(group.geometry, Geometry::Empty));
         assert_eq!(group.children.len(), 2);
-        assert!(group.children.contains(&"c1".to_string()));
-        assert!(group.children.contains(&"c2".to_string()));
+        assert!(scene.child_names(group).contains(&"c1"));
+        assert!(scene.child_names(group).contains(&"c2"));
 
-        // 자식의 parent_id 확인
+        // 자식의 부모 확인
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert_eq!(c1.parent_id, Some("grp".to_string()));
+        assert_eq!(scene.parent_name(c1), Some("grp"));
 
         let c2 = scene.find_by_name("c2").expect("c2 not found");
-        assert_eq!(c2.parent_id, Some("grp".to_string()));
+        assert_eq!(scene.parent_name(c2), Some("grp"));
 
         // last_operation 확인
         assert_eq!(scene.last_operation, Some("create_group(grp)".to_string()));
@@ -547,7 +508,7 @@ mod tests {
         // 존재하는 자식만 포함되어야 함
         let group = scene.find_by_name("grp").expect("Group not found");
         assert_eq!(group.children.len(), 1);
-        assert!(group.children.contains(&"c1".to_string()));
+        assert!(scene.child_names(group).contains(&"c1"));
     }
 
     #[test]
@@ -597,9 +558,9 @@ mod tests {
         let outer = scene.find_by_name("outer").expect("outer not found");
         assert_eq!(outer.children.len(), 2);
 
-        // inner 그룹의 parent_id 확인
+        // inner 그룹의 부모 확인
         let inner = scene.find_by_name("inner").expect("inner not found");
-        assert_eq!(inner.parent_id, Some("outer".to_string()));
+        assert_eq!(scene.parent_name(inner), Some("outer"));
     }
 
     #[test]
@@ -620,15 +581,15 @@ mod tests {
 
         // c1은 grp2의 자식이어야 함
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert_eq!(c1.parent_id, Some("grp2".to_string()));
+        assert_eq!(scene.parent_name(c1), Some("grp2"));
 
         // grp1에서는 c1이 제거되어야 함
         let grp1 = scene.find_by_name("grp1").expect("grp1 not found");
-        assert!(!grp1.children.contains(&"c1".to_string()));
+        assert!(!scene.child_names(grp1).contains(&"c1"));
 
         // grp2에는 c1이 있어야 함
         let grp2 = scene.find_by_name("grp2").expect("grp2 not found");
-        assert!(grp2.children.contains(&"c1".to_string()));
+        assert!(scene.child_names(grp2).contains(&"c1"));
     }
 
     #[test]
@@ -671,12 +632,12 @@ mod tests {
         // 그룹이 삭제되었는지 확인
         assert!(scene.find_by_name("grp").is_none());
 
-        // 자식들의 parent_id가 None인지 확인
+        // 자식들의 부모가 None인지 확인
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert!(c1.parent_id.is_none());
+        assert!(c1.parent.is_none());
 
         let c2 = scene.find_by_name("c2").expect("c2 not found");
-        assert!(c2.parent_id.is_none());
+        assert!(c2.parent.is_none());
 
         // last_operation 확인
         assert_eq!(scene.last_operation, Some("ungroup(grp)".to_string()));
@@ -726,13 +687,13 @@ mod tests {
         // outer는 삭제됨
         assert!(scene.find_by_name("outer").is_none());
 
-        // inner의 parent_id는 None
+        // inner의 부모는 None
         let inner = scene.find_by_name("inner").expect("inner not found");
-        assert!(inner.parent_id.is_none());
+        assert!(inner.parent.is_none());
 
         // c1은 여전히 inner의 자식
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert_eq!(c1.parent_id, Some("inner".to_string()));
+        assert_eq!(scene.parent_name(c1), Some("inner"));
     }
 
     #[test]
@@ -786,11 +747,11 @@ mod tests {
 
         // 그룹의 children 확인
         let grp = scene.find_by_name("grp").expect("grp not found");
-        assert!(grp.children.contains(&"c1".to_string()));
+        assert!(scene.child_names(grp).contains(&"c1"));
 
-        // c1의 parent_id 확인
+        // c1의 부모 확인
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert_eq!(c1.parent_id, Some("grp".to_string()));
+        assert_eq!(scene.parent_name(c1), Some("grp"));
     }
 
     #[test]
@@ -841,15 +802,15 @@ mod tests {
 
         // grp1에서 제거됨
         let grp1 = scene.find_by_name("grp1").expect("grp1 not found");
-        assert!(!grp1.children.contains(&"c1".to_string()));
+        assert!(!scene.child_names(grp1).contains(&"c1"));
 
         // grp2에 추가됨
         let grp2 = scene.find_by_name("grp2").expect("grp2 not found");
-        assert!(grp2.children.contains(&"c1".to_string()));
+        assert!(scene.child_names(grp2).contains(&"c1"));
 
-        // c1의 parent_id 변경
+        // c1의 부모 변경
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert_eq!(c1.parent_id, Some("grp2".to_string()));
+        assert_eq!(scene.parent_name(c1), Some("grp2"));
     }
 
     #[test]
@@ -920,11 +881,11 @@ mod tests {
 
         // 그룹의 children에서 제거됨
         let grp = scene.find_by_name("grp").expect("grp not found");
-        assert!(!grp.children.contains(&"c1".to_string()));
+        assert!(!scene.child_names(grp).contains(&"c1"));
 
-        // c1의 parent_id가 None
+        // c1의 부모가 None
         let c1 = scene.find_by_name("c1").expect("c1 not found");
-        assert!(c1.parent_id.is_none());
+        assert!(c1.parent.is_none());
     }
 
     #[test]
@@ -983,7 +944,7 @@ mod tests {
         add_test_circle(&mut scene, "c1");
         scene.create_group_internal("grp", vec![]).unwrap();
 
-        // 독립 entity를 그룹에서 제거 시도 (parent_id가 None)
+        // 독립 entity를 그룹에서 제거 시도 (부모가 None)
         let result = scene.remove_from_group_internal("grp", "c1");
         assert!(result.is_ok());
         assert!(!result.unwrap());
@@ -0,0 +1,604 @@
+//! 교점 계산 모듈
+//!
+//! 두 Entity 사이, 또는 선택된 여러 Entity 사이의 월드 좌표 교점을 구합니다.
+//! trim/extend, 스냅, 닫힌 영역 검출이 같은 결과를 공유하기 위한 API입니다.
+//! - 외곽선은 Entity별 조각(edge) 목록으로 분해하고, 교점마다 조각 번호와 조각 위 매개변수 t를 함께 반환
+//! - Line/Rect/Polygon 변, (회전·균일 스케일·반전 변환 아래의) Circle/Arc/Path 원호는 해석적으로 정확
+//! - Bezier는 제어점을 그대로 변환해 분할로 교차, 타원과 비균일 스케일된 원호는 큐빅 근사
+//! - 곡선 교점 계산: geometry/intersect.rs
+
+use std::f64::consts::PI;
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Geometry, Matrix3x3, Transform};
+use super::flatten::max_scale;
+use super::{Scene, SceneError};
+use crate::geometry::distance::{Curve, polyline_curves};
+use crate::geometry::flatten::{arc_sweep, ellipse_point, geometry_polylines, rect_points};
+use crate::geometry::intersect::{Edge, edge_intersections};
+use crate::geometry::path::{PathPiece, arc_to_cubics, subpath_pieces};
+
+/// 곡선 근사/베지어 분할 허용 오차 (Entity 월드 바운드 대각선 대비 비율)
+const EDGE_TOLERANCE_RATIO: f64 = 1e-5;
+
+/// 한 도형의 월드 좌표 외곽선 조각들
+#[derive(Debug, Clone)]
+pub(super) struct WorldEdges {
+    pub name: String,
+    pub edges: Vec<Edge>,
+    /// 베지어 근사/분할 허용 오차 (월드 단위)
+    pub tolerance: f64,
+}
+
+/// 교점이 놓인 외곽선 조각
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct EdgeHit {
+    pub name: String,
+    pub edge: usize,
+    pub t: f64,
+}
+
+/// 월드 좌표 교점
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct Intersection {
+    pub point: [f64; 2],
+    pub a: EdgeHit,
+    pub b: EdgeHit,
+}
+
+/// 변환이 원을 원으로 보내는지 (회전 + 균일 스케일, 반전 포함) 확인합니다.
+fn is_similarity(m: &Matrix3x3) -> bool {
+    let (a, b, c, d) = (m[0][0], m[0][1], m[1][0], m[1][1]);
+    let eps = 1e-9 * a.hypot(b).max(c.hypot(d)).max(f64::MIN_POSITIVE);
+    ((a - d).abs() <= eps && (b + c).abs() <= eps) || ((a + d).abs() <= eps && (b - c).abs() <= eps)
+}
+
+/// 로컬 원호를 월드 원호로 변환합니다 (sweep 부호 = 방향, 유사 변환 전용).
+fn world_arc(m: &Matrix3x3, center: [f64; 2], radius: f64, start: f64, sweep: f64) -> Edge {
+    let (start, sweep) = if sweep < 0.0 {
+        (start + sweep, -sweep)
+    } else {
+        (start, sweep)
+    };
+    let sweep = sweep.min(2.0 * PI);
+    let world_center = Transform::transform_point(m, center);
+    let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+    // 반전 변환은 방향이 뒤집히므로 끝점이 새 시작점
+    let from = if det >= 0.0 { start } else { start + sweep };
+    let p = Transform::transform_point(
+        m,
+        [
+            center[0] + radius * from.cos(),
+            center[1] + radius * from.sin(),
+        ],
+    );
+    Edge::Curve(Curve::Arc {
+        center: world_center,
+        radius: radius * det.abs().sqrt(),
+        start: (p[1] - world_center[1]).atan2(p[0] - world_center[0]),
+        sweep,
+    })
+}
+
+fn world_polyline(m: &Matrix3x3, points: &[[f64; 2]], closed: bool) -> Vec<Edge> {
+    let points: Vec<[f64; 2]> = points
+        .iter()
+        .map(|p| Transform::transform_point(m, *p))
+        .collect();
+    polyline_curves(&points, closed)
+        .into_iter()
+        .map(Edge::Curve)
+        .collect()
+}
+
+fn world_cubic(m: &Matrix3x3, cubic: [[f64; 2]; 4]) -> Edge {
+    Edge::Cubic(cubic.map(|p| Transform::transform_point(m, p)))
+}
+
+/// 타원호를 큐빅들로 근사해 월드 좌표로 변환합니다.
+#[allow(clippy::too_many_arguments)]
+fn world_elliptical_arc(
+    m: &Matrix3x3,
+    center: [f64; 2],
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    start: f64,
+    sweep: f64,
+    tolerance: f64,
+) -> Vec<Edge> {
+    let mut current = ellipse_point(center, rx, ry, rotation, start);
+    arc_to_cubics(center, rx, ry, rotation, start, sweep, tolerance)
+        .into_iter()
+        .map(|[c1, c2, end]| {
+            let edge = world_cubic(m, [current, c1, c2, end]);
+            current = end;
+            edge
+        })
+        .collect()
+}
+
+impl Scene {
+    /// Entity(그룹이면 하위 도형 각각)의 월드 좌표 외곽선 조각을 수집합니다.
+    ///
+    /// 조각 번호(edge)는 도형별 순서: Polygon/Line은 변 순서(holes는 외곽 다음),
+    /// Bezier/Path는 세그먼트 순서(원호 앞 연결 직선, 닫는 직선 포함)입니다.
+    pub(super) fn collect_world_edges(&self, name: &str, out: &mut Vec<WorldEdges>) {
+        let Some(entity) = self.find_by_name(name) else {
+            return;
+        };
+
+        if matches!(entity.entity_type, EntityType::Group) {
+            for child in self.children_of(entity) {
+                self.collect_world_edges(&child.metadata.name, out);
+            }
+            return;
+        }
+
+        let Some(m) = self.get_world_transform_internal(name) else {
+            return;
+        };
+        let scale = max_scale(&m);
+        if scale <= f64::EPSILON {
+            return;
+        }
+        let exact_arcs = is_similarity(&m);
+        let tolerance = self
+            .get_world_bounds_internal(name)
+            .map_or(0.0, |(min, max)| (max[0] - min[0]).hypot(max[1] - min[1]))
+            * EDGE_TOLERANCE_RATIO;
+        let tolerance = tolerance.max(f64::EPSILON);
+        let local_tolerance = tolerance / scale;
+
+        let mut edges = Vec::new();
+        match &entity.geometry {
+            Geometry::Line { points } => edges.extend(world_polyline(&m, points, false)),
+            Geometry::Rect {
+                center,
+                width,
+                height,
+            } => edges.extend(world_polyline(
+                &m,
+                &rect_points(*center, *width, *height),
+                true,
+            )),
+            Geometry::Polygon { points, holes } => {
+                for ring in std::iter::once(points).chain(holes.iter()) {
+                    edges.extend(world_polyline(&m, ring, true));
+                }
+            }
+            Geometry::Circle { center, radius } => {
+                if exact_arcs {
+                    edges.push(world_arc(&m, *center, *radius, 0.0, 2.0 * PI));
+                } else {
+                    edges.extend(world_elliptical_arc(
+                        &m,
+                        *center,
+                        *radius,
+                        *radius,
+                        0.0,
+                        0.0,
+                        2.0 * PI,
+                        local_tolerance,
+                    ));
+                }
+            }
+            Geometry::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+            } => {
+                let sweep = arc_sweep(*start_angle, *end_angle);
+                if exact_arcs {
+                    edges.push(world_arc(&m, *center, *radius, *start_angle, sweep));
+                } else {
+                    edges.extend(world_elliptical_arc(
+                        &m,
+                        *center,
+                        *radius,
+                        *radius,
+                        0.0,
+                        *start_angle,
+                        sweep,
+                        local_tolerance,
+                    ));
+                }
+            }
+            Geometry::Ellipse {
+                center,
+                rx,
+                ry,
+                rotation,
+            } => edges.extend(world_elliptical_arc(
+                &m,
+                *center,
+                *rx,
+                *ry,
+                *rotation,
+                0.0,
+                2.0 * PI,
+                local_tolerance,
+            )),
+            Geometry::EllipticalArc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                end_angle,
+            } => edges.extend(world_elliptical_arc(
+                &m,
+                *center,
+                *rx,
+                *ry,
+                *rotation,
+                *start_angle,
+                arc_sweep(*start_angle, *end_angle),
+                local_tolerance,
+            )),
+            Geometry::Bezier {
+                start,
+                segments,
+                closed,
+            } => {
+                let mut current = *start;
+                for [c1, c2, end] in segments {
+                    edges.push(world_cubic(&m, [current, *c1, *c2, *end]));
+                    current = *end;
+                }
+                if *closed && current != *start {
+                    edges.extend(world_polyline(&m, &[current, *start], false));
+                }
+            }
+            Geometry::Path { subpaths, .. } => {
+                for sub in subpaths.iter().filter(|sub| !sub.segments.is_empty()) {
+                    for piece in subpath_pieces(sub) {
+                        match piece {
+                            PathPiece::Line(p, q) => {
+                                edges.extend(world_polyline(&m, &[p, q], false))
+                            }
+                            PathPiece::Cubic(cubic) => edges.push(world_cubic(&m, cubic)),
+                            PathPiece::Arc {
+                                center,
+                                rx,
+                                ry,
+                                rotation,
+                                start_angle,
+                                sweep,
+                            } => {
+                                if exact_arcs && (rx - ry).abs() <= 1e-12 * rx.max(ry) {
+                                    // 원호: 매개변수 각도에 장축 회전을 더하면 실제 각도
+                                    edges.push(world_arc(
+                                        &m,
+                                        center,
+                                        rx,
+                                        start_angle + rotation,
+                                        sweep,
+                                    ));
+                                } else {
+                                    edges.extend(world_elliptical_arc(
+                                        &m,
+                                        center,
+                                        rx,
+                                        ry,
+                                        rotation,
+                                        start_angle,
+                                        sweep,
+                                        local_tolerance,
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            geometry => {
+                for polyline in geometry_polylines(geometry, local_tolerance) {
+                    edges.extend(world_polyline(&m, &polyline.points, polyline.closed));
+                }
+            }
+        }
+
+        if !edges.is_empty() {
+            out.push(WorldEdges {
+                name: name.to_string(),
+                edges,
+                tolerance,
+            });
+        }
+    }
+
+    /// 이름 목록을 외곽선이 있는 하위 도형들로 펼칩니다 (중복 제거, 순서 유지).
+    fn collect_leaf_edges(
+        &self,
+        fn_name: &str,
+        names: &[String],
+    ) -> Result<Vec<WorldEdges>, SceneError> {
+        let mut leaves: Vec<WorldEdges> = Vec::new();
+        for name in names {
+            if !self.has_entity(name) {
+                return Err(SceneError::EntityNotFound(
+                    fn_name.to_string(),
+                    name.to_string(),
+                ));
+            }
+            let mut found = Vec::new();
+            self.collect_world_edges(name, &mut found);
+            for leaf in found {
+                if leaves.iter().all(|l| l.name != leaf.name) {
+                    leaves.push(leaf);
+                }
+            }
+        }
+        Ok(leaves)
+    }
+
+    /// 두 도형의 모든 조각 쌍 교점을 out에 추가합니다.
+    pub(super) fn intersect_leaves(
+        &self,
+        a: &WorldEdges,
+        b: &WorldEdges,
+        out: &mut Vec<Intersection>,
+    ) {
+        // 빠른 제외: 월드 바운드가 겹치지 않으면 교점 없음
+        if let (Some((a_min, a_max)), Some((b_min, b_max))) = (
+            self.get_world_bounds_internal(&a.name),
+            self.get_world_bounds_internal(&b.name),
+        ) {
+            let slack = a.tolerance.max(b.tolerance);
+            if a_min[0] > b_max[0] + slack
+                || b_min[0] > a_max[0] + slack
+                || a_min[1] > b_max[1] + slack
+                || b_min[1] > a_max[1] + slack
+            {
+                return;
+            }
+        }
+
+        let tolerance = a.tolerance.min(b.tolerance);
+        let start = out.len();
+        for (i, ea) in a.edges.iter().enumerate() {
+            for (j, eb) in b.edges.iter().enumerate() {
+                for crossing in edge_intersections(ea, eb, tolerance) {
+                    // 다각형 꼭짓점처럼 이웃한 두 조각이 같은 교점을 내면 하나만 유지
+                    let duplicate = out[start..].iter().any(|hit| {
+                        (hit.point[0] - crossing.point[0]).hypot(hit.point[1] - crossing.point[1])
+                            <= tolerance
+                    });
+                    if duplicate {
+                        continue;
+                    }
+                    out.push(Intersection {
+                        point: crossing.point,
+                        a: EdgeHit {
+                            name: a.name.clone(),
+                            edge: i,
+                            t: crossing.t_a,
+                        },
+                        b: EdgeHit {
+                            name: b.name.clone(),
+                            edge: j,
+                            t: crossing.t_b,
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    /// 두 Entity 외곽선의 월드 좌표 교점을 계산합니다 (내부용).
+    ///
+    /// Group이면 하위 도형 각각의 외곽선을 사용하며, 같은 도형 안의 자기 교차는 제외합니다.
+    ///
+    /// # Errors
+    /// * Entity 미발견
+    pub(crate) fn get_intersections_internal(
+        &self,
+        a: &str,
+        b: &str,
+    ) -> Result<Vec<Intersection>, SceneError> {
+        let leaves_a = self.collect_leaf_edges("get_intersections", &[a.to_string()])?;
+        let leaves_b = self.collect_leaf_edges("get_intersections", &[b.to_string()])?;
+        let mut out = Vec::new();
+        for la in &leaves_a {
+            for lb in leaves_b.iter().filter(|lb| lb.name != la.name) {
+                self.intersect_leaves(la, lb, &mut out);
+            }
+        }
+        Ok(out)
+    }
+
+    /// 선택된 Entity들(그룹은 하위 도형)의 서로 다른 도형 쌍마다 교점을 계산합니다 (내부용).
+    ///
+    /// # Errors
+    /// * Entity 미발견
+    pub(crate) fn get_selection_intersections_internal(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Intersection>, SceneError> {
+        let leaves = self.collect_leaf_edges("get_selection_intersections", names)?;
+        let mut out = Vec::new();
+        for (i, la) in leaves.iter().enumerate() {
+            for lb in &leaves[i + 1..] {
+                self.intersect_leaves(la, lb, &mut out);
+            }
+        }
+        Ok(out)
+    }
+}
+
+// ========================================
+// WASM Bindings for Intersections
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 두 Entity 외곽선의 월드 좌표 교점을 모두 반환합니다.
+    ///
+    /// - 선분/원/원호끼리는 정확, Bezier가 포함되면 분할로 바운드 대각선의 1e-5 이내
+    /// - 접하는 곳은 교점 하나, 겹치는 선분/원호는 겹친 구간의 끝점
+    /// - Group이면 하위 도형 각각과 교차 (같은 도형 안의 자기 교차는 제외)
+    ///
+    /// # Arguments
+    /// * `a`, `b` - Entity 또는 Group 이름
+    ///
+    /// # Returns
+    /// JSON 배열: `[{"point": [x, y], "a": {"name": "wall", "edge": 2, "t": 0.5},
+    /// "b": {"name": "door", "edge": 0, "t": 0.25}}, ...]`
+    /// - `name`: 교점이 놓인 하위 도형 이름, `edge`: 그 도형 외곽선의 조각 번호
+    ///   (Polygon/Rect/Line은 변, Bezier/Path는 세그먼트 순서)
+    /// - `t`: 조각 위 매개변수 [0, 1] (선분/베지어는 곡선 매개변수, 원/원호는 시작 각도에서의 스윕 비율)
+    ///
+    /// # Errors
+    /// * Entity 미발견
+    pub fn get_intersections(&self, a: &str, b: &str) -> Result<String, JsValue> {
+        let hits = self
+            .get_intersections_internal(a, b)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&hits).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// 선택된 여러 Entity 사이의 교점을 모두 반환합니다.
+    ///
+    /// Group은 하위 도형으로 펼치고, 서로 다른 도형의 모든 쌍을 교차합니다.
+    ///
+    /// # Arguments
+    /// * `names_json` - 이름들의 JSON 배열 (예: '["wall", "door", "furniture"]')
+    ///
+    /// # Returns
+    /// JSON 배열 (형식은 `get_intersections`와 동일, `a`가 목록에서 앞선 도형)
+    ///
+    /// # Errors
+    /// * 잘못된 JSON, Entity 미발견
+    pub fn get_selection_intersections(&self, names_json: &str) -> Result<String, JsValue> {
+        let names: Vec<String> = serde_json::from_str(names_json).map_err(|e| {
+            JsValue::from_str(&format!(
+                "[get_selection_intersections] invalid_json: {}",
+                e
+            ))
+        })?;
+        let hits = self
+            .get_selection_intersections_internal(&names)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&hits).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_intersections_line_and_circle_with_params() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![-10.0, 3.0, 10.0, 3.0])
+            .unwrap();
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+
+        let hits = scene.get_intersections_internal("l", "c").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(approx_eq(hits[0].point[0], -4.0, 1e-12));
+        assert!(approx_eq(hits[1].point[0], 4.0, 1e-12));
+        assert_eq!(hits[1].a.name, "l");
+        assert_eq!(hits[1].a.edge, 0);
+        assert!(approx_eq(hits[1].a.t, 0.7, 1e-12));
+        assert_eq!(hits[1].b.name, "c");
+        assert!(approx_eq(
+            hits[1].b.t,
+            3.0_f64.atan2(4.0) / (2.0 * PI),
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_intersections_rect_corners_deduplicated_under_group() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 10.0, 10.0).unwrap();
+        let (min, max) = scene.get_world_bounds_internal("r").unwrap();
+        // 사각형 대각선: 두 꼭짓점에서만 만남 (각 꼭짓점은 두 변에 걸쳐 있음)
+        scene
+            .add_line_internal("diag", vec![min[0], min[1], max[0], max[1]])
+            .unwrap();
+        let hits = scene.get_intersections_internal("diag", "r").unwrap();
+        assert_eq!(hits.len(), 2);
+
+        // 그룹 변환 후에도 월드 좌표 기준
+        scene
+            .create_group_internal("g", vec!["r".to_string()])
+            .unwrap();
+        scene.translate("g", 100.0, 0.0).unwrap();
+        assert!(
+            scene
+                .get_intersections_internal("diag", "g")
+                .unwrap()
+                .is_empty()
+        );
+        scene.translate("diag", 100.0, 0.0).unwrap();
+        let hits = scene.get_intersections_internal("g", "diag").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].a.name, "r");
+    }
+
+    #[test]
+    fn test_intersections_bezier_and_scaled_circle() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("hump", "M 0,0 C 0,4 10,4 10,0", "{}")
+            .unwrap();
+        scene
+            .add_line_internal("l", vec![-1.0, 1.0, 11.0, 1.0])
+            .unwrap();
+        let hits = scene.get_intersections_internal("hump", "l").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| approx_eq(h.point[1], 1.0, 1e-6)));
+        assert!(hits[0].a.t < hits[1].a.t);
+
+        // 비균일 스케일된 원 (타원 x²/4 + y² = 1) 과 x축
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        scene.scale("c", 2.0, 1.0).unwrap();
+        scene
+            .add_line_internal("axis", vec![-5.0, 0.0, 5.0, 0.0])
+            .unwrap();
+        let hits = scene.get_intersections_internal("axis", "c").unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| approx_eq(h.point[0].abs(), 2.0, 1e-4)));
+    }
+
+    #[test]
+    fn test_selection_intersections_all_pairs() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("h", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .add_line_internal("v1", vec![2.0, -1.0, 2.0, 1.0])
+            .unwrap();
+        scene
+            .add_line_internal("v2", vec![8.0, -1.0, 8.0, 1.0])
+            .unwrap();
+        scene
+            .create_group_internal("verticals", vec!["v1".to_string(), "v2".to_string()])
+            .unwrap();
+
+        let names = vec!["h".to_string(), "verticals".to_string(), "v1".to_string()];
+        let hits = scene.get_selection_intersections_internal(&names).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|h| h.a.name == "h"));
+        assert_eq!(hits[0].b.name, "v1");
+        assert_eq!(hits[1].b.name, "v2");
+
+        let missing = vec!["h".to_string(), "nope".to_string()];
+        assert!(matches!(
+            scene.get_selection_intersections_internal(&missing),
+            Err(SceneError::EntityNotFound(..))
+        ));
+    }
+}
@@ -1,27 +1,53 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
 use uuid::Uuid;
 use wasm_bindgen::prelude::*;
 
+mod align;
+mod array;
+mod boolean;
+mod constraint;
+mod corner;
+mod dimension;
+mod distance;
+mod duplicate;
 pub mod entity;
+mod flatten;
 mod groups;
+mod intersect;
+mod offset;
+mod path;
 mod path_parser;
+mod pick;
 mod primitives;
 mod query;
+mod select;
+mod snap;
+mod spatial;
 pub mod style;
+mod text;
 mod transforms;
+mod triangulate;
 mod z_order;
 
+use crate::geometry::bounds::{bbox_of, bbox_union, geometry_bbox, visual_bbox};
 use crate::serializers::json::serialize_scene;
-use crate::serializers::svg::serialize_scene_svg;
-use entity::{Entity, EntityType, Geometry, Matrix3x3, Metadata, Style, Transform};
-pub use style::{FillStyle, LineCap, LineJoin, StrokeStyle};
+use crate::serializers::svg::{SvgOptions, serialize_scene_svg_with_options};
+use entity::{
+    Constraint, Entity, EntityHandle, EntityType, Geometry, Matrix3x3, Metadata, Style, Transform,
+};
+pub use style::{
+    FillStyle, Gradient, GradientStop, HatchPattern, HatchStyle, LineCap, LineJoin, StrokeStyle,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum SceneError {
     DuplicateEntityName(String, String), // (fn_name, entity_name)
     InvalidInput(String),
-    NotAGroup(String, String), // (fn_name, entity_name)
-    InvalidOperation(String),  // 순환 참조 등 유효하지 않은 작업
+    NotAGroup(String, String),      // (fn_name, entity_name)
+    InvalidOperation(String),       // 순환 참조 등 유효하지 않은 작업
+    EntityNotFound(String, String), // (fn_name, entity_name)
 }
 
 impl fmt::Display for SceneError {
@@ -47,6 +73,9 @@ impl fmt::Display for SceneError {
             SceneError::InvalidOperation(msg) => {
                 write!(f, "invalid_operation: {}", msg)
             }
+            SceneError::EntityNotFound(fn_name, name) => {
+                write!(f, "[{}] not_found: Entity '{}' not found", fn_name, name)
+            }
         }
     }
 }
@@ -56,9 +85,19 @@ impl std::error::Error for SceneError {}
 #[wasm_bindgen]
 pub struct Scene {
     name: String,
+    /// Entity 저장소 (삭제 시 마지막 Entity가 빈 자리로 이동하므로 순서 무의미,
+    /// 같은 z_index일 때의 그리기 순서는 핸들 번호 = 추가 순서)
     entities: Vec<Entity>,
+    /// 이름 → 핸들
+    names: HashMap<String, EntityHandle>,
+    /// 핸들 번호 → entities 내 위치 (삭제된 핸들은 None)
+    slots: Vec<Option<usize>>,
     /// 마지막 실행된 작업 (LLM 작업 추적용)
     last_operation: Option<String>,
+    /// 도형 월드 바운드 공간 인덱스 (질의 시 갱신, spatial.rs)
+    spatial: RefCell<spatial::SceneSpatial>,
+    /// 기하 제약 조건 (추가 순서 = 중복 판정 우선순위, constraint.rs)
+    constraints: Vec<Constraint>,
 }
 
 fn generate_id() -> String {
@@ -66,16 +105,120 @@ fn generate_id() -> String {
 }
 
 impl Scene {
+    // ========================================
+    // Entity Storage (이름 → 핸들 → 위치)
+    // ========================================
+
+    /// 핸들로 Entity를 조회합니다 (삭제된 핸들은 None).
+    fn entity_at(&self, handle: EntityHandle) -> Option<&Entity> {
+        let position = (*self.slots.get(handle.index())?)?;
+        self.entities.get(position)
+    }
+
+    /// 핸들로 Entity를 가변 조회합니다 (변경될 수 있으므로 공간 인덱스 갱신 대상으로 표시).
+    fn entity_at_mut(&mut self, handle: EntityHandle) -> Option<&mut Entity> {
+        let position = (*self.slots.get(handle.index())?)?;
+        self.mark_spatial_dirty(handle);
+        self.entities.get_mut(position)
+    }
+
+    fn handle_of(&self, name: &str) -> Option<EntityHandle> {
+        self.names.get(name).copied()
+    }
+
     fn find_by_name(&self, name: &str) -> Option<&Entity> {
-        self.entities
-            .iter()
-            .find(|entity| entity.metadata.name == name)
+        self.entity_at(self.handle_of(name)?)
     }
 
     fn find_by_name_mut(&mut self, name: &str) -> Option<&mut Entity> {
-        self.entities
-            .iter_mut()
-            .find(|entity| entity.metadata.name == name)
+        let handle = self.handle_of(name)?;
+        self.entity_at_mut(handle)
+    }
+
+    /// 부모 그룹 (최상위면 None)
+    fn parent_of(&self, entity: &Entity) -> Option<&Entity> {
+        self.entity_at(entity.parent?)
+    }
+
+    /// 자식 Entity들 (children 순서)
+    fn children_of<'a>(&'a self, entity: &'a Entity) -> impl Iterator<Item = &'a Entity> + 'a {
+        entity
+            .children
+            .iter()
+            .filter_map(|child| self.entity_at(*child))
+    }
+
+    /// Entity를 추가하고 새 핸들을 할당합니다.
+    fn push_entity(&mut self, mut entity: Entity) -> EntityHandle {
+        let handle = EntityHandle::from_index(self.slots.len());
+        entity.handle = handle;
+        self.slots.push(Some(self.entities.len()));
+        self.names.insert(entity.metadata.name.clone(), handle);
+        self.mark_spatial_dirty(handle);
+        self.invalidate_paint_order();
+        self.entities.push(entity);
+        handle
+    }
+
+    /// Entity를 저장소에서 제거합니다 (부모/자식 링크 정리는 호출자 책임).
+    ///
+    /// 마지막 Entity를 빈 자리로 옮기고(swap_remove) 그 슬롯만 갱신하므로 O(1)이며,
+    /// 핸들은 그대로 유지됩니다.
+    fn remove_entity(&mut self, handle: EntityHandle) -> Option<Entity> {
+        let position = self.slots.get_mut(handle.index())?.take()?;
+        let entity = self.entities.swap_remove(position);
+        if let Some(moved) = self.entities.get(position) {
+            self.slots[moved.handle.index()] = Some(position);
+        }
+        self.names.remove(&entity.metadata.name);
+        self.mark_spatial_dirty(handle);
+        self.invalidate_paint_order();
+        Some(entity)
+    }
+
+    /// Entity 이름을 변경합니다 (내부용).
+    ///
+    /// 계층 링크는 핸들이므로 그대로이며, 이름으로 참조하는 치수만 함께 갱신합니다.
+    ///
+    /// # Returns
+    /// * Ok(true) - 변경 성공 (같은 이름이면 no-op)
+    /// * Ok(false) - old_name 미발견
+    ///
+    /// # Errors
+    /// * 빈 이름, 이미 존재하는 new_name
+    pub(crate) fn rename_internal(
+        &mut self,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<bool, SceneError> {
+        if new_name.is_empty() {
+            return Err(SceneError::InvalidInput(
+                "[rename] invalid_input: name must not be empty".to_string(),
+            ));
+        }
+        let Some(handle) = self.handle_of(old_name) else {
+            return Ok(false);
+        };
+        if old_name == new_name {
+            return Ok(true);
+        }
+        if self.has_entity(new_name) {
+            return Err(SceneError::DuplicateEntityName(
+                "rename".to_string(),
+                new_name.to_string(),
+            ));
+        }
+
+        self.names.remove(old_name);
+        self.names.insert(new_name.to_string(), handle);
+        if let Some(position) = self.slots[handle.index()] {
+            self.entities[position].metadata.name = new_name.to_string();
+        }
+        self.rename_dimension_references(old_name, new_name);
+        self.rename_constraint_references(old_name, new_name);
+
+        self.last_operation = Some(format!("rename({}, {})", old_name, new_name));
+        Ok(true)
     }
 
     fn has_entity(&self, name: &str) -> bool {
@@ -97,8 +240,7 @@ impl Scene {
         }
 
         // Calculate geometry center for default pivot
-        let (min, max) = Self::geometry_bounds(&geometry);
-        let pivot = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        let pivot = Self::default_pivot(&geometry);
 
         // Auto-increment z_order (0 = bottom, higher = front)
         let z_order = self.allocate_z_order();
@@ -117,15 +259,17 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         Ok(name.to_string())
     }
 
-    // Primitive creation helpers: add_line_internal, add_circle_internal, add_rect_internal, add_arc_internal
+    // Primitive creation helpers: add_line_internal, add_circle_internal, add_rect_internal, add_arc_internal,
+    // add_ellipse_internal, add_elliptical_arc_internal
     // See primitives.rs for implementations
 
     /// Set pivot point for an entity (internal, for native testing)
@@ -155,7 +299,11 @@ impl Scene {
         Scene {
             name: name.to_string(),
             entities: Vec::new(),
+            names: HashMap::new(),
+            slots: Vec::new(),
             last_operation: None,
+            spatial: RefCell::default(),
+            constraints: Vec::new(),
         }
     }
 
@@ -167,7 +315,7 @@ impl Scene {
         let max_root_z = self
             .entities
             .iter()
-            .filter(|e| e.parent_id.is_none())
+            .filter(|e| e.parent.is_none())
             .map(|e| e.metadata.z_index)
             .max()
             .unwrap_or(-1);
@@ -182,6 +330,25 @@ impl Scene {
         self.entities.len()
     }
 
+    /// Entity 이름을 변경합니다.
+    ///
+    /// 그룹 관계는 유지되며, 이 Entity를 참조하는 치수도 새 이름을 따라갑니다.
+    ///
+    /// # Arguments
+    /// * `old_name` - 현재 이름
+    /// * `new_name` - 새 이름 (Scene 내 unique)
+    ///
+    /// # Returns
+    /// * Ok(true) - 변경 성공
+    /// * Ok(false) - old_name 미발견 (no-op)
+    ///
+    /// # Errors
+    /// * 빈 이름, 이미 존재하는 new_name
+    pub fn rename(&mut self, old_name: &str, new_name: &str) -> Result<bool, JsValue> {
+        self.rename_internal(old_name, new_name)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Scene을 JSON으로 내보냅니다.
     pub fn export_json(&self) -> String {
         serialize_scene(self)
@@ -189,16 +356,40 @@ impl Scene {
 
     /// Scene을 SVG로 내보냅니다.
     pub fn export_svg(&self) -> String {
-        serialize_scene_svg(&self.entities)
+        serialize_scene_svg_with_options(&self.entities, &self.svg_options(false))
+    }
+
+    /// Scene을 SVG로 내보냅니다 (텍스트를 글리프 외곽선 path로 변환).
+    ///
+    /// 뷰어/환경의 폰트에 관계없이 동일하게 렌더링됩니다.
+    /// 내장 단선 폰트에는 소문자 글리프가 없어 소문자는 x-height 크기의 대문자(small caps)로 그려집니다
+    /// (글자 폭과 바운드는 `export_svg`의 `<text>`와 동일).
+    pub fn export_svg_with_text_outlines(&self) -> String {
+        serialize_scene_svg_with_options(&self.entities, &self.svg_options(true))
     }
 
     // Primitives (add_*, draw_*): see primitives.rs
+    // Text (draw_text): see text.rs
+    // Path (add_path, draw_path, draw_svg_path, get_path_vertex_count): see path.rs
+    // Dimensions (add_*_dimension, set_dimension_format, get_dimension): see dimension.rs
 
     // Style Functions: see style.rs
-    // Transform Functions (translate, rotate, scale, translate_world, scale_world, delete, set_pivot): see transforms.rs
+    // Transform Functions (translate, rotate, scale, skew, mirror, translate_world, scale_world, delete, set_pivot): see transforms.rs
     // Z-Order: see z_order.rs
     // Group Functions (create_group, ungroup, add_to_group, remove_from_group): see groups.rs
     // Scene Query Functions, World Transform API: see query.rs
+    // Boolean Operations (boolean_op): see boolean.rs
+    // Offset (offset): see offset.rs
+    // Corner Operations (fillet, chamfer): see corner.rs
+    // Flattening (flatten_entity): see flatten.rs
+    // Triangulation (triangulate): see triangulate.rs
+    // Picking (pick): see pick.rs
+    // Region Selection (select_box, select_lasso, set_layer): see select.rs
+    // Spatial Index (query_viewport): see spatial.rs
+    // Constraints (add_constraint, solve_constraints, get_constraint_status): see constraint.rs
+    // Align / Distribute (align, distribute): see align.rs
+    // Array Copies (array_grid, array_linear, array_polar): see array.rs
+    // Duplicate (duplicate): see duplicate.rs
 }
 
 impl Scene {
@@ -211,6 +402,29 @@ impl Scene {
         &self.entities
     }
 
+    /// 추가 순서(핸들 번호)로 Entity를 순회합니다.
+    ///
+    /// 저장소 순서는 삭제 시 바뀌므로 목록/직렬화 출력은 이 순서를 사용합니다.
+    pub fn entities_in_order(&self) -> impl Iterator<Item = &Entity> + '_ {
+        self.slots
+            .iter()
+            .flatten()
+            .filter_map(|&position| self.entities.get(position))
+    }
+
+    /// 부모 그룹 이름 (최상위면 None, 직렬화용)
+    pub fn parent_name(&self, entity: &Entity) -> Option<&str> {
+        self.parent_of(entity)
+            .map(|parent| parent.metadata.name.as_str())
+    }
+
+    /// 자식 Entity 이름 목록 (children 순서, 직렬화용)
+    pub fn child_names<'a>(&'a self, entity: &'a Entity) -> Vec<&'a str> {
+        self.children_of(entity)
+            .map(|child| child.metadata.name.as_str())
+            .collect()
+    }
+
     /// Scene name getter
     pub fn name(&self) -> &str {
         &self.name
@@ -221,6 +435,14 @@ impl Scene {
         self.last_operation.as_deref()
     }
 
+    /// SVG 출력 옵션 (치수 레이아웃은 현재 참조 도형 기준으로 계산)
+    fn svg_options(&self, text_as_paths: bool) -> SvgOptions {
+        SvgOptions {
+            text_as_paths,
+            dimensions: self.dimension_local_layouts(),
+        }
+    }
+
     /// Entity의 월드 좌표 바운딩 박스 (직렬화용 public wrapper)
     pub fn get_world_bounds_for_entity(&self, name: &str) -> Option<([f64; 2], [f64; 2])> {
         self.get_world_bounds_internal(name)
@@ -232,57 +454,19 @@ impl Scene {
 
         // Group은 자식들의 로컬 바운드 합산 (각 자식의 transform 포함)
         if matches!(entity.entity_type, EntityType::Group) {
-            if entity.children.is_empty() {
-                return None;
-            }
-
-            let mut min_x = f64::INFINITY;
-            let mut min_y = f64::INFINITY;
-            let mut max_x = f64::NEG_INFINITY;
-            let mut max_y = f64::NEG_INFINITY;
-
-            for child_name in &entity.children {
-                if let Some(child) = self.find_by_name(child_name) {
-                    let child_matrix = child.transform.to_matrix();
-                    let local_vertices = Self::geometry_vertices(&child.geometry);
-
-                    for vertex in local_vertices {
-                        let transformed = Transform::transform_point(&child_matrix, vertex);
-                        min_x = min_x.min(transformed[0]);
-                        min_y = min_y.min(transformed[1]);
-                        max_x = max_x.max(transformed[0]);
-                        max_y = max_y.max(transformed[1]);
-                    }
-                }
-            }
-
-            if min_x == f64::INFINITY {
-                return None;
-            }
-            return Some(([min_x, min_y], [max_x, max_y]));
+            return self
+                .children_of(entity)
+                .map(|child| geometry_bbox(&child.geometry, &child.transform.to_matrix()))
+                .fold(None, bbox_union);
         }
 
         // 일반 도형: geometry + entity transform (부모 transform 제외)
-        let local_vertices = Self::geometry_vertices(&entity.geometry);
-        if local_vertices.is_empty() {
-            return None;
-        }
-
-        let entity_matrix = entity.transform.to_matrix();
-        let mut min_x = f64::INFINITY;
-        let mut min_y = f64::INFINITY;
-        let mut max_x = f64::NEG_INFINITY;
-        let mut max_y = f64::NEG_INFINITY;
-
-        for vertex in local_vertices {
-            let transformed = Transform::transform_point(&entity_matrix, vertex);
-            min_x = min_x.min(transformed[0]);
-            min_y = min_y.min(transformed[1]);
-            max_x = max_x.max(transformed[0]);
-            max_y = max_y.max(transformed[1]);
-        }
+        geometry_bbox(&entity.geometry, &entity.transform.to_matrix())
+    }
 
-        Some(([min_x, min_y], [max_x, max_y]))
+    /// Entity의 시각적(stroke 포함) 월드 바운딩 박스 (직렬화용 public wrapper)
+    pub fn get_visual_bounds_for_entity(&self, name: &str) -> Option<([f64; 2], [f64; 2])> {
+        self.get_visual_bounds_internal(name)
     }
 
     // ========================================
@@ -291,30 +475,14 @@ impl Scene {
 
     /// Entity의 부모 체인을 수집합니다 (루트부터 순서대로)
     ///
-    /// 순환 참조 방지를 위해 최대 100단계까지만 탐색합니다.
+    /// 부모 링크는 add_to_group에서 순환이 막히지만, 방어적으로 Entity 수만큼만 따라갑니다.
     fn collect_parent_chain(&self, name: &str) -> Vec<&Entity> {
-        use std::collections::HashSet;
-        const MAX_DEPTH: usize = 100;
-
-        let mut chain = Vec::new();
-        let mut visited = HashSet::new();
-        let mut current_name = Some(name.to_string());
-        let mut depth = 0;
-
-        while let Some(ref n) = current_name {
-            // 순환 참조 또는 깊이 제한 도달 시 중단
-            if visited.contains(n) || depth >= MAX_DEPTH {
-                break;
-            }
-            visited.insert(n.clone());
-
-            if let Some(entity) = self.find_by_name(n) {
-                chain.push(entity);
-                current_name = entity.parent_id.clone();
-                depth += 1;
-            } else {
+        let mut chain: Vec<&Entity> = self.find_by_name(name).into_iter().collect();
+        while let Some(parent) = chain.last().and_then(|e| self.parent_of(e)) {
+            if chain.len() > self.entities.len() {
                 break;
             }
+            chain.push(parent);
         }
 
         // 루트부터 순서대로 (현재 엔티티가 마지막)
@@ -342,60 +510,6 @@ impl Scene {
         Some(world_matrix)
     }
 
-    /// Geometry의 로컬 정점들을 반환합니다
-    fn geometry_vertices(geometry: &Geometry) -> Vec<[f64; 2]> {
-        match geometry {
-            Geometry::Line { points } => points.clone(),
-            Geometry::Circle { center, radius } => {
-                // 원은 4개 극점으로 근사
-                vec![
-                    [center[0] - radius, center[1]],
-                    [center[0] + radius, center[1]],
-                    [center[0], center[1] - radius],
-                    [center[0], center[1] + radius],
-                ]
-            }
-            Geometry::Rect {
-                center,
-                width,
-                height,
-            } => {
-                // 사각형 4개 꼭짓점 (center 기준)
-                let hw = width / 2.0;
-                let hh = height / 2.0;
-                vec![
-                    [center[0] - hw, center[1] - hh],
-                    [center[0] + hw, center[1] - hh],
-                    [center[0] + hw, center[1] + hh],
-                    [center[0] - hw, center[1] + hh],
-                ]
-            }
-            Geometry::Arc { center, radius, .. } => {
-                // Arc도 보수적으로 원 극점 사용
-                vec![
-                    [center[0] - radius, center[1]],
-                    [center[0] + radius, center[1]],
-                    [center[0], center[1] - radius],
-                    [center[0], center[1] + radius],
-                ]
-            }
-            Geometry::Polygon { points, .. } => points.clone(),
-            Geometry::Bezier {
-                start, segments, ..
-            } => {
-                // 베지어 커브는 모든 제어점과 끝점을 포함
-                let mut vertices = vec![*start];
-                for seg in segments {
-                    vertices.push(seg[0]); // cp1
-                    vertices.push(seg[1]); // cp2
-                    vertices.push(seg[2]); // end
-                }
-                vertices
-            }
-            Geometry::Empty => vec![],
-        }
-    }
-
     /// Entity의 월드 좌표 바운딩 박스를 계산합니다 (내부용)
     fn get_world_bounds_internal(&self, name: &str) -> Option<([f64; 2], [f64; 2])> {
         let entity = self.find_by_name(name)?;
@@ -412,8 +526,10 @@ impl Scene {
             let mut max_x = f64::NEG_INFINITY;
             let mut max_y = f64::NEG_INFINITY;
 
-            for child_name in &entity.children {
-                if let Some((child_min, child_max)) = self.get_world_bounds_internal(child_name) {
+            for child in self.children_of(entity) {
+                if let Some((child_min, child_max)) =
+                    self.get_world_bounds_internal(&child.metadata.name)
+                {
                     min_x = min_x.min(child_min[0]);
                     min_y = min_y.min(child_min[1]);
                     max_x = max_x.max(child_max[0]);
@@ -427,26 +543,39 @@ impl Scene {
             return Some(([min_x, min_y], [max_x, max_y]));
         }
 
-        // 일반 도형: 로컬 정점들을 월드 좌표로 변환
-        let local_vertices = Self::geometry_vertices(&entity.geometry);
-        if local_vertices.is_empty() {
-            return None;
+        // 치수: 참조 도형으로 계산한 치수선/화살표/문자 상자 기준
+        if matches!(entity.entity_type, EntityType::Dimension) {
+            return bbox_of(&self.dimension_world_layout(name)?.points());
         }
 
-        let mut min_x = f64::INFINITY;
-        let mut min_y = f64::INFINITY;
-        let mut max_x = f64::NEG_INFINITY;
-        let mut max_y = f64::NEG_INFINITY;
+        // 일반 도형: 변환 후 좌표계에서 해석적으로 계산한 극점 기준
+        geometry_bbox(&entity.geometry, &world_matrix)
+    }
+
+    /// Entity의 시각적 월드 바운딩 박스를 계산합니다 (내부용)
+    ///
+    /// stroke 폭, join(miter 돌출), cap 형태를 포함하여 렌더링 결과와 일치합니다.
+    /// Group은 자식들의 시각적 바운드 합집합입니다.
+    fn get_visual_bounds_internal(&self, name: &str) -> Option<([f64; 2], [f64; 2])> {
+        let entity = self.find_by_name(name)?;
 
-        for vertex in local_vertices {
-            let world_point = Transform::transform_point(&world_matrix, vertex);
-            min_x = min_x.min(world_point[0]);
-            min_y = min_y.min(world_point[1]);
-            max_x = max_x.max(world_point[0]);
-            max_y = max_y.max(world_point[1]);
+        if matches!(entity.entity_type, EntityType::Group) {
+            return self
+                .children_of(entity)
+                .map(|child| self.get_visual_bounds_internal(&child.metadata.name))
+                .fold(None, bbox_union);
         }
 
-        Some(([min_x, min_y], [max_x, max_y]))
+        if matches!(entity.entity_type, EntityType::Dimension) {
+            return self.get_world_bounds_internal(name);
+        }
+
+        let world_matrix = self.get_world_transform_internal(name)?;
+        visual_bbox(
+            &entity.geometry,
+            &world_matrix,
+            entity.style.stroke.as_ref(),
+        )
     }
 
     /// Scene의 전체 bounding box를 계산합니다.
@@ -476,84 +605,33 @@ impl Scene {
         Some(([min_x, min_y], [max_x, max_y]))
     }
 
-    /// Geometry의 bounding box를 계산합니다.
-    fn geometry_bounds(geometry: &Geometry) -> ([f64; 2], [f64; 2]) {
-        match geometry {
-            Geometry::Line { points } => {
-                let mut min_x = f64::INFINITY;
-                let mut min_y = f64::INFINITY;
-                let mut max_x = f64::NEG_INFINITY;
-                let mut max_y = f64::NEG_INFINITY;
-
-                for point in points {
-                    min_x = min_x.min(point[0]);
-                    min_y = min_y.min(point[1]);
-                    max_x = max_x.max(point[0]);
-                    max_y = max_y.max(point[1]);
-                }
-
-                ([min_x, min_y], [max_x, max_y])
-            }
-            Geometry::Circle { center, radius } => (
-                [center[0] - radius, center[1] - radius],
-                [center[0] + radius, center[1] + radius],
-            ),
-            Geometry::Rect {
-                center,
-                width,
-                height,
-            } => (
-                [center[0] - width / 2.0, center[1] - height / 2.0],
-                [center[0] + width / 2.0, center[1] + height / 2.0],
-            ),
-            Geometry::Arc { center, radius, .. } => {
-                // Arc는 보수적으로 전체 원의 bounding box 사용
-                (
-                    [center[0] - radius, center[1] - radius],
-                    [center[0] + radius, center[1] + radius],
-                )
-            }
-            Geometry::Polygon { points, .. } => {
-                // holes는 bounds 계산에 영향 없음 (outer points만 사용)
-                let mut min_x = f64::INFINITY;
-                let mut min_y = f64::INFINITY;
-                let mut max_x = f64::NEG_INFINITY;
-                let mut max_y = f64::NEG_INFINITY;
-
-                for point in points {
-                    min_x = min_x.min(point[0]);
-                    min_y = min_y.min(point[1]);
-                    max_x = max_x.max(point[0]);
-                    max_y = max_y.max(point[1]);
-                }
-
-                ([min_x, min_y], [max_x, max_y])
-            }
+    /// 새 Entity의 기본 pivot (bounding box 중심)
+    ///
+    /// Arc는 원 중심, Bezier는 제어점까지 포함한 박스 중심을 그대로 사용합니다.
+    /// 바운드가 곡선 극점 기준으로 바뀌어도 생성 시 pivot은 달라지지 않습니다.
+    fn default_pivot(geometry: &Geometry) -> [f64; 2] {
+        let (min, max) = match geometry {
+            Geometry::Arc { center, .. } => return *center,
             Geometry::Bezier {
                 start, segments, ..
-            } => {
-                // 베지어 커브: 모든 제어점과 끝점의 bounds
-                let mut min_x = start[0];
-                let mut min_y = start[1];
-                let mut max_x = start[0];
-                let mut max_y = start[1];
-
-                for seg in segments {
-                    for point in seg {
-                        min_x = min_x.min(point[0]);
-                        min_y = min_y.min(point[1]);
-                        max_x = max_x.max(point[0]);
-                        max_y = max_y.max(point[1]);
-                    }
-                }
+            } => segments
+                .iter()
+                .flatten()
+                .fold((*start, *start), |(min, max), p| {
+                    (
+                        [min[0].min(p[0]), min[1].min(p[1])],
+                        [max[0].max(p[0]), max[1].max(p[1])],
+                    )
+                }),
+            _ => Self::geometry_bounds(geometry),
+        };
+        [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]
+    }
 
-                ([min_x, min_y], [max_x, max_y])
-            }
-            Geometry::Empty => {
-                // Group은 자체 geometry가 없으므로 영점 반환 (자식 bounds는 별도 계산)
-                ([0.0, 0.0], [0.0, 0.0])
-            }
-        }
+    /// Geometry의 bounding box를 계산합니다 (Arc/Bezier는 실제 곡선 극점 기준).
+    fn geometry_bounds(geometry: &Geometry) -> ([f64; 2], [f64; 2]) {
+        // Group은 자체 geometry가 없으므로 영점 반환 (자식 bounds는 별도 계산)
+        geometry_bbox(geometry, &Transform::identity_matrix()).unwrap_or(([0.0, 0.0], [0.0, 0.0]))
     }
 }
 
@@ -673,6 +751,113 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_handles_stable_across_delete() {
+        let mut scene = Scene::new("test");
+        for name in ["a", "b", "c", "d"] {
+            scene
+                .add_entity_internal("add_entity", name, EntityType::Line, sample_geometry())
+                .unwrap();
+        }
+        let c = scene.handle_of("c").unwrap();
+        let d = scene.handle_of("d").unwrap();
+
+        scene.delete("b").unwrap();
+        assert_eq!(scene.handle_of("c"), Some(c));
+        assert_eq!(scene.entity_at(c).unwrap().metadata.name, "c");
+        assert_eq!(scene.entity_at(d).unwrap().metadata.name, "d");
+        assert!(scene.find_by_name("b").is_none());
+
+        // 삭제된 슬롯은 재사용하지 않음
+        scene
+            .add_entity_internal("add_entity", "b", EntityType::Line, sample_geometry())
+            .unwrap();
+        assert_ne!(scene.handle_of("b"), Some(c));
+        // 삭제 자리는 마지막 Entity(d)가 채움
+        let order: Vec<&str> = scene
+            .entities()
+            .iter()
+            .map(|e| e.metadata.name.as_str())
+            .collect();
+        assert_eq!(order, ["a", "d", "c", "b"]);
+        assert_eq!(scene.entity_at(d).unwrap().metadata.name, "d");
+
+        // 저장소 순서가 바뀌어도 같은 z_index의 그리기 순서는 추가 순서
+        for name in ["a", "c", "d", "b"] {
+            scene.set_z_order(name, 0).unwrap();
+        }
+        let draw_order: serde_json::Value =
+            serde_json::from_str(&scene.get_draw_order("")).unwrap();
+        assert_eq!(draw_order["order"], serde_json::json!(["a", "c", "d", "b"]));
+
+        // 목록/직렬화 출력도 저장소 순서가 아닌 추가 순서
+        let listed: Vec<serde_json::Value> = serde_json::from_str(&scene.list_entities()).unwrap();
+        let listed: Vec<&str> = listed.iter().map(|e| e["name"].as_str().unwrap()).collect();
+        assert_eq!(listed, ["a", "c", "d", "b"]);
+        let exported: serde_json::Value = serde_json::from_str(&scene.export_json()).unwrap();
+        let exported: Vec<&str> = exported["entities"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["metadata"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(exported, ["a", "c", "d", "b"]);
+    }
+
+    #[test]
+    fn test_rename_keeps_hierarchy_and_references() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["c".to_string()])
+            .unwrap();
+        scene
+            .add_dimension_internal(
+                "add_radius_dimension",
+                "r",
+                entity::DimensionKind::Radius {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(scene.rename_internal("c", "wheel"), Ok(true));
+        assert!(scene.find_by_name("c").is_none());
+        let group = scene.find_by_name("g").unwrap();
+        assert_eq!(scene.child_names(group), ["wheel"]);
+        let wheel = scene.find_by_name("wheel").unwrap();
+        assert_eq!(scene.parent_name(wheel), Some("g"));
+        assert!(scene.dimension_world_layout("r").is_some());
+
+        assert_eq!(scene.rename_internal("missing", "x"), Ok(false));
+        assert_eq!(
+            scene.rename_internal("wheel", "g").unwrap_err().to_string(),
+            "[rename] duplicate_name: Entity 'g' already exists"
+        );
+
+        let json = scene.export_json();
+        assert!(json.contains("\"parent_id\": \"g\""));
+        assert!(json.contains("\"wheel\""));
+    }
+
+    #[test]
+    fn test_deep_hierarchy_world_transform() {
+        // 100단계를 넘는 중첩 그룹도 끝까지 변환을 누적
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("leaf", 0.0, 0.0, 1.0).unwrap();
+        let mut inner = "leaf".to_string();
+        for depth in 0..150 {
+            let name = format!("g{}", depth);
+            scene.create_group_internal(&name, vec![inner]).unwrap();
+            scene.translate(&name, 1.0, 0.0).unwrap();
+            inner = name;
+        }
+        assert_eq!(scene.collect_parent_chain("leaf").len(), 151);
+        let (min, max) = scene.get_world_bounds_internal("leaf").unwrap();
+        assert!((min[0] + max[0] - 300.0).abs() < 1e-9);
+    }
+
     // set_pivot tests: see transforms.rs
     // world transform tests: see query.rs
 }
@@ -0,0 +1,267 @@
+//! 오프셋(offset) 모듈
+//!
+//! Entity의 월드 좌표 외곽선을 distance만큼 바깥(outset) 또는 안쪽(inset)으로 옮긴 새 Polygon을 생성합니다.
+//! - 닫힌 도형(Polygon holes 포함, Circle, Rect, Arc 부채꼴, 닫힌 Bezier, Group): 영역 오프셋
+//! - 열린 Line/Bezier/Path(subpath 1개): 경로 양쪽으로 distance 폭을 갖는 외곽 폴리곤 (벽 중심선 → 벽 두께)
+//! - inset으로 도형이 갈라지면 `{name}_0`, `{name}_1`, ... Polygon을 `name` 그룹으로 묶음
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Geometry, LineJoin, Style, Transform};
+use super::primitives::parse_style_with_warning;
+use super::{Scene, SceneError};
+use crate::geometry::flatten::{DEFAULT_CURVE_SEGMENTS, bezier_points, closed_rings};
+use crate::geometry::offset::{offset_polyline, offset_regions};
+use crate::geometry::path::subpath_points;
+
+/// join 문자열 파싱 (대소문자 무시, 그 외 → Miter)
+fn parse_join(join: &str) -> LineJoin {
+    match join.trim().to_lowercase().as_str() {
+        "round" => LineJoin::Round,
+        "bevel" => LineJoin::Bevel,
+        _ => LineJoin::Miter,
+    }
+}
+
+impl Scene {
+    /// 열린 경로(열린 Line/Bezier)의 월드 좌표 폴리라인 (닫힌 도형이면 None)
+    fn world_open_polyline(&self, name: &str, curve_segments: usize) -> Option<Vec<[f64; 2]>> {
+        let entity = self.find_by_name(name)?;
+        if closed_rings(&entity.geometry, curve_segments).is_some() {
+            return None;
+        }
+        let points = match &entity.geometry {
+            Geometry::Line { points } => points.clone(),
+            Geometry::Bezier {
+                start,
+                segments,
+                closed: false,
+            } => bezier_points(*start, segments, (curve_segments / 4).max(4)),
+            // 열린 subpath 하나로 된 Path만 경로로 취급
+            Geometry::Path { subpaths, .. } if subpaths.len() == 1 && !subpaths[0].closed => {
+                subpath_points(&subpaths[0], curve_segments)
+            }
+            _ => return None,
+        };
+        let world_matrix = self
+            .get_world_transform_internal(name)
+            .unwrap_or_else(Transform::identity_matrix);
+        Some(
+            points
+                .into_iter()
+                .map(|p| Transform::transform_point(&world_matrix, p))
+                .collect(),
+        )
+    }
+
+    /// 내부용 오프셋 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - 결과 Entity 이름
+    /// * `source` - 원본 Entity 이름 (원본은 유지)
+    /// * `distance` - 양수 = outset, 음수 = inset (월드 좌표 단위)
+    /// * `join` - 꺾임점 처리 (Miter | Round | Bevel)
+    /// * `curve_segments` - 원 전체 근사 세그먼트 수 (0 = 기본값 64)
+    /// * `style` - 결과 스타일 (None이면 원본 스타일 상속)
+    ///
+    /// # Errors
+    /// * name 중복, source 미발견, NaN/Infinity/0 distance
+    /// * 열린 도형에 음수 distance (inset할 영역 없음)
+    /// * 결과가 비어 있음 (inset이 도형보다 큼)
+    pub(crate) fn offset_internal(
+        &mut self,
+        name: &str,
+        source: &str,
+        distance: f64,
+        join: LineJoin,
+        curve_segments: usize,
+        style: Option<Style>,
+    ) -> Result<String, SceneError> {
+        if !distance.is_finite() || distance == 0.0 {
+            return Err(SceneError::InvalidInput(
+                "[offset] invalid_input: distance must be a non-zero finite number".to_string(),
+            ));
+        }
+        if self.has_entity(name) {
+            return Err(SceneError::DuplicateEntityName(
+                "offset".to_string(),
+                name.to_string(),
+            ));
+        }
+        let curve_segments = if curve_segments == 0 {
+            DEFAULT_CURVE_SEGMENTS
+        } else {
+            curve_segments.max(3)
+        };
+
+        let polygons = match self.world_open_polyline(source, curve_segments) {
+            // 닫히지 않은 Line/Bezier → 경로 양쪽 buffer
+            Some(points) => {
+                if distance < 0.0 {
+                    return Err(SceneError::InvalidInput(format!(
+                        "[offset] invalid_input: Entity '{}' is an open path; use a positive distance",
+                        source
+                    )));
+                }
+                offset_polyline(&points, distance, join, curve_segments)
+            }
+            None => {
+                let regions = self.world_regions("offset", source, curve_segments)?;
+                offset_regions(&regions, distance, join, curve_segments)
+            }
+        };
+
+        if polygons.is_empty() {
+            return Err(SceneError::InvalidOperation(format!(
+                "offset of '{}' by {} produced an empty result",
+                source, distance
+            )));
+        }
+
+        let style = style.unwrap_or_else(|| {
+            self.find_by_name(source)
+                .filter(|e| !matches!(e.entity_type, EntityType::Group))
+                .map(|e| e.style.clone())
+                .unwrap_or_default()
+        });
+
+        self.add_polygon_results("offset", name, polygons, &style)?;
+        self.last_operation = Some(format!("offset({}, {}, {})", name, source, distance));
+        Ok(name.to_string())
+    }
+}
+
+// ========================================
+// WASM Bindings for Offset
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 도형의 외곽선을 distance만큼 오프셋한 새 Polygon을 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - 결과 Entity 이름 - Scene 내 unique
+    /// * `source` - 원본 Entity 이름 (Group이면 하위 도형 합집합)
+    /// * `distance` - 양수 = 바깥(outset), 음수 = 안쪽(inset), 월드 좌표 단위
+    ///   - 열린 Line/Bezier는 양수만 허용: 경로 양쪽으로 distance 폭 (벽 두께 = 2 × distance)
+    /// * `join` - "miter" | "round" | "bevel" (그 외 → miter, miter limit 4 초과 시 bevel)
+    /// * `curve_segments` - 원 전체 근사 세그먼트 수 (0 = 기본값 64)
+    /// * `style_json` - 결과 스타일 JSON (빈 문자열이면 원본 스타일 상속)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///   - inset으로 여러 조각이 되면 `{name}_0`, `{name}_1`, ... 를 자식으로 갖는 Group `name`
+    ///
+    /// # Errors
+    /// * name 중복, source 미발견, distance가 0/NaN/Infinity
+    /// * 결과가 비어 있음 (inset 거리가 도형보다 큼)
+    ///
+    /// # Notes
+    /// 결과는 월드 좌표로 저장되며 원본 도형은 그대로 유지됩니다. holes는 outset 시 줄어들고 inset 시 커집니다.
+    pub fn offset(
+        &mut self,
+        name: &str,
+        source: &str,
+        distance: f64,
+        join: &str,
+        curve_segments: u32,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        let style = if style_json.is_empty() {
+            None
+        } else {
+            Some(parse_style_with_warning(style_json, "offset"))
+        };
+
+        self.offset_internal(
+            name,
+            source,
+            distance,
+            parse_join(join),
+            curve_segments as usize,
+            style,
+        )
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::polygon::signed_area;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn polygon_area(scene: &Scene, name: &str) -> f64 {
+        match &scene.find_by_name(name).unwrap().geometry {
+            Geometry::Polygon { points, holes } => {
+                signed_area(points).abs() - holes.iter().map(|h| signed_area(h).abs()).sum::<f64>()
+            }
+            other => panic!("Expected Polygon geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_offset_rect_outset_follows_transform() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.find_by_name_mut("r").unwrap().transform.translate = [100.0, 0.0];
+
+        scene
+            .offset_internal("r_out", "r", 1.0, LineJoin::Miter, 0, None)
+            .unwrap();
+        assert!(approx_eq(polygon_area(&scene, "r_out"), 144.0, 1e-9));
+        let (min, max) = scene.get_world_bounds_internal("r_out").unwrap();
+        assert!(approx_eq(min[0], 94.0, 1e-9));
+        assert!(approx_eq(max[0], 106.0, 1e-9));
+        assert_eq!(scene.last_operation.as_deref(), Some("offset(r_out, r, 1)"));
+    }
+
+    #[test]
+    fn test_offset_circle_inset() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 10.0).unwrap();
+        scene
+            .offset_internal("c_in", "c", -2.0, LineJoin::Round, 256, None)
+            .unwrap();
+        let area = polygon_area(&scene, "c_in");
+        assert!((area - std::f64::consts::PI * 64.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_offset_open_line_makes_wall() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("center", vec![0.0, 0.0, 100.0, 0.0])
+            .unwrap();
+        scene
+            .offset_internal("wall", "center", 5.0, LineJoin::Miter, 0, None)
+            .unwrap();
+        assert!(approx_eq(polygon_area(&scene, "wall"), 1000.0, 1e-9));
+
+        let err = scene
+            .offset_internal("bad", "center", -5.0, LineJoin::Miter, 0, None)
+            .expect_err("inset of open path should fail");
+        assert!(err.to_string().contains("open path"));
+    }
+
+    #[test]
+    fn test_offset_inset_too_large() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 10.0, 10.0).unwrap();
+        let err = scene
+            .offset_internal("gone", "r", -6.0, LineJoin::Miter, 0, None)
+            .expect_err("empty result should fail");
+        assert!(matches!(err, SceneError::InvalidOperation(_)));
+        assert!(!scene.has_entity("gone"));
+    }
+
+    #[test]
+    fn test_parse_join() {
+        assert_eq!(parse_join("Round"), LineJoin::Round);
+        assert_eq!(parse_join("bevel"), LineJoin::Bevel);
+        assert_eq!(parse_join(""), LineJoin::Miter);
+    }
+}
@@ -0,0 +1,566 @@
+//! 일반 경로(Path) Entity 모듈
+//!
+//! 여러 subpath로 이루어진 복합 도형(글자, 개스킷, 구멍 뚫린 외곽선 등)을 생성합니다.
+//! - 직선/원호/타원호/2차·3차 베지어 세그먼트를 변환 없이 저장 → JSON/SVG에 그대로 출력
+//! - 닫힌 subpath들은 boolean/offset/hatch에서 영역으로 사용 (geometry/path.rs)
+//! - SVG path 데이터(`d` 속성)를 그대로 가져오기 (draw_svg_path, path_parser.rs)
+
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, FillRule, Geometry, PathSegment, Subpath};
+use super::path_parser::parse_svg_path_data;
+use super::primitives::parse_style_with_warning;
+use super::{Scene, SceneError};
+use crate::geometry::path::{path_vertices, subpath_arcs_to_cubics};
+
+/// draw_path 입력 JSON
+#[derive(Deserialize)]
+struct PathInput {
+    subpaths: Vec<Subpath>,
+    #[serde(default)]
+    fill_rule: FillRule,
+}
+
+fn parse_path_input(fn_name: &str, path_json: &str) -> Result<PathInput, SceneError> {
+    serde_json::from_str(path_json).map_err(|e| {
+        SceneError::InvalidInput(format!(
+            "[{}] invalid_input: invalid path JSON: {}",
+            fn_name, e
+        ))
+    })
+}
+
+/// 세그먼트의 숫자 검증 (NaN/Infinity, 양수가 아닌 반지름)
+fn validate_segment(segment: &PathSegment) -> Result<(), String> {
+    let (values, radii): (Vec<f64>, Vec<f64>) = match segment {
+        PathSegment::Line { to } => (to.to_vec(), vec![]),
+        PathSegment::Quadratic { control, to } => ([*control, *to].concat(), vec![]),
+        PathSegment::Cubic {
+            control1,
+            control2,
+            to,
+        } => ([*control1, *control2, *to].concat(), vec![]),
+        PathSegment::Arc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        } => (
+            vec![center[0], center[1], *start_angle, *sweep_angle],
+            vec![*radius],
+        ),
+        PathSegment::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            sweep_angle,
+        } => (
+            vec![center[0], center[1], *rotation, *start_angle, *sweep_angle],
+            vec![*rx, *ry],
+        ),
+    };
+    if values.iter().chain(radii.iter()).any(|v| !v.is_finite()) {
+        return Err("NaN or Infinity not allowed".to_string());
+    }
+    if radii.iter().any(|r| *r <= 0.0) {
+        return Err("arc radius must be positive".to_string());
+    }
+    Ok(())
+}
+
+impl Scene {
+    /// 내부용 Path 생성 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 - Scene 내 unique
+    /// * `subpaths` - subpath 목록 (최소 1개 세그먼트 필요)
+    /// * `fill_rule` - 채우기 규칙 (NonZero | EvenOdd)
+    ///
+    /// # Errors
+    /// * name 중복, 빈 경로, NaN/Infinity, 양수가 아닌 원호 반지름
+    pub(crate) fn add_path_internal(
+        &mut self,
+        name: &str,
+        subpaths: Vec<Subpath>,
+        fill_rule: FillRule,
+    ) -> Result<String, SceneError> {
+        self.add_path_with_fn("add_path", name, subpaths, fill_rule)
+    }
+
+    /// 내부용 SVG path 데이터 → Path 생성 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 - Scene 내 unique
+    /// * `d` - SVG path 데이터 (M/L/H/V/C/S/Q/T/A/Z, 여러 subpath 허용)
+    /// * `arc_tolerance` - 0 이하: 원호를 Arc/EllipticalArc로 정확히 보존,
+    ///   양수: 원호를 허용 오차 이내의 큐빅 베지어로 변환
+    ///
+    /// # Errors
+    /// * name 중복, 문법 오류(오류 위치 포함), NaN/Infinity tolerance
+    pub(crate) fn draw_svg_path_internal(
+        &mut self,
+        name: &str,
+        d: &str,
+        arc_tolerance: f64,
+    ) -> Result<String, SceneError> {
+        if !arc_tolerance.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[draw_svg_path] invalid_input: arc_tolerance must be finite".to_string(),
+            ));
+        }
+        let mut subpaths = parse_svg_path_data(d).map_err(|e| {
+            SceneError::InvalidInput(format!("[draw_svg_path] invalid_path: {}", e))
+        })?;
+        if arc_tolerance > 0.0 {
+            subpaths = subpaths
+                .iter()
+                .map(|sub| subpath_arcs_to_cubics(sub, arc_tolerance))
+                .collect();
+        }
+        self.add_path_with_fn("draw_svg_path", name, subpaths, FillRule::NonZero)
+    }
+
+    fn add_path_with_fn(
+        &mut self,
+        fn_name: &str,
+        name: &str,
+        subpaths: Vec<Subpath>,
+        fill_rule: FillRule,
+    ) -> Result<String, SceneError> {
+        if subpaths.iter().all(|sub| sub.segments.is_empty()) {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: Path requires at least one segment",
+                fn_name
+            )));
+        }
+        for (i, subpath) in subpaths.iter().enumerate() {
+            if !subpath.start[0].is_finite() || !subpath.start[1].is_finite() {
+                return Err(SceneError::InvalidInput(format!(
+                    "[{}] invalid_input: subpaths[{}].start contains NaN or Infinity",
+                    fn_name, i
+                )));
+            }
+            for (j, segment) in subpath.segments.iter().enumerate() {
+                validate_segment(segment).map_err(|msg| {
+                    SceneError::InvalidInput(format!(
+                        "[{}] invalid_input: subpaths[{}].segments[{}]: {}",
+                        fn_name, i, j, msg
+                    ))
+                })?;
+            }
+        }
+
+        self.add_entity_internal(
+            fn_name,
+            name,
+            EntityType::Path,
+            Geometry::Path {
+                subpaths,
+                fill_rule,
+            },
+        )
+    }
+}
+
+// ========================================
+// WASM Bindings for Path
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 여러 subpath와 혼합 세그먼트로 된 일반 경로(Path)를 기본 스타일로 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "gasket") - Scene 내 unique
+    /// * `path_json` - 경로 JSON (형식은 `draw_path` 참고)
+    ///
+    /// # Errors
+    /// * name 중복, JSON 파싱 실패, 빈 경로, NaN/Infinity, 양수가 아닌 원호 반지름
+    pub fn add_path(&mut self, name: &str, path_json: &str) -> Result<String, JsValue> {
+        let input = parse_path_input("add_path", path_json)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let result = self
+            .add_path_internal(name, input.subpaths, input.fill_rule)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        self.last_operation = Some(format!("add_path({})", name));
+        Ok(result)
+    }
+
+    /// 여러 subpath와 혼합 세그먼트로 된 일반 경로(Path)를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "gasket") - Scene 내 unique
+    /// * `path_json` - 경로 JSON
+    ///   - `subpaths`: `[{ start: [x, y], segments: [...], closed: bool }, ...]`
+    ///   - 세그먼트: `{"Line": {"to": [x, y]}}`, `{"Quadratic": {"control", "to"}}`,
+    ///     `{"Cubic": {"control1", "control2", "to"}}`,
+    ///     `{"Arc": {"center", "radius", "start_angle", "sweep_angle"}}`,
+    ///     `{"EllipticalArc": {"center", "rx", "ry", "rotation", "start_angle", "sweep_angle"}}`
+    ///   - 각도는 라디안, sweep_angle 양수 = CCW / 음수 = CW
+    ///   - `fill_rule`: "NonZero"(기본) | "EvenOdd" (소문자 "nonzero" | "evenodd"도 허용)
+    /// * `style_json` - 스타일 JSON (파싱 실패 시 기본 스타일 사용)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복, JSON 파싱 실패, 빈 경로, NaN/Infinity, 양수가 아닌 원호 반지름
+    ///
+    /// # Notes
+    /// 원호의 시작점이 이전 끝점과 다르면 그 사이는 직선으로 이어집니다.
+    /// boolean/offset/hatch는 닫힌 subpath만 영역으로 사용하며 even-odd로 해석합니다.
+    pub fn draw_path(
+        &mut self,
+        name: &str,
+        path_json: &str,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        parse_path_input("draw_path", path_json)
+            .and_then(|input| {
+                self.add_path_with_fn("draw_path", name, input.subpaths, input.fill_rule)
+            })
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let style = parse_style_with_warning(style_json, "draw_path");
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.style = style;
+        }
+        self.last_operation = Some(format!("draw_path({})", name));
+        Ok(name.to_string())
+    }
+
+    /// SVG path 데이터(`<path d="...">`)를 그대로 받아 일반 경로(Path)를 생성합니다.
+    ///
+    /// 아이콘 세트나 SVG 파일에서 복사한 경로를 수정 없이 사용할 수 있습니다.
+    /// - 모든 명령어: M L H V C S Q T A Z (절대/상대)
+    /// - 암시적 반복 (`M 0,0 10,0 10,10` → M + L + L), 여러 subpath
+    /// - 압축 표기 (`10-5`, `0.5.5`, 붙여 쓴 원호 플래그 `a5 5 0 1010,0`)
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "icon_home") - Scene 내 unique
+    /// * `d` - SVG path 데이터
+    /// * `arc_tolerance` - 0 이하: 원호를 정확히 보존, 양수: 허용 오차 이내의 큐빅 베지어로 변환
+    /// * `style_json` - 스타일 JSON (파싱 실패 시 기본 스타일 사용)
+    ///
+    /// # Examples
+    /// ```javascript
+    /// drawSvgPath('icon', 'M2 2h20v20H2zM7 7v10h10V7z', 0, '{"fill": {"color": [0,0,0,1]}}')
+    /// drawSvgPath('rounded', 'M0 0h80a10 10 0 0110 10v40', 0.01, '{}')
+    /// ```
+    ///
+    /// # Errors
+    /// * name 중복, 문법 오류 (메시지에 `at position N` 바이트 위치 포함)
+    pub fn draw_svg_path(
+        &mut self,
+        name: &str,
+        d: &str,
+        arc_tolerance: f64,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        self.draw_svg_path_internal(name, d, arc_tolerance)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+
+        let style = parse_style_with_warning(style_json, "draw_svg_path");
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.style = style;
+        }
+        self.last_operation = Some(format!("draw_svg_path({})", name));
+        Ok(name.to_string())
+    }
+
+    /// Path의 꼭짓점 수 (subpath 시작점 + 세그먼트 끝점, 치수 `vertex:N` 참조용)
+    ///
+    /// # Returns
+    /// * Path가 아니거나 존재하지 않으면 0
+    pub fn get_path_vertex_count(&self, name: &str) -> u32 {
+        match self.find_by_name(name).map(|e| &e.geometry) {
+            Some(Geometry::Path { subpaths, .. }) => path_vertices(subpaths).len() as u32,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::flatten::closed_rings;
+    use std::f64::consts::PI;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    /// 20x20 사각형 외곽 + 반지름 5 원형 구멍 (even-odd)
+    fn gasket() -> Vec<Subpath> {
+        vec![
+            Subpath {
+                start: [-10.0, -10.0],
+                segments: vec![
+                    PathSegment::Line { to: [10.0, -10.0] },
+                    PathSegment::Line { to: [10.0, 10.0] },
+                    PathSegment::Line { to: [-10.0, 10.0] },
+                ],
+                closed: true,
+            },
+            Subpath {
+                start: [5.0, 0.0],
+                segments: vec![PathSegment::Arc {
+                    center: [0.0, 0.0],
+                    radius: 5.0,
+                    start_angle: 0.0,
+                    sweep_angle: 2.0 * PI,
+                }],
+                closed: true,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_add_path_gasket_regions_and_bounds() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_path_internal("gasket", gasket(), FillRule::EvenOdd)
+            .unwrap();
+        let entity = scene.find_by_name("gasket").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Path));
+
+        let rings = closed_rings(&entity.geometry, 256).unwrap();
+        assert_eq!(rings.len(), 2);
+
+        let (min, max) = scene.get_world_bounds_internal("gasket").unwrap();
+        assert!(approx_eq(min[0], -10.0, 1e-9) && approx_eq(max[1], 10.0, 1e-9));
+    }
+
+    /// 같은 방향(CCW)으로 겹쳐 그린 두 사각형 [0,4]², [2,6]²
+    fn overlapping_squares() -> Vec<Subpath> {
+        let square = |x: f64| Subpath {
+            start: [x, x],
+            segments: vec![
+                PathSegment::Line { to: [x + 4.0, x] },
+                PathSegment::Line {
+                    to: [x + 4.0, x + 4.0],
+                },
+                PathSegment::Line { to: [x, x + 4.0] },
+            ],
+            closed: true,
+        };
+        vec![square(0.0), square(2.0)]
+    }
+
+    #[test]
+    fn test_nonzero_path_regions_follow_fill_rule() {
+        use crate::geometry::hatch::hatch_geometry;
+        use crate::geometry::polygon::signed_area;
+        use crate::scene::style::HatchStyle;
+
+        let mut scene = Scene::new("test");
+        scene
+            .add_path_internal("nonzero", overlapping_squares(), FillRule::NonZero)
+            .unwrap();
+        scene
+            .add_path_internal("evenodd", overlapping_squares(), FillRule::EvenOdd)
+            .unwrap();
+        scene
+            .add_rect_internal("overlap", 3.0, 3.0, 2.0, 2.0)
+            .unwrap();
+
+        // nonzero: 겹친 부분도 내부 → 합집합 외곽선 하나
+        let geometry = |name: &str| scene.find_by_name(name).unwrap().geometry.clone();
+        let rings = closed_rings(&geometry("nonzero"), 16).unwrap();
+        assert_eq!(rings.len(), 1);
+        assert!(approx_eq(signed_area(&rings[0]).abs(), 28.0, 1e-9));
+        assert_eq!(closed_rings(&geometry("evenodd"), 16).unwrap().len(), 2);
+
+        // 해치: nonzero는 겹친 부분까지 채움
+        let hatch_length = |name: &str| -> f64 {
+            hatch_geometry(&geometry(name), &HatchStyle::default())
+                .unwrap()
+                .lines
+                .iter()
+                .map(|[a, b]| (b[0] - a[0]).hypot(b[1] - a[1]))
+                .sum()
+        };
+        assert!(hatch_length("nonzero") > hatch_length("evenodd") + 1.0);
+
+        // Boolean: 겹친 부분과의 교집합은 nonzero에서만 남음
+        let sources = |path: &str| vec![path.to_string(), "overlap".to_string()];
+        scene
+            .boolean_op_internal("both", "intersection", sources("nonzero"), 0, None)
+            .unwrap();
+        let Geometry::Polygon { points, .. } = &scene.find_by_name("both").unwrap().geometry else {
+            panic!("Expected Polygon geometry");
+        };
+        assert!(approx_eq(signed_area(points).abs(), 4.0, 1e-9));
+        assert!(
+            scene
+                .boolean_op_internal("none", "intersection", sources("evenodd"), 0, None)
+                .is_err()
+        );
+
+        // 오프셋: 겹친 부분이 구멍이 아니므로 inset 결과에 구멍 없음
+        scene
+            .offset_internal(
+                "inset",
+                "nonzero",
+                -0.5,
+                crate::scene::style::LineJoin::Miter,
+                0,
+                None,
+            )
+            .unwrap();
+        let Geometry::Polygon { holes, .. } = &scene.find_by_name("inset").unwrap().geometry else {
+            panic!("Expected Polygon geometry");
+        };
+        assert!(holes.is_empty());
+    }
+
+    #[test]
+    fn test_path_bounds_use_arc_extremes() {
+        // 반원 (CW): (5,0) → (-5,0) 아래쪽
+        let mut scene = Scene::new("test");
+        scene
+            .add_path_internal(
+                "bowl",
+                vec![Subpath {
+                    start: [5.0, 0.0],
+                    segments: vec![PathSegment::Arc {
+                        center: [0.0, 0.0],
+                        radius: 5.0,
+                        start_angle: 0.0,
+                        sweep_angle: -PI,
+                    }],
+                    closed: false,
+                }],
+                FillRule::NonZero,
+            )
+            .unwrap();
+        let (min, max) = scene.get_world_bounds_internal("bowl").unwrap();
+        assert!(approx_eq(min[1], -5.0, 1e-9));
+        assert!(approx_eq(max[1], 0.0, 1e-9));
+    }
+
+    #[test]
+    fn test_path_svg_keeps_native_segments() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_path_internal("gasket", gasket(), FillRule::EvenOdd)
+            .unwrap();
+        let svg = scene.export_svg();
+        assert!(svg.contains(r#"fill-rule="evenodd""#));
+        assert!(svg.contains("M -10,-10 L 10,-10"));
+        // 한 바퀴 원호는 A 명령 2개로 분할
+        assert_eq!(svg.matches(" A 5,5 0 0 1 ").count(), 2);
+        assert_eq!(svg.matches(" Z").count(), 2);
+    }
+
+    #[test]
+    fn test_path_json_roundtrip() {
+        let input = r#"{
+            "subpaths": [{
+                "start": [0, 0],
+                "segments": [
+                    {"Quadratic": {"control": [5, 10], "to": [10, 0]}},
+                    {"EllipticalArc": {"center": [10, -2], "rx": 4, "ry": 2,
+                                       "start_angle": 1.5707963267948966, "sweep_angle": -3.141592653589793}}
+                ]
+            }],
+            "fill_rule": "evenodd"
+        }"#;
+        let parsed = parse_path_input("draw_path", input).unwrap();
+        assert_eq!(parsed.fill_rule, FillRule::EvenOdd);
+        assert!(!parsed.subpaths[0].closed);
+
+        let mut scene = Scene::new("test");
+        scene
+            .add_path_internal("p", parsed.subpaths, parsed.fill_rule)
+            .unwrap();
+        let json = scene.export_json();
+        assert!(json.contains("\"Path\""));
+        assert!(json.contains("\"Quadratic\""));
+        assert_eq!(scene.get_path_vertex_count("p"), 3);
+    }
+
+    #[test]
+    fn test_add_path_validation() {
+        let mut scene = Scene::new("test");
+        let empty = vec![Subpath {
+            start: [0.0, 0.0],
+            segments: vec![],
+            closed: false,
+        }];
+        assert!(
+            scene
+                .add_path_internal("empty", empty, FillRule::NonZero)
+                .is_err()
+        );
+
+        let bad_radius = vec![Subpath {
+            start: [0.0, 0.0],
+            segments: vec![PathSegment::Arc {
+                center: [0.0, 0.0],
+                radius: 0.0,
+                start_angle: 0.0,
+                sweep_angle: 1.0,
+            }],
+            closed: false,
+        }];
+        let err = scene
+            .add_path_internal("bad", bad_radius, FillRule::NonZero)
+            .expect_err("zero radius should fail");
+        assert!(err.to_string().contains("segments[0]"));
+    }
+
+    #[test]
+    fn test_draw_svg_path_icon_with_hole() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_svg_path_internal("icon", "M2 2h20v20H2zM7 7v10h10V7z", 0.0)
+            .unwrap();
+        let entity = scene.find_by_name("icon").unwrap();
+        let Geometry::Path { subpaths, .. } = &entity.geometry else {
+            panic!("Expected Path geometry");
+        };
+        assert_eq!(subpaths.len(), 2);
+        assert!(subpaths.iter().all(|s| s.closed));
+        assert_eq!(closed_rings(&entity.geometry, 16).unwrap().len(), 2);
+        assert_eq!(scene.get_path_vertex_count("icon"), 8);
+    }
+
+    #[test]
+    fn test_draw_svg_path_arc_tolerance() {
+        let d = "M0 0h80a10 10 0 0110 10v40";
+        let mut scene = Scene::new("test");
+        scene.draw_svg_path_internal("exact", d, 0.0).unwrap();
+        scene.draw_svg_path_internal("approx", d, 0.01).unwrap();
+
+        let segments = |name: &str| match &scene.find_by_name(name).unwrap().geometry {
+            Geometry::Path { subpaths, .. } => subpaths[0].segments.clone(),
+            _ => panic!("Expected Path geometry"),
+        };
+        assert!(matches!(segments("exact")[1], PathSegment::Arc { .. }));
+        let approx = segments("approx");
+        assert!(approx.iter().all(|s| !matches!(
+            s,
+            PathSegment::Arc { .. } | PathSegment::EllipticalArc { .. }
+        )));
+        let Some(PathSegment::Cubic { to, .. }) = approx.get(approx.len() - 2) else {
+            panic!("Expected Cubic before final line");
+        };
+        assert!(approx_eq(to[0], 90.0, 1e-9) && approx_eq(to[1], 10.0, 1e-9));
+    }
+
+    #[test]
+    fn test_draw_svg_path_error_position() {
+        let mut scene = Scene::new("test");
+        let err = scene
+            .draw_svg_path_internal("bad", "M0 0 L10 10 Q5", 0.0)
+            .expect_err("truncated Q should fail");
+        let msg = err.to_string();
+        assert!(msg.contains("[draw_svg_path] invalid_path"));
+        assert!(msg.contains("at position 14"));
+        assert!(!scene.has_entity("bad"));
+    }
+}
@@ -1,7 +1,18 @@
 //! SVG Path Parser
 //!
-//! Parses SVG path strings into Bezier geometry.
-//! Note: Implicit command continuation is NOT supported (each coordinate pair needs explicit command).
+//! Parses SVG path data (SVG 1.1 / SVG 2 path grammar) into subpaths.
+//! - All commands: M L H V C S Q T A Z (absolute and relative)
+//! - Implicit command repetition ("M 0,0 10,10 20,0" → M + two implicit L)
+//! - Multiple subpaths (each M starts a new one; drawing after Z restarts at the subpath start)
+//! - Compact numbers ("10-5", "0.5.5", "1e-3") and packed arc flags ("a5 5 0 1010,0")
+//!
+//! Arcs are converted exactly to center-parameterized Arc/EllipticalArc segments.
+//! Errors report the byte offset where parsing failed.
+
+use std::fmt;
+
+use super::entity::{PathSegment, Subpath};
+use crate::geometry::path::{DEFAULT_ARC_TOLERANCE, subpath_to_cubics, svg_arc_segment};
 
 /// Parsed bezier path result
 #[derive(Debug, Clone)]
@@ -11,269 +22,411 @@ pub struct ParsedPath {
     pub closed: bool,
 }
 
-/// Parse SVG path string to bezier segments
+/// Path parse error with the byte offset where it occurred
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+/// Parse SVG path string to a single cubic bezier path (for `Geometry::Bezier`)
 ///
-/// Supported commands (absolute and relative):
-/// - M/m x,y (moveto) - sets start point
-/// - C/c cp1x,cp1y cp2x,cp2y x,y (cubic bezier)
-/// - S/s cp2x,cp2y x,y (smooth cubic - reflects previous cp2)
-/// - Q/q cpx,cpy x,y (quadratic bezier - converted to cubic)
-/// - L/l x,y (lineto - converted to bezier)
-/// - Z/z (closepath)
+/// Lines and quadratics are converted exactly, arcs within `DEFAULT_ARC_TOLERANCE`.
+/// Paths with more than one subpath are rejected (use `parse_svg_path_data`).
 ///
 /// Example: "M 0,0 C 30,50 70,50 100,0 S 170,50 200,0"
 pub fn parse_svg_path(path: &str) -> Result<ParsedPath, String> {
-    let tokens = tokenize(path)?;
-
-    let mut start: Option<[f64; 2]> = None;
-    let mut current: [f64; 2] = [0.0, 0.0];
-    let mut segments: Vec<[[f64; 2]; 3]> = Vec::new();
-    let mut last_cp2: Option<[f64; 2]> = None;
-    let mut closed = false;
-
-    let mut i = 0;
-    while i < tokens.len() {
-        let cmd = &tokens[i];
-        i += 1;
-
-        match cmd.as_str() {
-            "M" | "m" => {
-                // MoveTo: M x,y
-                if i + 1 >= tokens.len() {
-                    return Err("M command requires 2 coordinates".to_string());
-                }
-                let x = parse_num(&tokens[i])?;
-                let y = parse_num(&tokens[i + 1])?;
-                i += 2;
+    let subpaths = parse_svg_path_data(path).map_err(|e| e.to_string())?;
+    let [subpath] = subpaths.as_slice() else {
+        return Err(format!(
+            "Path has {} subpaths; a Bezier holds one continuous path (use draw_svg_path)",
+            subpaths.len()
+        ));
+    };
+    Ok(ParsedPath {
+        start: subpath.start,
+        segments: subpath_to_cubics(subpath, DEFAULT_ARC_TOLERANCE),
+        closed: subpath.closed,
+    })
+}
 
-                let point = if cmd == "m" && start.is_some() {
-                    [current[0] + x, current[1] + y]
-                } else {
-                    [x, y]
-                };
+/// Parse SVG path data into subpaths with native segments
+///
+/// Segments keep their kind: L/H/V → Line, Q/T → Quadratic, C/S → Cubic, A → Arc/EllipticalArc.
+/// Zero-length arcs are dropped and zero-radius arcs become lines, as the SVG spec requires.
+///
+/// # Errors
+/// * Path does not start with M, unknown command, missing/invalid numbers or flags,
+///   path without any drawing segment
+pub fn parse_svg_path_data(path: &str) -> Result<Vec<Subpath>, PathParseError> {
+    let mut parser = Parser::new(path);
+    parser.parse()?;
+    if parser.subpaths.iter().all(|s| s.segments.is_empty()) {
+        return Err(PathParseError {
+            position: path.len(),
+            message: "Path must contain at least one drawing segment".to_string(),
+        });
+    }
+    Ok(parser
+        .subpaths
+        .into_iter()
+        .filter(|s| !s.segments.is_empty())
+        .collect())
+}
 
-                if start.is_none() {
-                    start = Some(point);
-                }
-                current = point;
-                last_cp2 = None;
-            }
-            "C" | "c" => {
-                // Cubic Bezier: C cp1x,cp1y cp2x,cp2y x,y
-                if i + 5 >= tokens.len() {
-                    return Err("C command requires 6 coordinates".to_string());
-                }
-                let cp1x = parse_num(&tokens[i])?;
-                let cp1y = parse_num(&tokens[i + 1])?;
-                let cp2x = parse_num(&tokens[i + 2])?;
-                let cp2y = parse_num(&tokens[i + 3])?;
-                let x = parse_num(&tokens[i + 4])?;
-                let y = parse_num(&tokens[i + 5])?;
-                i += 6;
-
-                let (cp1, cp2, end) = if cmd == "c" {
-                    (
-                        [current[0] + cp1x, current[1] + cp1y],
-                        [current[0] + cp2x, current[1] + cp2y],
-                        [current[0] + x, current[1] + y],
-                    )
-                } else {
-                    ([cp1x, cp1y], [cp2x, cp2y], [x, y])
-                };
+/// Number of arguments per command
+fn arg_count(cmd: u8) -> usize {
+    match cmd.to_ascii_uppercase() {
+        b'M' | b'L' | b'T' => 2,
+        b'H' | b'V' => 1,
+        b'S' | b'Q' => 4,
+        b'C' => 6,
+        b'A' => 7,
+        _ => 0,
+    }
+}
 
-                segments.push([cp1, cp2, end]);
-                last_cp2 = Some(cp2);
-                current = end;
-            }
-            "S" | "s" => {
-                // Smooth Cubic: S cp2x,cp2y x,y (cp1 is reflection of previous cp2)
-                if i + 3 >= tokens.len() {
-                    return Err("S command requires 4 coordinates".to_string());
-                }
-                let cp2x = parse_num(&tokens[i])?;
-                let cp2y = parse_num(&tokens[i + 1])?;
-                let x = parse_num(&tokens[i + 2])?;
-                let y = parse_num(&tokens[i + 3])?;
-                i += 4;
-
-                let (cp2, end) = if cmd == "s" {
-                    (
-                        [current[0] + cp2x, current[1] + cp2y],
-                        [current[0] + x, current[1] + y],
-                    )
-                } else {
-                    ([cp2x, cp2y], [x, y])
-                };
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    subpaths: Vec<Subpath>,
+    /// Subpath being built (None right after Z or before the first M)
+    open: Option<Subpath>,
+    current: [f64; 2],
+    subpath_start: [f64; 2],
+    /// Last cubic control point (for S), reset by non-cubic commands
+    last_cubic: Option<[f64; 2]>,
+    /// Last quadratic control point (for T), reset by non-quadratic commands
+    last_quad: Option<[f64; 2]>,
+}
 
-                // Reflect previous cp2 around current point
-                let cp1 = if let Some(prev_cp2) = last_cp2 {
-                    [
-                        2.0 * current[0] - prev_cp2[0],
-                        2.0 * current[1] - prev_cp2[1],
-                    ]
-                } else {
-                    current // No previous cp2, use current point
-                };
+impl<'a> Parser<'a> {
+    fn new(path: &'a str) -> Self {
+        Self {
+            bytes: path.as_bytes(),
+            pos: 0,
+            subpaths: Vec::new(),
+            open: None,
+            current: [0.0, 0.0],
+            subpath_start: [0.0, 0.0],
+            last_cubic: None,
+            last_quad: None,
+        }
+    }
+
+    fn error(&self, position: usize, message: impl Into<String>) -> PathParseError {
+        PathParseError {
+            position,
+            message: message.into(),
+        }
+    }
 
-                segments.push([cp1, cp2, end]);
-                last_cp2 = Some(cp2);
-                current = end;
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_wsp(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r' | 0x0C)) {
+            self.pos += 1;
+        }
+    }
+
+    /// comma-wsp: wsp* (',' wsp*)?
+    fn skip_comma_wsp(&mut self) {
+        self.skip_wsp();
+        if self.peek() == Some(b',') {
+            self.pos += 1;
+            self.skip_wsp();
+        }
+    }
+
+    fn at_number_start(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'+' | b'-'))
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    /// SVG number: sign? (digits ('.' digits?)? | '.' digits) exponent?
+    fn number(&mut self) -> Option<f64> {
+        let start = self.pos;
+        if matches!(self.peek(), Some(b'+' | b'-')) {
+            self.pos += 1;
+        }
+        let mut mantissa = self.digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            mantissa += self.digits();
+        }
+        if mantissa == 0 {
+            self.pos = start;
+            return None;
+        }
+        // exponent: only consumed when followed by digits ("1e" + command is not an exponent)
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            let before_exp = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
             }
-            "L" | "l" => {
-                // LineTo: L x,y (convert to bezier with control points on the line)
-                if i + 1 >= tokens.len() {
-                    return Err("L command requires 2 coordinates".to_string());
-                }
-                let x = parse_num(&tokens[i])?;
-                let y = parse_num(&tokens[i + 1])?;
-                i += 2;
+            if self.digits() == 0 {
+                self.pos = before_exp;
+            }
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse::<f64>().ok())
+            .filter(|v| v.is_finite())
+            .or_else(|| {
+                self.pos = start;
+                None
+            })
+    }
 
-                let end = if cmd == "l" {
-                    [current[0] + x, current[1] + y]
-                } else {
-                    [x, y]
-                };
+    /// Arc flag: a single '0' or '1' (may be packed without separators)
+    fn flag(&mut self) -> Option<bool> {
+        let value = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(value)
+    }
 
-                // Convert line to cubic bezier (control points at 1/3 and 2/3)
-                let cp1 = [
-                    current[0] + (end[0] - current[0]) / 3.0,
-                    current[1] + (end[1] - current[1]) / 3.0,
-                ];
-                let cp2 = [
-                    current[0] + 2.0 * (end[0] - current[0]) / 3.0,
-                    current[1] + 2.0 * (end[1] - current[1]) / 3.0,
-                ];
-
-                segments.push([cp1, cp2, end]);
-                last_cp2 = None; // L breaks smooth continuation
-                current = end;
+    /// Read one argument group for `cmd` (arc flags at indices 3 and 4)
+    fn arguments(&mut self, cmd: u8) -> Result<Vec<f64>, PathParseError> {
+        let count = arg_count(cmd);
+        let mut args = Vec::with_capacity(count);
+        for i in 0..count {
+            if i > 0 {
+                self.skip_comma_wsp();
             }
-            "Q" | "q" => {
-                // Quadratic Bezier: Q cpx,cpy x,y (convert to cubic)
-                if i + 3 >= tokens.len() {
-                    return Err("Q command requires 4 coordinates".to_string());
+            let is_flag = cmd.eq_ignore_ascii_case(&b'A') && (i == 3 || i == 4);
+            let value = if is_flag {
+                self.flag().map(|f| if f { 1.0 } else { 0.0 })
+            } else {
+                self.number()
+            };
+            match value {
+                Some(v) => args.push(v),
+                None => {
+                    let what = if is_flag {
+                        format!("{} command arc flag must be 0 or 1", cmd as char)
+                    } else {
+                        format!(
+                            "{} command requires {} coordinates",
+                            cmd.to_ascii_uppercase() as char,
+                            count
+                        )
+                    };
+                    return Err(self.error(self.pos, what));
                 }
-                let cpx = parse_num(&tokens[i])?;
-                let cpy = parse_num(&tokens[i + 1])?;
-                let x = parse_num(&tokens[i + 2])?;
-                let y = parse_num(&tokens[i + 3])?;
-                i += 4;
-
-                let (cp, end) = if cmd == "q" {
-                    (
-                        [current[0] + cpx, current[1] + cpy],
-                        [current[0] + x, current[1] + y],
-                    )
+            }
+        }
+        Ok(args)
+    }
+
+    fn parse(&mut self) -> Result<(), PathParseError> {
+        self.skip_wsp();
+        if self.peek().is_none() {
+            return Err(self.error(0, "Path must start with M command"));
+        }
+        let mut first = true;
+        while let Some(byte) = {
+            self.skip_wsp();
+            self.peek()
+        } {
+            let cmd_pos = self.pos;
+            if !byte.is_ascii_alphabetic() {
+                let message = if first {
+                    "Path must start with M command".to_string()
                 } else {
-                    ([cpx, cpy], [x, y])
+                    format!("Expected command, found '{}'", byte as char)
                 };
-
-                // Convert quadratic to cubic
-                // CP1 = P0 + 2/3 * (CP - P0)
-                // CP2 = P1 + 2/3 * (CP - P1)
-                let cp1 = [
-                    current[0] + 2.0 / 3.0 * (cp[0] - current[0]),
-                    current[1] + 2.0 / 3.0 * (cp[1] - current[1]),
-                ];
-                let cp2 = [
-                    end[0] + 2.0 / 3.0 * (cp[0] - end[0]),
-                    end[1] + 2.0 / 3.0 * (cp[1] - end[1]),
-                ];
-
-                segments.push([cp1, cp2, end]);
-                last_cp2 = Some(cp2);
-                current = end;
+                return Err(self.error(cmd_pos, message));
             }
-            "Z" | "z" => {
-                closed = true;
-                // Optionally add segment back to start if not already there
-                if let Some(s) = start
-                    && ((current[0] - s[0]).abs() > 0.001 || (current[1] - s[1]).abs() > 0.001)
-                {
-                    // Add closing line segment
-                    let cp1 = [
-                        current[0] + (s[0] - current[0]) / 3.0,
-                        current[1] + (s[1] - current[1]) / 3.0,
-                    ];
-                    let cp2 = [
-                        current[0] + 2.0 * (s[0] - current[0]) / 3.0,
-                        current[1] + 2.0 * (s[1] - current[1]) / 3.0,
-                    ];
-                    segments.push([cp1, cp2, s]);
-                }
+            if first && !matches!(byte, b'M' | b'm') {
+                return Err(self.error(cmd_pos, "Path must start with M command"));
+            }
+            first = false;
+            self.pos += 1;
+
+            if matches!(byte, b'Z' | b'z') {
+                self.close_path();
+                continue;
             }
-            _ => {
-                // Implicit command continuation (e.g., "M 0,0 10,10") is not supported
-                // to avoid complexity and potential infinite loops
-                return Err(format!(
-                    "Unknown command: {}. Use explicit commands (M, C, S, Q, L, Z)",
-                    cmd
+            if arg_count(byte) == 0 {
+                return Err(self.error(
+                    cmd_pos,
+                    format!(
+                        "Unknown command: {} (expected M, L, H, V, C, S, Q, T, A or Z)",
+                        byte as char
+                    ),
                 ));
             }
+
+            // First argument group is mandatory, further groups repeat the command
+            let mut cmd = byte;
+            loop {
+                self.skip_wsp();
+                let args = self.arguments(cmd)?;
+                self.apply(cmd, &args);
+                // After M, implicit repeats are line-to
+                cmd = match cmd {
+                    b'M' => b'L',
+                    b'm' => b'l',
+                    other => other,
+                };
+                self.skip_comma_wsp();
+                if !self.at_number_start() {
+                    break;
+                }
+            }
         }
+        self.finish_subpath();
+        Ok(())
     }
 
-    let start = start.ok_or("Path must start with M command")?;
+    fn finish_subpath(&mut self) {
+        if let Some(subpath) = self.open.take() {
+            self.subpaths.push(subpath);
+        }
+    }
 
-    if segments.is_empty() {
-        return Err("Path must contain at least one curve segment (C, S, Q, or L)".to_string());
+    fn close_path(&mut self) {
+        if let Some(subpath) = self.open.as_mut() {
+            subpath.closed = true;
+        }
+        self.finish_subpath();
+        self.current = self.subpath_start;
+        self.last_cubic = None;
+        self.last_quad = None;
     }
 
-    Ok(ParsedPath {
-        start,
-        segments,
-        closed,
-    })
-}
+    /// Drawing command target subpath (restarts at the subpath start after Z)
+    fn push_segment(&mut self, segment: PathSegment) {
+        let start = self.current;
+        self.open
+            .get_or_insert_with(|| Subpath {
+                start,
+                segments: Vec::new(),
+                closed: false,
+            })
+            .segments
+            .push(segment);
+    }
 
-/// Tokenize SVG path string
-fn tokenize(path: &str) -> Result<Vec<String>, String> {
-    let mut tokens = Vec::new();
-    let mut current = String::new();
-
-    for ch in path.chars() {
-        match ch {
-            'M' | 'm' | 'C' | 'c' | 'S' | 's' | 'L' | 'l' | 'Q' | 'q' | 'Z' | 'z' => {
-                if !current.is_empty() {
-                    tokens.push(current.clone());
-                    current.clear();
-                }
-                tokens.push(ch.to_string());
+    fn apply(&mut self, cmd: u8, args: &[f64]) {
+        let relative = cmd.is_ascii_lowercase();
+        let base = if relative { self.current } else { [0.0, 0.0] };
+        let point = |x: f64, y: f64| [base[0] + x, base[1] + y];
+        let (mut last_cubic, mut last_quad) = (None, None);
+
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                self.finish_subpath();
+                let p = point(args[0], args[1]);
+                self.subpath_start = p;
+                self.current = p;
+                self.open = Some(Subpath {
+                    start: p,
+                    segments: Vec::new(),
+                    closed: false,
+                });
             }
-            ',' | ' ' | '\t' | '\n' | '\r' => {
-                if !current.is_empty() {
-                    tokens.push(current.clone());
-                    current.clear();
-                }
+            b'L' => {
+                let to = point(args[0], args[1]);
+                self.push_segment(PathSegment::Line { to });
+                self.current = to;
             }
-            '-' => {
-                // Minus can be part of number or separator
-                if !current.is_empty() && !current.ends_with('e') && !current.ends_with('E') {
-                    tokens.push(current.clone());
-                    current.clear();
-                }
-                current.push(ch);
+            b'H' => {
+                let to = [base[0] * f64::from(relative) + args[0], self.current[1]];
+                self.push_segment(PathSegment::Line { to });
+                self.current = to;
+            }
+            b'V' => {
+                let to = [self.current[0], base[1] * f64::from(relative) + args[0]];
+                self.push_segment(PathSegment::Line { to });
+                self.current = to;
+            }
+            b'C' => {
+                let control1 = point(args[0], args[1]);
+                let control2 = point(args[2], args[3]);
+                let to = point(args[4], args[5]);
+                self.push_segment(PathSegment::Cubic {
+                    control1,
+                    control2,
+                    to,
+                });
+                last_cubic = Some(control2);
+                self.current = to;
+            }
+            b'S' => {
+                let control1 = self.reflect(self.last_cubic);
+                let control2 = point(args[0], args[1]);
+                let to = point(args[2], args[3]);
+                self.push_segment(PathSegment::Cubic {
+                    control1,
+                    control2,
+                    to,
+                });
+                last_cubic = Some(control2);
+                self.current = to;
+            }
+            b'Q' => {
+                let control = point(args[0], args[1]);
+                let to = point(args[2], args[3]);
+                self.push_segment(PathSegment::Quadratic { control, to });
+                last_quad = Some(control);
+                self.current = to;
             }
-            '0'..='9' | '.' | 'e' | 'E' | '+' => {
-                current.push(ch);
+            b'T' => {
+                let control = self.reflect(self.last_quad);
+                let to = point(args[0], args[1]);
+                self.push_segment(PathSegment::Quadratic { control, to });
+                last_quad = Some(control);
+                self.current = to;
             }
-            _ => {
-                // Ignore other characters
+            b'A' => {
+                let to = point(args[5], args[6]);
+                if let Some(segment) = svg_arc_segment(
+                    self.current,
+                    args[0],
+                    args[1],
+                    args[2].to_radians(),
+                    args[3] != 0.0,
+                    args[4] != 0.0,
+                    to,
+                ) {
+                    self.push_segment(segment);
+                }
+                self.current = to;
             }
+            _ => {}
         }
+        self.last_cubic = last_cubic;
+        self.last_quad = last_quad;
     }
 
-    if !current.is_empty() {
-        tokens.push(current);
+    /// Reflection of the previous control point around the current point
+    fn reflect(&self, control: Option<[f64; 2]>) -> [f64; 2] {
+        match control {
+            Some(c) => [2.0 * self.current[0] - c[0], 2.0 * self.current[1] - c[1]],
+            None => self.current,
+        }
     }
-
-    Ok(tokens)
-}
-
-/// Parse number from string
-fn parse_num(s: &str) -> Result<f64, String> {
-    s.parse::<f64>()
-        .map_err(|_| format!("Invalid number: {}", s))
 }
 
 #[cfg(test)]
@@ -327,7 +480,7 @@ mod tests {
 
     #[test]
     fn test_consecutive_minus_as_negative() {
-        // Tokenizer splits "10-5" into "10" and "-5" (negative number)
+        // "10-5" is two numbers: 10 and -5
         let path = "M 10,10 L -5,-10";
         let result = parse_svg_path(path).unwrap();
         assert_eq!(result.segments[0][2], [-5.0, -10.0]);
@@ -368,4 +521,122 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("Unknown command"));
     }
+
+    #[test]
+    fn test_error_reports_position() {
+        let err = parse_svg_path_data("M 0,0 L 10,10 C 1,2 3").unwrap_err();
+        assert_eq!(err.position, 21);
+        assert!(err.to_string().ends_with("at position 21"));
+
+        let err = parse_svg_path_data("M 0,0 X 10,20").unwrap_err();
+        assert_eq!(err.position, 6);
+    }
+
+    #[test]
+    fn test_implicit_repeats_and_h_v() {
+        // M + implicit L, relative m + implicit l, H/V
+        let subpaths = parse_svg_path_data("M0 0 10 0 10 10 H 0 V 5 v-5").unwrap();
+        assert_eq!(subpaths.len(), 1);
+        let ends: Vec<[f64; 2]> = subpaths[0]
+            .segments
+            .iter()
+            .map(|s| match s {
+                PathSegment::Line { to } => *to,
+                other => panic!("Expected Line, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            ends,
+            vec![
+                [10.0, 0.0],
+                [10.0, 10.0],
+                [0.0, 10.0],
+                [0.0, 5.0],
+                [0.0, 0.0]
+            ]
+        );
+
+        let subpaths = parse_svg_path_data("m 5,5 10,0 0,10").unwrap();
+        assert_eq!(
+            subpaths[0].segments[1],
+            PathSegment::Line { to: [15.0, 15.0] }
+        );
+    }
+
+    #[test]
+    fn test_compact_numbers() {
+        // "0.5.5" = 0.5, .5 / "10-5" = 10, -5
+        let subpaths = parse_svg_path_data("M0.5.5L10-5").unwrap();
+        assert_eq!(subpaths[0].start, [0.5, 0.5]);
+        assert_eq!(
+            subpaths[0].segments[0],
+            PathSegment::Line { to: [10.0, -5.0] }
+        );
+    }
+
+    #[test]
+    fn test_multiple_subpaths_and_restart_after_close() {
+        let subpaths = parse_svg_path_data("M0,0 L10,0 L10,10 Z M20,20 l5,0 z l0,5").unwrap();
+        assert_eq!(subpaths.len(), 3);
+        assert!(subpaths[0].closed && subpaths[1].closed && !subpaths[2].closed);
+        // Drawing after Z restarts at the previous subpath start (20,20)
+        assert_eq!(subpaths[2].start, [20.0, 20.0]);
+        assert_eq!(
+            subpaths[2].segments[0],
+            PathSegment::Line { to: [20.0, 25.0] }
+        );
+
+        let err = parse_svg_path("M0,0 L1,0 M5,5 L6,5").unwrap_err();
+        assert!(err.contains("2 subpaths"));
+    }
+
+    #[test]
+    fn test_smooth_quadratic() {
+        let subpaths = parse_svg_path_data("M0,0 Q5,10 10,0 T20,0").unwrap();
+        assert_eq!(
+            subpaths[0].segments[1],
+            PathSegment::Quadratic {
+                control: [15.0, -10.0],
+                to: [20.0, 0.0]
+            }
+        );
+        // T without previous Q: control = current point
+        let subpaths = parse_svg_path_data("M0,0 L5,0 T10,0").unwrap();
+        assert_eq!(
+            subpaths[0].segments[1],
+            PathSegment::Quadratic {
+                control: [5.0, 0.0],
+                to: [10.0, 0.0]
+            }
+        );
+    }
+
+    #[test]
+    fn test_arc_packed_flags() {
+        // "a5 5 0 1010,0" = rx 5, ry 5, rot 0, large 1, sweep 0, dx 10, dy 0
+        let subpaths = parse_svg_path_data("M0,0 a5 5 0 1010,0").unwrap();
+        let PathSegment::Arc {
+            center,
+            radius,
+            sweep_angle,
+            ..
+        } = subpaths[0].segments[0]
+        else {
+            panic!("Expected Arc segment");
+        };
+        assert!((center[0] - 5.0).abs() < 1e-9 && center[1].abs() < 1e-9);
+        assert!((radius - 5.0).abs() < 1e-9);
+        assert!(sweep_angle < 0.0);
+
+        let err = parse_svg_path_data("M0,0 A5 5 0 2 0 10 0").unwrap_err();
+        assert!(err.message.contains("arc flag"));
+    }
+
+    #[test]
+    fn test_arc_in_bezier_within_tolerance() {
+        let result = parse_svg_path("M 0,0 A 50,50 0 0 1 100,0").unwrap();
+        assert!(result.segments.len() >= 2);
+        let end = result.segments.last().unwrap()[2];
+        assert!((end[0] - 100.0).abs() < 1e-9 && end[1].abs() < 1e-9);
+    }
 }
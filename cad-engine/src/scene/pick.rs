@@ -0,0 +1,322 @@
+//! 점 선택(pick) 모듈
+//!
+//! 월드 좌표의 한 점 아래에 실제로 그려진 Entity를 위에서부터 찾습니다.
+//! world_bounds 상자 대신 렌더링되는 모양 그대로 판정하므로, 상자가 겹쳐도 올바른 도형을 고릅니다.
+//! - 그리기 순서: 뷰어와 같이 같은 부모 안에서 z_index 오름차순 (같으면 추가 순서)
+//! - fill: fill이 있으면 외곽선 내부 (Polygon holes는 even-odd, Path는 fill_rule)
+//! - stroke: stroke가 있으면 외곽선에서 월드 stroke 폭의 절반 이내
+//! - Text는 문자 상자, Dimension은 치수선/화살표/문자 상자 기준
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{Entity, FillRule, Geometry, Matrix3x3, Transform};
+use super::flatten::max_scale;
+use super::{Scene, SceneError};
+use crate::geometry::flatten::geometry_polylines;
+use crate::geometry::hit::{point_in_fill, point_polyline_distance};
+use crate::geometry::polygon::point_in_ring;
+use crate::geometry::text::text_geometry_bounds;
+
+/// 곡선 근사 오차 하한 (월드 바운드 대각선 대비 비율, tolerance = 0일 때 사용)
+const MIN_CHORD_RATIO: f64 = 1e-4;
+
+/// 변환 후 stroke 폭 배율 (비균일 스케일은 면적 기준 평균 배율로 근사)
+fn stroke_scale(m: &Matrix3x3) -> f64 {
+    (m[0][0] * m[1][1] - m[0][1] * m[1][0]).abs().sqrt()
+}
+
+/// 점이 닫힌 링 내부이거나 링 경계에서 `tolerance` 이내인지 확인합니다.
+fn ring_hit(ring: &[[f64; 2]], p: [f64; 2], tolerance: f64) -> bool {
+    point_in_ring(ring, p) || point_polyline_distance(p, ring, true) <= tolerance
+}
+
+impl Scene {
+    /// 월드 좌표 점 아래의 Entity 이름들을 위(앞)에서부터 반환합니다 (내부용).
+    ///
+    /// Group 자체가 아닌, 실제로 그려지는 하위 도형의 이름을 반환합니다.
+    ///
+    /// # Arguments
+    /// * `x`, `y` - 월드 좌표
+    /// * `tolerance` - 선택 허용 거리 (0 이상, 월드 단위)
+    ///
+    /// # Errors
+    /// * NaN/Infinity 좌표, 음수이거나 유한하지 않은 tolerance
+    pub(crate) fn pick_internal(
+        &self,
+        x: f64,
+        y: f64,
+        tolerance: f64,
+    ) -> Result<Vec<String>, SceneError> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[pick] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        if !(tolerance.is_finite() && tolerance >= 0.0) {
+            return Err(SceneError::InvalidInput(
+                "[pick] invalid_input: tolerance must be a non-negative finite number".to_string(),
+            ));
+        }
+
+        // 공간 인덱스로 후보를 좁힌 뒤 그리기 순서의 역순(위 → 아래)으로 정확히 판정
+        let probe = (
+            [x - tolerance, y - tolerance],
+            [x + tolerance, y + tolerance],
+        );
+        let mut candidates = self.query_spatial_internal(&probe);
+        self.sort_by_paint_order(&mut candidates);
+
+        Ok(candidates
+            .into_iter()
+            .rev()
+            .filter(|name| {
+                self.find_by_name(name)
+                    .is_some_and(|entity| self.hit_entity(entity, [x, y], tolerance))
+            })
+            .collect())
+    }
+
+    fn hit_entity(&self, entity: &Entity, p: [f64; 2], tolerance: f64) -> bool {
+        let name = &entity.metadata.name;
+
+        // 빠른 제외: stroke 포함 시각적 바운드 + tolerance
+        let Some((min, max)) = self.get_visual_bounds_internal(name) else {
+            return false;
+        };
+        if p[0] < min[0] - tolerance
+            || p[0] > max[0] + tolerance
+            || p[1] < min[1] - tolerance
+            || p[1] > max[1] + tolerance
+        {
+            return false;
+        }
+        let Some(world_matrix) = self.get_world_transform_internal(name) else {
+            return false;
+        };
+
+        match &entity.geometry {
+            Geometry::Text { .. } => {
+                text_geometry_bounds(&entity.geometry).is_some_and(|(lo, hi)| {
+                    let corners = [
+                        [lo[0], lo[1]],
+                        [hi[0], lo[1]],
+                        [hi[0], hi[1]],
+                        [lo[0], hi[1]],
+                    ]
+                    .map(|c| Transform::transform_point(&world_matrix, c));
+                    ring_hit(&corners, p, tolerance)
+                })
+            }
+            Geometry::Dimension { .. } => {
+                let Some(layout) = self.dimension_world_layout(name) else {
+                    return false;
+                };
+                let stroke_width = entity.style.stroke.as_ref().map_or(1.0, |s| s.width);
+                let reach = stroke_width * stroke_scale(&world_matrix) / 2.0 + tolerance;
+                layout
+                    .lines
+                    .iter()
+                    .any(|line| point_polyline_distance(p, line, false) <= reach)
+                    || layout
+                        .arcs
+                        .iter()
+                        .any(|arc| point_polyline_distance(p, arc, false) <= reach)
+                    || layout
+                        .arrows
+                        .iter()
+                        .any(|arrow| ring_hit(arrow, p, tolerance))
+                    || ring_hit(&layout.text_box(), p, tolerance)
+            }
+            _ => self.hit_shape(entity, &world_matrix, (min, max), p, tolerance),
+        }
+    }
+
+    /// 일반 도형: 월드 좌표 외곽선에 대해 fill 내부 / stroke 거리 판정
+    fn hit_shape(
+        &self,
+        entity: &Entity,
+        world_matrix: &Matrix3x3,
+        (min, max): ([f64; 2], [f64; 2]),
+        p: [f64; 2],
+        tolerance: f64,
+    ) -> bool {
+        let scale = max_scale(world_matrix);
+        if scale <= f64::EPSILON {
+            return false;
+        }
+        let chord =
+            (tolerance / 2.0).max((max[0] - min[0]).hypot(max[1] - min[1]) * MIN_CHORD_RATIO);
+        let polylines: Vec<(Vec<[f64; 2]>, bool)> =
+            geometry_polylines(&entity.geometry, chord / scale)
+                .into_iter()
+                .map(|polyline| {
+                    let points = polyline
+                        .points
+                        .into_iter()
+                        .map(|q| Transform::transform_point(world_matrix, q))
+                        .collect();
+                    (points, polyline.closed)
+                })
+                .collect();
+
+        if let Some(stroke) = &entity.style.stroke {
+            let reach = stroke.width * stroke_scale(world_matrix) / 2.0 + tolerance;
+            if polylines
+                .iter()
+                .any(|(points, closed)| point_polyline_distance(p, points, *closed) <= reach)
+            {
+                return true;
+            }
+        }
+
+        if entity.style.fill.is_some() {
+            // SVG처럼 열린 폴리라인도 암묵적으로 닫아서 채움
+            let even_odd = match &entity.geometry {
+                Geometry::Polygon { holes, .. } => !holes.is_empty(),
+                Geometry::Path { fill_rule, .. } => *fill_rule == FillRule::EvenOdd,
+                _ => false,
+            };
+            let rings: Vec<Vec<[f64; 2]>> =
+                polylines.iter().map(|(points, _)| points.clone()).collect();
+            if point_in_fill(&rings, p, even_odd)
+                || rings
+                    .iter()
+                    .any(|ring| point_polyline_distance(p, ring, true) <= tolerance)
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// ========================================
+// WASM Bindings for Picking
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 월드 좌표 점 아래에 그려진 Entity 이름들을 위(앞)에서부터 반환합니다.
+    ///
+    /// 바운딩 박스가 아닌 실제 모양으로 판정합니다.
+    /// - fill이 있는 도형은 내부 (Polygon holes와 Path fill_rule 반영)
+    /// - stroke가 있는 도형은 외곽선에서 stroke 폭의 절반 + tolerance 이내
+    /// - fill도 stroke도 없는 도형은 선택되지 않음
+    /// - Group 변환(부모 체인)이 모두 적용되며, 결과는 Group이 아닌 하위 도형 이름
+    ///
+    /// # Arguments
+    /// * `x`, `y` - 월드 좌표
+    /// * `tolerance` - 선택 허용 거리 (0 이상, 월드 단위, 예: 화면 3px에 해당하는 값)
+    ///
+    /// # Returns
+    /// JSON 배열: `["front", "behind", ...]` (첫 요소가 가장 위, 없으면 `[]`)
+    ///
+    /// # Errors
+    /// * NaN/Infinity 좌표, 음수 tolerance
+    pub fn pick(&self, x: f64, y: f64, tolerance: f64) -> Result<String, JsValue> {
+        let names = self
+            .pick_internal(x, y, tolerance)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&names).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::entity::EntityType;
+
+    fn fill(scene: &mut Scene, name: &str) {
+        scene.set_fill(name, r#"{"color": [1, 0, 0, 1]}"#).unwrap();
+    }
+
+    #[test]
+    fn test_pick_overlapping_bounds_uses_shape() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_rect_internal("back", 0.0, 0.0, 40.0, 40.0)
+            .unwrap();
+        scene
+            .add_circle_internal("front", 20.0, 20.0, 10.0)
+            .unwrap();
+        fill(&mut scene, "back");
+        fill(&mut scene, "front");
+
+        assert_eq!(
+            scene.pick_internal(20.0, 20.0, 0.0).unwrap(),
+            ["front", "back"]
+        );
+        // 원의 바운딩 박스 모서리 안쪽이지만 원 밖
+        assert_eq!(scene.pick_internal(11.0, 11.0, 0.0).unwrap(), ["back"]);
+        assert!(scene.pick_internal(50.0, 50.0, 0.0).unwrap().is_empty());
+
+        // z-order 변경 → 결과 순서 반영
+        scene.set_z_order("back", 5).unwrap();
+        assert_eq!(
+            scene.pick_internal(20.0, 20.0, 0.0).unwrap(),
+            ["back", "front"]
+        );
+    }
+
+    #[test]
+    fn test_pick_stroke_only_respects_width() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("ring", 0.0, 0.0, 10.0).unwrap();
+
+        // fill 없음 → 내부는 선택 안 됨, 기본 stroke 폭 1
+        assert!(scene.pick_internal(0.0, 0.0, 0.0).unwrap().is_empty());
+        assert_eq!(scene.pick_internal(10.4, 0.0, 0.0).unwrap(), ["ring"]);
+        assert!(scene.pick_internal(11.0, 0.0, 0.0).unwrap().is_empty());
+        assert_eq!(scene.pick_internal(11.0, 0.0, 0.6).unwrap(), ["ring"]);
+
+        scene.set_stroke("ring", r#"{"width": 4}"#).unwrap();
+        assert_eq!(scene.pick_internal(11.9, 0.0, 0.0).unwrap(), ["ring"]);
+
+        scene.remove_stroke("ring").unwrap();
+        assert!(scene.pick_internal(10.0, 0.0, 0.5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pick_polygon_hole_under_group_transform() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_entity_internal(
+                "test",
+                "frame",
+                EntityType::Polygon,
+                Geometry::Polygon {
+                    points: vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]],
+                    holes: vec![vec![[3.0, 3.0], [7.0, 3.0], [7.0, 7.0], [3.0, 7.0]]],
+                },
+            )
+            .unwrap();
+        fill(&mut scene, "frame");
+        scene
+            .create_group_internal("g", vec!["frame".to_string()])
+            .unwrap();
+        scene.translate("g", 100.0, 0.0).unwrap();
+        scene.scale("g", 2.0, 2.0).unwrap();
+
+        // 월드: 외곽 (100,0)-(120,20), 구멍 (106,6)-(114,14)
+        assert_eq!(scene.pick_internal(102.0, 10.0, 0.0).unwrap(), ["frame"]);
+        assert!(scene.pick_internal(110.0, 10.0, 0.0).unwrap().is_empty());
+        assert!(scene.pick_internal(5.0, 5.0, 0.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pick_text_box_and_errors() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_text("label", 0.0, 0.0, "HELLO", 10.0, "sans-serif", "", "", "{}")
+            .unwrap();
+        let (min, max) = scene.get_world_bounds_internal("label").unwrap();
+        let center = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        assert_eq!(
+            scene.pick_internal(center[0], center[1], 0.0).unwrap(),
+            ["label"]
+        );
+
+        assert!(scene.pick_internal(f64::NAN, 0.0, 0.0).is_err());
+        assert!(scene.pick_internal(0.0, 0.0, -1.0).is_err());
+    }
+}
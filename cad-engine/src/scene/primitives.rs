@@ -7,16 +7,17 @@
 //! - Arc: 호
 //! - Polygon: 다각형
 //! - Bezier: 베지어 커브
+//! - Ellipse / EllipticalArc: 타원 / 타원 호 (자체 회전 포함)
 
 use js_sys::Float64Array;
 use wasm_bindgen::prelude::*;
 
-use super::entity::{Entity, EntityType, Geometry, Metadata, Style, Transform};
+use super::entity::{Entity, EntityHandle, EntityType, Geometry, Metadata, Style, Transform};
 use super::{Scene, SceneError, generate_id};
 use crate::primitives::parse_line_points;
 
 /// Parse style JSON with warning on failure
-fn parse_style_with_warning(style_json: &str, context: &str) -> Style {
+pub(super) fn parse_style_with_warning(style_json: &str, context: &str) -> Style {
     serde_json::from_str::<Style>(style_json).unwrap_or_else(|err| {
         if !style_json.is_empty() && style_json != "{}" {
             web_sys::console::warn_1(
@@ -197,6 +198,105 @@ impl Scene {
             },
         )
     }
+
+    /// 타원 입력 검증 + 반지름 보정 (음수/0 → abs().max(0.001))
+    fn ellipse_radii(
+        fn_name: &str,
+        values: &[f64],
+        rx: f64,
+        ry: f64,
+    ) -> Result<(f64, f64), SceneError> {
+        if values.iter().chain([&rx, &ry]).any(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: NaN or Infinity not allowed",
+                fn_name
+            )));
+        }
+        let correct = |r: f64| if r <= 0.0 { r.abs().max(0.001) } else { r };
+        Ok((correct(rx), correct(ry)))
+    }
+
+    /// 내부용 Ellipse 생성 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "eye_left") - Scene 내 unique
+    /// * `cx`, `cy` - 중심점 좌표
+    /// * `rx`, `ry` - 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `rotation` - 타원 회전 (라디안, CCW)
+    ///
+    /// # Errors
+    /// * NaN/Infinity 입력 시 에러 반환
+    pub(crate) fn add_ellipse_internal(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+    ) -> Result<String, SceneError> {
+        let (rx, ry) = Self::ellipse_radii("add_ellipse", &[cx, cy, rotation], rx, ry)?;
+        self.add_entity_internal(
+            "add_ellipse",
+            name,
+            EntityType::Ellipse,
+            Geometry::Ellipse {
+                center: [cx, cy],
+                rx,
+                ry,
+                rotation,
+            },
+        )
+    }
+
+    /// 내부용 EllipticalArc 생성 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `start_angle`, `end_angle` - 매개변수 각도 (라디안, 0 = rx 축 방향, 양수 = CCW)
+    ///
+    /// 나머지 인자는 add_ellipse_internal과 동일합니다.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn add_elliptical_arc_internal(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Result<String, SceneError> {
+        let (rx, ry) = Self::ellipse_radii(
+            "add_elliptical_arc",
+            &[cx, cy, rotation, start_angle, end_angle],
+            rx,
+            ry,
+        )?;
+        self.add_entity_internal(
+            "add_elliptical_arc",
+            name,
+            EntityType::EllipticalArc,
+            Geometry::EllipticalArc {
+                center: [cx, cy],
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                end_angle,
+            },
+        )
+    }
+
+    /// draw_* 공통: 생성된 Entity에 스타일과 중심 pivot 적용
+    fn apply_draw_style(&mut self, fn_name: &str, name: &str, center: [f64; 2], style_json: &str) {
+        let style = parse_style_with_warning(style_json, fn_name);
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.style = style;
+            entity.transform.pivot = center;
+        }
+        self.last_operation = Some(format!("{}({})", fn_name, name));
+    }
 }
 
 // ========================================
@@ -312,6 +412,65 @@ impl Scene {
             .map_err(|err| JsValue::from_str(&err.to_string()))
     }
 
+    /// 타원(Ellipse) 도형을 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "eye_left") - Scene 내 unique
+    /// * `cx`, `cy` - 중심점 좌표
+    /// * `rx`, `ry` - 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `rotation` - 타원 회전 (라디안, CCW)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력 시 에러
+    pub fn add_ellipse(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+    ) -> Result<String, JsValue> {
+        self.add_ellipse_internal(name, cx, cy, rx, ry, rotation)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 타원 호(EllipticalArc) 도형을 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "smile") - Scene 내 unique
+    /// * `cx`, `cy` - 중심점 좌표
+    /// * `rx`, `ry` - 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `rotation` - 타원 회전 (라디안, CCW)
+    /// * `start_angle` - 시작 매개변수 각도 (라디안, 0 = rx 축 방향)
+    /// * `end_angle` - 끝 매개변수 각도 (라디안, 양수 = CCW)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력 시 에러
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_elliptical_arc(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+    ) -> Result<String, JsValue> {
+        self.add_elliptical_arc_internal(name, cx, cy, rx, ry, rotation, start_angle, end_angle)
+            .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
     /// 스타일이 적용된 호(Arc)를 생성합니다.
     ///
     /// # Arguments
@@ -395,15 +554,119 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_arc({})", name));
         Ok(name.to_string())
     }
 
+    /// 스타일이 적용된 타원(Ellipse)을 생성합니다.
+    ///
+    /// 비균일 scale과 달리 stroke 두께가 왜곡되지 않고 pivot이 중심에 유지됩니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "eye_left") - Scene 내 unique
+    /// * `cx` - 중심점 x 좌표
+    /// * `cy` - 중심점 y 좌표
+    /// * `rx` - x축 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `ry` - y축 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `rotation` - 타원 회전 (라디안, CCW)
+    /// * `style_json` - 스타일 JSON (파싱 실패 시 기본 스타일 사용)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력 시 에러
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ellipse(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        let (rx, ry) = Self::ellipse_radii("draw_ellipse", &[cx, cy, rotation], rx, ry)
+            .map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.add_entity_internal(
+            "draw_ellipse",
+            name,
+            EntityType::Ellipse,
+            Geometry::Ellipse {
+                center: [cx, cy],
+                rx,
+                ry,
+                rotation,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.apply_draw_style("draw_ellipse", name, [cx, cy], style_json);
+        Ok(name.to_string())
+    }
+
+    /// 스타일이 적용된 타원 호(EllipticalArc)를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "smile") - Scene 내 unique
+    /// * `cx`, `cy` - 중심점 좌표
+    /// * `rx`, `ry` - 반지름 (음수/0 → abs().max(0.001)로 보정)
+    /// * `rotation` - 타원 회전 (라디안, CCW)
+    /// * `start_angle` - 시작 매개변수 각도 (라디안, 0 = rx 축 방향)
+    /// * `end_angle` - 끝 매개변수 각도 (라디안, 양수 = CCW)
+    /// * `style_json` - 스타일 JSON (파싱 실패 시 기본 스타일 사용)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력 시 에러
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_elliptical_arc(
+        &mut self,
+        name: &str,
+        cx: f64,
+        cy: f64,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        start_angle: f64,
+        end_angle: f64,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        let (rx, ry) = Self::ellipse_radii(
+            "draw_elliptical_arc",
+            &[cx, cy, rotation, start_angle, end_angle],
+            rx,
+            ry,
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.add_entity_internal(
+            "draw_elliptical_arc",
+            name,
+            EntityType::EllipticalArc,
+            Geometry::EllipticalArc {
+                center: [cx, cy],
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                end_angle,
+            },
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.apply_draw_style("draw_elliptical_arc", name, [cx, cy], style_json);
+        Ok(name.to_string())
+    }
+
     /// 스타일이 적용된 원(Circle)을 생성합니다.
     ///
     /// # Arguments
@@ -475,11 +738,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_circle({})", name));
         Ok(name.to_string())
     }
@@ -523,8 +787,7 @@ impl Scene {
         let geometry = Geometry::Line {
             points: point_pairs,
         };
-        let (min, max) = Self::geometry_bounds(&geometry);
-        let pivot = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        let pivot = Self::default_pivot(&geometry);
 
         // Auto-increment z_order
         let z_order = self.allocate_z_order();
@@ -543,11 +806,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_line({})", name));
         Ok(name.to_string())
     }
@@ -598,8 +862,7 @@ impl Scene {
             points: point_pairs,
             holes: Vec::new(),
         };
-        let (min, max) = Self::geometry_bounds(&geometry);
-        let pivot = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        let pivot = Self::default_pivot(&geometry);
 
         // Auto-increment z_order
         let z_order = self.allocate_z_order();
@@ -618,11 +881,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_polygon({})", name));
         Ok(name.to_string())
     }
@@ -703,8 +967,7 @@ impl Scene {
             points: point_pairs,
             holes,
         };
-        let (min, max) = Self::geometry_bounds(&geometry);
-        let pivot = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        let pivot = Self::default_pivot(&geometry);
 
         // Auto-increment z_order
         let z_order = self.allocate_z_order();
@@ -723,11 +986,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_polygon_with_holes({})", name));
         Ok(name.to_string())
     }
@@ -737,12 +1001,14 @@ impl Scene {
     /// # Arguments
     /// * `name` - Entity 이름 (예: "curve1") - Scene 내 unique
     /// * `path` - SVG path 문자열
-    ///   - `M x,y` : 시작점 (Move to)
+    ///   - `M x,y` : 시작점 (Move to, 뒤따르는 좌표쌍은 암시적 L)
     ///   - `C cp1x,cp1y cp2x,cp2y x,y` : 큐빅 베지어
     ///   - `S cp2x,cp2y x,y` : 부드러운 연결 (cp1 자동 반영)
-    ///   - `Q cpx,cpy x,y` : 쿼드라틱 (큐빅으로 변환)
-    ///   - `L x,y` : 직선 (베지어로 변환)
+    ///   - `Q cpx,cpy x,y` / `T x,y` : 쿼드라틱 (큐빅으로 변환)
+    ///   - `L x,y` / `H x` / `V y` : 직선 (베지어로 변환)
+    ///   - `A rx ry rot large sweep x,y` : 타원호 (허용 오차 0.01 이내 큐빅으로 변환)
     ///   - `Z` : 경로 닫기
+    ///   - 명령어 반복 생략, 소문자 상대 좌표 지원. subpath가 여러 개면 `draw_svg_path` 사용
     /// * `style_json` - 스타일 JSON
     ///
     /// # Examples
@@ -762,7 +1028,7 @@ impl Scene {
     ///
     /// # Errors
     /// * name 중복 시 에러
-    /// * 잘못된 SVG path 문법
+    /// * 잘못된 SVG path 문법 (메시지에 `at position N` 바이트 위치 포함)
     pub fn draw_bezier(
         &mut self,
         name: &str,
@@ -790,8 +1056,7 @@ impl Scene {
             segments: parsed.segments,
             closed: parsed.closed,
         };
-        let (min, max) = Self::geometry_bounds(&geometry);
-        let pivot = [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0];
+        let pivot = Self::default_pivot(&geometry);
 
         // Auto-increment z_order
         let z_order = self.allocate_z_order();
@@ -810,11 +1075,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_bezier({})", name));
         Ok(name.to_string())
     }
@@ -901,11 +1167,12 @@ impl Scene {
                 z_index: z_order,
                 ..Default::default()
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         };
 
-        self.entities.push(entity);
+        self.push_entity(entity);
         self.last_operation = Some(format!("draw_rect({})", name));
         Ok(name.to_string())
     }
@@ -1341,6 +1608,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_arc_default_pivot_is_center() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_arc_internal("arc90", 10.0, 5.0, 30.0, 0.0, std::f64::consts::FRAC_PI_2)
+            .unwrap();
+
+        // 바운드는 1사분면만이지만 pivot은 원 중심
+        let entity = scene.find_by_name("arc90").unwrap();
+        assert_eq!(entity.transform.pivot, [10.0, 5.0]);
+    }
+
+    #[test]
+    fn test_draw_bezier_default_pivot_includes_control_points() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("hump", "M 0,0 C 0,4 10,4 10,0", "{}")
+            .unwrap();
+
+        // 곡선 극점(y=3)이 아닌 제어점 박스(y ∈ [0, 4]) 중심
+        let entity = scene.find_by_name("hump").unwrap();
+        assert_eq!(entity.transform.pivot, [5.0, 2.0]);
+    }
+
     #[test]
     fn test_add_arc_90_degrees() {
         let mut scene = Scene::new("test");
@@ -1495,4 +1786,102 @@ mod tests {
             "[add_arc] invalid_input: NaN or Infinity not allowed"
         );
     }
+
+    // ========================================
+    // add_ellipse / add_elliptical_arc 테스트
+    // ========================================
+
+    #[test]
+    fn test_add_ellipse_basic() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_ellipse_internal("eye", 10.0, 20.0, 30.0, 15.0, 0.5)
+            .unwrap();
+
+        let entity = scene.find_by_name("eye").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Ellipse));
+        assert!((entity.transform.pivot[0] - 10.0).abs() < 1e-10);
+        assert!((entity.transform.pivot[1] - 20.0).abs() < 1e-10);
+        if let Geometry::Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+        } = &entity.geometry
+        {
+            assert_eq!(*center, [10.0, 20.0]);
+            assert_eq!(*rx, 30.0);
+            assert_eq!(*ry, 15.0);
+            assert_eq!(*rotation, 0.5);
+        } else {
+            panic!("Expected Ellipse geometry");
+        }
+    }
+
+    #[test]
+    fn test_add_ellipse_radius_corrected() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_ellipse_internal("e", 0.0, 0.0, -30.0, 0.0, 0.0)
+            .unwrap();
+
+        if let Geometry::Ellipse { rx, ry, .. } = &scene.find_by_name("e").unwrap().geometry {
+            assert_eq!(*rx, 30.0);
+            assert_eq!(*ry, 0.001);
+        } else {
+            panic!("Expected Ellipse geometry");
+        }
+    }
+
+    #[test]
+    fn test_add_ellipse_invalid_input() {
+        let mut scene = Scene::new("test");
+        let err = scene
+            .add_ellipse_internal("e", 0.0, 0.0, 10.0, 5.0, f64::NAN)
+            .expect_err("NaN rotation should error");
+        assert_eq!(
+            err.to_string(),
+            "[add_ellipse] invalid_input: NaN or Infinity not allowed"
+        );
+
+        scene
+            .add_ellipse_internal("e", 0.0, 0.0, 10.0, 5.0, 0.0)
+            .unwrap();
+        let err = scene
+            .add_ellipse_internal("e", 0.0, 0.0, 10.0, 5.0, 0.0)
+            .expect_err("duplicate should error");
+        assert!(err.to_string().contains("duplicate_name"));
+    }
+
+    #[test]
+    fn test_add_elliptical_arc_json_roundtrip() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_elliptical_arc_internal(
+                "smile",
+                0.0,
+                0.0,
+                20.0,
+                10.0,
+                0.0,
+                std::f64::consts::PI,
+                2.0 * std::f64::consts::PI,
+            )
+            .unwrap();
+
+        let json = scene.export_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let entity = &value["entities"][0];
+        assert_eq!(entity["entity_type"], "EllipticalArc");
+        assert_eq!(entity["geometry"]["EllipticalArc"]["rx"], 20.0);
+
+        // 아래쪽 절반: y ∈ [-10, 0]
+        let bounds = &entity["computed"]["world_bounds"];
+        assert!((bounds["min"][1].as_f64().unwrap() + 10.0).abs() < 1e-10);
+        assert!(bounds["max"][1].as_f64().unwrap().abs() < 1e-10);
+
+        let geometry: Geometry =
+            serde_json::from_value(entity["geometry"].clone()).expect("geometry deserializes");
+        assert!(matches!(geometry, Geometry::EllipticalArc { .. }));
+    }
 }
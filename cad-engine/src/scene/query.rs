@@ -4,6 +4,7 @@
 
 use super::Scene;
 use super::entity::{EntityType, Transform};
+use crate::geometry::hatch::hatch_geometry;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -12,7 +13,7 @@ impl Scene {
     // Scene Query Functions (Story 3.0-a)
     // ========================================
 
-    /// Scene 내 모든 Entity의 이름과 타입을 반환합니다.
+    /// Scene 내 모든 Entity의 이름과 타입을 추가 순서대로 반환합니다.
     ///
     /// # Returns
     /// JSON 배열: [{"name": "head", "type": "Circle"}, ...]
@@ -24,8 +25,7 @@ impl Scene {
     /// ```
     pub fn list_entities(&self) -> String {
         let list: Vec<serde_json::Value> = self
-            .entities
-            .iter()
+            .entities_in_order()
             .map(|e| {
                 serde_json::json!({
                     "name": e.metadata.name,
@@ -60,15 +60,15 @@ impl Scene {
     /// LLM은 순서만 확인하고, bringToFront/sendToBack/moveAbove 등으로 조작
     pub fn get_draw_order(&self, group_name: &str) -> String {
         if group_name.is_empty() {
-            // Root level: parent_id가 None인 엔티티들
+            // Root level: parent가 None인 엔티티들
             let mut root_entities: Vec<_> = self
                 .entities
                 .iter()
-                .filter(|e| e.parent_id.is_none())
+                .filter(|e| e.parent.is_none())
                 .collect();
 
-            // z_index 오름차순 정렬 (뒤→앞)
-            root_entities.sort_by_key(|e| e.metadata.z_index);
+            // z_index 오름차순 정렬 (뒤→앞, 같으면 추가 순서)
+            root_entities.sort_by_key(|e| (e.metadata.z_index, e.handle.index()));
 
             let order: Vec<&str> = root_entities
                 .iter()
@@ -99,11 +99,7 @@ impl Scene {
             // 그룹 drill-down: 특정 그룹의 자식들
             match self.find_by_name(group_name) {
                 Some(group) if matches!(group.entity_type, EntityType::Group) => {
-                    let mut children: Vec<_> = group
-                        .children
-                        .iter()
-                        .filter_map(|child_name| self.find_by_name(child_name))
-                        .collect();
+                    let mut children: Vec<_> = self.children_of(group).collect();
 
                     // z_index 오름차순 정렬
                     children.sort_by_key(|e| e.metadata.z_index);
@@ -199,7 +195,7 @@ impl Scene {
         let response = serde_json::json!({
             "name": entity.metadata.name,
             "type": entity.entity_type.as_str(),
-            "parent": entity.parent_id,
+            "parent": self.parent_name(entity),
             "local": {
                 "geometry": entity.geometry,
                 "transform": entity.transform,
@@ -223,7 +219,7 @@ impl Scene {
                 None => serde_json::Value::Null
             },
             "style": entity.style,
-            "children": if entity.children.is_empty() { serde_json::Value::Null } else { serde_json::json!(entity.children) },
+            "children": if entity.children.is_empty() { serde_json::Value::Null } else { serde_json::json!(self.child_names(entity)) },
             "z_order": entity.metadata.z_index
         });
 
@@ -335,6 +331,58 @@ impl Scene {
         )
     }
 
+    /// Entity의 시각적(stroke 포함) 월드 바운딩 박스를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름
+    ///
+    /// # Returns
+    /// * Some(JSON) - {"min": [x, y], "max": [x, y]}
+    /// * None - Entity가 없거나 빈 그룹이면 None
+    ///
+    /// # Notes
+    /// get_world_bounds에 stroke 폭의 절반, miter 돌출부, cap(square/round) 확장을 더한
+    /// 실제 렌더링 영역입니다. stroke가 없으면 get_world_bounds와 같습니다.
+    pub fn get_visual_bounds(&self, name: &str) -> Option<String> {
+        let (min, max) = self.get_visual_bounds_internal(name)?;
+        Some(
+            serde_json::to_string(&serde_json::json!({
+                "min": min,
+                "max": max
+            }))
+            .unwrap_or_else(|_| "{}".to_string()),
+        )
+    }
+
+    /// Entity의 해치 채움 선을 월드 좌표로 반환합니다 (SVG 외 exporter용).
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름
+    ///
+    /// # Returns
+    /// * Some(JSON) - {"lines": [[[x1, y1], [x2, y2]], ...], "dots": [[x, y], ...], "color": [r, g, b, a], "line_width": w}
+    /// * None - Entity가 없거나, 해치 fill이 없거나, 해치할 수 없는 도형(열린 도형, Arc, Text 등)
+    ///
+    /// # Notes
+    /// 선은 영역(holes 포함)에 맞게 잘려 있고, Entity/부모 transform이 적용되어 있습니다.
+    /// line_width는 로컬 단위입니다.
+    pub fn get_hatch_lines(&self, name: &str) -> Option<String> {
+        let entity = self.find_by_name(name)?;
+        let fill = entity.style.fill.as_ref()?;
+        let hatch = fill.hatch.as_ref()?;
+        let world_matrix = self.get_world_transform_internal(name)?;
+        let lines = hatch_geometry(&entity.geometry, hatch)?.transformed(&world_matrix);
+        Some(
+            serde_json::to_string(&serde_json::json!({
+                "lines": lines.lines,
+                "dots": lines.dots,
+                "color": fill.color,
+                "line_width": hatch.line_width
+            }))
+            .unwrap_or_else(|_| "{}".to_string()),
+        )
+    }
+
     /// Entity가 존재하는지 확인합니다.
     ///
     /// # Arguments
@@ -351,6 +399,7 @@ impl Scene {
 #[cfg(test)]
 mod tests {
     use super::Scene;
+    use crate::scene::StrokeStyle;
     use crate::scene::entity::Transform;
 
     fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
@@ -474,6 +523,93 @@ mod tests {
         assert!(approx_eq(max[1], 55.0, 1e-10));
     }
 
+    #[test]
+    fn test_world_bounds_arc_is_tight() {
+        use std::f64::consts::PI;
+
+        let mut scene = Scene::new("test");
+        scene
+            .add_arc_internal("a1", 0.0, 0.0, 10.0, 0.0, PI / 2.0)
+            .unwrap();
+
+        let (min, max) = scene.get_world_bounds_internal("a1").unwrap();
+        assert!(approx_eq(min[0], 0.0, 1e-10));
+        assert!(approx_eq(min[1], 0.0, 1e-10));
+        assert!(approx_eq(max[0], 10.0, 1e-10));
+        assert!(approx_eq(max[1], 10.0, 1e-10));
+    }
+
+    #[test]
+    fn test_visual_bounds_includes_stroke() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r1", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene.find_by_name_mut("r1").unwrap().style.stroke = Some(StrokeStyle {
+            width: 2.0,
+            ..StrokeStyle::default()
+        });
+        scene
+            .create_group_internal("g1", vec!["r1".to_string()])
+            .unwrap();
+
+        let (min, max) = scene.get_visual_bounds_internal("g1").unwrap();
+        assert!(approx_eq(min[0], -6.0, 1e-10));
+        assert!(approx_eq(min[1], -6.0, 1e-10));
+        assert!(approx_eq(max[0], 6.0, 1e-10));
+        assert!(approx_eq(max[1], 6.0, 1e-10));
+
+        // 기하 바운드는 stroke를 포함하지 않음
+        let (min, _) = scene.get_world_bounds_internal("g1").unwrap();
+        assert!(approx_eq(min[0], -5.0, 1e-10));
+    }
+
+    #[test]
+    fn test_get_hatch_lines_world() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r1", 0.0, 0.0, 10.0, 10.0).unwrap();
+        assert!(scene.get_hatch_lines("r1").is_none());
+
+        scene
+            .set_fill(
+                "r1",
+                r#"{"color":[0,0,0,1],"hatch":{"pattern":"Ansi31","spacing":1}}"#,
+            )
+            .unwrap();
+        scene.find_by_name_mut("r1").unwrap().transform.translate = [100.0, 0.0];
+
+        let json: serde_json::Value =
+            serde_json::from_str(&scene.get_hatch_lines("r1").unwrap()).unwrap();
+        let lines = json["lines"].as_array().unwrap();
+        assert!(!lines.is_empty());
+        for point in lines.iter().flat_map(|l| l.as_array().unwrap()) {
+            let x = point[0].as_f64().unwrap();
+            assert!((95.0 - 1e-9..=105.0 + 1e-9).contains(&x));
+        }
+    }
+
+    #[test]
+    fn test_export_json_computed_hatch_local() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r1", 0.0, 0.0, 10.0, 10.0).unwrap();
+        scene
+            .set_fill(
+                "r1",
+                r#"{"color":[0,0,0,1],"hatch":{"pattern":"Ansi31","spacing":1}}"#,
+            )
+            .unwrap();
+        scene.find_by_name_mut("r1").unwrap().transform.translate = [100.0, 0.0];
+
+        let json: serde_json::Value = serde_json::from_str(&scene.export_json()).unwrap();
+        let lines = json["entities"][0]["computed"]["hatch"]["lines"]
+            .as_array()
+            .unwrap();
+        assert!(!lines.is_empty());
+        // Viewer가 transform을 적용하므로 로컬 좌표
+        for point in lines.iter().flat_map(|l| l.as_array().unwrap()) {
+            let x = point[0].as_f64().unwrap();
+            assert!((-5.0 - 1e-9..=5.0 + 1e-9).contains(&x));
+        }
+    }
+
     #[test]
     fn test_get_world_point() {
         use std::f64::consts::PI;
@@ -0,0 +1,456 @@
+//! 영역 선택 모듈
+//!
+//! 월드 좌표 사각형(box) 또는 임의 다각형(lasso)으로 Entity를 선택합니다 (AutoCAD window/crossing).
+//! - window: 도형 전체가 영역 안에 있어야 선택
+//! - crossing: 영역 안에 있거나 영역 경계와 만나면 선택
+//! - 판정은 도형 외곽선(월드 좌표 근사) 기준이며 stroke 폭과 fill 내부는 보지 않음
+//! - 후보는 scope(루트 또는 그룹)의 직계 자식이며, 그룹은 하위 도형 전체로 판정
+
+use std::collections::HashSet;
+
+use js_sys::Float64Array;
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{Entity, EntityType, Geometry};
+use super::{Scene, SceneError};
+use crate::geometry::bounds::bbox_of;
+use crate::geometry::hit::{polyline_inside_ring, polyline_touches_ring};
+use crate::primitives::parse_line_points;
+
+/// 외곽선 근사 오차 (Entity 월드 바운드 대각선 대비 비율)
+const OUTLINE_TOLERANCE_RATIO: f64 = 1e-3;
+
+/// 영역 선택 방식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum SelectMode {
+    /// 영역 안에 완전히 포함된 것만 선택
+    Window,
+    /// 영역과 겹치거나 포함된 것 모두 선택
+    Crossing,
+}
+
+impl SelectMode {
+    /// 문자열에서 SelectMode 파싱 (대소문자 무시, 알 수 없는 값은 None)
+    fn parse_str(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "window" | "inside" => Some(SelectMode::Window),
+            "crossing" | "cross" => Some(SelectMode::Crossing),
+            _ => None,
+        }
+    }
+}
+
+impl Scene {
+    /// 선택 영역(닫힌 링)으로 Entity를 선택합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `fn_name` - 에러 메시지에 표시할 함수 이름
+    /// * `ring` - 월드 좌표 선택 영역 (3점 이상)
+    /// * `scope` - 빈 문자열이면 루트, 그룹 이름이면 해당 그룹의 자식들만 후보
+    /// * `layer` - 빈 문자열이 아니면 해당 layer의 Entity만 선택
+    ///
+    /// # Errors
+    /// * scope 미발견 또는 그룹이 아님
+    pub(crate) fn select_in_region_internal(
+        &self,
+        fn_name: &str,
+        ring: &[[f64; 2]],
+        mode: SelectMode,
+        scope: &str,
+        layer: &str,
+    ) -> Result<Vec<&Entity>, SceneError> {
+        let candidates: Vec<&Entity> = if scope.is_empty() {
+            self.entities_in_order()
+                .filter(|e| e.parent.is_none())
+                .collect()
+        } else {
+            let group = self.find_by_name(scope).ok_or_else(|| {
+                SceneError::EntityNotFound(fn_name.to_string(), scope.to_string())
+            })?;
+            if !matches!(group.entity_type, EntityType::Group) {
+                return Err(SceneError::NotAGroup(
+                    fn_name.to_string(),
+                    scope.to_string(),
+                ));
+            }
+            self.children_of(group).collect()
+        };
+
+        let Some((ring_min, ring_max)) = bbox_of(ring) else {
+            return Ok(Vec::new());
+        };
+
+        // 공간 인덱스: 선택 영역 바운드와 겹치는 도형이 하나라도 있는 후보만 남김
+        let touched: HashSet<String> = self
+            .query_spatial_internal(&(ring_min, ring_max))
+            .iter()
+            .filter_map(|leaf| {
+                let chain = self.collect_parent_chain(leaf);
+                let depth = if scope.is_empty() {
+                    0
+                } else {
+                    chain.iter().position(|e| e.metadata.name == scope)? + 1
+                };
+                chain.get(depth).map(|e| e.metadata.name.clone())
+            })
+            .collect();
+
+        Ok(candidates
+            .into_iter()
+            .filter(|e| touched.contains(&e.metadata.name))
+            .filter(|e| layer.is_empty() || e.metadata.layer.as_deref() == Some(layer))
+            .filter(|e| {
+                // 빠른 제외: 바운드가 선택 영역 바운드와 겹치지 않거나 (window) 벗어나면 제외
+                let Some((min, max)) = self.get_world_bounds_internal(&e.metadata.name) else {
+                    return false;
+                };
+                let passes_bounds = match mode {
+                    SelectMode::Window => {
+                        min[0] >= ring_min[0]
+                            && min[1] >= ring_min[1]
+                            && max[0] <= ring_max[0]
+                            && max[1] <= ring_max[1]
+                    }
+                    SelectMode::Crossing => {
+                        min[0] <= ring_max[0]
+                            && min[1] <= ring_max[1]
+                            && max[0] >= ring_min[0]
+                            && max[1] >= ring_min[1]
+                    }
+                };
+                if !passes_bounds {
+                    return false;
+                }
+
+                let outlines = self.selection_outlines(e);
+                match mode {
+                    SelectMode::Window => {
+                        !outlines.is_empty()
+                            && outlines
+                                .iter()
+                                .all(|(points, closed)| polyline_inside_ring(points, *closed, ring))
+                    }
+                    SelectMode::Crossing => outlines
+                        .iter()
+                        .any(|(points, closed)| polyline_touches_ring(points, *closed, ring)),
+                }
+            })
+            .collect())
+    }
+
+    /// 선택 판정용 월드 좌표 외곽선 (그룹이면 하위 도형 전체, (점들, closed))
+    fn selection_outlines(&self, entity: &Entity) -> Vec<(Vec<[f64; 2]>, bool)> {
+        let name = &entity.metadata.name;
+        match &entity.geometry {
+            _ if matches!(entity.entity_type, EntityType::Group) => self
+                .children_of(entity)
+                .flat_map(|child| self.selection_outlines(child))
+                .collect(),
+            Geometry::Dimension { .. } => {
+                let Some(layout) = self.dimension_world_layout(name) else {
+                    return Vec::new();
+                };
+                let mut outlines: Vec<(Vec<[f64; 2]>, bool)> = layout
+                    .lines
+                    .iter()
+                    .map(|line| (line.to_vec(), false))
+                    .chain(layout.arcs.iter().map(|arc| (arc.clone(), false)))
+                    .chain(layout.arrows.iter().map(|arrow| (arrow.to_vec(), true)))
+                    .collect();
+                outlines.push((layout.text_box().to_vec(), true));
+                outlines
+            }
+            _ => {
+                let diagonal = self
+                    .get_world_bounds_internal(name)
+                    .map_or(0.0, |(min, max)| (max[0] - min[0]).hypot(max[1] - min[1]));
+                let tolerance = (diagonal * OUTLINE_TOLERANCE_RATIO).max(f64::EPSILON);
+                self.flatten_entity_internal(name, tolerance)
+                    .map(|polylines| {
+                        polylines
+                            .into_iter()
+                            .map(|polyline| (polyline.points, polyline.closed))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// 선택 결과를 list_entities와 같은 JSON 형태로 변환합니다.
+    fn selection_to_json(entities: &[&Entity]) -> String {
+        let list: Vec<serde_json::Value> = entities
+            .iter()
+            .map(|e| {
+                json!({
+                    "name": e.metadata.name,
+                    "type": e.entity_type.as_str()
+                })
+            })
+            .collect();
+        serde_json::to_string(&list).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    fn parse_select_mode(fn_name: &str, mode: &str) -> Result<SelectMode, SceneError> {
+        SelectMode::parse_str(mode).ok_or_else(|| {
+            SceneError::InvalidInput(format!(
+                "[{}] invalid_input: mode must be 'window' or 'crossing', got '{}'",
+                fn_name, mode
+            ))
+        })
+    }
+
+    /// 사각형 영역 선택 (내부용, 두 모서리 순서 무관)
+    pub(crate) fn select_box_internal(
+        &self,
+        corner1: [f64; 2],
+        corner2: [f64; 2],
+        mode: &str,
+        scope: &str,
+        layer: &str,
+    ) -> Result<Vec<&Entity>, SceneError> {
+        if corner1.iter().chain(corner2.iter()).any(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(
+                "[select_box] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let mode = Self::parse_select_mode("select_box", mode)?;
+        let (x0, x1) = (corner1[0].min(corner2[0]), corner1[0].max(corner2[0]));
+        let (y0, y1) = (corner1[1].min(corner2[1]), corner1[1].max(corner2[1]));
+        let ring = [[x0, y0], [x1, y0], [x1, y1], [x0, y1]];
+        self.select_in_region_internal("select_box", &ring, mode, scope, layer)
+    }
+
+    /// 다각형(lasso) 영역 선택 (내부용)
+    pub(crate) fn select_lasso_internal(
+        &self,
+        points: Vec<f64>,
+        mode: &str,
+        scope: &str,
+        layer: &str,
+    ) -> Result<Vec<&Entity>, SceneError> {
+        let ring = parse_line_points(points).map_err(|msg| {
+            SceneError::InvalidInput(format!("[select_lasso] invalid_input: {}", msg))
+        })?;
+        if ring.len() < 3 {
+            return Err(SceneError::InvalidInput(
+                "[select_lasso] invalid_input: Lasso requires at least 3 points".to_string(),
+            ));
+        }
+        let mode = Self::parse_select_mode("select_lasso", mode)?;
+        self.select_in_region_internal("select_lasso", &ring, mode, scope, layer)
+    }
+
+    /// Entity의 layer를 설정합니다 (내부용, 빈 문자열이면 해제).
+    pub(crate) fn set_layer_internal(&mut self, name: &str, layer: &str) -> bool {
+        let Some(entity) = self.find_by_name_mut(name) else {
+            return false;
+        };
+        entity.metadata.layer = (!layer.is_empty()).then(|| layer.to_string());
+        self.last_operation = Some(format!("set_layer({}, {})", name, layer));
+        true
+    }
+}
+
+// ========================================
+// WASM Bindings for Region Selection
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 월드 좌표 사각형 안의 Entity들을 선택합니다.
+    ///
+    /// # Arguments
+    /// * `x1`, `y1`, `x2`, `y2` - 사각형의 두 모서리 (순서 무관)
+    /// * `mode` - "window" (완전히 포함) 또는 "crossing" (겹치거나 포함)
+    /// * `scope` - 빈 문자열이면 루트 Entity, 그룹 이름이면 그 그룹의 자식들만 후보
+    /// * `layer` - 빈 문자열이 아니면 해당 layer의 Entity만 선택
+    ///
+    /// # Returns
+    /// JSON 배열 (list_entities와 같은 형태): `[{"name": "door", "type": "Arc"}, ...]`
+    ///
+    /// # Errors
+    /// * 알 수 없는 mode, NaN/Infinity 좌표, scope 미발견 또는 그룹이 아님
+    #[allow(clippy::too_many_arguments)]
+    pub fn select_box(
+        &self,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        mode: &str,
+        scope: &str,
+        layer: &str,
+    ) -> Result<String, JsValue> {
+        let selected = self
+            .select_box_internal([x1, y1], [x2, y2], mode, scope, layer)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Self::selection_to_json(&selected))
+    }
+
+    /// 월드 좌표 다각형(lasso) 안의 Entity들을 선택합니다.
+    ///
+    /// # Arguments
+    /// * `points` - lasso 꼭짓점 [x1, y1, x2, y2, ...] (3점 이상, 자동으로 닫힘)
+    /// * `mode` - "window" (완전히 포함) 또는 "crossing" (겹치거나 포함)
+    /// * `scope` - 빈 문자열이면 루트 Entity, 그룹 이름이면 그 그룹의 자식들만 후보
+    /// * `layer` - 빈 문자열이 아니면 해당 layer의 Entity만 선택
+    ///
+    /// # Returns
+    /// JSON 배열 (list_entities와 같은 형태): `[{"name": "door", "type": "Arc"}, ...]`
+    ///
+    /// # Errors
+    /// * 3점 미만, 알 수 없는 mode, NaN/Infinity 좌표, scope 미발견 또는 그룹이 아님
+    pub fn select_lasso(
+        &self,
+        points: Float64Array,
+        mode: &str,
+        scope: &str,
+        layer: &str,
+    ) -> Result<String, JsValue> {
+        let selected = self
+            .select_lasso_internal(points.to_vec(), mode, scope, layer)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        Ok(Self::selection_to_json(&selected))
+    }
+
+    /// Entity의 layer를 설정합니다 (select_box/select_lasso의 layer 필터용).
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름
+    /// * `layer` - layer 이름 (빈 문자열이면 layer 해제)
+    ///
+    /// # Returns
+    /// * true - 성공
+    /// * false - name 미발견
+    pub fn set_layer(&mut self, name: &str, layer: &str) -> bool {
+        self.set_layer_internal(name, layer)
+    }
+
+    /// Entity의 layer를 조회합니다 (없으면 None).
+    pub fn get_layer(&self, name: &str) -> Option<String> {
+        self.find_by_name(name)?.metadata.layer.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(selected: Vec<&Entity>) -> Vec<String> {
+        selected.iter().map(|e| e.metadata.name.clone()).collect()
+    }
+
+    fn sample_scene() -> Scene {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("inner", 0.0, 0.0, 5.0).unwrap();
+        scene
+            .add_rect_internal("wide", 0.0, 0.0, 40.0, 4.0)
+            .unwrap();
+        scene.add_circle_internal("far", 100.0, 100.0, 5.0).unwrap();
+        scene
+    }
+
+    #[test]
+    fn test_select_box_window_vs_crossing() {
+        let scene = sample_scene();
+
+        let window = scene
+            .select_box_internal([10.0, 10.0], [-10.0, -10.0], "window", "", "")
+            .unwrap();
+        assert_eq!(names(window), ["inner"]);
+
+        let crossing = scene
+            .select_box_internal([-10.0, -10.0], [10.0, 10.0], "Crossing", "", "")
+            .unwrap();
+        assert_eq!(names(crossing), ["inner", "wide"]);
+
+        let json: serde_json::Value = serde_json::from_str(&Scene::selection_to_json(
+            &scene
+                .select_box_internal([-10.0, -10.0], [10.0, 10.0], "window", "", "")
+                .unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(json, json!([{"name": "inner", "type": "Circle"}]));
+    }
+
+    #[test]
+    fn test_select_lasso_concave_and_arc_shape() {
+        let mut scene = Scene::new("test");
+        // 원의 바운드는 lasso 안이지만 원호가 홈을 지나감
+        scene.add_circle_internal("c", 5.0, 6.0, 3.0).unwrap();
+        scene
+            .add_line_internal("l", vec![1.0, 1.0, 2.0, 8.0])
+            .unwrap();
+        let lasso = vec![
+            0.0, 0.0, 10.0, 0.0, 10.0, 10.0, 6.0, 10.0, 6.0, 4.0, 4.0, 4.0, 4.0, 10.0, 0.0, 10.0,
+        ];
+
+        let window = scene
+            .select_lasso_internal(lasso.clone(), "window", "", "")
+            .unwrap();
+        assert_eq!(names(window), ["l"]);
+        let crossing = scene
+            .select_lasso_internal(lasso, "crossing", "", "")
+            .unwrap();
+        assert_eq!(names(crossing), ["c", "l"]);
+    }
+
+    #[test]
+    fn test_select_scope_and_layer() {
+        let mut scene = sample_scene();
+        scene
+            .create_group_internal("g", vec!["inner".to_string(), "wide".to_string()])
+            .unwrap();
+        scene.translate("g", 1000.0, 0.0).unwrap();
+
+        // 루트: 그룹 단위로 판정 (wide가 밖으로 나가므로 window에서는 제외)
+        let root = scene
+            .select_box_internal([979.0, -10.0], [1021.0, 10.0], "window", "", "")
+            .unwrap();
+        assert_eq!(names(root), ["g"]);
+        let narrow = scene
+            .select_box_internal([990.0, -10.0], [1010.0, 10.0], "window", "", "")
+            .unwrap();
+        assert!(narrow.is_empty());
+
+        // 그룹 scope: 자식 단위, 월드 좌표 기준
+        let scoped = scene
+            .select_box_internal([990.0, -10.0], [1010.0, 10.0], "window", "g", "")
+            .unwrap();
+        assert_eq!(names(scoped), ["inner"]);
+
+        assert!(scene.set_layer_internal("wide", "walls"));
+        let walls = scene
+            .select_box_internal([990.0, -10.0], [1010.0, 10.0], "crossing", "g", "walls")
+            .unwrap();
+        assert_eq!(names(walls), ["wide"]);
+        assert_eq!(scene.get_layer("wide").as_deref(), Some("walls"));
+        assert!(scene.set_layer_internal("wide", ""));
+        assert_eq!(scene.get_layer("wide"), None);
+    }
+
+    #[test]
+    fn test_select_errors() {
+        let scene = sample_scene();
+        assert!(
+            scene
+                .select_box_internal([0.0, 0.0], [1.0, 1.0], "touching", "", "")
+                .is_err()
+        );
+        assert!(matches!(
+            scene.select_box_internal([0.0, 0.0], [1.0, 1.0], "window", "missing", ""),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(matches!(
+            scene.select_box_internal([0.0, 0.0], [1.0, 1.0], "window", "inner", ""),
+            Err(SceneError::NotAGroup(..))
+        ));
+        assert!(
+            scene
+                .select_lasso_internal(vec![0.0, 0.0, 1.0, 1.0], "window", "", "")
+                .is_err()
+        );
+    }
+}
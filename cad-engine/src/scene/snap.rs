@@ -0,0 +1,497 @@
+//! 객체 스냅 모듈
+//!
+//! 월드 좌표 커서 주변의 스냅 후보 점(끝점, 중점, 중심, 사분점, 교점, 수직점, 접점, 최근접점)을
+//! 거리 순으로 반환합니다. 뷰어의 그리기 도구와 "팔 끝에 붙여줘" 같은 요청이 같은 결과를 사용합니다.
+//! - 후보 도형: 공간 인덱스에서 검색 반경과 바운드가 겹치는 도형 (Text, Dimension 제외)
+//! - 외곽선 분해: scene/intersect.rs `collect_world_edges` (부모 그룹 변환 포함 월드 좌표)
+//! - 조각별 후보 계산: geometry/snap.rs (큐빅으로 분해된 원/타원은 도형 자체의 끝점/사분점)
+
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, Geometry, Transform};
+use super::intersect::WorldEdges;
+use super::{Scene, SceneError};
+use crate::geometry::flatten::arc_sweep;
+use crate::geometry::intersect::Edge;
+use crate::geometry::snap::{SnapKind, conic_snap_points, edge_snap_points};
+
+/// 같은 스냅 점으로 볼 거리
+const DUPLICATE_EPSILON: f64 = 1e-9;
+
+/// 스냅 후보 점
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct SnapPoint {
+    pub kind: SnapKind,
+    pub point: [f64; 2],
+    /// 커서까지의 거리
+    pub distance: f64,
+    /// 후보를 만든 도형 이름
+    pub name: String,
+    /// 교점의 다른 쪽 도형 이름
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other: Option<String>,
+}
+
+/// 스냅 옵션
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct SnapOptions {
+    /// 수직점/접점 기준점 (그리는 선의 앞 점)
+    pub from: Option<[f64; 2]>,
+    /// 허용할 스냅 종류 (None = 전부)
+    pub kinds: Option<Vec<SnapKind>>,
+}
+
+#[derive(Deserialize)]
+struct SnapOptionsInput {
+    #[serde(default)]
+    from: Option<[f64; 2]>,
+    #[serde(default)]
+    kinds: Option<Vec<String>>,
+}
+
+/// options_json 파싱 ("" → 기본값)
+fn parse_snap_options(options_json: &str) -> Result<SnapOptions, SceneError> {
+    if options_json.trim().is_empty() {
+        return Ok(SnapOptions::default());
+    }
+    let input: SnapOptionsInput = serde_json::from_str(options_json)
+        .map_err(|e| SceneError::InvalidInput(format!("[get_snap_points] invalid_json: {}", e)))?;
+    let kinds = input
+        .kinds
+        .map(|kinds| {
+            kinds
+                .iter()
+                .map(|k| {
+                    SnapKind::parse_str(k).ok_or_else(|| {
+                        SceneError::InvalidInput(format!(
+                            "[get_snap_points] invalid_input: unknown snap kind '{}'",
+                            k
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+    Ok(SnapOptions {
+        from: input.from,
+        kinds,
+    })
+}
+
+impl Scene {
+    /// 커서 주변 반경 안의 스냅 후보 점을 거리 순으로 계산합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `x`, `y` - 커서 월드 좌표
+    /// * `radius` - 검색 반경 (0 이상, 월드 단위)
+    /// * `options` - 기준점과 허용 종류
+    ///
+    /// # Errors
+    /// * NaN/Infinity 좌표, 음수이거나 유한하지 않은 radius, 유한하지 않은 기준점
+    pub(crate) fn get_snap_points_internal(
+        &self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        options: &SnapOptions,
+    ) -> Result<Vec<SnapPoint>, SceneError> {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[get_snap_points] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        if !(radius.is_finite() && radius >= 0.0) {
+            return Err(SceneError::InvalidInput(
+                "[get_snap_points] invalid_input: radius must be a non-negative finite number"
+                    .to_string(),
+            ));
+        }
+        if options
+            .from
+            .is_some_and(|p| !p[0].is_finite() || !p[1].is_finite())
+        {
+            return Err(SceneError::InvalidInput(
+                "[get_snap_points] invalid_input: from must be a finite point".to_string(),
+            ));
+        }
+
+        let cursor = [x, y];
+        let wants = |kind: SnapKind| {
+            options
+                .kinds
+                .as_ref()
+                .is_none_or(|kinds| kinds.contains(&kind))
+        };
+        let mut out: Vec<SnapPoint> = Vec::new();
+        let mut push = |kind: SnapKind, point: [f64; 2], name: &str, other: Option<&str>| {
+            let distance = (point[0] - x).hypot(point[1] - y);
+            if distance <= radius && wants(kind) {
+                out.push(SnapPoint {
+                    kind,
+                    point,
+                    distance,
+                    name: name.to_string(),
+                    other: other.map(str::to_string),
+                });
+            }
+        };
+
+        let probe = ([x - radius, y - radius], [x + radius, y + radius]);
+        let mut names = self.query_spatial_internal(&probe);
+        names.sort();
+        let mut leaves: Vec<WorldEdges> = Vec::new();
+        for name in &names {
+            let Some(entity) = self.find_by_name(name) else {
+                continue;
+            };
+            if matches!(entity.entity_type, EntityType::Text) {
+                continue;
+            }
+            self.collect_world_edges(name, &mut leaves);
+        }
+
+        for leaf in &leaves {
+            // 큐빅 조각으로 분해된 타원(호)은 이음매 끝점/중점 대신 도형 자체의 점,
+            // 최근접점은 조각 중 가장 가까운 하나
+            let conic = leaf
+                .edges
+                .iter()
+                .any(|edge| matches!(edge, Edge::Cubic(_)))
+                .then(|| self.world_conic_snap_points(&leaf.name))
+                .flatten();
+            let mut nearest: Option<[f64; 2]> = None;
+            for edge in &leaf.edges {
+                for (kind, point) in edge_snap_points(edge, cursor, options.from, leaf.tolerance) {
+                    if conic.is_some() {
+                        match kind {
+                            SnapKind::Endpoint | SnapKind::Midpoint => continue,
+                            SnapKind::Nearest => {
+                                let closer = |q: [f64; 2]| {
+                                    (point[0] - x).hypot(point[1] - y) < (q[0] - x).hypot(q[1] - y)
+                                };
+                                if nearest.is_none_or(closer) {
+                                    nearest = Some(point);
+                                }
+                                continue;
+                            }
+                            _ => {}
+                        }
+                    }
+                    push(kind, point, &leaf.name, None);
+                }
+            }
+            for (kind, point) in conic.into_iter().flatten() {
+                push(kind, point, &leaf.name, None);
+            }
+            if let Some(point) = nearest {
+                push(SnapKind::Nearest, point, &leaf.name, None);
+            }
+        }
+
+        if wants(SnapKind::Intersection) {
+            let mut hits = Vec::new();
+            for (i, a) in leaves.iter().enumerate() {
+                for b in &leaves[i + 1..] {
+                    self.intersect_leaves(a, b, &mut hits);
+                }
+            }
+            for hit in hits {
+                push(
+                    SnapKind::Intersection,
+                    hit.point,
+                    &hit.a.name,
+                    Some(&hit.b.name),
+                );
+            }
+        }
+
+        out.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.kind.cmp(&b.kind))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        // 이웃한 조각이 공유하는 끝점 등 같은 도형·종류의 중복 제거
+        let mut unique: Vec<SnapPoint> = Vec::with_capacity(out.len());
+        for snap in out {
+            let duplicate = unique.iter().any(|u| {
+                u.kind == snap.kind
+                    && u.name == snap.name
+                    && u.other == snap.other
+                    && (u.point[0] - snap.point[0]).hypot(u.point[1] - snap.point[1])
+                        <= DUPLICATE_EPSILON * (1.0 + snap.distance)
+            });
+            if !duplicate {
+                unique.push(snap);
+            }
+        }
+        Ok(unique)
+    }
+}
+
+impl Scene {
+    /// 원/원호/타원/타원 호의 끝점·중점·중심·사분점 (월드 좌표, 그 외 도형은 None)
+    fn world_conic_snap_points(&self, name: &str) -> Option<Vec<(SnapKind, [f64; 2])>> {
+        let entity = self.find_by_name(name)?;
+        let (center, rx, ry, rotation, start, sweep) = match entity.geometry {
+            Geometry::Circle { center, radius } => (center, radius, radius, 0.0, 0.0, 2.0 * PI),
+            Geometry::Arc {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+            } => (
+                center,
+                radius,
+                radius,
+                0.0,
+                start_angle,
+                arc_sweep(start_angle, end_angle),
+            ),
+            Geometry::Ellipse {
+                center,
+                rx,
+                ry,
+                rotation,
+            } => (center, rx, ry, rotation, 0.0, 2.0 * PI),
+            Geometry::EllipticalArc {
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                end_angle,
+            } => (
+                center,
+                rx,
+                ry,
+                rotation,
+                start_angle,
+                arc_sweep(start_angle, end_angle),
+            ),
+            _ => return None,
+        };
+        let m = self.get_world_transform_internal(name)?;
+        Some(
+            conic_snap_points(center, rx, ry, rotation, start, sweep)
+                .into_iter()
+                .map(|(kind, point)| (kind, Transform::transform_point(&m, point)))
+                .collect(),
+        )
+    }
+}
+
+// ========================================
+// WASM Bindings for Snapping
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 커서 주변 반경 안의 스냅 후보 점을 가까운 순서로 반환합니다.
+    ///
+    /// 부모 그룹 변환이 모두 적용된 월드 좌표 기준이며, 같은 거리면
+    /// endpoint > intersection > midpoint > center > quadrant > tangent > perpendicular > nearest 순입니다.
+    /// - endpoint: 선분/원호/베지어 끝점 (Polygon/Rect 꼭짓점 포함)
+    /// - midpoint: 선분/원호 중점
+    /// - center: 원/원호/타원 중심, quadrant: 원/원호의 0°/90°/180°/270° 점, 타원(호)의 장축/단축 끝
+    /// - intersection: 서로 다른 두 도형의 교점 (`other`에 다른 쪽 이름)
+    /// - perpendicular, tangent: 기준점 `from`에서 내린 수선의 발, 그은 접선의 접점 (`from`이 있을 때만)
+    /// - nearest: 커서에서 외곽선 위로 가장 가까운 점
+    ///
+    /// # Arguments
+    /// * `x`, `y` - 커서 월드 좌표
+    /// * `radius` - 검색 반경 (0 이상, 월드 단위, 예: 화면 10px에 해당하는 값)
+    /// * `options_json` - 옵션 JSON (빈 문자열이면 기본값)
+    ///   - `from`: `[x, y]` 수직점/접점 기준점
+    ///   - `kinds`: 허용할 종류 배열 (예: `["endpoint", "intersection"]`, 생략 시 전부)
+    ///
+    /// # Returns
+    /// JSON 배열: `[{"kind": "endpoint", "point": [x, y], "distance": 0.5, "name": "arm"},
+    /// {"kind": "intersection", "point": [x, y], "distance": 1.2, "name": "wall", "other": "door"}, ...]`
+    ///
+    /// # Errors
+    /// * NaN/Infinity 좌표, 음수 radius, 잘못된 options JSON 또는 알 수 없는 kind
+    pub fn get_snap_points(
+        &self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        options_json: &str,
+    ) -> Result<String, JsValue> {
+        let snaps = parse_snap_options(options_json)
+            .and_then(|options| self.get_snap_points_internal(x, y, radius, &options))
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        serde_json::to_string(&snaps).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    fn only(kinds: &[SnapKind]) -> SnapOptions {
+        SnapOptions {
+            from: None,
+            kinds: Some(kinds.to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_snap_endpoint_under_group_ranked_first() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("arm", vec![0.0, 0.0, 10.0, 0.0])
+            .unwrap();
+        scene
+            .create_group_internal("body", vec!["arm".to_string()])
+            .unwrap();
+        scene.translate("body", 5.0, 5.0).unwrap();
+
+        let snaps = scene
+            .get_snap_points_internal(15.5, 5.2, 1.0, &SnapOptions::default())
+            .unwrap();
+        assert_eq!(snaps[0].kind, SnapKind::Endpoint);
+        assert_eq!(snaps[0].name, "arm");
+        assert_eq!(snaps[0].point, [15.0, 5.0]);
+        // 최근접점도 후보에 있지만 더 멀거나 같은 거리면 뒤에
+        assert!(snaps.iter().any(|s| s.kind == SnapKind::Nearest));
+        assert!(snaps.windows(2).all(|w| w[0].distance <= w[1].distance));
+
+        // 반경 밖이면 후보 없음
+        assert!(
+            scene
+                .get_snap_points_internal(30.0, 30.0, 1.0, &SnapOptions::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_snap_rect_corner_deduplicated_and_intersection() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("r", 0.0, 0.0, 10.0, 10.0).unwrap();
+        let (min, _) = scene.get_world_bounds_internal("r").unwrap();
+        let corners = scene
+            .get_snap_points_internal(min[0], min[1], 0.5, &only(&[SnapKind::Endpoint]))
+            .unwrap();
+        assert_eq!(corners.len(), 1);
+
+        scene.add_circle_internal("c", min[0], min[1], 2.0).unwrap();
+        let hits = scene
+            .get_snap_points_internal(min[0] + 2.0, min[1], 0.5, &only(&[SnapKind::Intersection]))
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(approx_eq(hits[0].point[0], min[0] + 2.0, 1e-9));
+        assert_eq!(hits[0].other.as_deref(), Some("r"));
+    }
+
+    #[test]
+    fn test_snap_circle_center_quadrant_and_tangent() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+
+        let snaps = scene
+            .get_snap_points_internal(0.2, 4.9, 0.5, &SnapOptions::default())
+            .unwrap();
+        assert_eq!(snaps[0].kind, SnapKind::Nearest);
+        assert!(
+            snaps
+                .iter()
+                .any(|s| s.kind == SnapKind::Quadrant && approx_eq(s.point[1], 5.0, 1e-12))
+        );
+
+        let center = scene
+            .get_snap_points_internal(0.0, 0.0, 6.0, &only(&[SnapKind::Center]))
+            .unwrap();
+        assert_eq!(center.len(), 1);
+
+        let options = SnapOptions {
+            from: Some([0.0, 10.0]),
+            kinds: Some(vec![SnapKind::Tangent]),
+        };
+        let tangents = scene
+            .get_snap_points_internal(4.0, 2.5, 1.0, &options)
+            .unwrap();
+        assert_eq!(tangents.len(), 1);
+        assert!(approx_eq(tangents[0].point[1], 2.5, 1e-9));
+    }
+
+    #[test]
+    fn test_snap_ellipse_quadrants_without_piece_joints() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_ellipse_internal("e", 0.0, 0.0, 3.0, 1.0, 0.0)
+            .unwrap();
+
+        let snaps = scene
+            .get_snap_points_internal(0.0, 0.9, 5.0, &SnapOptions::default())
+            .unwrap();
+        let of = |kind: SnapKind| -> Vec<[f64; 2]> {
+            snaps
+                .iter()
+                .filter(|s| s.kind == kind)
+                .map(|s| s.point)
+                .collect()
+        };
+        // 큐빅 조각 이음매는 끝점/중점이 아님
+        assert!(of(SnapKind::Endpoint).is_empty());
+        assert!(of(SnapKind::Midpoint).is_empty());
+        let quadrants = of(SnapKind::Quadrant);
+        assert_eq!(quadrants.len(), 4);
+        assert!(
+            quadrants
+                .iter()
+                .any(|p| approx_eq(p[0], 0.0, 1e-12) && approx_eq(p[1], 1.0, 1e-12))
+        );
+        assert_eq!(of(SnapKind::Center), [[0.0, 0.0]]);
+        // 최근접점은 하나
+        let nearest = of(SnapKind::Nearest);
+        assert_eq!(nearest.len(), 1);
+        assert!(approx_eq(nearest[0][1], 1.0, 1e-6));
+
+        // 타원 호: 실제 호 끝점
+        scene
+            .add_elliptical_arc_internal("ea", 20.0, 0.0, 3.0, 1.0, 0.0, 0.0, PI / 2.0)
+            .unwrap();
+        let ends = scene
+            .get_snap_points_internal(21.5, 0.5, 3.0, &only(&[SnapKind::Endpoint]))
+            .unwrap();
+        assert_eq!(ends.len(), 2);
+        assert!(ends.iter().all(|s| s.name == "ea"));
+        assert!(ends.iter().any(|s| approx_eq(s.point[0], 23.0, 1e-12)));
+        assert!(ends.iter().any(|s| approx_eq(s.point[1], 1.0, 1e-12)));
+    }
+
+    #[test]
+    fn test_snap_options_and_errors() {
+        let options =
+            parse_snap_options(r#"{"from": [1, 2], "kinds": ["Endpoint", "nearest"]}"#).unwrap();
+        assert_eq!(options.from, Some([1.0, 2.0]));
+        assert_eq!(
+            options.kinds,
+            Some(vec![SnapKind::Endpoint, SnapKind::Nearest])
+        );
+        assert_eq!(parse_snap_options("").unwrap(), SnapOptions::default());
+        assert!(parse_snap_options(r#"{"kinds": ["vertex"]}"#).is_err());
+
+        let scene = Scene::new("test");
+        let defaults = SnapOptions::default();
+        assert!(
+            scene
+                .get_snap_points_internal(f64::NAN, 0.0, 1.0, &defaults)
+                .is_err()
+        );
+        assert!(
+            scene
+                .get_snap_points_internal(0.0, 0.0, -1.0, &defaults)
+                .is_err()
+        );
+    }
+}
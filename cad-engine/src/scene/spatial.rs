@@ -0,0 +1,298 @@
+//! 공간 인덱스 모듈
+//!
+//! 도형(Group 제외)의 시각적 월드 바운드를 R-tree(geometry/spatial.rs)로 관리합니다.
+//! pick, 영역 선택, 뷰포트 컬링이 전체 Entity를 훑지 않고 후보만 검사하기 위한 것입니다.
+//! - 추가/삭제/변환/스타일 변경 시 해당 핸들만 dirty로 표시 (entity_at_mut, push_entity, remove_entity)
+//! - 질의 직전에 dirty 이름만 다시 계산 (Group은 하위 도형 전체로 확장)
+//! - 치수는 참조 도형에 따라 바운드가 바뀌므로 트리 대신 질의 때마다 계산
+//! - 그리기 순서 키는 한 번 계산해 캐시하고, z-order/계층 변경 시에만 무효화
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{Entity, EntityHandle, EntityType};
+use super::{Scene, SceneError};
+use crate::geometry::bounds::{BoundingBox, bbox_intersects};
+use crate::geometry::spatial::SpatialIndex;
+
+/// Scene 공간 인덱스 상태
+#[derive(Debug, Default)]
+pub(crate) struct SceneSpatial {
+    index: SpatialIndex<EntityHandle>,
+    /// 아직 인덱스에 반영되지 않은 변경 Entity
+    dirty: HashSet<EntityHandle>,
+    /// 치수 Entity (질의 때마다 바운드 계산)
+    dimensions: HashSet<EntityHandle>,
+    /// 핸들 → 그리기 순위 (뒤 → 앞, None이면 다음 정렬 때 다시 계산)
+    paint_order: Option<HashMap<EntityHandle, usize>>,
+}
+
+impl Scene {
+    /// Entity의 바운드가 바뀌었을 수 있음을 표시합니다 (추가/삭제/변경 시).
+    pub(super) fn mark_spatial_dirty(&mut self, handle: EntityHandle) {
+        self.spatial.get_mut().dirty.insert(handle);
+    }
+
+    /// 그리기 순서가 바뀌었음을 표시합니다 (추가/삭제, z_index 변경, 그룹 구성 변경 시).
+    pub(super) fn invalidate_paint_order(&mut self) {
+        self.spatial.get_mut().paint_order = None;
+    }
+
+    /// 전체 Entity의 그리기 순위를 계산합니다.
+    ///
+    /// 뷰어와 같이 루트부터 각 단계에서 (z_index, 형제 순서)로 정렬해 깊이 우선으로 번호를 매깁니다.
+    /// 루트의 형제 순서는 추가 순서(핸들 번호)입니다.
+    fn compute_paint_order(&self) -> HashMap<EntityHandle, usize> {
+        let mut roots: Vec<&Entity> = self
+            .entities
+            .iter()
+            .filter(|e| e.parent.is_none())
+            .collect();
+        roots.sort_by_key(|e| (e.metadata.z_index, e.handle.index()));
+
+        let mut order = HashMap::with_capacity(self.entities.len());
+        let mut stack: Vec<&Entity> = roots.into_iter().rev().collect();
+        while let Some(entity) = stack.pop() {
+            order.insert(entity.handle, order.len());
+            let mut children: Vec<(usize, &Entity)> =
+                self.children_of(entity).enumerate().collect();
+            children.sort_by_key(|(i, e)| (e.metadata.z_index, *i));
+            stack.extend(children.into_iter().rev().map(|(_, e)| e));
+        }
+        order
+    }
+
+    /// dirty로 표시된 Entity만 다시 계산해 인덱스에 반영합니다.
+    fn refresh_spatial_index(&self) {
+        let mut state = self.spatial.borrow_mut();
+        if state.dirty.is_empty() {
+            return;
+        }
+        let mut pending: Vec<EntityHandle> = state.dirty.drain().collect();
+        let mut visited = HashSet::new();
+        while let Some(handle) = pending.pop() {
+            if !visited.insert(handle) {
+                continue;
+            }
+            state.dimensions.remove(&handle);
+            let Some(entity) = self.entity_at(handle) else {
+                state.index.remove(&handle);
+                continue;
+            };
+            match entity.entity_type {
+                // 그룹 변환은 하위 도형 전체의 월드 바운드를 바꿈
+                EntityType::Group => {
+                    state.index.remove(&handle);
+                    pending.extend(entity.children.iter().copied());
+                }
+                EntityType::Dimension => {
+                    state.index.remove(&handle);
+                    state.dimensions.insert(handle);
+                }
+                _ => match self.get_visual_bounds_internal(&entity.metadata.name) {
+                    Some(bbox) => state.index.insert(handle, bbox),
+                    None => state.index.remove(&handle),
+                },
+            }
+        }
+    }
+
+    /// 시각적 월드 바운드가 박스와 겹치는 도형(Group 제외) 이름들 (순서 무관, 내부용)
+    pub(crate) fn query_spatial_internal(&self, bbox: &BoundingBox) -> Vec<String> {
+        self.refresh_spatial_index();
+        let state = self.spatial.borrow();
+        let dimensions = state.dimensions.iter().copied().filter(|handle| {
+            self.entity_at(*handle).is_some_and(|e| {
+                self.get_visual_bounds_internal(&e.metadata.name)
+                    .is_some_and(|b| bbox_intersects(&b, bbox))
+            })
+        });
+        state
+            .index
+            .query(bbox)
+            .into_iter()
+            .chain(dimensions)
+            .filter_map(|handle| self.entity_at(handle))
+            .map(|e| e.metadata.name.clone())
+            .collect()
+    }
+
+    /// 도형 이름들을 그리기 순서(뒤 → 앞)로 정렬합니다.
+    ///
+    /// 캐시된 그리기 순위를 사용하므로 z-order/계층이 바뀌지 않았다면 후보 수에만 비례합니다.
+    pub(crate) fn sort_by_paint_order(&self, names: &mut [String]) {
+        if names.len() < 2 {
+            return;
+        }
+        let mut state = self.spatial.borrow_mut();
+        let order = state
+            .paint_order
+            .get_or_insert_with(|| self.compute_paint_order());
+        names.sort_by_cached_key(|name| {
+            self.handle_of(name)
+                .and_then(|handle| order.get(&handle).copied())
+                .unwrap_or(usize::MAX)
+        });
+    }
+
+    /// 뷰포트와 겹치는 도형들을 그리기 순서로 반환합니다 (내부용).
+    pub(crate) fn query_viewport_internal(
+        &self,
+        corner1: [f64; 2],
+        corner2: [f64; 2],
+    ) -> Result<Vec<String>, SceneError> {
+        if corner1.iter().chain(corner2.iter()).any(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(
+                "[query_viewport] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let bbox = (
+            [corner1[0].min(corner2[0]), corner1[1].min(corner2[1])],
+            [corner1[0].max(corner2[0]), corner1[1].max(corner2[1])],
+        );
+        let mut names = self.query_spatial_internal(&bbox);
+        self.sort_by_paint_order(&mut names);
+        Ok(names)
+    }
+}
+
+// ========================================
+// WASM Bindings for Spatial Queries
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 뷰포트(월드 좌표 사각형)와 겹치는 도형들을 반환합니다 (뷰포트 컬링용).
+    ///
+    /// stroke를 포함한 시각적 바운드 기준이며, Group은 펼쳐서 하위 도형만 반환합니다.
+    /// 공간 인덱스를 사용하므로 Entity 수가 많아도 겹치는 후보만 검사합니다.
+    ///
+    /// # Arguments
+    /// * `x1`, `y1`, `x2`, `y2` - 뷰포트의 두 모서리 (순서 무관)
+    ///
+    /// # Returns
+    /// JSON 배열 (그리기 순서, 뒤 → 앞): `[{"name": "floor", "type": "Polygon"}, ...]`
+    ///
+    /// # Errors
+    /// * NaN/Infinity 좌표
+    pub fn query_viewport(&self, x1: f64, y1: f64, x2: f64, y2: f64) -> Result<String, JsValue> {
+        let names = self
+            .query_viewport_internal([x1, y1], [x2, y2])
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let list: Vec<serde_json::Value> = names
+            .iter()
+            .filter_map(|name| self.find_by_name(name))
+            .map(|e| {
+                json!({
+                    "name": e.metadata.name,
+                    "type": e.entity_type.as_str()
+                })
+            })
+            .collect();
+        serde_json::to_string(&list).map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::entity::DimensionKind;
+
+    fn viewport(scene: &Scene, min: [f64; 2], max: [f64; 2]) -> Vec<String> {
+        scene.query_viewport_internal(min, max).unwrap()
+    }
+
+    #[test]
+    fn test_index_tracks_add_transform_delete() {
+        let mut scene = Scene::new("test");
+        for i in 0..300 {
+            scene
+                .add_circle_internal(&format!("c{}", i), i as f64 * 10.0, 0.0, 1.0)
+                .unwrap();
+        }
+        assert_eq!(viewport(&scene, [95.0, -5.0], [115.0, 5.0]), ["c10", "c11"]);
+
+        // 이동: 이전 위치에서 사라지고 새 위치에서 찾아짐
+        scene.translate("c10", 0.0, 500.0).unwrap();
+        assert_eq!(viewport(&scene, [95.0, -5.0], [115.0, 5.0]), ["c11"]);
+        assert_eq!(viewport(&scene, [95.0, 495.0], [105.0, 505.0]), ["c10"]);
+
+        // stroke 폭 변경은 시각적 바운드에 반영
+        assert!(viewport(&scene, [112.0, -1.0], [113.0, 1.0]).is_empty());
+        scene.set_stroke("c11", r#"{"width": 6}"#).unwrap();
+        assert_eq!(viewport(&scene, [112.0, -1.0], [113.0, 1.0]), ["c11"]);
+
+        scene.delete("c11").unwrap();
+        assert!(viewport(&scene, [95.0, -5.0], [115.0, 5.0]).is_empty());
+    }
+
+    #[test]
+    fn test_index_follows_group_transform_and_paint_order() {
+        let mut scene = Scene::new("test");
+        scene.add_rect_internal("a", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("b", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_circle_internal("top", 0.0, 0.0, 1.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["a".to_string(), "b".to_string()])
+            .unwrap();
+        scene.set_z_order("g", 10).unwrap();
+        assert_eq!(
+            viewport(&scene, [-1.0, -1.0], [1.0, 1.0]),
+            ["top", "a", "b"]
+        );
+
+        // z-order/계층 변경 시 캐시된 그리기 순서도 갱신
+        scene.draw_order("a", "front").unwrap();
+        assert_eq!(
+            viewport(&scene, [-1.0, -1.0], [1.0, 1.0]),
+            ["top", "b", "a"]
+        );
+        scene.draw_order("g", "back").unwrap();
+        // 그룹에서 빠진 b는 자식 z_index(0)를 유지 → 같은 z면 먼저 추가된 b가 뒤
+        scene.remove_from_group_internal("g", "b").unwrap();
+        assert_eq!(
+            viewport(&scene, [-1.0, -1.0], [1.0, 1.0]),
+            ["b", "a", "top"]
+        );
+        scene.add_to_group_internal("g", "b").unwrap();
+        assert_eq!(
+            viewport(&scene, [-1.0, -1.0], [1.0, 1.0]),
+            ["a", "b", "top"]
+        );
+
+        // 그룹 변환 → 하위 도형 바운드 갱신
+        scene.translate("g", 100.0, 0.0).unwrap();
+        assert_eq!(viewport(&scene, [-1.0, -1.0], [1.0, 1.0]), ["top"]);
+        assert_eq!(viewport(&scene, [99.0, -1.0], [101.0, 1.0]), ["a", "b"]);
+
+        // ungroup: 자식은 로컬 좌표 그대로 루트가 됨 → 인덱스도 원위치로 갱신
+        scene.ungroup("g").unwrap();
+        assert!(viewport(&scene, [99.0, -1.0], [101.0, 1.0]).is_empty());
+        let (min, max) = scene.get_world_bounds_internal("a").unwrap();
+        let names = viewport(&scene, min, max);
+        assert!(names.contains(&"a".to_string()) && names.contains(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_index_dimension_follows_reference() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 5.0).unwrap();
+        scene
+            .add_dimension_internal(
+                "test",
+                "d",
+                DimensionKind::Diameter {
+                    target: "c".to_string(),
+                    angle: 0.0,
+                },
+            )
+            .unwrap();
+        assert!(viewport(&scene, [-6.0, -6.0], [6.0, 6.0]).contains(&"d".to_string()));
+
+        scene.translate("c", 1000.0, 0.0).unwrap();
+        assert!(!viewport(&scene, [-6.0, -6.0], [6.0, 6.0]).contains(&"d".to_string()));
+        assert!(viewport(&scene, [994.0, -6.0], [1006.0, 6.0]).contains(&"d".to_string()));
+    }
+}
@@ -1,7 +1,8 @@
 /// Style 모듈
 ///
 /// 스타일 타입 정의 및 스타일 조작 함수를 제공합니다.
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
 use wasm_bindgen::prelude::*;
 
 use super::Scene;
@@ -89,21 +90,243 @@ impl Default for StrokeStyle {
     }
 }
 
+/// 해치 패턴 종류 (역직렬화는 `parse_str`와 같이 대소문자 무시)
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Default)]
+pub enum HatchPattern {
+    /// ANSI31: 45° 평행선 (일반 단면)
+    #[default]
+    Ansi31,
+    /// 45°/135° 교차선
+    Crosshatch,
+    /// 벽돌 쌓기 (가로줄 + 엇갈린 세로 줄눈)
+    Brick,
+    /// 격자 점
+    Dots,
+}
+
+impl HatchPattern {
+    /// 문자열에서 HatchPattern 파싱 (대소문자 무시, 실패 시 기본값 반환)
+    pub fn parse_str(s: &str) -> Self {
+        match s.to_ascii_lowercase().as_str() {
+            "crosshatch" => HatchPattern::Crosshatch,
+            "brick" => HatchPattern::Brick,
+            "dots" => HatchPattern::Dots,
+            _ => HatchPattern::Ansi31,
+        }
+    }
+}
+
+/// 해치 채움 (fill color로 패턴 선을 그림)
+///
+/// 역직렬화는 `parse_hatch`를 거치므로 style_json과 set_fill이 같은 규칙을 따릅니다.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HatchStyle {
+    pub pattern: HatchPattern,
+    /// 패턴 회전 (라디안, CCW)
+    pub angle: f64,
+    /// 선 간격 (로컬 좌표 단위)
+    pub spacing: f64,
+    /// 간격 배율 (실제 간격 = spacing * scale)
+    pub scale: f64,
+    /// 패턴 선 두께 (Dots는 점 지름)
+    pub line_width: f64,
+}
+
+impl Default for HatchStyle {
+    fn default() -> Self {
+        HatchStyle {
+            pattern: HatchPattern::default(),
+            angle: 0.0,
+            spacing: 4.0,
+            scale: 1.0,
+            line_width: 0.25,
+        }
+    }
+}
+
+/// 그라디언트 색상 정지점
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GradientStop {
+    /// 위치 (0.0-1.0)
+    pub offset: f64,
+    pub color: [f64; 4], // RGBA, 0.0-1.0
+}
+
+/// 그라디언트 채움 (좌표는 Entity 로컬 좌표계 → Transform을 따라감)
+///
+/// 역직렬화는 `parse_gradient`를 거치므로 style_json과 set_fill이 같은 규칙을 따릅니다.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum Gradient {
+    /// start → end 방향 선형 그라디언트
+    Linear {
+        start: [f64; 2],
+        end: [f64; 2],
+        stops: Vec<GradientStop>,
+    },
+    /// center 기준 원형 그라디언트 (focal: 0% 색상의 초점, 없으면 center)
+    Radial {
+        center: [f64; 2],
+        radius: f64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        focal: Option<[f64; 2]>,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl Gradient {
+    pub fn stops(&self) -> &[GradientStop] {
+        match self {
+            Gradient::Linear { stops, .. } | Gradient::Radial { stops, .. } => stops,
+        }
+    }
+}
+
 /// 면(fill) 스타일
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
 pub struct FillStyle {
     pub color: [f64; 4], // RGBA, 0.0-1.0
+    /// 해치 채움 (있으면 단색 대신 color로 패턴 선을 그림)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hatch: Option<HatchStyle>,
+    /// 그라디언트 채움 (있으면 color 대신 사용, 해치와 함께 쓰면 해치 아래 배경)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradient: Option<Gradient>,
 }
 
 impl Default for FillStyle {
     fn default() -> Self {
         FillStyle {
             color: [0.0, 0.0, 0.0, 1.0], // 검은색
+            hatch: None,
+            gradient: None,
         }
     }
 }
 
+/// JSON 객체에서 Gradient 파싱
+///
+/// type: "linear" | "radial" (대소문자 무시), stops: [{ "offset": 0-1, "color": [r,g,b,a] }]
+/// stop offset은 0-1로 클램핑 후 정렬됩니다.
+///
+/// # Errors
+/// * type이 없거나 알 수 없음, 좌표 누락, stop이 없으면 에러 메시지
+fn parse_gradient(value: &serde_json::Value) -> Result<Gradient, String> {
+    let point = |key: &str| -> Result<[f64; 2], String> {
+        value
+            .get(key)
+            .and_then(|v| v.as_array())
+            .filter(|arr| arr.len() == 2)
+            .and_then(|arr| Some([arr[0].as_f64()?, arr[1].as_f64()?]))
+            .filter(|p| p[0].is_finite() && p[1].is_finite())
+            .ok_or_else(|| format!("gradient '{}' must be [x, y]", key))
+    };
+
+    let mut stops: Vec<GradientStop> = value
+        .get("stops")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|stop| {
+                    let offset = stop.get("offset")?.as_f64()?;
+                    let color = stop.get("color")?.as_array()?;
+                    Some(GradientStop {
+                        offset: if offset.is_finite() {
+                            offset.clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        },
+                        color: parse_rgba_color(color, [0.0, 0.0, 0.0, 1.0]),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    if stops.is_empty() {
+        return Err("gradient needs at least one stop".to_string());
+    }
+    stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+
+    let kind = value
+        .get("type")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match kind.as_str() {
+        "linear" => Ok(Gradient::Linear {
+            start: point("start")?,
+            end: point("end")?,
+            stops,
+        }),
+        "radial" => {
+            let radius = value
+                .get("radius")
+                .and_then(|v| v.as_f64())
+                .filter(|r| r.is_finite() && *r > 0.0)
+                .ok_or_else(|| "gradient 'radius' must be a positive number".to_string())?;
+            Ok(Gradient::Radial {
+                center: point("center")?,
+                radius,
+                focal: value.get("focal").and_then(|_| point("focal").ok()),
+                stops,
+            })
+        }
+        _ => Err("gradient 'type' must be \"linear\" or \"radial\"".to_string()),
+    }
+}
+
+impl<'de> Deserialize<'de> for Gradient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        parse_gradient(&value).map_err(D::Error::custom)
+    }
+}
+
+/// JSON 객체에서 HatchStyle 파싱 (누락 필드는 기본값, 0 이하 간격/배율은 기본값으로 보정)
+fn parse_hatch(value: &serde_json::Value) -> HatchStyle {
+    let defaults = HatchStyle::default();
+    let positive = |key: &str, default: f64| {
+        value
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .filter(|v| v.is_finite() && *v > 0.0)
+            .unwrap_or(default)
+    };
+    HatchStyle {
+        pattern: value
+            .get("pattern")
+            .and_then(|v| v.as_str())
+            .map(HatchPattern::parse_str)
+            .unwrap_or_default(),
+        angle: value
+            .get("angle")
+            .and_then(|v| v.as_f64())
+            .filter(|v| v.is_finite())
+            .unwrap_or(defaults.angle),
+        spacing: positive("spacing", defaults.spacing),
+        scale: positive("scale", defaults.scale),
+        line_width: positive("line_width", defaults.line_width),
+    }
+}
+
+impl<'de> Deserialize<'de> for HatchPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(HatchPattern::parse_str(&name))
+    }
+}
+
+impl<'de> Deserialize<'de> for HatchStyle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if !value.is_object() {
+            return Err(D::Error::custom("hatch must be an object"));
+        }
+        Ok(parse_hatch(&value))
+    }
+}
+
 /// Entity 스타일 (stroke + fill)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
@@ -214,10 +437,18 @@ impl Scene {
     /// # Arguments
     /// * `name` - Entity 이름
     /// * `fill_json` - FillStyle JSON
+    ///   (예: { "color": [0,0,0,1], "hatch": { "pattern": "Ansi31", "angle": 0, "spacing": 4, "scale": 1 } })
+    ///   그라디언트 (좌표는 Entity 로컬 좌표):
+    ///   { "gradient": { "type": "linear", "start": [0,0], "end": [100,0],
+    ///                   "stops": [{ "offset": 0, "color": [1,0,0,1] }, { "offset": 1, "color": [0,0,1,1] }] } }
+    ///   { "gradient": { "type": "radial", "center": [0,0], "radius": 50, "focal": [10,10], "stops": [...] } }
     ///
     /// # Returns
     /// * Ok(true) - 성공
     /// * Ok(false) - name 미발견
+    ///
+    /// # Errors
+    /// * JSON 파싱 실패, gradient 형식 오류 (type/좌표 누락, stop 없음)
     pub fn set_fill(&mut self, name: &str, fill_json: &str) -> Result<bool, JsValue> {
         let entity = match self.find_by_name_mut(name) {
             Some(e) => e,
@@ -233,7 +464,25 @@ impl Scene {
             .map(|arr| parse_rgba_color(arr, [0.0, 0.0, 0.0, 1.0]))
             .unwrap_or([0.0, 0.0, 0.0, 1.0]);
 
-        entity.style.fill = Some(FillStyle { color });
+        // hatch: 객체면 해치 채움, 없거나 null이면 단색
+        let hatch = json_value
+            .get("hatch")
+            .filter(|v| v.is_object())
+            .map(parse_hatch);
+
+        // gradient: 객체면 그라디언트 채움, 없거나 null이면 단색
+        let gradient = json_value
+            .get("gradient")
+            .filter(|v| v.is_object())
+            .map(parse_gradient)
+            .transpose()
+            .map_err(|msg| JsValue::from_str(&format!("[set_fill] invalid_input: {}", msg)))?;
+
+        entity.style.fill = Some(FillStyle {
+            color,
+            hatch,
+            gradient,
+        });
         Ok(true)
     }
 
@@ -345,6 +594,7 @@ mod tests {
     fn test_fill_style_json_serialization() {
         let fill = FillStyle {
             color: [1.0, 0.0, 0.0, 0.5], // 반투명 빨간색
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&fill).expect("serialization should succeed");
@@ -355,6 +605,132 @@ mod tests {
         assert_eq!(parsed.color, [1.0, 0.0, 0.0, 0.5]);
     }
 
+    #[test]
+    fn test_fill_style_hatch_json() {
+        // hatch 없으면 필드 생략 (기존 JSON과 호환)
+        let solid = serde_json::to_string(&FillStyle::default()).unwrap();
+        assert!(!solid.contains("hatch"));
+
+        let parsed: FillStyle =
+            serde_json::from_str(r#"{"color":[0,0,0,1],"hatch":{"pattern":"Brick","spacing":2}}"#)
+                .expect("deserialization should succeed");
+        let hatch = parsed.hatch.expect("hatch should be parsed");
+        assert_eq!(hatch.pattern, HatchPattern::Brick);
+        assert_eq!(hatch.spacing, 2.0);
+        assert_eq!(hatch.scale, 1.0);
+    }
+
+    #[test]
+    fn test_parse_hatch_lenient() {
+        let hatch = parse_hatch(&serde_json::json!({
+            "pattern": "crosshatch",
+            "spacing": -1.0,
+            "angle": 0.5
+        }));
+        assert_eq!(hatch.pattern, HatchPattern::Crosshatch);
+        assert_eq!(hatch.spacing, HatchStyle::default().spacing);
+        assert_eq!(hatch.angle, 0.5);
+    }
+
+    #[test]
+    fn test_style_json_hatch_uses_set_fill_rules() {
+        // style_json(serde)도 set_fill과 같이 대소문자 무시, 0 이하 값은 기본값
+        let style: Style = serde_json::from_str(
+            r#"{
+                "stroke": {"width": 2, "color": [1, 0, 0, 1]},
+                "fill": {"color": [0, 0, 1, 1],
+                         "hatch": {"pattern": "crosshatch", "spacing": -2, "line_width": 0}}
+            }"#,
+        )
+        .expect("lowercase hatch pattern should deserialize");
+        assert_eq!(style.stroke.as_ref().unwrap().width, 2.0);
+        let fill = style.fill.unwrap();
+        assert_eq!(fill.color, [0.0, 0.0, 1.0, 1.0]);
+        let hatch = fill.hatch.unwrap();
+        assert_eq!(hatch.pattern, HatchPattern::Crosshatch);
+        assert_eq!(hatch.spacing, HatchStyle::default().spacing);
+        assert_eq!(hatch.line_width, HatchStyle::default().line_width);
+
+        // 직렬화 결과는 그대로 다시 읽힘
+        let json = serde_json::to_string(&hatch).unwrap();
+        assert_eq!(serde_json::from_str::<HatchStyle>(&json).unwrap(), hatch);
+    }
+
+    #[test]
+    fn test_parse_gradient_linear_sorts_stops() {
+        let gradient = parse_gradient(&serde_json::json!({
+            "type": "Linear",
+            "start": [0.0, 0.0],
+            "end": [10.0, 0.0],
+            "stops": [
+                { "offset": 1.5, "color": [0, 0, 1, 1] },
+                { "offset": 0.0, "color": [1, 0, 0, 1] }
+            ]
+        }))
+        .expect("valid gradient");
+        let stops = gradient.stops();
+        assert_eq!(stops[0].offset, 0.0);
+        assert_eq!(stops[1].offset, 1.0);
+        assert_eq!(stops[1].color, [0.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_parse_gradient_errors() {
+        assert!(parse_gradient(&serde_json::json!({ "type": "linear", "stops": [] })).is_err());
+        let err = parse_gradient(&serde_json::json!({
+            "type": "radial",
+            "center": [0, 0],
+            "stops": [{ "offset": 0, "color": [0, 0, 0, 1] }]
+        }))
+        .unwrap_err();
+        assert!(err.contains("radius"));
+    }
+
+    #[test]
+    fn test_gradient_json_roundtrip() {
+        let fill = FillStyle {
+            gradient: Some(Gradient::Radial {
+                center: [1.0, 2.0],
+                radius: 3.0,
+                focal: None,
+                stops: vec![GradientStop {
+                    offset: 0.0,
+                    color: [1.0, 1.0, 1.0, 1.0],
+                }],
+            }),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&fill).unwrap();
+        assert!(json.contains(r#""type":"Radial""#));
+        let parsed: FillStyle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, fill);
+    }
+
+    #[test]
+    fn test_style_json_gradient_uses_set_fill_rules() {
+        // style_json(serde)도 set_fill과 같이 소문자 type, stop 클램핑/정렬
+        let style: Style = serde_json::from_str(
+            r#"{
+                "stroke": {"width": 2, "color": [1, 0, 0, 1]},
+                "fill": {"gradient": {"type": "linear", "start": [0, 0], "end": [10, 0],
+                         "stops": [{"offset": 2, "color": [0, 0, 1, 1]},
+                                   {"offset": 0, "color": [1, 1, 1, 1]}]}}
+            }"#,
+        )
+        .expect("lowercase gradient type should deserialize");
+        assert_eq!(style.stroke.as_ref().unwrap().width, 2.0);
+        let gradient = style.fill.unwrap().gradient.unwrap();
+        assert!(matches!(gradient, Gradient::Linear { .. }));
+        let offsets: Vec<f64> = gradient.stops().iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, [0.0, 1.0]);
+
+        let err = serde_json::from_str::<FillStyle>(
+            r#"{"gradient": {"type": "radial", "center": [0, 0], "stops": [{"offset": 0, "color": [0, 0, 0, 1]}]}}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("radius"));
+    }
+
     #[test]
     fn test_style_json_serialization() {
         // AC5: 전체 Style JSON 직렬화
@@ -368,6 +744,7 @@ mod tests {
             }),
             fill: Some(FillStyle {
                 color: [1.0, 0.0, 0.0, 0.5],
+                ..Default::default()
             }),
         };
 
@@ -0,0 +1,403 @@
+//! 텍스트 Entity 모듈
+//!
+//! 라벨, 방 이름, 부품 번호 등을 Scene에 배치합니다.
+//! - 바운드는 내장 폰트 메트릭(geometry/text.rs)으로 계산하므로 get_world_bounds, z-order가 그대로 동작
+//! - SVG는 기본 `<text>`로, `export_svg_with_text_outlines`는 단선 폰트 path로 출력
+//!   (단선 폰트에는 소문자 글리프가 없어 소문자는 x-height 크기의 대문자(small caps)로 그려지므로
+//!   `<text>` 렌더링과 글자 모양이 다름, 폭/바운드는 동일)
+
+use wasm_bindgen::prelude::*;
+
+use super::entity::{EntityType, FillStyle, Geometry, Style, TextAlign, TextAnchor};
+use super::primitives::parse_style_with_warning;
+use super::{Scene, SceneError};
+
+/// font_family 미지정 시 기본값
+pub const DEFAULT_FONT_FAMILY: &str = "sans-serif";
+
+/// 텍스트 기본 스타일: 검은색 fill, stroke 없음
+fn default_text_style() -> Style {
+    Style {
+        stroke: None,
+        fill: Some(FillStyle::default()),
+    }
+}
+
+/// draw_text 스타일 JSON 파싱
+///
+/// Style의 serde 기본값은 검은색 1px stroke이므로, JSON에 "stroke"가 없으면 텍스트 기본값(stroke 없음)을
+/// 유지합니다. stroke/fill이 모두 없으면(파싱 실패 등) 기본 fill을 사용합니다.
+fn parse_text_style(style_json: &str) -> Style {
+    let mut style = parse_style_with_warning(style_json, "draw_text");
+    let has_stroke = serde_json::from_str::<serde_json::Value>(style_json)
+        .is_ok_and(|value| value.get("stroke").is_some());
+    if !has_stroke {
+        style.stroke = None;
+    }
+    if style.stroke.is_none() && style.fill.is_none() {
+        style.fill = default_text_style().fill;
+    }
+    style
+}
+
+impl Scene {
+    /// 내부용 Text 생성 함수 (테스트용)
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "label_kitchen") - Scene 내 unique
+    /// * `x`, `y` - 기준점 좌표 (anchor/align이 가리키는 위치)
+    /// * `content` - 내용 ('\n'으로 줄바꿈, 빈 문자열 불가)
+    /// * `font_size` - 글자 크기 (양수)
+    /// * `font_family` - 폰트 (빈 문자열이면 sans-serif)
+    /// * `anchor` - 가로 기준 (Start/Middle/End)
+    /// * `align` - 세로 기준 (Baseline/Top/Middle/Bottom)
+    ///
+    /// # Errors
+    /// * NaN/Infinity 입력, 0 이하 font_size, 빈 content
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn add_text_internal(
+        &mut self,
+        name: &str,
+        x: f64,
+        y: f64,
+        content: &str,
+        font_size: f64,
+        font_family: &str,
+        anchor: TextAnchor,
+        align: TextAlign,
+    ) -> Result<String, SceneError> {
+        self.add_text_with_fn(
+            "add_text",
+            name,
+            x,
+            y,
+            content,
+            font_size,
+            font_family,
+            anchor,
+            align,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_text_with_fn(
+        &mut self,
+        fn_name: &str,
+        name: &str,
+        x: f64,
+        y: f64,
+        content: &str,
+        font_size: f64,
+        font_family: &str,
+        anchor: TextAnchor,
+        align: TextAlign,
+    ) -> Result<String, SceneError> {
+        if !x.is_finite() || !y.is_finite() || !font_size.is_finite() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: NaN or Infinity not allowed",
+                fn_name
+            )));
+        }
+        if font_size <= 0.0 {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: font_size must be positive",
+                fn_name
+            )));
+        }
+        if content.is_empty() {
+            return Err(SceneError::InvalidInput(format!(
+                "[{}] invalid_input: content must not be empty",
+                fn_name
+            )));
+        }
+
+        let font_family = if font_family.trim().is_empty() {
+            DEFAULT_FONT_FAMILY
+        } else {
+            font_family
+        };
+
+        self.add_entity_internal(
+            fn_name,
+            name,
+            EntityType::Text,
+            Geometry::Text {
+                position: [x, y],
+                content: content.to_string(),
+                font_family: font_family.to_string(),
+                font_size,
+                anchor,
+                align,
+            },
+        )?;
+
+        // 텍스트는 기준점을 중심으로 회전/스케일
+        if let Some(entity) = self.find_by_name_mut(name) {
+            entity.transform.pivot = [x, y];
+            entity.style = default_text_style();
+        }
+        Ok(name.to_string())
+    }
+}
+
+// ========================================
+// WASM Bindings for Text
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 텍스트(Text)를 기본 옵션으로 생성합니다 (sans-serif, start/baseline, 검은색 fill).
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "label_kitchen") - Scene 내 unique
+    /// * `x`, `y` - 기준점 좌표 (첫 줄 baseline의 왼쪽 끝)
+    /// * `content` - 내용 ('\n'으로 줄바꿈)
+    /// * `font_size` - 글자 크기 (양수)
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력, 0 이하 font_size, 빈 content 시 에러
+    pub fn add_text(
+        &mut self,
+        name: &str,
+        x: f64,
+        y: f64,
+        content: &str,
+        font_size: f64,
+    ) -> Result<String, JsValue> {
+        self.add_text_internal(
+            name,
+            x,
+            y,
+            content,
+            font_size,
+            DEFAULT_FONT_FAMILY,
+            TextAnchor::Start,
+            TextAlign::Baseline,
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+
+    /// 텍스트(Text)를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 이름 (예: "label_kitchen") - Scene 내 unique
+    /// * `x`, `y` - 기준점 좌표
+    /// * `content` - 내용 ('\n'으로 줄바꿈)
+    /// * `font_size` - 글자 크기 (양수)
+    /// * `font_family` - 폰트 (예: "sans-serif", "monospace"; 빈 문자열이면 sans-serif)
+    /// * `anchor` - 가로 기준: "start" | "middle" | "end" (그 외 → start)
+    /// * `align` - 세로 기준: "baseline" | "top" | "middle" | "bottom" (그 외 → baseline)
+    /// * `style_json` - 스타일 JSON (빈 문자열이면 검은색 fill, "stroke"를 지정하지 않으면 stroke 없음)
+    ///
+    /// # Returns
+    /// * Ok(name) - 성공 시 name 반환
+    ///
+    /// # Errors
+    /// * name 중복 시 에러
+    /// * NaN 또는 Infinity 입력, 0 이하 font_size, 빈 content 시 에러
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text(
+        &mut self,
+        name: &str,
+        x: f64,
+        y: f64,
+        content: &str,
+        font_size: f64,
+        font_family: &str,
+        anchor: &str,
+        align: &str,
+        style_json: &str,
+    ) -> Result<String, JsValue> {
+        self.add_text_with_fn(
+            "draw_text",
+            name,
+            x,
+            y,
+            content,
+            font_size,
+            font_family,
+            TextAnchor::parse_str(anchor),
+            TextAlign::parse_str(align),
+        )
+        .map_err(|err| JsValue::from_str(&err.to_string()))?;
+
+        if !style_json.trim().is_empty() {
+            let style = parse_text_style(style_json);
+            if let Some(entity) = self.find_by_name_mut(name) {
+                entity.style = style;
+            }
+        }
+
+        self.last_operation = Some(format!("draw_text({})", name));
+        Ok(name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_add_text_basic() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_text_internal(
+                "label",
+                10.0,
+                20.0,
+                "Kitchen",
+                12.0,
+                "",
+                TextAnchor::Middle,
+                TextAlign::Middle,
+            )
+            .unwrap();
+
+        let entity = scene.find_by_name("label").unwrap();
+        assert!(matches!(entity.entity_type, EntityType::Text));
+        assert_eq!(entity.transform.pivot, [10.0, 20.0]);
+        assert!(entity.style.stroke.is_none());
+        assert!(entity.style.fill.is_some());
+        match &entity.geometry {
+            Geometry::Text { font_family, .. } => assert_eq!(font_family, DEFAULT_FONT_FAMILY),
+            _ => panic!("Expected Text geometry"),
+        }
+    }
+
+    #[test]
+    fn test_text_world_bounds() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_text_internal(
+                "t",
+                0.0,
+                0.0,
+                "AA",
+                10.0,
+                "sans-serif",
+                TextAnchor::Middle,
+                TextAlign::Baseline,
+            )
+            .unwrap();
+        scene.find_by_name_mut("t").unwrap().transform.translate = [100.0, 0.0];
+
+        let (min, max) = scene.get_world_bounds_internal("t").unwrap();
+        assert!(approx_eq(min[0], 100.0 - 6.67, 1e-10));
+        assert!(approx_eq(max[0], 100.0 + 6.67, 1e-10));
+        assert!(approx_eq(min[1], -2.07, 1e-10));
+        assert!(approx_eq(max[1], 7.18, 1e-10));
+    }
+
+    #[test]
+    fn test_add_text_invalid_input() {
+        let mut scene = Scene::new("test");
+        let err = scene
+            .add_text_internal(
+                "t",
+                0.0,
+                0.0,
+                "",
+                10.0,
+                "",
+                TextAnchor::Start,
+                TextAlign::Baseline,
+            )
+            .expect_err("empty content should error");
+        assert_eq!(
+            err.to_string(),
+            "[add_text] invalid_input: content must not be empty"
+        );
+
+        scene
+            .add_text_internal(
+                "t",
+                f64::NAN,
+                0.0,
+                "A",
+                10.0,
+                "",
+                TextAnchor::Start,
+                TextAlign::Baseline,
+            )
+            .expect_err("NaN should error");
+
+        for font_size in [0.0, -5.0] {
+            let err = scene
+                .add_text_internal(
+                    "t",
+                    0.0,
+                    0.0,
+                    "A",
+                    font_size,
+                    "",
+                    TextAnchor::Start,
+                    TextAlign::Baseline,
+                )
+                .expect_err("non-positive font_size should error");
+            assert_eq!(
+                err.to_string(),
+                "[add_text] invalid_input: font_size must be positive"
+            );
+        }
+    }
+
+    #[test]
+    fn test_text_style_is_fill_only_by_default() {
+        let style = parse_text_style(r#"{"fill": {"color": [1, 0, 0, 1]}}"#);
+        assert!(style.stroke.is_none());
+        assert_eq!(style.fill.unwrap().color, [1.0, 0.0, 0.0, 1.0]);
+
+        let style = parse_text_style(r#"{"stroke": {"width": 0.5, "color": [0, 0, 1, 1]}}"#);
+        assert_eq!(style.stroke.unwrap().width, 0.5);
+        assert!(style.fill.is_none());
+
+        assert_eq!(parse_text_style("{}"), default_text_style());
+
+        let mut scene = Scene::new("test");
+        scene
+            .add_text_internal(
+                "t",
+                0.0,
+                0.0,
+                "A",
+                10.0,
+                "",
+                TextAnchor::Start,
+                TextAlign::Baseline,
+            )
+            .unwrap();
+        let svg = scene.export_svg();
+        assert!(svg.contains(r#"stroke="none""#));
+        assert!(!svg.contains(r#"stroke="black""#));
+    }
+
+    #[test]
+    fn test_text_svg_export() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_text_internal(
+                "t",
+                5.0,
+                5.0,
+                "A<B",
+                10.0,
+                "",
+                TextAnchor::Start,
+                TextAlign::Baseline,
+            )
+            .unwrap();
+
+        let svg = scene.export_svg();
+        assert!(svg.contains("<text"));
+        assert!(svg.contains("A&lt;B"));
+
+        let outlined = scene.export_svg_with_text_outlines();
+        assert!(!outlined.contains("<text"));
+        assert!(outlined.contains("<path"));
+    }
+}
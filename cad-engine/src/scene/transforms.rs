@@ -1,7 +1,9 @@
 /// Transform 관리 모듈
 ///
-/// 엔티티의 변환(translate, rotate, scale) 관련 기능을 제공합니다.
-use super::Scene;
+/// 엔티티의 변환(translate, rotate, scale, skew, mirror) 관련 기능을 제공합니다.
+use super::entity::{Matrix3x3, Transform};
+use super::{Scene, SceneError};
+use std::f64::consts::FRAC_PI_2;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -88,12 +90,10 @@ impl Scene {
 
     /// Entity를 월드 좌표 기준으로 이동합니다.
     ///
-    /// 부모 그룹의 scale을 역산하여 로컬 delta로 변환 후 적용합니다.
+    /// 부모 그룹의 월드 변환(회전·스케일)을 역산하여 로컬 delta로 변환 후 적용합니다.
     pub fn translate_world(&mut self, name: &str, dx: f64, dy: f64) -> Result<bool, JsValue> {
-        let parent_scale = self.get_parent_world_scale(name);
-        let local_dx = dx / parent_scale[0];
-        let local_dy = dy / parent_scale[1];
-        self.translate(name, local_dx, local_dy)
+        self.translate_world_internal(name, dx, dy)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
     /// Entity를 월드 좌표 기준으로 스케일합니다.
@@ -106,9 +106,59 @@ impl Scene {
         self.scale(name, local_sx, local_sy)
     }
 
+    /// Entity를 로컬 축 기준으로 기울입니다 (pivot 중심).
+    ///
+    /// x' = x + tan(ax)·y, y' = y + tan(ay)·x 를 기존 변환보다 먼저 적용합니다 (SVG skewX/skewY).
+    ///
+    /// # Arguments
+    /// * `name` - 대상 Entity의 이름
+    /// * `ax` - x축 방향 기울임 각도 (라디안, |ax| < π/2)
+    /// * `ay` - y축 방향 기울임 각도 (라디안, |ay| < π/2)
+    ///
+    /// # Returns
+    /// * Ok(true) - 성공
+    /// * Ok(false) - name 미발견 (no-op)
+    ///
+    /// # Errors
+    /// * NaN/Infinity, |각도| >= π/2, 도형이 선으로 찌그러지는 조합 (tan(ax)·tan(ay) = 1)
+    pub fn skew(&mut self, name: &str, ax: f64, ay: f64) -> Result<bool, JsValue> {
+        self.skew_internal(name, ax, ay)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    /// Entity를 월드 좌표의 직선 (x1, y1)-(x2, y2)에 대해 대칭 이동합니다.
+    ///
+    /// Group이면 하위 트리 전체가 뒤집힙니다. 결과 변환은 scale.y 부호로 뒤집힘을 표현합니다.
+    ///
+    /// # Arguments
+    /// * `name` - 대상 Entity/Group 이름
+    /// * `x1`, `y1`, `x2`, `y2` - 대칭축 위의 두 점 (월드 좌표)
+    /// * `copy_name` - "" = 제자리에서 뒤집기, 이름 지정 = 원본은 두고 그 이름의 대칭 복사본 생성
+    ///   (자식 이름 규칙은 duplicate와 같음)
+    ///
+    /// # Returns
+    /// 뒤집힌 Entity 이름 (제자리면 name, 복사면 copy_name)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 두 점이 같음, NaN/Infinity, copy_name 중복, 부모 그룹 변환이 퇴화
+    #[allow(clippy::too_many_arguments)]
+    pub fn mirror(
+        &mut self,
+        name: &str,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        copy_name: &str,
+    ) -> Result<String, JsValue> {
+        let copy_name = (!copy_name.is_empty()).then_some(copy_name);
+        self.mirror_internal(name, [x1, y1], [x2, y2], copy_name)
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
     /// Entity를 삭제합니다.
     ///
-    /// Group 삭제 시 자식들의 parent_id를 정리하고,
+    /// Group 삭제 시 자식들의 부모 링크를 정리하고,
     /// 부모가 있는 경우 부모의 children 목록에서 제거합니다.
     ///
     /// # Arguments
@@ -118,40 +168,30 @@ impl Scene {
     /// * Ok(true) - 삭제 성공
     /// * Ok(false) - name 미발견 (no-op)
     pub fn delete(&mut self, name: &str) -> Result<bool, JsValue> {
-        let idx = self.entities.iter().position(|e| e.metadata.name == name);
-
-        match idx {
-            Some(i) => {
-                // 삭제 전에 parent_id와 children 정보 저장
-                let parent_id = self.entities[i].parent_id.clone();
-                let children = self.entities[i].children.clone();
-
-                // 1. 자식들의 parent_id를 None으로 설정 (고아가 됨)
-                for child_name in &children {
-                    if let Some(child) = self.find_by_name_mut(child_name) {
-                        child.parent_id = None;
-                    }
-                }
-
-                // 2. 부모의 children 목록에서 자신 제거
-                if let Some(ref parent_name) = parent_id
-                    && let Some(parent) = self.find_by_name_mut(parent_name)
-                {
-                    parent.children.retain(|c| c != name);
-                }
-
-                // 3. 엔티티 삭제 (인덱스로 다시 찾아야 함 - 위에서 borrow 해제됨)
-                if let Some(idx) = self.entities.iter().position(|e| e.metadata.name == name) {
-                    self.entities.remove(idx);
-                    self.last_operation = Some(format!("delete({})", name));
-                    Ok(true)
-                } else {
-                    // 방어적 처리: 논리적으로 도달하지 않아야 하나, 안전하게 false 반환
-                    Ok(false)
-                }
+        let Some(handle) = self.handle_of(name) else {
+            return Ok(false);
+        };
+        let Some(removed) = self.remove_entity(handle) else {
+            // 방어적 처리: 논리적으로 도달하지 않아야 하나, 안전하게 false 반환
+            return Ok(false);
+        };
+
+        // 1. 자식들의 부모를 해제 (고아가 됨)
+        for child in &removed.children {
+            if let Some(child_entity) = self.entity_at_mut(*child) {
+                child_entity.parent = None;
             }
-            None => Ok(false),
         }
+
+        // 2. 부모의 children 목록에서 자신 제거
+        if let Some(parent) = removed.parent
+            && let Some(parent_entity) = self.entity_at_mut(parent)
+        {
+            parent_entity.children.retain(|c| *c != handle);
+        }
+
+        self.last_operation = Some(format!("delete({})", name));
+        Ok(true)
     }
 
     /// Entity의 회전/스케일 중심점(pivot)을 설정합니다.
@@ -169,6 +209,29 @@ impl Scene {
             .map_err(|e| JsValue::from_str(&e.to_string()))
     }
 
+    /// 월드 이동량을 부모 좌표계의 translate 변화량으로 변환합니다.
+    ///
+    /// 부모 월드 행렬의 선형 부분을 역변환하므로 회전된 그룹 안에서도 정확합니다.
+    /// 역행렬이 없으면(퇴화 스케일) 부모 scale만 역산합니다.
+    fn world_delta_to_local(&self, name: &str, dx: f64, dy: f64) -> [f64; 2] {
+        let parent_matrix = self
+            .find_by_name(name)
+            .and_then(|entity| self.parent_of(entity))
+            .and_then(|parent| self.get_world_transform_internal(&parent.metadata.name));
+        let Some(m) = parent_matrix else {
+            return [dx, dy];
+        };
+        let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+        if det.abs() < 1e-12 {
+            let parent_scale = self.get_parent_world_scale(name);
+            return [dx / parent_scale[0], dy / parent_scale[1]];
+        }
+        [
+            (m[1][1] * dx - m[0][1] * dy) / det,
+            (m[0][0] * dy - m[1][0] * dx) / det,
+        ]
+    }
+
     /// 부모의 누적 world scale을 반환합니다.
     fn get_parent_world_scale(&self, name: &str) -> [f64; 2] {
         // Find entity and its parent
@@ -177,13 +240,13 @@ impl Scene {
             None => return [1.0, 1.0],
         };
 
-        let parent_id = match &entity.parent_id {
-            Some(id) => id.clone(),
+        let parent = match self.parent_of(entity) {
+            Some(parent) => parent,
             None => return [1.0, 1.0], // Root entity
         };
 
         // Get parent's world transform
-        if let Some(matrix) = self.get_world_transform_internal(&parent_id) {
+        if let Some(matrix) = self.get_world_transform_internal(&parent.metadata.name) {
             // Extract scale from matrix
             // For a 2D affine matrix [[a, b, tx], [c, d, ty], [0, 0, 1]]
             // scale_x = sqrt(a^2 + c^2), scale_y = sqrt(b^2 + d^2)
@@ -196,9 +259,188 @@ impl Scene {
     }
 }
 
+impl Scene {
+    /// 월드 이동량을 로컬 translate 변화량으로 변환하고 유한한지 검사합니다.
+    fn checked_world_delta_to_local(
+        &self,
+        name: &str,
+        dx: f64,
+        dy: f64,
+    ) -> Result<[f64; 2], SceneError> {
+        if !dx.is_finite() || !dy.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[translate_world] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let local = self.world_delta_to_local(name, dx, dy);
+        if !local.iter().all(|v| v.is_finite()) {
+            return Err(SceneError::InvalidOperation(format!(
+                "[translate_world] parent transform of '{}' cannot be inverted",
+                name
+            )));
+        }
+        Ok(local)
+    }
+
+    /// 월드 기준 이동 (내부용, translate_world 참고)
+    ///
+    /// # Errors
+    /// * NaN/Infinity, 부모 변환을 역산할 수 없는 경우
+    pub(crate) fn translate_world_internal(
+        &mut self,
+        name: &str,
+        dx: f64,
+        dy: f64,
+    ) -> Result<bool, SceneError> {
+        let [local_dx, local_dy] = self.checked_world_delta_to_local(name, dx, dy)?;
+        let Some(entity) = self.find_by_name_mut(name) else {
+            return Ok(false);
+        };
+        entity.transform.translate[0] += local_dx;
+        entity.transform.translate[1] += local_dy;
+
+        self.last_operation = Some(format!("translate_world({}, {}, {})", name, dx, dy));
+        Ok(true)
+    }
+
+    /// 복제 전에 월드 오프셋을 적용할 수 있는지 검사합니다 (복제본은 원본과 같은 부모).
+    pub(crate) fn check_translate_world(
+        &self,
+        name: &str,
+        dx: f64,
+        dy: f64,
+    ) -> Result<(), SceneError> {
+        self.checked_world_delta_to_local(name, dx, dy).map(|_| ())
+    }
+
+    /// 로컬 행렬을 Transform으로 분해하여 설정합니다 (pivot 유지).
+    fn set_local_matrix(&mut self, name: &str, m: &Matrix3x3) -> bool {
+        let Some(entity) = self.find_by_name_mut(name) else {
+            return false;
+        };
+        entity.transform = Transform::from_matrix_with_pivot(m, entity.transform.pivot);
+        true
+    }
+
+    /// 기울임 (내부용, skew 참고)
+    pub(crate) fn skew_internal(
+        &mut self,
+        name: &str,
+        ax: f64,
+        ay: f64,
+    ) -> Result<bool, SceneError> {
+        if !ax.is_finite() || !ay.is_finite() {
+            return Err(SceneError::InvalidInput(
+                "[skew] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        if ax.abs() >= FRAC_PI_2 || ay.abs() >= FRAC_PI_2 {
+            return Err(SceneError::InvalidInput(
+                "[skew] invalid_input: skew angles must be within (-π/2, π/2)".to_string(),
+            ));
+        }
+        let (kx, ky) = (ax.tan(), ay.tan());
+        if (1.0 - kx * ky).abs() < 1e-10 {
+            return Err(SceneError::InvalidInput(
+                "[skew] invalid_input: skew angles collapse the shape to a line".to_string(),
+            ));
+        }
+        let Some(entity) = self.find_by_name(name) else {
+            return Ok(false);
+        };
+
+        // 로컬 = 기존 로컬 · T(pv) · H · T(-pv)
+        let [px, py] = entity.transform.pivot;
+        let shear = [[1.0, kx, -kx * py], [ky, 1.0, -ky * px], [0.0, 0.0, 1.0]];
+        let local = Transform::multiply_matrices(&entity.transform.to_matrix(), &shear);
+        self.set_local_matrix(name, &local);
+
+        self.last_operation = Some(format!(
+            "skew({}, {:.1}°, {:.1}°)",
+            name,
+            ax.to_degrees(),
+            ay.to_degrees()
+        ));
+        Ok(true)
+    }
+
+    /// 월드 직선 대칭 (내부용, mirror 참고)
+    ///
+    /// 새 로컬 = 부모⁻¹ · 반사 · 부모 · 기존 로컬 → 부모 그룹이 회전/비균등 스케일이어도 월드에서 정확한 대칭
+    pub(crate) fn mirror_internal(
+        &mut self,
+        name: &str,
+        from: [f64; 2],
+        to: [f64; 2],
+        copy_name: Option<&str>,
+    ) -> Result<String, SceneError> {
+        if from.iter().chain(&to).any(|v| !v.is_finite()) {
+            return Err(SceneError::InvalidInput(
+                "[mirror] invalid_input: NaN or Infinity not allowed".to_string(),
+            ));
+        }
+        let direction = [to[0] - from[0], to[1] - from[1]];
+        let length = direction[0].hypot(direction[1]);
+        if length < 1e-12 {
+            return Err(SceneError::InvalidInput(
+                "[mirror] invalid_input: mirror line points must be distinct".to_string(),
+            ));
+        }
+        let Some(entity) = self.find_by_name(name) else {
+            return Err(SceneError::EntityNotFound(
+                "mirror".to_string(),
+                name.to_string(),
+            ));
+        };
+
+        // 축 방향 u에 대한 반사: [[ux² - uy², 2uxuy], [2uxuy, uy² - ux²]], 축 위의 점은 고정
+        let [ux, uy] = [direction[0] / length, direction[1] / length];
+        let (a, b, d) = (ux * ux - uy * uy, 2.0 * ux * uy, uy * uy - ux * ux);
+        let reflection = [
+            [a, b, from[0] - (a * from[0] + b * from[1])],
+            [b, d, from[1] - (b * from[0] + d * from[1])],
+            [0.0, 0.0, 1.0],
+        ];
+        let parent = self
+            .parent_of(entity)
+            .and_then(|p| self.get_world_transform_internal(&p.metadata.name))
+            .unwrap_or_else(Transform::identity_matrix);
+        let parent_inverse = Transform::inverse_matrix(&parent).ok_or_else(|| {
+            SceneError::InvalidOperation(
+                "[mirror] invalid_operation: parent group transform is not invertible".to_string(),
+            )
+        })?;
+        let local_reflection = Transform::multiply_matrices(
+            &parent_inverse,
+            &Transform::multiply_matrices(&reflection, &parent),
+        );
+
+        let target = match copy_name {
+            Some(copy_name) => self.duplicate_internal(name, copy_name, 0.0, 0.0)?.name,
+            None => name.to_string(),
+        };
+        if let Some(entity) = self.find_by_name(&target) {
+            let local =
+                Transform::multiply_matrices(&local_reflection, &entity.transform.to_matrix());
+            self.set_local_matrix(&target, &local);
+        }
+
+        self.last_operation = Some(format!(
+            "mirror({}, [{}, {}] - [{}, {}]{})",
+            name,
+            from[0],
+            from[1],
+            to[0],
+            to[1],
+            copy_name.map_or(String::new(), |c| format!(", copy: {}", c))
+        ));
+        Ok(target)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Scene;
+    use super::{Scene, SceneError};
 
     // ========================================
     // Story 4-4: set_pivot Tests
@@ -365,4 +607,139 @@ mod tests {
         assert!(result.is_ok());
         assert!(!result.unwrap()); // false = not found
     }
+
+    // ========================================
+    // skew / mirror Tests
+    // ========================================
+
+    fn world_point(scene: &Scene, name: &str, local: [f64; 2]) -> [f64; 2] {
+        let m = scene.get_world_transform_internal(name).unwrap();
+        super::Transform::transform_point(&m, local)
+    }
+
+    fn assert_point(actual: [f64; 2], expected: [f64; 2]) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 1e-9 && (actual[1] - expected[1]).abs() < 1e-9,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_skew_about_pivot() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![0.0, 0.0, 0.0, 2.0])
+            .unwrap();
+        scene.set_pivot_internal("l", 0.0, 0.0).unwrap();
+        scene.rotate("l", std::f64::consts::FRAC_PI_2).unwrap();
+
+        // 로컬 skewX 45° 후 회전 90°: (0, 2) → (2, 2) → (-2, 2)
+        assert!(
+            scene
+                .skew_internal("l", std::f64::consts::FRAC_PI_4, 0.0)
+                .unwrap()
+        );
+        assert_point(world_point(&scene, "l", [0.0, 2.0]), [-2.0, 2.0]);
+        assert_point(world_point(&scene, "l", [0.0, 0.0]), [0.0, 0.0]);
+        assert!(scene.find_by_name("l").unwrap().transform.skew != 0.0);
+
+        assert!(!scene.skew_internal("missing", 0.1, 0.0).unwrap());
+        assert!(
+            scene
+                .skew_internal("l", std::f64::consts::FRAC_PI_2, 0.0)
+                .is_err()
+        );
+        let k = std::f64::consts::FRAC_PI_4;
+        assert!(scene.skew_internal("l", k, k).is_err());
+    }
+
+    #[test]
+    fn test_mirror_in_place_and_copy() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![1.0, 0.0, 3.0, 1.0])
+            .unwrap();
+
+        // y = x 대칭: (x, y) → (y, x)
+        let name = scene
+            .mirror_internal("l", [0.0, 0.0], [1.0, 1.0], None)
+            .unwrap();
+        assert_eq!(name, "l");
+        assert_point(world_point(&scene, "l", [1.0, 0.0]), [0.0, 1.0]);
+        assert_point(world_point(&scene, "l", [3.0, 1.0]), [1.0, 3.0]);
+        let transform = &scene.find_by_name("l").unwrap().transform;
+        assert!(transform.scale[0] * transform.scale[1] < 0.0);
+
+        // 다시 뒤집으면 원래대로
+        scene
+            .mirror_internal("l", [0.0, 0.0], [2.0, 2.0], None)
+            .unwrap();
+        assert_point(world_point(&scene, "l", [3.0, 1.0]), [3.0, 1.0]);
+
+        // 수직선 x = 5 대칭 복사본
+        let name = scene
+            .mirror_internal("l", [5.0, 0.0], [5.0, 1.0], Some("l_mirror"))
+            .unwrap();
+        assert_eq!(name, "l_mirror");
+        assert_point(world_point(&scene, "l_mirror", [3.0, 1.0]), [7.0, 1.0]);
+        assert_point(world_point(&scene, "l", [3.0, 1.0]), [3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_mirror_inside_rotated_nonuniform_group_and_reparent() {
+        let mut scene = Scene::new("test");
+        scene
+            .add_line_internal("l", vec![1.0, 0.0, 3.0, 1.0])
+            .unwrap();
+        scene.rotate("l", 0.4).unwrap();
+        scene
+            .create_group_internal("g", vec!["l".to_string()])
+            .unwrap();
+        scene.scale("g", 3.0, 1.0).unwrap();
+        scene.rotate("g", 0.5).unwrap();
+
+        let p = world_point(&scene, "l", [3.0, 1.0]);
+        scene
+            .mirror_internal("l", [0.0, -1.0], [1.0, -1.0], None)
+            .unwrap();
+        // y = -1 대칭
+        assert_point(world_point(&scene, "l", [3.0, 1.0]), [p[0], -2.0 - p[1]]);
+
+        // 기울어진 결과를 다른 그룹으로 옮겨도 월드 위치 유지 (skew 포함 분해)
+        let before = [
+            world_point(&scene, "l", [1.0, 0.0]),
+            world_point(&scene, "l", [3.0, 1.0]),
+        ];
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        scene
+            .create_group_internal("h", vec!["c".to_string()])
+            .unwrap();
+        scene.rotate("h", -0.3).unwrap();
+        scene.scale("h", 1.0, 2.0).unwrap();
+        scene.add_to_group_internal("h", "l").unwrap();
+        assert_point(world_point(&scene, "l", [1.0, 0.0]), before[0]);
+        assert_point(world_point(&scene, "l", [3.0, 1.0]), before[1]);
+    }
+
+    #[test]
+    fn test_mirror_errors() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 1.0).unwrap();
+        scene.add_circle_internal("d", 0.0, 0.0, 1.0).unwrap();
+
+        assert!(matches!(
+            scene.mirror_internal("missing", [0.0, 0.0], [1.0, 0.0], None),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(matches!(
+            scene.mirror_internal("c", [1.0, 1.0], [1.0, 1.0], None),
+            Err(SceneError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            scene.mirror_internal("c", [0.0, 0.0], [1.0, 0.0], Some("d")),
+            Err(SceneError::DuplicateEntityName(..))
+        ));
+    }
 }
@@ -0,0 +1,229 @@
+//! 채우기 삼각분할 모듈
+//!
+//! 닫힌 도형(구멍 있는 Polygon, 닫힌 Bezier/Path 포함)의 채움 영역을 월드 좌표 인덱스 삼각형
+//! 메시로 반환합니다. 뷰어가 SVG를 다시 파싱하지 않고 WebGL로 그릴 수 있고,
+//! 면적/무게중심도 같은 메시에서 계산합니다.
+//! - 외곽선: flatten_entity와 같은 허용 오차 근사
+//! - 링 정규화: even-odd 해석(nonzero Path는 winding 기준) + 자기교차 해소 (boolean union),
+//!   Group은 하위 도형의 합집합
+//! - 분할: geometry/triangulate.rs (ear clipping)
+
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use super::entity::{FillRule, Geometry};
+use super::{Scene, SceneError};
+use crate::geometry::boolean::{BooleanOp, boolean_fold, nonzero_union};
+use crate::geometry::polygon::Region;
+use crate::geometry::triangulate::{TriangleMesh, triangulate_polygons};
+
+impl Scene {
+    /// Entity의 채움 영역을 월드 좌표 삼각형 메시로 분할합니다 (내부용).
+    ///
+    /// # Arguments
+    /// * `name` - Entity 또는 Group 이름
+    /// * `tolerance` - 곡선 근사 허용 오차 (양수, 월드 단위)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 양수가 아닌 tolerance, 닫힌 영역이 없는 도형
+    pub(crate) fn triangulate_internal(
+        &self,
+        name: &str,
+        tolerance: f64,
+    ) -> Result<TriangleMesh, SceneError> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(SceneError::InvalidInput(
+                "[triangulate] invalid_input: tolerance must be a positive finite number"
+                    .to_string(),
+            ));
+        }
+        if !self.has_entity(name) {
+            return Err(SceneError::EntityNotFound(
+                "triangulate".to_string(),
+                name.to_string(),
+            ));
+        }
+
+        // Entity별 닫힌 링 묶음 (첫 점 = 끝 점인 열린 폴리라인도 닫힌 링으로 취급)
+        let mut shapes: Vec<(String, Region)> = Vec::new();
+        for polyline in self.flatten_entity_internal(name, tolerance)? {
+            let mut points = polyline.points;
+            let closes_itself = points.len() >= 4 && points.first() == points.last();
+            if closes_itself {
+                points.pop();
+            }
+            if !(polyline.closed || closes_itself) || points.len() < 3 {
+                continue;
+            }
+            match shapes.last_mut() {
+                Some((owner, region)) if *owner == polyline.name => region.rings.push(points),
+                _ => shapes.push((
+                    polyline.name,
+                    Region {
+                        rings: vec![points],
+                    },
+                )),
+            }
+        }
+        if shapes.is_empty() {
+            let entity_type = self
+                .find_by_name(name)
+                .map(|e| e.entity_type.as_str())
+                .unwrap_or_default();
+            return Err(SceneError::InvalidInput(format!(
+                "[triangulate] invalid_input: Entity '{}' ({}) has no closed area",
+                name, entity_type
+            )));
+        }
+
+        // nonzero Path는 같은 방향으로 겹친 링도 채우므로 winding 기준으로 먼저 정규화
+        let shapes: Vec<Vec<Region>> = shapes
+            .into_iter()
+            .map(|(owner, region)| {
+                let nonzero = self.find_by_name(&owner).is_some_and(|e| {
+                    matches!(
+                        e.geometry,
+                        Geometry::Path {
+                            fill_rule: FillRule::NonZero,
+                            ..
+                        }
+                    )
+                });
+                if nonzero {
+                    nonzero_union(&region.rings)
+                        .iter()
+                        .map(|poly| poly.to_region())
+                        .collect()
+                } else {
+                    vec![region]
+                }
+            })
+            .collect();
+        Ok(triangulate_polygons(&boolean_fold(
+            &shapes,
+            BooleanOp::Union,
+        )))
+    }
+}
+
+// ========================================
+// WASM Bindings for Triangulation
+// ========================================
+
+#[wasm_bindgen]
+impl Scene {
+    /// 닫힌 도형의 채움 영역을 월드 좌표 인덱스 삼각형 메시로 반환합니다.
+    ///
+    /// # Arguments
+    /// * `name` - Entity 또는 Group 이름 (Group은 하위 도형의 합집합)
+    /// * `tolerance` - 곡선 근사 허용 오차 (양수, 월드 단위, 예: 0.01)
+    ///
+    /// # Returns
+    /// JSON: `{"vertices": [x0, y0, x1, y1, ...], "indices": [i0, i1, i2, ...],
+    /// "area": number, "centroid": [x, y] | null}`
+    /// - `vertices`는 Float32Array, `indices`는 Uint32Array로 바로 업로드 가능한 평탄 배열
+    /// - 삼각형은 모두 CCW, 구멍은 비어 있음 (even-odd, Path는 fill_rule)
+    ///
+    /// # Errors
+    /// * Entity 미발견, 양수가 아닌 tolerance, 열린 Line/Bezier/Arc처럼 닫힌 영역이 없는 도형
+    pub fn triangulate(&self, name: &str, tolerance: f64) -> Result<String, JsValue> {
+        let mesh = self
+            .triangulate_internal(name, tolerance)
+            .map_err(|e| JsValue::from_str(&e.to_string()))?;
+        let vertices: Vec<f64> = mesh.vertices.iter().flatten().copied().collect();
+        serde_json::to_string(&json!({
+            "vertices": vertices,
+            "indices": mesh.indices,
+            "area": mesh.area(),
+            "centroid": mesh.centroid(),
+        }))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f64, b: f64, eps: f64) -> bool {
+        (a - b).abs() < eps
+    }
+
+    #[test]
+    fn test_triangulate_polygon_with_hole() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_svg_path_internal("frame", "M0 0h10v10H0zM2 2v6h6V2z", 0.0)
+            .unwrap();
+        scene.translate("frame", 5.0, 0.0).unwrap();
+
+        let mesh = scene.triangulate_internal("frame", 0.01).unwrap();
+        assert!(approx_eq(mesh.area(), 100.0 - 36.0, 1e-9));
+        let c = mesh.centroid().unwrap();
+        assert!(approx_eq(c[0], 10.0, 1e-9) && approx_eq(c[1], 5.0, 1e-9));
+    }
+
+    #[test]
+    fn test_triangulate_nonzero_path_same_winding_fills_inner_ring() {
+        // 같은 방향 안쪽 링: nonzero에서는 구멍이 아님 (SVG/pick과 같은 100)
+        let d = "M0 0 L10 0 L10 10 L0 10 Z M2 2 L8 2 L8 8 L2 8 Z";
+        let mut scene = Scene::new("test");
+        scene.draw_svg_path_internal("solid", d, 0.0).unwrap();
+        let mesh = scene.triangulate_internal("solid", 0.01).unwrap();
+        assert!(approx_eq(mesh.area(), 100.0, 1e-9));
+
+        if let Geometry::Path { fill_rule, .. } =
+            &mut scene.find_by_name_mut("solid").unwrap().geometry
+        {
+            *fill_rule = FillRule::EvenOdd;
+        }
+        let mesh = scene.triangulate_internal("solid", 0.01).unwrap();
+        assert!(approx_eq(mesh.area(), 64.0, 1e-9));
+    }
+
+    #[test]
+    fn test_triangulate_circle_area_converges() {
+        let mut scene = Scene::new("test");
+        scene.add_circle_internal("c", 0.0, 0.0, 10.0).unwrap();
+        let exact = std::f64::consts::PI * 100.0;
+        let coarse = scene.triangulate_internal("c", 0.1).unwrap().area();
+        let fine = scene.triangulate_internal("c", 0.001).unwrap().area();
+        assert!(coarse < fine && fine < exact);
+        assert!((exact - fine) / exact < 1e-3);
+    }
+
+    #[test]
+    fn test_triangulate_closed_bezier_and_group_union() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("blob", "M 0,0 C 0,10 10,10 10,0 Z", "{}")
+            .unwrap();
+        let blob = scene.triangulate_internal("blob", 0.01).unwrap();
+        assert!(blob.area() > 0.0);
+        assert_eq!(blob.indices.len() % 3, 0);
+
+        // 겹치는 두 사각형 그룹 → 합집합 면적 (4 + 4 - 1)
+        scene.add_rect_internal("r1", 0.0, 0.0, 2.0, 2.0).unwrap();
+        scene.add_rect_internal("r2", 1.0, 1.0, 2.0, 2.0).unwrap();
+        scene
+            .create_group_internal("g", vec!["r1".to_string(), "r2".to_string()])
+            .unwrap();
+        let union = scene.triangulate_internal("g", 0.01).unwrap();
+        assert!(approx_eq(union.area(), 7.0, 1e-9));
+    }
+
+    #[test]
+    fn test_triangulate_errors() {
+        let mut scene = Scene::new("test");
+        scene
+            .draw_bezier("open", "M 0,0 C 0,10 10,10 10,0", "{}")
+            .unwrap();
+        let err = scene.triangulate_internal("open", 0.01).unwrap_err();
+        assert!(err.to_string().contains("no closed area"));
+        assert!(matches!(
+            scene.triangulate_internal("missing", 0.01),
+            Err(SceneError::EntityNotFound(..))
+        ));
+        assert!(scene.triangulate_internal("open", 0.0).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 ///
 /// 엔티티의 드로우 순서(z-order) 관련 기능을 제공합니다.
 use super::Scene;
+use super::entity::EntityHandle;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -31,37 +32,37 @@ impl Scene {
         if mode_lower == "front" {
             let result = self.bring_to_front_internal(name)?;
             if result {
-                let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                self.normalize_scope_z_indices(parent_id.as_deref());
+                let parent = self.find_by_name(name).and_then(|e| e.parent);
+                self.normalize_scope_z_indices(parent);
             }
             return Ok(result);
         } else if mode_lower == "back" {
             let result = self.send_to_back_internal(name)?;
             if result {
-                let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                self.normalize_scope_z_indices(parent_id.as_deref());
+                let parent = self.find_by_name(name).and_then(|e| e.parent);
+                self.normalize_scope_z_indices(parent);
             }
             return Ok(result);
         } else if let Some(target) = mode_lower.strip_prefix("above:") {
             let result = self.move_above_internal(name, target)?;
             if result {
-                let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                self.normalize_scope_z_indices(parent_id.as_deref());
+                let parent = self.find_by_name(name).and_then(|e| e.parent);
+                self.normalize_scope_z_indices(parent);
             }
             return Ok(result);
         } else if let Some(target) = mode_lower.strip_prefix("below:") {
             let result = self.move_below_internal(name, target)?;
             if result {
-                let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                self.normalize_scope_z_indices(parent_id.as_deref());
+                let parent = self.find_by_name(name).and_then(|e| e.parent);
+                self.normalize_scope_z_indices(parent);
             }
             return Ok(result);
         } else if let Some(stripped) = mode_lower.strip_prefix('+') {
             if let Ok(steps) = stripped.parse::<i32>() {
                 let result = self.move_by_steps_internal(name, steps)?;
                 if result {
-                    let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                    self.normalize_scope_z_indices(parent_id.as_deref());
+                    let parent = self.find_by_name(name).and_then(|e| e.parent);
+                    self.normalize_scope_z_indices(parent);
                 }
                 return Ok(result);
             }
@@ -69,16 +70,16 @@ impl Scene {
             if let Ok(steps) = stripped.parse::<i32>() {
                 let result = self.move_by_steps_internal(name, -steps)?;
                 if result {
-                    let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                    self.normalize_scope_z_indices(parent_id.as_deref());
+                    let parent = self.find_by_name(name).and_then(|e| e.parent);
+                    self.normalize_scope_z_indices(parent);
                 }
                 return Ok(result);
             }
         } else if let Ok(steps) = mode_lower.parse::<i32>() {
             let result = self.move_by_steps_internal(name, steps)?;
             if result {
-                let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-                self.normalize_scope_z_indices(parent_id.as_deref());
+                let parent = self.find_by_name(name).and_then(|e| e.parent);
+                self.normalize_scope_z_indices(parent);
             }
             return Ok(result);
         }
@@ -89,24 +90,25 @@ impl Scene {
 
     /// 특정 스코프의 z-index를 0, 1, 2...로 정규화
     ///
-    /// parent_id가 None이면 root level, Some이면 해당 그룹의 children을 정규화
-    fn normalize_scope_z_indices(&mut self, parent_id: Option<&str>) {
+    /// parent가 None이면 root level, Some이면 해당 그룹의 children을 정규화
+    pub(super) fn normalize_scope_z_indices(&mut self, parent: Option<EntityHandle>) {
         // 해당 스코프의 엔티티 인덱스와 z-index 수집
-        let mut scope_entities: Vec<(usize, i32)> = self
+        let mut scope_entities: Vec<(usize, (i32, usize))> = self
             .entities
             .iter()
             .enumerate()
-            .filter(|(_, e)| e.parent_id.as_deref() == parent_id)
-            .map(|(idx, e)| (idx, e.metadata.z_index))
+            .filter(|(_, e)| e.parent == parent)
+            .map(|(idx, e)| (idx, (e.metadata.z_index, e.handle.index())))
             .collect();
 
-        // 현재 z_index 순으로 정렬 (상대 순서 유지)
-        scope_entities.sort_by_key(|(_, z)| *z);
+        // 현재 z_index 순으로 정렬 (같으면 추가 순서, 상대 순서 유지)
+        scope_entities.sort_by_key(|(_, key)| *key);
 
         // 0, 1, 2...로 재할당
         for (new_z, (idx, _)) in scope_entities.iter().enumerate() {
             self.entities[*idx].metadata.z_index = new_z as i32;
         }
+        self.invalidate_paint_order();
     }
 
     // ========================================
@@ -118,12 +120,12 @@ impl Scene {
             Some(e) => e,
             None => return Ok(false),
         };
-        let parent_id = entity.parent_id.clone();
+        let parent = entity.parent;
 
         let max_z = self
             .entities
             .iter()
-            .filter(|e| e.parent_id == parent_id)
+            .filter(|e| e.parent == parent)
             .map(|e| e.metadata.z_index)
             .max()
             .unwrap_or(0);
@@ -132,6 +134,7 @@ impl Scene {
             e.metadata.z_index = max_z + 1;
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("draw_order({}, front)", name));
         Ok(true)
     }
@@ -141,12 +144,12 @@ impl Scene {
             Some(e) => e,
             None => return Ok(false),
         };
-        let parent_id = entity.parent_id.clone();
+        let parent = entity.parent;
 
         let min_z = self
             .entities
             .iter()
-            .filter(|e| e.parent_id == parent_id)
+            .filter(|e| e.parent == parent)
             .map(|e| e.metadata.z_index)
             .min()
             .unwrap_or(0);
@@ -155,6 +158,7 @@ impl Scene {
             e.metadata.z_index = min_z - 1;
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("draw_order({}, back)", name));
         Ok(true)
     }
@@ -164,13 +168,13 @@ impl Scene {
             Some(e) => e,
             None => return Ok(false),
         };
-        let parent_id = entity.parent_id.clone();
+        let parent = entity.parent;
         let current_z = entity.metadata.z_index;
 
         let next_sibling = self
             .entities
             .iter()
-            .filter(|e| e.parent_id == parent_id && e.metadata.z_index > current_z)
+            .filter(|e| e.parent == parent && e.metadata.z_index > current_z)
             .min_by_key(|e| e.metadata.z_index)
             .map(|e| (e.metadata.name.clone(), e.metadata.z_index));
 
@@ -182,6 +186,7 @@ impl Scene {
                 if let Some(e) = self.find_by_name_mut(&sibling_name) {
                     e.metadata.z_index = current_z;
                 }
+                self.invalidate_paint_order();
                 self.last_operation = Some(format!("draw_order({}, +1)", name));
                 Ok(true)
             }
@@ -194,13 +199,13 @@ impl Scene {
             Some(e) => e,
             None => return Ok(false),
         };
-        let parent_id = entity.parent_id.clone();
+        let parent = entity.parent;
         let current_z = entity.metadata.z_index;
 
         let prev_sibling = self
             .entities
             .iter()
-            .filter(|e| e.parent_id == parent_id && e.metadata.z_index < current_z)
+            .filter(|e| e.parent == parent && e.metadata.z_index < current_z)
             .max_by_key(|e| e.metadata.z_index)
             .map(|e| (e.metadata.name.clone(), e.metadata.z_index));
 
@@ -212,6 +217,7 @@ impl Scene {
                 if let Some(e) = self.find_by_name_mut(&sibling_name) {
                     e.metadata.z_index = current_z;
                 }
+                self.invalidate_paint_order();
                 self.last_operation = Some(format!("draw_order({}, -1)", name));
                 Ok(true)
             }
@@ -237,13 +243,13 @@ impl Scene {
             Some(e) => e,
             None => return Ok(false),
         };
-        let parent_id = entity.parent_id.clone();
+        let parent = entity.parent;
 
         // 같은 스코프의 siblings를 z-index로 정렬
         let mut siblings: Vec<(String, i32)> = self
             .entities
             .iter()
-            .filter(|e| e.parent_id == parent_id)
+            .filter(|e| e.parent == parent)
             .map(|e| (e.metadata.name.clone(), e.metadata.z_index))
             .collect();
         siblings.sort_by_key(|(_, z)| *z);
@@ -276,6 +282,7 @@ impl Scene {
         }
 
         let direction = if steps > 0 { "+" } else { "" };
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("draw_order({}, {}{})", name, direction, steps));
         Ok(true)
     }
@@ -294,7 +301,7 @@ impl Scene {
             None => return Ok(false),
         };
 
-        if entity.parent_id != target_entity.parent_id {
+        if entity.parent != target_entity.parent {
             return Ok(false);
         }
 
@@ -304,6 +311,7 @@ impl Scene {
             e.metadata.z_index = target_z + 1;
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("draw_order({}, above:{})", name, target));
         Ok(true)
     }
@@ -322,7 +330,7 @@ impl Scene {
             None => return Ok(false),
         };
 
-        if entity.parent_id != target_entity.parent_id {
+        if entity.parent != target_entity.parent {
             return Ok(false);
         }
 
@@ -332,6 +340,7 @@ impl Scene {
             e.metadata.z_index = target_z - 1;
         }
 
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("draw_order({}, below:{})", name, target));
         Ok(true)
     }
@@ -345,8 +354,8 @@ impl Scene {
     pub fn bring_to_front(&mut self, name: &str) -> Result<bool, JsValue> {
         let result = self.bring_to_front_internal(name)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -355,8 +364,8 @@ impl Scene {
     pub fn send_to_back(&mut self, name: &str) -> Result<bool, JsValue> {
         let result = self.send_to_back_internal(name)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -365,8 +374,8 @@ impl Scene {
     pub fn bring_forward(&mut self, name: &str) -> Result<bool, JsValue> {
         let result = self.bring_forward_internal(name)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -375,8 +384,8 @@ impl Scene {
     pub fn send_backward(&mut self, name: &str) -> Result<bool, JsValue> {
         let result = self.send_backward_internal(name)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -385,8 +394,8 @@ impl Scene {
     pub fn move_above(&mut self, name: &str, target: &str) -> Result<bool, JsValue> {
         let result = self.move_above_internal(name, target)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -395,8 +404,8 @@ impl Scene {
     pub fn move_below(&mut self, name: &str, target: &str) -> Result<bool, JsValue> {
         let result = self.move_below_internal(name, target)?;
         if result {
-            let parent_id = self.find_by_name(name).and_then(|e| e.parent_id.clone());
-            self.normalize_scope_z_indices(parent_id.as_deref());
+            let parent = self.find_by_name(name).and_then(|e| e.parent);
+            self.normalize_scope_z_indices(parent);
         }
         Ok(result)
     }
@@ -414,6 +423,7 @@ impl Scene {
         };
 
         entity.metadata.z_index = z_index;
+        self.invalidate_paint_order();
         self.last_operation = Some(format!("set_z_order({}, {})", name, z_index));
         Ok(true)
     }
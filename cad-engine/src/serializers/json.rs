@@ -1,8 +1,10 @@
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 
+use crate::geometry::dimension::DimensionLayout;
+use crate::geometry::hatch::{HatchLines, hatch_geometry};
 use crate::scene::Scene;
-use crate::scene::entity::{Entity, EntityType};
+use crate::scene::entity::{Constraint, Entity, EntityHandle, EntityType};
 
 /// 계산된 필드 (Viewer용, WASM에서 계산)
 #[derive(Serialize, Clone)]
@@ -13,12 +15,21 @@ pub struct Computed {
     /// 로컬 좌표계 바운딩 박스
     #[serde(skip_serializing_if = "Option::is_none")]
     pub local_bounds: Option<Bounds>,
+    /// stroke 폭/join/cap을 포함한 시각적 월드 바운딩 박스
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visual_bounds: Option<Bounds>,
     /// 월드 좌표계 중심점
     #[serde(skip_serializing_if = "Option::is_none")]
     pub center: Option<[f64; 2]>,
     /// 크기 [width, height]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<[f64; 2]>,
+    /// 치수 Entity의 현재 측정값과 레이아웃 (월드 좌표)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dimension: Option<DimensionLayout>,
+    /// 해치 fill의 잘린 선/점 (로컬 좌표, 해치할 수 없는 도형은 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hatch: Option<HatchLines>,
 }
 
 #[derive(Serialize, Clone)]
@@ -45,6 +56,12 @@ pub struct TreeNode {
 struct EntityWithComputed<'a> {
     #[serde(flatten)]
     entity: &'a Entity,
+    /// 부모 그룹 이름 (최상위면 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<&'a str>,
+    /// 자식 Entity 이름 목록 (Group만 사용)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<&'a str>,
     computed: Computed,
 }
 
@@ -58,18 +75,9 @@ struct SceneJson<'a> {
     /// LLM이 마지막으로 실행한 작업
     #[serde(skip_serializing_if = "Option::is_none")]
     last_operation: Option<&'a str>,
-}
-
-/// Find entity by ID or name from lookup maps
-fn find_entity_by_key<'a>(
-    key: &str,
-    entity_by_id: &HashMap<&str, &'a Entity>,
-    entity_by_name: &HashMap<&str, &'a Entity>,
-) -> Option<&'a Entity> {
-    entity_by_id
-        .get(key)
-        .copied()
-        .or_else(|| entity_by_name.get(key).copied())
+    /// 기하 제약 조건 정의 (상태는 get_constraint_status)
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    constraints: &'a [Constraint],
 }
 
 /// Get z-order from entity
@@ -79,40 +87,34 @@ fn get_z_order(entity: &Entity) -> i32 {
 
 /// Build tree structure for LayerPanel (Dumb View)
 fn build_tree(entities: &[Entity]) -> Vec<TreeNode> {
-    // Build lookup maps by ID and name
-    let entity_by_id: HashMap<&str, &Entity> =
-        entities.iter().map(|e| (e.id.as_str(), e)).collect();
-    let entity_by_name: HashMap<&str, &Entity> = entities
-        .iter()
-        .map(|e| (e.metadata.name.as_str(), e))
-        .collect();
+    // Build lookup map by handle
+    let entity_by_handle: HashMap<EntityHandle, &Entity> =
+        entities.iter().map(|e| (e.handle, e)).collect();
 
-    // Collect all child IDs (entities that belong to a group)
-    let mut child_ids: HashSet<&str> = HashSet::new();
+    // Collect all child handles (entities that belong to a group)
+    let mut child_handles: HashSet<EntityHandle> = HashSet::new();
     for entity in entities {
         if entity.entity_type == EntityType::Group {
-            for child_key in &entity.children {
-                if let Some(child) = find_entity_by_key(child_key, &entity_by_id, &entity_by_name) {
-                    child_ids.insert(&child.id);
-                }
-            }
+            child_handles.extend(
+                entity
+                    .children
+                    .iter()
+                    .filter(|child| entity_by_handle.contains_key(*child)),
+            );
         }
     }
 
     // Recursively convert Entity to TreeNode
     fn to_tree_node(
         entity: &Entity,
-        entity_by_id: &HashMap<&str, &Entity>,
-        entity_by_name: &HashMap<&str, &Entity>,
+        entity_by_handle: &HashMap<EntityHandle, &Entity>,
     ) -> TreeNode {
         let children = if entity.entity_type == EntityType::Group && !entity.children.is_empty() {
             let mut child_entities: Vec<(usize, &Entity)> = entity
                 .children
                 .iter()
                 .enumerate()
-                .filter_map(|(idx, key)| {
-                    find_entity_by_key(key, entity_by_id, entity_by_name).map(|e| (idx, e))
-                })
+                .filter_map(|(idx, handle)| entity_by_handle.get(handle).map(|e| (idx, *e)))
                 .collect();
 
             // LayerPanel 표시 순서: 높은 z-order가 먼저 (위에 표시 = 앞에 그려짐)
@@ -128,7 +130,7 @@ fn build_tree(entities: &[Entity]) -> Vec<TreeNode> {
 
             let child_nodes: Vec<TreeNode> = child_entities
                 .into_iter()
-                .map(|(_, e)| to_tree_node(e, entity_by_id, entity_by_name))
+                .map(|(_, e)| to_tree_node(e, entity_by_handle))
                 .collect();
 
             if child_nodes.is_empty() {
@@ -150,10 +152,11 @@ fn build_tree(entities: &[Entity]) -> Vec<TreeNode> {
     }
 
     // Get root-level entities (not children of any group)
+    // 저장소 순서는 삭제 시 바뀌므로 핸들 번호(추가 순서)를 사용
     let mut root_entities: Vec<(usize, &Entity)> = entities
         .iter()
-        .enumerate()
-        .filter(|(_, e)| !child_ids.contains(e.id.as_str()))
+        .filter(|e| !child_handles.contains(&e.handle))
+        .map(|e| (e.handle.index(), e))
         .collect();
 
     // LayerPanel 표시 순서: 높은 z-order가 먼저 (위에 표시 = 앞에 그려짐)
@@ -169,15 +172,16 @@ fn build_tree(entities: &[Entity]) -> Vec<TreeNode> {
 
     root_entities
         .into_iter()
-        .map(|(_, e)| to_tree_node(e, &entity_by_id, &entity_by_name))
+        .map(|(_, e)| to_tree_node(e, &entity_by_handle))
         .collect()
 }
 
 pub fn serialize_scene(scene: &Scene) -> String {
     let entities = scene.entities();
 
-    let entities_with_computed: Vec<EntityWithComputed> = entities
-        .iter()
+    // 저장소 순서는 삭제 시 바뀌므로 추가 순서로 출력
+    let entities_with_computed: Vec<EntityWithComputed> = scene
+        .entities_in_order()
         .map(|entity| {
             let name = &entity.metadata.name;
 
@@ -191,6 +195,11 @@ pub fn serialize_scene(scene: &Scene) -> String {
                 .get_local_bounds_for_entity(name)
                 .map(|(min, max)| Bounds { min, max });
 
+            // 시각적 바운드 계산 (stroke 포함)
+            let visual_bounds = scene
+                .get_visual_bounds_for_entity(name)
+                .map(|(min, max)| Bounds { min, max });
+
             // center, size 계산 (world_bounds 기준)
             let (center, size) = if let Some(ref wb) = world_bounds {
                 let center = [(wb.min[0] + wb.max[0]) / 2.0, (wb.min[1] + wb.max[1]) / 2.0];
@@ -200,13 +209,29 @@ pub fn serialize_scene(scene: &Scene) -> String {
                 (None, None)
             };
 
+            // 치수 레이아웃 (참조 도형 기준으로 재계산)
+            let dimension = scene.get_dimension_layout_for_entity(name);
+
+            // 해치 선 (Entity transform은 Viewer가 적용)
+            let hatch = entity
+                .style
+                .fill
+                .as_ref()
+                .and_then(|fill| fill.hatch.as_ref())
+                .and_then(|hatch| hatch_geometry(&entity.geometry, hatch));
+
             EntityWithComputed {
                 entity,
+                parent_id: scene.parent_name(entity),
+                children: scene.child_names(entity),
                 computed: Computed {
                     world_bounds,
                     local_bounds,
+                    visual_bounds,
                     center,
                     size,
+                    dimension,
+                    hatch,
                 },
             }
         })
@@ -225,6 +250,7 @@ pub fn serialize_scene(scene: &Scene) -> String {
         entities: entities_with_computed,
         tree,
         last_operation: scene.last_operation(),
+        constraints: scene.constraints(),
     };
 
     serde_json::to_string_pretty(&scene_json).unwrap_or_else(|err| {
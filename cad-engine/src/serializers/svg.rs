@@ -1,10 +1,114 @@
-use crate::scene::entity::{Entity, EntityType, Geometry, Style, Transform};
+use crate::geometry::dimension::DimensionLayout;
+use crate::geometry::flatten::{arc_sweep, ellipse_point};
+use crate::geometry::hatch::{HatchLines, hatch_geometry};
+use crate::geometry::path::segment_end;
+use crate::geometry::text::{OUTLINE_STROKE_RATIO, layout_text, text_outline_polylines};
+use crate::scene::entity::{
+    Entity, EntityHandle, EntityType, FillRule, Geometry, Gradient, PathSegment, Style, Subpath,
+    Transform,
+};
 use std::collections::HashMap;
 
+/// SVG 출력 옵션
+#[derive(Debug, Clone, Default)]
+pub struct SvgOptions {
+    /// true면 Text를 `<text>` 대신 단선 폰트 path로 출력 (폰트 미설치 환경에서도 동일하게 렌더링)
+    pub text_as_paths: bool,
+    /// 치수 Entity 이름 → 레이아웃 (치수 Entity 로컬 좌표계, Scene에서 참조 도형으로 계산)
+    /// 없는 치수(참조 도형 삭제 등)는 렌더링하지 않음
+    pub dimensions: HashMap<String, DimensionLayout>,
+}
+
 /// Entity를 SVG 요소로 변환합니다 (단일 엔티티, 그룹 제외).
-fn entity_to_svg_element(entity: &Entity, indent: &str) -> String {
+///
+/// 해치 채움이 있으면 잘린 해치 선을 먼저 그리고 외곽선을 그 위에 그립니다.
+/// 그라디언트 fill이 함께 있으면 채워진 도형이 해치를 덮지 않도록 도형을 먼저 그립니다.
+fn entity_to_svg_element(entity: &Entity, indent: &str, options: &SvgOptions) -> String {
+    let hatch = entity
+        .style
+        .fill
+        .as_ref()
+        .and_then(|fill| Some((fill, fill.hatch.as_ref()?)))
+        .and_then(|(fill, hatch)| {
+            let lines = hatch_geometry(&entity.geometry, hatch)?;
+            Some(hatch_to_svg(
+                &lines,
+                fill.color,
+                hatch.line_width,
+                &transform_to_svg(&entity.transform),
+                indent,
+            ))
+        })
+        .unwrap_or_default();
+    let shape = shape_to_svg_element(entity, indent, options);
+    let has_gradient = entity
+        .style
+        .fill
+        .as_ref()
+        .is_some_and(|fill| fill.gradient.is_some());
+    if has_gradient {
+        shape + &hatch
+    } else {
+        hatch + &shape
+    }
+}
+
+/// 해치 선/점을 `<path>`로 변환합니다 (점은 길이 0 + round cap).
+fn hatch_to_svg(
+    hatch: &HatchLines,
+    color: [f64; 4],
+    line_width: f64,
+    transform_attr: &str,
+    indent: &str,
+) -> String {
+    if hatch.is_empty() {
+        return String::new();
+    }
+    let color = rgba_to_svg(color);
+    let mut result = String::new();
+    if !hatch.lines.is_empty() {
+        let d: Vec<String> = hatch
+            .lines
+            .iter()
+            .map(|[a, b]| format!("M {},{} L {},{}", a[0], a[1], b[0], b[1]))
+            .collect();
+        result.push_str(&format!(
+            r#"{}<path d="{}" stroke="{}" stroke-width="{}" fill="none" {}/>"#,
+            indent,
+            d.join(" "),
+            color,
+            line_width,
+            transform_attr
+        ));
+        result.push('\n');
+    }
+    if !hatch.dots.is_empty() {
+        let d: Vec<String> = hatch
+            .dots
+            .iter()
+            .map(|p| format!("M {},{} h 0", p[0], p[1]))
+            .collect();
+        result.push_str(&format!(
+            r#"{}<path d="{}" stroke="{}" stroke-width="{}" stroke-linecap="round" fill="none" {}/>"#,
+            indent,
+            d.join(" "),
+            color,
+            line_width,
+            transform_attr
+        ));
+        result.push('\n');
+    }
+    result
+}
+
+/// 도형 자체를 SVG 요소로 변환합니다 (해치 제외).
+fn shape_to_svg_element(entity: &Entity, indent: &str, options: &SvgOptions) -> String {
     let transform_attr = transform_to_svg(&entity.transform);
-    let style_attr = style_to_svg(&entity.style);
+    let style_attr = style_to_svg(
+        &entity.style,
+        gradient_transform(&entity.geometry).as_deref(),
+        !matches!(entity.geometry, Geometry::Text { .. }),
+    );
 
     match &entity.geometry {
         Geometry::Line { points } => {
@@ -102,6 +206,44 @@ fn entity_to_svg_element(entity: &Entity, indent: &str) -> String {
             &transform_attr,
             indent,
         ),
+        Geometry::Ellipse {
+            center,
+            rx,
+            ry,
+            rotation,
+        } => {
+            // 타원 자체 회전은 entity transform 뒤에 center 기준 rotate로 추가
+            let transform_attr = if *rotation != 0.0 {
+                let degrees = rotation * 180.0 / std::f64::consts::PI;
+                append_svg_transform(
+                    &transform_attr,
+                    &format!("rotate({}, {}, {})", degrees, center[0], center[1]),
+                )
+            } else {
+                transform_attr
+            };
+            format!(
+                r#"{}<ellipse cx="{}" cy="{}" rx="{}" ry="{}" {}{}/>"#,
+                indent, center[0], center[1], rx, ry, style_attr, transform_attr
+            ) + "\n"
+        }
+        Geometry::EllipticalArc {
+            center,
+            rx,
+            ry,
+            rotation,
+            start_angle,
+            end_angle,
+        } => elliptical_arc_to_svg_path(
+            center,
+            [*rx, *ry],
+            *rotation,
+            *start_angle,
+            *end_angle,
+            &style_attr,
+            &transform_attr,
+            indent,
+        ),
         Geometry::Bezier {
             start,
             segments,
@@ -114,15 +256,187 @@ fn entity_to_svg_element(entity: &Entity, indent: &str) -> String {
             &transform_attr,
             indent,
         ),
+        Geometry::Path {
+            subpaths,
+            fill_rule,
+        } => path_to_svg_path(subpaths, *fill_rule, &style_attr, &transform_attr, indent),
+        Geometry::Text {
+            position,
+            content,
+            font_family,
+            font_size,
+            anchor,
+            align,
+        } => {
+            if options.text_as_paths {
+                return text_to_svg_outline(entity, &transform_attr, indent);
+            }
+            // Scene은 y-up이므로 글자가 뒤집히지 않도록 기준점에서 다시 y 반전
+            let transform_attr = append_svg_transform(
+                &transform_attr,
+                &format!("translate({}, {}) scale(1, -1)", position[0], position[1]),
+            );
+            let layout = layout_text(content, font_family, *font_size, *anchor, *align);
+            let tspans: String = layout
+                .lines
+                .iter()
+                .map(|line| {
+                    format!(
+                        r#"<tspan x="0" y="{}">{}</tspan>"#,
+                        -line.baseline,
+                        escape_xml(line.text)
+                    )
+                })
+                .collect();
+            format!(
+                r#"{}<text font-family="{}" font-size="{}" text-anchor="{}" {}{}>{}</text>"#,
+                indent,
+                escape_xml(font_family),
+                font_size,
+                anchor.as_svg(),
+                style_attr,
+                transform_attr,
+                tspans
+            ) + "\n"
+        }
+        Geometry::Dimension { .. } => match options.dimensions.get(&entity.metadata.name) {
+            Some(layout) => dimension_to_svg(entity, layout, &transform_attr, indent),
+            None => String::new(),
+        },
         Geometry::Empty => String::new(),
     }
 }
 
+/// 치수를 `<g>`로 변환합니다 (치수선 path + 화살표 polygon + 치수 문자).
+fn dimension_to_svg(
+    entity: &Entity,
+    layout: &DimensionLayout,
+    transform_attr: &str,
+    indent: &str,
+) -> String {
+    let color = entity
+        .style
+        .stroke
+        .as_ref()
+        .map(|s| s.color)
+        .unwrap_or([0.0, 0.0, 0.0, 1.0]);
+    let color = rgba_to_svg(color);
+    let stroke_width = entity.style.stroke.as_ref().map_or(1.0, |s| s.width);
+    let child_indent = format!("{}  ", indent);
+
+    let mut path_data: Vec<String> = layout
+        .lines
+        .iter()
+        .map(|[a, b]| format!("M {},{} L {},{}", a[0], a[1], b[0], b[1]))
+        .collect();
+    for arc in &layout.arcs {
+        if let Some((first, rest)) = arc.split_first() {
+            let mut d = format!("M {},{}", first[0], first[1]);
+            for p in rest {
+                d.push_str(&format!(" L {},{}", p[0], p[1]));
+            }
+            path_data.push(d);
+        }
+    }
+
+    let mut result = if transform_attr.is_empty() {
+        format!("{}<g>\n", indent)
+    } else {
+        format!("{}<g {}>\n", indent, transform_attr)
+    };
+    result.push_str(&format!(
+        r#"{}<path d="{}" stroke="{}" stroke-width="{}" fill="none"/>"#,
+        child_indent,
+        path_data.join(" "),
+        color,
+        stroke_width
+    ));
+    result.push('\n');
+    for arrow in &layout.arrows {
+        let points: Vec<String> = arrow.iter().map(|p| format!("{},{}", p[0], p[1])).collect();
+        result.push_str(&format!(
+            r#"{}<polygon points="{}" fill="{}" stroke="none"/>"#,
+            child_indent,
+            points.join(" "),
+            color
+        ));
+        result.push('\n');
+    }
+    // Scene은 y-up이므로 문자 중심에서 다시 y 반전
+    result.push_str(&format!(
+        r#"{}<text font-family="sans-serif" font-size="{}" text-anchor="middle" dominant-baseline="central" fill="{}" transform="translate({}, {}) rotate({}) scale(1, -1)">{}</text>"#,
+        child_indent,
+        layout.text_size,
+        color,
+        layout.text_position[0],
+        layout.text_position[1],
+        layout.text_angle.to_degrees(),
+        escape_xml(&layout.text)
+    ));
+    result.push('\n');
+    result.push_str(&format!("{}</g>\n", indent));
+    result
+}
+
+/// Text를 단선 폰트 path로 변환합니다 (fill 색상이 있으면 그 색으로 stroke).
+fn text_to_svg_outline(entity: &Entity, transform_attr: &str, indent: &str) -> String {
+    let Geometry::Text { font_size, .. } = &entity.geometry else {
+        return String::new();
+    };
+    let path_data: Vec<String> = text_outline_polylines(&entity.geometry)
+        .iter()
+        .filter_map(|polyline| {
+            let (first, rest) = polyline.split_first()?;
+            let mut d = format!("M {},{}", first[0], first[1]);
+            for p in rest {
+                d.push_str(&format!(" L {},{}", p[0], p[1]));
+            }
+            Some(d)
+        })
+        .collect();
+    if path_data.is_empty() {
+        return String::new();
+    }
+
+    let color = entity
+        .style
+        .fill
+        .as_ref()
+        .map(|f| f.color)
+        .or_else(|| entity.style.stroke.as_ref().map(|s| s.color))
+        .unwrap_or([0.0, 0.0, 0.0, 1.0]);
+    format!(
+        r#"{}<path d="{}" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" fill="none" {}/>"#,
+        indent,
+        path_data.join(" "),
+        rgba_to_svg(color),
+        OUTLINE_STROKE_RATIO * font_size,
+        transform_attr
+    ) + "\n"
+}
+
+/// XML 텍스트/속성값 이스케이프
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Entity를 SVG로 변환합니다 (계층 구조 지원).
 fn entity_to_svg_hierarchical(
     entity: &Entity,
-    entities_by_name: &HashMap<String, &Entity>,
+    entities_by_handle: &HashMap<EntityHandle, &Entity>,
     indent: &str,
+    options: &SvgOptions,
 ) -> String {
     match entity.entity_type {
         EntityType::Group => {
@@ -139,25 +453,26 @@ fn entity_to_svg_hierarchical(
             let child_indent = format!("{}  ", indent);
 
             // 자식들을 z_index로 정렬 (낮은 값이 먼저 렌더링 = 뒤에 위치)
-            let mut sorted_children: Vec<_> = entity
+            let mut sorted_children: Vec<&Entity> = entity
                 .children
                 .iter()
-                .filter_map(|name| entities_by_name.get(name).map(|e| (name, *e)))
+                .filter_map(|handle| entities_by_handle.get(handle).copied())
                 .collect();
-            sorted_children.sort_by_key(|(_, e)| e.metadata.z_index);
+            sorted_children.sort_by_key(|e| e.metadata.z_index);
 
-            for (_, child) in sorted_children {
+            for child in sorted_children {
                 result.push_str(&entity_to_svg_hierarchical(
                     child,
-                    entities_by_name,
+                    entities_by_handle,
                     &child_indent,
+                    options,
                 ));
             }
 
             result.push_str(&format!("{}</g>\n", indent));
             result
         }
-        _ => entity_to_svg_element(entity, indent),
+        _ => entity_to_svg_element(entity, indent, options),
     }
 }
 
@@ -210,6 +525,49 @@ fn arc_to_svg_path(
     ) + "\n"
 }
 
+/// EllipticalArc를 SVG path로 변환합니다 (A 명령의 x-axis-rotation 사용).
+#[allow(clippy::too_many_arguments)]
+fn elliptical_arc_to_svg_path(
+    center: &[f64; 2],
+    radii: [f64; 2],
+    rotation: f64,
+    start_angle: f64,
+    end_angle: f64,
+    style_attr: &str,
+    transform_attr: &str,
+    indent: &str,
+) -> String {
+    let [rx, ry] = radii;
+    let [start_x, start_y] = ellipse_point(*center, rx, ry, rotation, start_angle);
+    let [end_x, end_y] = ellipse_point(*center, rx, ry, rotation, end_angle);
+
+    // Arc와 동일한 플래그 계산 (CCW 스윕)
+    let angle_diff = arc_sweep(start_angle, end_angle);
+    let large_arc_flag = if angle_diff > std::f64::consts::PI {
+        1
+    } else {
+        0
+    };
+    let sweep_flag = if angle_diff > 0.0 { 1 } else { 0 };
+    let degrees = rotation * 180.0 / std::f64::consts::PI;
+
+    format!(
+        r#"{}<path d="M {},{} A {},{} {} {} {} {},{}" {}{}/>"#,
+        indent,
+        start_x,
+        start_y,
+        rx,
+        ry,
+        degrees,
+        large_arc_flag,
+        sweep_flag,
+        end_x,
+        end_y,
+        style_attr,
+        transform_attr
+    ) + "\n"
+}
+
 /// Bezier 커브를 SVG path로 변환합니다.
 fn bezier_to_svg_path(
     start: &[f64; 2],
@@ -243,8 +601,129 @@ fn bezier_to_svg_path(
     ) + "\n"
 }
 
+/// 원호/타원호 세그먼트를 SVG A 명령으로 변환합니다 (현재 점과 시작점이 다르면 L 추가).
+///
+/// 한 바퀴 이상(|sweep| ≥ 2π)은 A 명령 하나로 표현할 수 없으므로 반으로 나눕니다.
+fn arc_segment_to_svg(
+    path_data: &mut String,
+    current: [f64; 2],
+    frame: ([f64; 2], f64, f64, f64),
+    start_angle: f64,
+    sweep_angle: f64,
+) {
+    let (center, rx, ry, rotation) = frame;
+    let start = ellipse_point(center, rx, ry, rotation, start_angle);
+    if (start[0] - current[0]).abs() > 1e-9 || (start[1] - current[1]).abs() > 1e-9 {
+        path_data.push_str(&format!(" L {},{}", start[0], start[1]));
+    }
+    let sweep = sweep_angle.clamp(-2.0 * std::f64::consts::PI, 2.0 * std::f64::consts::PI);
+    let pieces = if sweep.abs() > std::f64::consts::PI {
+        2
+    } else {
+        1
+    };
+    let degrees = rotation * 180.0 / std::f64::consts::PI;
+    let sweep_flag = if sweep > 0.0 { 1 } else { 0 };
+    for i in 1..=pieces {
+        let step = sweep / pieces as f64;
+        let end = ellipse_point(center, rx, ry, rotation, start_angle + step * i as f64);
+        let large_arc_flag = if step.abs() > std::f64::consts::PI {
+            1
+        } else {
+            0
+        };
+        path_data.push_str(&format!(
+            " A {},{} {} {} {} {},{}",
+            rx, ry, degrees, large_arc_flag, sweep_flag, end[0], end[1]
+        ));
+    }
+}
+
+/// Path를 SVG path로 변환합니다 (세그먼트 종류를 그대로 L/Q/C/A로 출력).
+fn path_to_svg_path(
+    subpaths: &[Subpath],
+    fill_rule: FillRule,
+    style_attr: &str,
+    transform_attr: &str,
+    indent: &str,
+) -> String {
+    let mut path_data = String::new();
+    for subpath in subpaths {
+        if !path_data.is_empty() {
+            path_data.push(' ');
+        }
+        path_data.push_str(&format!("M {},{}", subpath.start[0], subpath.start[1]));
+        let mut current = subpath.start;
+        for segment in &subpath.segments {
+            match segment {
+                PathSegment::Line { to } => {
+                    path_data.push_str(&format!(" L {},{}", to[0], to[1]));
+                }
+                PathSegment::Quadratic { control, to } => {
+                    path_data.push_str(&format!(
+                        " Q {},{} {},{}",
+                        control[0], control[1], to[0], to[1]
+                    ));
+                }
+                PathSegment::Cubic {
+                    control1,
+                    control2,
+                    to,
+                } => {
+                    path_data.push_str(&format!(
+                        " C {},{} {},{} {},{}",
+                        control1[0], control1[1], control2[0], control2[1], to[0], to[1]
+                    ));
+                }
+                PathSegment::Arc {
+                    center,
+                    radius,
+                    start_angle,
+                    sweep_angle,
+                } => arc_segment_to_svg(
+                    &mut path_data,
+                    current,
+                    (*center, *radius, *radius, 0.0),
+                    *start_angle,
+                    *sweep_angle,
+                ),
+                PathSegment::EllipticalArc {
+                    center,
+                    rx,
+                    ry,
+                    rotation,
+                    start_angle,
+                    sweep_angle,
+                } => arc_segment_to_svg(
+                    &mut path_data,
+                    current,
+                    (*center, *rx, *ry, *rotation),
+                    *start_angle,
+                    *sweep_angle,
+                ),
+            }
+            current = segment_end(segment);
+        }
+        if subpath.closed {
+            path_data.push_str(" Z");
+        }
+    }
+    if path_data.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        r#"{}<path d="{}" fill-rule="{}" {}{}/>"#,
+        indent,
+        path_data,
+        fill_rule.as_svg(),
+        style_attr,
+        transform_attr
+    ) + "\n"
+}
+
 /// Transform을 SVG transform 속성으로 변환합니다.
-/// pivot이 설정된 경우 rotate/scale의 중심점으로 사용됩니다.
+/// pivot이 설정된 경우 rotate/skew/scale의 중심점으로 사용됩니다.
 fn transform_to_svg(transform: &Transform) -> String {
     let mut parts = Vec::new();
     let has_pivot = transform.pivot != [0.0, 0.0];
@@ -258,8 +737,10 @@ fn transform_to_svg(transform: &Transform) -> String {
         ));
     }
 
-    // 2. Pivot을 중심으로 한 rotate/scale
-    if has_pivot && (transform.rotate != 0.0 || transform.scale != [1.0, 1.0]) {
+    // 2. Pivot을 중심으로 한 rotate/skew/scale
+    let has_linear =
+        transform.rotate != 0.0 || transform.skew != 0.0 || transform.scale != [1.0, 1.0];
+    if has_pivot && has_linear {
         // translate to pivot
         parts.push(format!("translate({}, {})", px, py));
     }
@@ -270,6 +751,10 @@ fn transform_to_svg(transform: &Transform) -> String {
         parts.push(format!("rotate({})", degrees));
     }
 
+    if transform.skew != 0.0 {
+        parts.push(format!("skewX({})", transform.skew.to_degrees()));
+    }
+
     if transform.scale != [1.0, 1.0] {
         parts.push(format!(
             "scale({}, {})",
@@ -277,7 +762,7 @@ fn transform_to_svg(transform: &Transform) -> String {
         ));
     }
 
-    if has_pivot && (transform.rotate != 0.0 || transform.scale != [1.0, 1.0]) {
+    if has_pivot && has_linear {
         // translate back from pivot
         parts.push(format!("translate({}, {})", -px, -py));
     }
@@ -289,40 +774,141 @@ fn transform_to_svg(transform: &Transform) -> String {
     }
 }
 
+/// transform 속성 뒤에 변환 하나를 덧붙입니다 (오른쪽에 붙을수록 먼저 적용됨).
+fn append_svg_transform(transform_attr: &str, extra: &str) -> String {
+    match transform_attr
+        .strip_prefix(r#"transform=""#)
+        .and_then(|rest| rest.strip_suffix('"'))
+    {
+        Some(existing) => format!(r#"transform="{} {}""#, existing, extra),
+        None => format!(r#"transform="{}""#, extra),
+    }
+}
+
+/// RGBA(0.0-1.0) 색상을 SVG rgba() 문자열로 변환합니다.
+fn rgba_to_svg(color: [f64; 4]) -> String {
+    let [r, g, b, a] = color;
+    // Clamp color values to prevent overflow (0.0 ~ 1.0 -> 0 ~ 255)
+    format!(
+        "rgba({},{},{},{})",
+        (r.clamp(0.0, 1.0) * 255.0) as u8,
+        (g.clamp(0.0, 1.0) * 255.0) as u8,
+        (b.clamp(0.0, 1.0) * 255.0) as u8,
+        a.clamp(0.0, 1.0)
+    )
+}
+
+/// 그라디언트 좌표(Entity 로컬)를 요소의 user space로 옮기는 gradientTransform
+///
+/// Text는 기준점에서 y를 다시 반전하므로 그 역변환이 필요합니다.
+fn gradient_transform(geometry: &Geometry) -> Option<String> {
+    match geometry {
+        Geometry::Text { position, .. } => Some(format!(
+            "scale(1, -1) translate({}, {})",
+            -position[0], -position[1]
+        )),
+        _ => None,
+    }
+}
+
+/// FNV-1a 64bit 해시 (그라디언트 id를 내용 기준으로 결정해 중복 제거)
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// 그라디언트의 `<defs>` 항목과 id
+///
+/// 같은 그라디언트(좌표, stop, gradientTransform)는 같은 id가 되므로 한 번만 출력됩니다.
+fn gradient_def(gradient: &Gradient, transform: Option<&str>) -> (String, String) {
+    let (tag, mut attrs) = match gradient {
+        Gradient::Linear { start, end, .. } => (
+            "linearGradient",
+            format!(
+                r#"x1="{}" y1="{}" x2="{}" y2="{}""#,
+                start[0], start[1], end[0], end[1]
+            ),
+        ),
+        Gradient::Radial {
+            center,
+            radius,
+            focal,
+            ..
+        } => {
+            let mut attrs = format!(r#"cx="{}" cy="{}" r="{}""#, center[0], center[1], radius);
+            if let Some(focal) = focal {
+                attrs.push_str(&format!(r#" fx="{}" fy="{}""#, focal[0], focal[1]));
+            }
+            ("radialGradient", attrs)
+        }
+    };
+    attrs.push_str(r#" gradientUnits="userSpaceOnUse""#);
+    if let Some(transform) = transform {
+        attrs.push_str(&format!(r#" gradientTransform="{}""#, transform));
+    }
+    let stops: String = gradient
+        .stops()
+        .iter()
+        .map(|stop| {
+            format!(
+                r#"<stop offset="{}" stop-color="{}"/>"#,
+                stop.offset,
+                rgba_to_svg(stop.color)
+            )
+        })
+        .collect();
+
+    let id = format!("grad-{:016x}", fnv1a(&format!("{}{}{}", tag, attrs, stops)));
+    let element = format!(r#"<{} id="{}" {}>{}</{}>"#, tag, id, attrs, stops, tag);
+    (id, element)
+}
+
+/// Scene 전체의 그라디언트 `<defs>` (중복 제거, 없으면 빈 문자열)
+fn gradient_defs(entities: &[Entity]) -> String {
+    let mut seen = std::collections::HashSet::new();
+    let mut defs = String::new();
+    for entity in entities {
+        let Some(gradient) = entity.style.fill.as_ref().and_then(|f| f.gradient.as_ref()) else {
+            continue;
+        };
+        let (id, element) = gradient_def(gradient, gradient_transform(&entity.geometry).as_deref());
+        if seen.insert(id) {
+            defs.push_str(&format!("    {}\n", element));
+        }
+    }
+    if defs.is_empty() {
+        defs
+    } else {
+        format!("  <defs>\n{}  </defs>\n", defs)
+    }
+}
+
 /// Style을 SVG 스타일 속성으로 변환합니다.
-fn style_to_svg(style: &Style) -> String {
+///
+/// * `gradient_transform` - 그라디언트 fill의 gradientTransform (Text 등)
+/// * `default_stroke` - stroke 미지정 시 검은색 1px stroke 적용 여부 (Text는 false → fill만)
+fn style_to_svg(style: &Style, gradient_transform: Option<&str>, default_stroke: bool) -> String {
     let mut attrs = Vec::new();
 
     // Stroke (default: black, width 1)
     if let Some(stroke) = &style.stroke {
-        let [r, g, b, a] = stroke.color;
-        // Clamp color values to prevent overflow (0.0 ~ 1.0 -> 0 ~ 255)
-        let color = format!(
-            "rgba({},{},{},{})",
-            (r.clamp(0.0, 1.0) * 255.0) as u8,
-            (g.clamp(0.0, 1.0) * 255.0) as u8,
-            (b.clamp(0.0, 1.0) * 255.0) as u8,
-            a.clamp(0.0, 1.0)
-        );
-        attrs.push(format!(r#"stroke="{}""#, color));
+        attrs.push(format!(r#"stroke="{}""#, rgba_to_svg(stroke.color)));
         attrs.push(format!(r#"stroke-width="{}""#, stroke.width));
-    } else {
+    } else if default_stroke {
         attrs.push(r#"stroke="black""#.to_string());
         attrs.push(r#"stroke-width="1""#.to_string());
+    } else {
+        attrs.push(r#"stroke="none""#.to_string());
     }
 
     // Fill (default: none)
-    if let Some(fill) = &style.fill {
-        let [r, g, b, a] = fill.color;
-        // Clamp color values to prevent overflow (0.0 ~ 1.0 -> 0 ~ 255)
-        let color = format!(
-            "rgba({},{},{},{})",
-            (r.clamp(0.0, 1.0) * 255.0) as u8,
-            (g.clamp(0.0, 1.0) * 255.0) as u8,
-            (b.clamp(0.0, 1.0) * 255.0) as u8,
-            a.clamp(0.0, 1.0)
-        );
-        attrs.push(format!(r#"fill="{}""#, color));
+    // 그라디언트 → url(#id), 해치만 있으면 별도 path로 그리므로 none
+    if let Some(gradient) = style.fill.as_ref().and_then(|f| f.gradient.as_ref()) {
+        let (id, _) = gradient_def(gradient, gradient_transform);
+        attrs.push(format!(r#"fill="url(#{})""#, id));
+    } else if let Some(fill) = style.fill.as_ref().filter(|f| f.hatch.is_none()) {
+        attrs.push(format!(r#"fill="{}""#, rgba_to_svg(fill.color)));
     } else {
         attrs.push(r#"fill="none""#.to_string());
     }
@@ -332,32 +918,39 @@ fn style_to_svg(style: &Style) -> String {
 
 /// Scene을 SVG 문자열로 직렬화합니다 (계층 구조 지원).
 pub fn serialize_scene_svg(entities: &[Entity]) -> String {
+    serialize_scene_svg_with_options(entities, &SvgOptions::default())
+}
+
+/// Scene을 옵션에 따라 SVG 문자열로 직렬화합니다.
+pub fn serialize_scene_svg_with_options(entities: &[Entity], options: &SvgOptions) -> String {
     let mut svg = String::new();
 
     // SVG header with viewBox
     svg.push_str(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-200 -200 400 400">"#);
     svg.push('\n');
 
+    // 그라디언트 정의 (Entity 로컬 좌표, userSpaceOnUse)
+    svg.push_str(&gradient_defs(entities));
+
     // Y-axis flip group (SVG y-axis increases downward)
     svg.push_str(r#"  <g transform="scale(1, -1)">"#);
     svg.push('\n');
 
-    // Build name -> entity map for hierarchical lookup
-    let entities_by_name: HashMap<String, &Entity> = entities
-        .iter()
-        .map(|e| (e.metadata.name.clone(), e))
-        .collect();
+    // Build handle -> entity map for hierarchical lookup
+    let entities_by_handle: HashMap<EntityHandle, &Entity> =
+        entities.iter().map(|e| (e.handle, e)).collect();
 
-    // Only render root entities (those without parent_id)
+    // Only render root entities (those without parent), z_index 순 (같으면 추가 순서)
     // Children will be rendered recursively by their parent groups
-    for entity in entities {
-        if entity.parent_id.is_none() {
-            svg.push_str(&entity_to_svg_hierarchical(
-                entity,
-                &entities_by_name,
-                "    ",
-            ));
-        }
+    let mut roots: Vec<&Entity> = entities.iter().filter(|e| e.parent.is_none()).collect();
+    roots.sort_by_key(|e| (e.metadata.z_index, e.handle.index()));
+    for entity in roots {
+        svg.push_str(&entity_to_svg_hierarchical(
+            entity,
+            &entities_by_handle,
+            "    ",
+            options,
+        ));
     }
 
     svg.push_str("  </g>\n");
@@ -379,14 +972,15 @@ mod tests {
             transform: Transform::default(),
             style: Style::default(),
             metadata: Metadata::default(),
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         }
     }
 
     /// 테스트용 헬퍼: entity_to_svg_element with default indent
     fn entity_to_svg(entity: &Entity) -> String {
-        entity_to_svg_element(entity, "")
+        entity_to_svg_element(entity, "", &SvgOptions::default())
     }
 
     #[test]
@@ -428,6 +1022,47 @@ mod tests {
         assert!(svg.contains(r#"y="0""#));
     }
 
+    #[test]
+    fn test_ellipse_to_svg() {
+        let mut entity = make_entity(Geometry::Ellipse {
+            center: [10.0, 5.0],
+            rx: 30.0,
+            ry: 15.0,
+            rotation: 0.0,
+        });
+        let svg = entity_to_svg(&entity);
+        assert!(svg.contains("<ellipse"));
+        assert!(svg.contains(r#"rx="30""#));
+        assert!(svg.contains(r#"ry="15""#));
+        assert!(!svg.contains("transform="));
+
+        // 타원 회전은 entity transform 뒤에 center 기준으로 붙음
+        entity.geometry = Geometry::Ellipse {
+            center: [10.0, 5.0],
+            rx: 30.0,
+            ry: 15.0,
+            rotation: std::f64::consts::FRAC_PI_2,
+        };
+        entity.transform.translate = [1.0, 2.0];
+        let svg = entity_to_svg(&entity);
+        assert!(svg.contains(r#"transform="translate(1, 2) rotate(90, 10, 5)""#));
+    }
+
+    #[test]
+    fn test_elliptical_arc_to_svg() {
+        let entity = make_entity(Geometry::EllipticalArc {
+            center: [0.0, 0.0],
+            rx: 20.0,
+            ry: 10.0,
+            rotation: 0.0,
+            start_angle: 0.0,
+            end_angle: std::f64::consts::PI,
+        });
+        let svg = entity_to_svg(&entity);
+        assert!(svg.contains("<path"));
+        assert!(svg.contains("M 20,0 A 20,10 0 0 1 -20,"));
+    }
+
     #[test]
     fn test_transform_to_svg() {
         let transform = Transform {
@@ -435,6 +1070,7 @@ mod tests {
             rotate: std::f64::consts::FRAC_PI_2,
             scale: [2.0, 0.5],
             pivot: [0.0, 0.0],
+            skew: 0.0,
         };
         let svg = transform_to_svg(&transform);
         assert!(svg.contains("translate(10, 20)"));
@@ -442,6 +1078,19 @@ mod tests {
         assert!(svg.contains("scale(2, 0.5)"));
     }
 
+    #[test]
+    fn test_transform_to_svg_skew_with_pivot() {
+        let transform = Transform {
+            skew: std::f64::consts::FRAC_PI_4,
+            pivot: [5.0, 2.0],
+            ..Default::default()
+        };
+        assert_eq!(
+            transform_to_svg(&transform),
+            r#"transform="translate(5, 2) skewX(45) translate(-5, -2)""#
+        );
+    }
+
     #[test]
     fn test_empty_transform() {
         let transform = Transform::default();
@@ -474,16 +1123,24 @@ mod tests {
                 locked: false,
                 z_index: 0,
             },
-            parent_id: None,
+            handle: EntityHandle::default(),
+            parent: None,
             children: Vec::new(),
         }
     }
 
+    /// 테스트용 헬퍼: 핸들을 할당하고 parent → child 링크 연결
+    fn link(parent: &mut Entity, child: &mut Entity, parent_index: usize, child_index: usize) {
+        parent.handle = EntityHandle::from_index(parent_index);
+        child.handle = EntityHandle::from_index(child_index);
+        child.parent = Some(parent.handle);
+        parent.children.push(child.handle);
+    }
+
     #[test]
     fn test_hierarchical_svg_group() {
         // Create a group with a circle child
         let mut group = make_named_entity("grp", Geometry::Empty, EntityType::Group);
-        group.children = vec!["circle1".to_string()];
 
         let mut circle = make_named_entity(
             "circle1",
@@ -493,7 +1150,7 @@ mod tests {
             },
             EntityType::Circle,
         );
-        circle.parent_id = Some("grp".to_string());
+        link(&mut group, &mut circle, 0, 1);
 
         let entities = vec![group, circle];
         let svg = serialize_scene_svg(&entities);
@@ -509,12 +1166,12 @@ mod tests {
     fn test_hierarchical_svg_group_with_transform() {
         // Create a group with transform
         let mut group = make_named_entity("grp", Geometry::Empty, EntityType::Group);
-        group.children = vec!["rect1".to_string()];
         group.transform = Transform {
             translate: [50.0, 50.0],
             rotate: 0.0,
             scale: [1.0, 1.0],
             pivot: [0.0, 0.0],
+            skew: 0.0,
         };
 
         let mut rect = make_named_entity(
@@ -526,7 +1183,7 @@ mod tests {
             },
             EntityType::Rect,
         );
-        rect.parent_id = Some("grp".to_string());
+        link(&mut group, &mut rect, 0, 1);
 
         let entities = vec![group, rect];
         let svg = serialize_scene_svg(&entities);
@@ -542,7 +1199,6 @@ mod tests {
     fn test_hierarchical_svg_children_not_rendered_at_root() {
         // Create a group with children - children should not appear twice
         let mut group = make_named_entity("grp", Geometry::Empty, EntityType::Group);
-        group.children = vec!["c1".to_string()];
 
         let mut circle = make_named_entity(
             "c1",
@@ -552,7 +1208,7 @@ mod tests {
             },
             EntityType::Circle,
         );
-        circle.parent_id = Some("grp".to_string());
+        link(&mut group, &mut circle, 0, 1);
 
         let entities = vec![group, circle];
         let svg = serialize_scene_svg(&entities);
@@ -569,11 +1225,8 @@ mod tests {
     fn test_hierarchical_svg_nested_groups() {
         // Create nested groups: outer_grp -> inner_grp -> circle
         let mut outer = make_named_entity("outer_grp", Geometry::Empty, EntityType::Group);
-        outer.children = vec!["inner_grp".to_string()];
-
         let mut inner = make_named_entity("inner_grp", Geometry::Empty, EntityType::Group);
-        inner.parent_id = Some("outer_grp".to_string());
-        inner.children = vec!["c".to_string()];
+        link(&mut outer, &mut inner, 0, 1);
 
         let mut circle = make_named_entity(
             "c",
@@ -583,7 +1236,7 @@ mod tests {
             },
             EntityType::Circle,
         );
-        circle.parent_id = Some("inner_grp".to_string());
+        link(&mut inner, &mut circle, 1, 2);
 
         let entities = vec![outer, inner, circle];
         let svg = serialize_scene_svg(&entities);
@@ -610,6 +1263,7 @@ mod tests {
             rotate: std::f64::consts::FRAC_PI_2, // 90 degrees
             scale: [1.0, 1.0],
             pivot: [50.0, 50.0],
+            skew: 0.0,
         };
 
         let svg = entity_to_svg(&entity);
@@ -619,4 +1273,106 @@ mod tests {
         assert!(svg.contains("rotate(90)"));
         assert!(svg.contains("translate(-50, -50)"));
     }
+
+    #[test]
+    fn test_hatch_fill_to_svg() {
+        use crate::scene::entity::{FillStyle, HatchStyle};
+
+        let mut entity = make_entity(Geometry::Rect {
+            center: [0.0, 0.0],
+            width: 20.0,
+            height: 20.0,
+        });
+        entity.style.fill = Some(FillStyle {
+            color: [1.0, 0.0, 0.0, 1.0],
+            hatch: Some(HatchStyle::default()),
+            ..Default::default()
+        });
+        let svg = entity_to_svg(&entity);
+
+        // 해치 path가 외곽선보다 먼저, 외곽선은 fill 없음
+        let hatch_pos = svg.find("<path").expect("hatch path");
+        let rect_pos = svg.find("<rect").expect("outline rect");
+        assert!(hatch_pos < rect_pos);
+        assert!(svg.contains(r#"stroke="rgba(255,0,0,1)" stroke-width="0.25""#));
+        assert!(!svg.contains(r#"fill="rgba(255,0,0,1)""#));
+    }
+
+    #[test]
+    fn test_gradient_and_hatch_fill_order() {
+        use crate::scene::entity::{FillStyle, GradientStop, HatchStyle};
+
+        let mut entity = make_entity(Geometry::Rect {
+            center: [0.0, 0.0],
+            width: 20.0,
+            height: 20.0,
+        });
+        entity.style.fill = Some(FillStyle {
+            color: [0.0, 0.0, 0.0, 1.0],
+            hatch: Some(HatchStyle::default()),
+            gradient: Some(Gradient::Linear {
+                start: [-10.0, 0.0],
+                end: [10.0, 0.0],
+                stops: vec![
+                    GradientStop {
+                        offset: 0.0,
+                        color: [1.0, 1.0, 1.0, 1.0],
+                    },
+                    GradientStop {
+                        offset: 1.0,
+                        color: [0.0, 0.0, 1.0, 1.0],
+                    },
+                ],
+            }),
+        });
+        let svg = entity_to_svg(&entity);
+
+        // 그라디언트로 채운 도형이 먼저, 해치 선은 그 위에
+        let rect_pos = svg.find("<rect").expect("gradient rect");
+        let hatch_pos = svg.find("<path").expect("hatch path");
+        assert!(rect_pos < hatch_pos);
+        assert!(svg[rect_pos..hatch_pos].contains(r#"fill="url(#"#));
+    }
+
+    #[test]
+    fn test_gradient_defs_deduplicated() {
+        use crate::scene::entity::{FillStyle, GradientStop};
+
+        let gradient = Gradient::Linear {
+            start: [0.0, 0.0],
+            end: [10.0, 0.0],
+            stops: vec![
+                GradientStop {
+                    offset: 0.0,
+                    color: [1.0, 0.0, 0.0, 1.0],
+                },
+                GradientStop {
+                    offset: 1.0,
+                    color: [0.0, 0.0, 1.0, 1.0],
+                },
+            ],
+        };
+        let mut a = make_entity(Geometry::Circle {
+            center: [0.0, 0.0],
+            radius: 5.0,
+        });
+        a.metadata.name = "a".to_string();
+        a.style.fill = Some(FillStyle {
+            gradient: Some(gradient.clone()),
+            ..Default::default()
+        });
+        let mut b = a.clone();
+        b.metadata.name = "b".to_string();
+        b.transform.translate = [20.0, 0.0];
+
+        let svg = serialize_scene_svg(&[a, b]);
+        assert_eq!(svg.matches("<linearGradient").count(), 1);
+        assert!(svg.contains(r#"gradientUnits="userSpaceOnUse""#));
+        assert!(svg.contains(r#"<stop offset="1" stop-color="rgba(0,0,255,1)"/>"#));
+
+        let (id, _) = gradient_def(&gradient, None);
+        assert_eq!(svg.matches(&format!(r#"fill="url(#{})""#, id)).count(), 2);
+        // defs는 y 반전 그룹 앞에 위치
+        assert!(svg.find("<defs>").unwrap() < svg.find("scale(1, -1)").unwrap());
+    }
 }
@@ -11,8 +11,12 @@ export {
   // Internal schemas (exported for reusability)
   TransformSchema,
   StyleSchema,
+  GradientSchema,
+  HatchStyleSchema,
   MetadataSchema,
   BoundsSchema,
+  DimensionLayoutSchema,
+  HatchLinesSchema,
   ComputedSchema,
   EntitySchema,
   SceneTreeNodeSchema,
@@ -31,6 +35,10 @@ export type {
   Style,
   Metadata,
   Bounds,
+  DimensionLayout,
+  Gradient,
+  HatchStyle,
+  HatchLines,
   Computed,
   Entity,
   SceneTreeNode,
@@ -7,10 +7,45 @@ export const TransformSchema = z.object({
   rotate: z.number(),
   scale: z.tuple([z.number(), z.number()]),
   pivot: z.tuple([z.number(), z.number()]).optional(),
+  skew: z.number().optional(),
+});
+
+const GradientStopSchema = z.object({
+  offset: z.number(),
+  color: z.tuple([z.number(), z.number(), z.number(), z.number()]),
+});
+
+// Gradient fill in entity local coordinates
+export const GradientSchema = z.discriminatedUnion('type', [
+  z.object({
+    type: z.literal('Linear'),
+    start: z.tuple([z.number(), z.number()]),
+    end: z.tuple([z.number(), z.number()]),
+    stops: z.array(GradientStopSchema),
+  }),
+  z.object({
+    type: z.literal('Radial'),
+    center: z.tuple([z.number(), z.number()]),
+    radius: z.number(),
+    focal: z.tuple([z.number(), z.number()]).optional(),
+    stops: z.array(GradientStopSchema),
+  }),
+]);
+
+export const HatchStyleSchema = z.object({
+  pattern: z.enum(['Ansi31', 'Crosshatch', 'Brick', 'Dots']),
+  angle: z.number(),
+  spacing: z.number(),
+  scale: z.number(),
+  line_width: z.number(),
 });
 
 export const StyleSchema = z.object({
-  fill: z.object({ color: z.tuple([z.number(), z.number(), z.number(), z.number()]) }).nullable().optional(),
+  fill: z.object({
+    color: z.tuple([z.number(), z.number(), z.number(), z.number()]),
+    hatch: HatchStyleSchema.optional(),
+    gradient: GradientSchema.optional(),
+  }).nullable().optional(),
   stroke: z.object({
     width: z.number(),
     color: z.tuple([z.number(), z.number(), z.number(), z.number()]),
@@ -32,16 +67,51 @@ export const BoundsSchema = z.object({
   max: z.tuple([z.number(), z.number()]),
 });
 
+const Vec2Schema = z.tuple([z.number(), z.number()]);
+
+// Dimension layout in world coordinates (Dimension entities only)
+export const DimensionLayoutSchema = z.object({
+  value: z.number(),
+  text: z.string(),
+  text_position: Vec2Schema,
+  text_angle: z.number(),
+  text_size: z.number(),
+  lines: z.array(z.tuple([Vec2Schema, Vec2Schema])),
+  arcs: z.array(z.array(Vec2Schema)).optional(),
+  arrows: z.array(z.tuple([Vec2Schema, Vec2Schema, Vec2Schema])),
+});
+
+// Clipped hatch lines/dots in entity local coordinates (hatch fills only)
+export const HatchLinesSchema = z.object({
+  lines: z.array(z.tuple([Vec2Schema, Vec2Schema])),
+  dots: z.array(Vec2Schema).optional(),
+});
+
 export const ComputedSchema = z.object({
   world_bounds: BoundsSchema.optional(),
   local_bounds: BoundsSchema.optional(),
   center: z.tuple([z.number(), z.number()]).optional(),
   size: z.tuple([z.number(), z.number()]).optional(),
+  dimension: DimensionLayoutSchema.optional(),
+  hatch: HatchLinesSchema.optional(),
 });
 
 export const EntitySchema = z.object({
   id: z.string(),
-  entity_type: z.enum(['Circle', 'Rect', 'Line', 'Polygon', 'Arc', 'Bezier', 'Text', 'Group']),
+  entity_type: z.enum([
+    'Circle',
+    'Rect',
+    'Line',
+    'Polygon',
+    'Arc',
+    'Bezier',
+    'Ellipse',
+    'EllipticalArc',
+    'Path',
+    'Text',
+    'Dimension',
+    'Group',
+  ]),
   geometry: z.unknown(), // Complex union, validated at runtime
   transform: TransformSchema,
   style: StyleSchema,
@@ -188,6 +258,10 @@ export type Transform = z.infer<typeof TransformSchema>;
 export type Style = z.infer<typeof StyleSchema>;
 export type Metadata = z.infer<typeof MetadataSchema>;
 export type Bounds = z.infer<typeof BoundsSchema>;
+export type DimensionLayout = z.infer<typeof DimensionLayoutSchema>;
+export type Gradient = z.infer<typeof GradientSchema>;
+export type HatchStyle = z.infer<typeof HatchStyleSchema>;
+export type HatchLines = z.infer<typeof HatchLinesSchema>;
 export type Computed = z.infer<typeof ComputedSchema>;
 export type Entity = z.infer<typeof EntitySchema>;
 export type SceneTreeNode = z.infer<typeof SceneTreeNodeSchema>;
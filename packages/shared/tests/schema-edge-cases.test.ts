@@ -113,6 +113,25 @@ describe('StyleSchema – edge cases', () => {
     });
     expect(result.success).toBe(false);
   });
+
+  it('should keep gradient and hatch on fill', () => {
+    const fill = {
+      color: [0, 0, 0, 1],
+      gradient: {
+        type: 'Linear',
+        start: [-5, 0],
+        end: [5, 0],
+        stops: [
+          { offset: 0, color: [1, 0, 0, 1] },
+          { offset: 1, color: [0, 0, 1, 1] },
+        ],
+      },
+      hatch: { pattern: 'Ansi31', angle: 0, spacing: 4, scale: 1, line_width: 0.25 },
+    };
+    const result = StyleSchema.safeParse({ fill });
+    expect(result.success).toBe(true);
+    expect(result.success && result.data.fill).toEqual(fill);
+  });
 });
 
 describe('BoundsSchema', () => {
@@ -173,6 +192,28 @@ describe('ComputedSchema', () => {
     });
     expect(result.success).toBe(true);
   });
+
+  it('should accept dimension layout', () => {
+    const result = ComputedSchema.safeParse({
+      dimension: {
+        value: 10,
+        text: 'R10.00',
+        text_position: [5, 2],
+        text_angle: 0,
+        text_size: 8,
+        lines: [[[0, 0], [10, 0]]],
+        arrows: [[[10, 0], [8, 1], [8, -1]]],
+      },
+    });
+    expect(result.success).toBe(true);
+  });
+
+  it('should accept hatch lines', () => {
+    const result = ComputedSchema.safeParse({
+      hatch: { lines: [[[-5, -5], [5, 5]]], dots: [[0, 0]] },
+    });
+    expect(result.success).toBe(true);
+  });
 });
 
 describe('EntitySchema – edge cases', () => {
@@ -213,7 +254,20 @@ describe('EntitySchema – edge cases', () => {
     expect(result.success).toBe(false);
   });
 
-  it.each(['Circle', 'Rect', 'Line', 'Polygon', 'Arc', 'Bezier', 'Text', 'Group'] as const)(
+  it.each([
+    'Circle',
+    'Rect',
+    'Line',
+    'Polygon',
+    'Arc',
+    'Bezier',
+    'Ellipse',
+    'EllipticalArc',
+    'Path',
+    'Text',
+    'Dimension',
+    'Group',
+  ] as const)(
     'should accept entity_type "%s"',
     (entityType) => {
       const result = EntitySchema.safeParse({